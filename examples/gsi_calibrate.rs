@@ -0,0 +1,161 @@
+//! Danger-detection threshold calibration.
+//!
+//! Reads a GSI session recorded with `[gsi_logging]`, reports the per-frame
+//! HP loss the hero actually took, and suggests `[danger_detection]` values
+//! that would have flagged the session's deaths. The analysis lives in
+//! `dota2_scripts::gsi::calibrate`.
+//!
+//! Usage:
+//!   cargo run --example gsi_calibrate -- --input logs/gsi_events/gsi_events_<session>.jsonl
+//!   cargo run --example gsi_calibrate -- --input <file> --lead 5 --max-false-alarms 0.5
+
+use dota2_scripts::gsi::calibrate::{calibrate, CalibrateOptions};
+use dota2_scripts::gsi::fixture_extract::read_recording;
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let args = Args::parse();
+
+    let (events, skipped) = read_recording(Path::new(&args.input)).unwrap_or_else(|e| {
+        eprintln!("Error: failed to read {}: {}", args.input, e);
+        std::process::exit(1);
+    });
+    println!("Loaded {} events from {}", events.len(), args.input);
+    if skipped > 0 {
+        println!("  Skipped {} malformed lines", skipped);
+    }
+
+    let report = calibrate(&events, &args.options);
+    if report.rapid_loss_hp.is_none() {
+        println!("No alive frames to analyse.");
+        return;
+    }
+
+    println!();
+    println!(
+        "Alive time: {:.1} min over {} frame pairs",
+        report.alive_minutes, report.frame_pairs
+    );
+    if let Some(interval) = report.frame_interval_ms {
+        println!("Average frame interval: {} ms", interval);
+    }
+    println!("HP lost per frame:");
+    for (pct, hp) in &report.loss_percentiles {
+        let label = if *pct == 100 {
+            "max".to_string()
+        } else {
+            format!("p{}", pct)
+        };
+        println!("  {:<4} {} HP", label, hp);
+    }
+
+    println!();
+    println!("Deaths: {}", report.deaths);
+    for (i, (peak, hp_percent)) in report
+        .death_peak_hits
+        .iter()
+        .zip(&report.death_lead_hp_percents)
+        .enumerate()
+    {
+        println!(
+            "  #{:<3} biggest hit {} HP, {}% HP {}s before",
+            i + 1,
+            peak,
+            hp_percent,
+            args.options.lead_secs
+        );
+    }
+    if let Some(threshold) = report.rapid_loss_hp {
+        println!(
+            "At rapid_loss_hp = {}: {}/{} deaths flagged, {:.2} false alarms/min",
+            threshold,
+            report.deaths_flagged,
+            report.death_peak_hits.len(),
+            report.false_alarms_per_min
+        );
+    }
+    if report.deaths == 0 {
+        println!("  No deaths recorded; rapid_loss_hp only reflects the false-alarm budget.");
+    }
+
+    println!();
+    println!("Suggested config (review before applying):");
+    println!();
+    print!("{}", report.suggested_toml());
+}
+
+struct Args {
+    input: String,
+    options: CalibrateOptions,
+}
+
+impl Args {
+    fn parse() -> Self {
+        let mut args = Self {
+            input: String::new(),
+            options: CalibrateOptions::default(),
+        };
+        let raw: Vec<String> = env::args().collect();
+        let mut i = 1;
+        while i < raw.len() {
+            match raw[i].as_str() {
+                "--input" => {
+                    i += 1;
+                    args.input = parse_string(&raw, i, "--input");
+                }
+                "--lead" => {
+                    i += 1;
+                    args.options.lead_secs = parse_number(&raw, i, "--lead");
+                }
+                "--max-false-alarms" => {
+                    i += 1;
+                    args.options.max_false_alarms_per_min =
+                        parse_number(&raw, i, "--max-false-alarms");
+                }
+                "--help" | "-h" => {
+                    print_usage();
+                    std::process::exit(0);
+                }
+                other => {
+                    eprintln!("Unknown argument: {}", other);
+                    print_usage();
+                    std::process::exit(1);
+                }
+            }
+            i += 1;
+        }
+        if args.input.is_empty() {
+            eprintln!("Error: --input is required");
+            print_usage();
+            std::process::exit(1);
+        }
+        args
+    }
+}
+
+fn parse_string(raw: &[String], i: usize, flag: &str) -> String {
+    if i >= raw.len() {
+        eprintln!("Error: {} requires a value", flag);
+        std::process::exit(1);
+    }
+    raw[i].clone()
+}
+
+fn parse_number<T: std::str::FromStr>(raw: &[String], i: usize, flag: &str) -> T {
+    let value = parse_string(raw, i, flag);
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("Error: {} value '{}' is not a valid number", flag, value);
+        std::process::exit(1);
+    })
+}
+
+fn print_usage() {
+    println!("Usage: cargo run --example gsi_calibrate -- --input <JSONL> [OPTIONS]");
+    println!();
+    println!("Options:");
+    println!("  --input <PATH>            Recorded GSI session (JSONL from [gsi_logging])");
+    println!("  --lead <SECS>             Seconds before a death that count as its lead-up (default: 3)");
+    println!("  --max-false-alarms <N>    Tolerated triggers per alive minute outside deaths (default: 1.0)");
+    println!("  --help, -h                Show this help");
+}
@@ -0,0 +1,152 @@
+//! Replay fixture generator.
+//!
+//! Reads a GSI session recorded with `[gsi_logging]` and writes one small
+//! JSONL fixture per interesting moment (death, rapid HP loss, item cast).
+//! The detection heuristics live in `dota2_scripts::gsi::fixture_extract`.
+//!
+//! Usage:
+//!   cargo run --example gsi_fixturegen -- --input logs/gsi_events/gsi_events_<session>.jsonl
+//!   cargo run --example gsi_fixturegen -- --input <file> --out tests/fixtures/replays --prefix huskar
+//!   cargo run --example gsi_fixturegen -- --input <file> --context 20 --hp-loss 25 --hp-window 4 --dry-run
+
+use dota2_scripts::gsi::fixture_extract::{
+    extract_moments, read_recording, write_fixtures, ExtractOptions,
+};
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let args = Args::parse();
+
+    let (events, skipped) = read_recording(Path::new(&args.input)).unwrap_or_else(|e| {
+        eprintln!("Error: failed to read {}: {}", args.input, e);
+        std::process::exit(1);
+    });
+    println!("Loaded {} events from {}", events.len(), args.input);
+    if skipped > 0 {
+        println!("  Skipped {} malformed lines", skipped);
+    }
+
+    let moments = extract_moments(&events, &args.options);
+    if moments.is_empty() {
+        println!("No moments found.");
+        return;
+    }
+
+    for moment in &moments {
+        println!(
+            "  {:<32} frames {}..{} ({:?})",
+            moment.name, moment.start, moment.end, moment.kind
+        );
+    }
+
+    if args.dry_run {
+        println!("Dry run: {} fixtures not written", moments.len());
+        return;
+    }
+
+    match write_fixtures(&events, &moments, Path::new(&args.out), &args.prefix) {
+        Ok(paths) => println!("Wrote {} fixtures to {}", paths.len(), args.out),
+        Err(e) => {
+            eprintln!("Error: failed to write fixtures: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+struct Args {
+    input: String,
+    out: String,
+    prefix: String,
+    dry_run: bool,
+    options: ExtractOptions,
+}
+
+impl Args {
+    fn parse() -> Self {
+        let mut args = Self {
+            input: String::new(),
+            out: "tests/fixtures/replays".to_string(),
+            prefix: "replay".to_string(),
+            dry_run: false,
+            options: ExtractOptions::default(),
+        };
+        let raw: Vec<String> = env::args().collect();
+        let mut i = 1;
+        while i < raw.len() {
+            match raw[i].as_str() {
+                "--input" => {
+                    i += 1;
+                    args.input = parse_string(&raw, i, "--input");
+                }
+                "--out" => {
+                    i += 1;
+                    args.out = parse_string(&raw, i, "--out");
+                }
+                "--prefix" => {
+                    i += 1;
+                    args.prefix = parse_string(&raw, i, "--prefix");
+                }
+                "--context" => {
+                    i += 1;
+                    args.options.context_frames = parse_number(&raw, i, "--context");
+                }
+                "--hp-loss" => {
+                    i += 1;
+                    args.options.hp_loss_percent = parse_number(&raw, i, "--hp-loss");
+                }
+                "--hp-window" => {
+                    i += 1;
+                    args.options.hp_loss_window = parse_number(&raw, i, "--hp-window");
+                }
+                "--dry-run" => args.dry_run = true,
+                "--help" | "-h" => {
+                    print_usage();
+                    std::process::exit(0);
+                }
+                other => {
+                    eprintln!("Unknown argument: {}", other);
+                    print_usage();
+                    std::process::exit(1);
+                }
+            }
+            i += 1;
+        }
+        if args.input.is_empty() {
+            eprintln!("Error: --input is required");
+            print_usage();
+            std::process::exit(1);
+        }
+        args
+    }
+}
+
+fn parse_string(raw: &[String], i: usize, flag: &str) -> String {
+    if i >= raw.len() {
+        eprintln!("Error: {} requires a value", flag);
+        std::process::exit(1);
+    }
+    raw[i].clone()
+}
+
+fn parse_number<T: std::str::FromStr>(raw: &[String], i: usize, flag: &str) -> T {
+    let value = parse_string(raw, i, flag);
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("Error: {} value '{}' is not a valid number", flag, value);
+        std::process::exit(1);
+    })
+}
+
+fn print_usage() {
+    println!("Usage: cargo run --example gsi_fixturegen -- --input <JSONL> [OPTIONS]");
+    println!();
+    println!("Options:");
+    println!("  --input <PATH>     Recorded GSI session (JSONL from [gsi_logging])");
+    println!("  --out <DIR>        Output directory (default: tests/fixtures/replays)");
+    println!("  --prefix <NAME>    Fixture file prefix (default: replay)");
+    println!("  --context <N>      Frames kept before/after each moment (default: 10)");
+    println!("  --hp-loss <PCT>    HP percent drop counted as rapid loss (default: 30)");
+    println!("  --hp-window <N>    Frames the HP drop must happen within (default: 5)");
+    println!("  --dry-run          List moments without writing files");
+    println!("  --help, -h         Show this help");
+}
@@ -1,12 +1,57 @@
+use crate::commands::state::refresh_keyboard_snapshot;
+use crate::ipc_types::ConfigErrorDto;
 use crate::TauriAppState;
+use dota2_scripts::config::settings::{
+    TinyComboTiming, MAX_MIN_ACTION_INTERVAL_MS, TINY_TIMING_PRESETS,
+};
 use dota2_scripts::config::Settings;
-use dota2_scripts::input::keyboard::KeyboardSnapshot;
+use dota2_scripts::gsi::server::rebind_gsi_server;
+use dota2_scripts::input::simulation::apply_input_settings;
+use dota2_scripts::state::poison::lock_or_recover;
+use std::collections::BTreeMap;
 use tracing::info;
 
+/// Applies the parts of a newly saved, switched or imported config that live
+/// outside `Settings` (input switches, overlay window), so it takes effect
+/// without a restart.
+fn apply_runtime_settings(app_handle: &tauri::AppHandle, settings: &Settings) {
+    apply_input_settings(settings);
+    crate::overlay::sync_overlay_window(app_handle, &settings.overlay);
+}
+
+/// Stores `new_settings` as the live config, applies it and rebuilds the
+/// keyboard snapshot from it.
+fn install_settings(
+    app_handle: &tauri::AppHandle,
+    state: &tauri::State<'_, TauriAppState>,
+    new_settings: Settings,
+) -> Result<(), ConfigErrorDto> {
+    apply_runtime_settings(app_handle, &new_settings);
+    *lock_or_recover(&state.settings) = new_settings;
+    let app = lock_or_recover(&state.app_state);
+    refresh_keyboard_snapshot(state, &app);
+    Ok(())
+}
+
 fn validate_settings(settings: &Settings) -> Result<(), String> {
     if settings.server.port == 0 {
         return Err("Server port must be greater than 0".to_string());
     }
+    if settings
+        .server
+        .bind_address
+        .trim()
+        .parse::<std::net::IpAddr>()
+        .is_err()
+    {
+        return Err("Server bind address must be an IP address".to_string());
+    }
+    if settings.input.min_action_interval_ms > MAX_MIN_ACTION_INTERVAL_MS {
+        return Err(format!(
+            "Minimum action interval must be 0-{} ms",
+            MAX_MIN_ACTION_INTERVAL_MS
+        ));
+    }
 
     let dd = &settings.danger_detection;
     if dd.hp_threshold_percent > 100 {
@@ -19,6 +64,24 @@ fn validate_settings(settings: &Settings) -> Result<(), String> {
     if settings.common.survivability_hp_threshold > 100 {
         return Err("Survivability HP threshold must be 0-100".to_string());
     }
+    let heroes = &settings.heroes;
+    let hp_overrides = [
+        heroes.huskar.survivability_hp_threshold_override,
+        heroes.legion_commander.survivability_hp_threshold_override,
+        heroes.shadow_fiend.survivability_hp_threshold_override,
+        heroes.tiny.survivability_hp_threshold_override,
+        heroes
+            .outworld_destroyer
+            .survivability_hp_threshold_override,
+        heroes.largo.survivability_hp_threshold_override,
+        heroes.broodmother.survivability_hp_threshold_override,
+        heroes.meepo.survivability_hp_threshold_override,
+        heroes.zeus.survivability_hp_threshold_override,
+        heroes.bristleback.survivability_hp_threshold_override,
+    ];
+    if hp_overrides.into_iter().flatten().any(|pct| pct > 100) {
+        return Err("Hero survivability HP threshold overrides must be 0-100".to_string());
+    }
 
     let sr = &settings.soul_ring;
     if sr.min_mana_percent > 100 {
@@ -28,6 +91,14 @@ fn validate_settings(settings: &Settings) -> Result<(), String> {
         return Err("Soul Ring min health must be 0-100".to_string());
     }
 
+    if let Some(conflict) = settings
+        .keybinding_conflicts()
+        .into_iter()
+        .find(|conflict| conflict.contains("_raze_key"))
+    {
+        return Err(format!("Shadow Fiend raze keys: {}", conflict));
+    }
+
     let meepo = &settings.heroes.meepo;
     if meepo.dig_hp_threshold_percent > 100 {
         return Err("Meepo dig HP threshold must be 0-100".to_string());
@@ -36,16 +107,17 @@ fn validate_settings(settings: &Settings) -> Result<(), String> {
         return Err("Meepo MegaMeepo HP threshold must be 0-100".to_string());
     }
 
+    if settings.heroes.bristleback.quill_min_mana_percent > 100 {
+        return Err("Bristleback Quill Spray mana floor must be 0-100".to_string());
+    }
+
     Ok(())
 }
 
 /// Returns the full config as JSON (snake_case keys matching config.toml)
 #[tauri::command]
-pub fn get_config(state: tauri::State<'_, TauriAppState>) -> Result<Settings, String> {
-    let settings = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?;
+pub fn get_config(state: tauri::State<'_, TauriAppState>) -> Result<Settings, ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
     Ok(settings.clone())
 }
 
@@ -54,15 +126,13 @@ pub fn get_config(state: tauri::State<'_, TauriAppState>) -> Result<Settings, St
 pub fn update_config(
     section: String,
     updates: serde_json::Value,
+    app_handle: tauri::AppHandle,
     state: tauri::State<'_, TauriAppState>,
-) -> Result<(), String> {
-    let mut settings = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?;
+) -> Result<(), ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
 
-    let mut config_value =
-        serde_json::to_value(&*settings).map_err(|e| format!("Serialize error: {}", e))?;
+    let mut config_value = serde_json::to_value(&*settings)
+        .map_err(|e| ConfigErrorDto::internal(format!("Serialize error: {}", e)))?;
 
     if let Some(section_value) = config_value.get_mut(&section) {
         if let (Some(existing_obj), Some(update_obj)) =
@@ -73,53 +143,82 @@ pub fn update_config(
             }
         }
     } else {
-        return Err(format!("Unknown config section: {}", section));
-    }
-
-    let new_settings: Settings =
-        serde_json::from_value(config_value).map_err(|e| format!("Deserialize error: {}", e))?;
-
-    validate_settings(&new_settings)?;
-    new_settings
-        .save()
-        .map_err(|e| format!("Failed to write config: {}", e))?;
-
-    *settings = new_settings;
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
-    let snapshot = KeyboardSnapshot::from_runtime(&settings, &app);
-    drop(app);
-    let mut keyboard_snapshot = state
-        .keyboard_snapshot
-        .write()
-        .map_err(|e| format!("Failed to lock keyboard snapshot: {}", e))?;
-    *keyboard_snapshot = snapshot;
+        return Err(ConfigErrorDto::invalid(format!(
+            "Unknown config section: {}",
+            section
+        )));
+    }
+
+    let new_settings: Settings = serde_json::from_value(config_value)
+        .map_err(|e| ConfigErrorDto::invalid(format!("Deserialize error: {}", e)))?;
+
+    validate_settings(&new_settings).map_err(ConfigErrorDto::invalid)?;
+    new_settings.save()?;
+
+    drop(settings);
+    install_settings(&app_handle, &state, new_settings)?;
     info!("Config section '{}' updated and persisted", section);
 
     Ok(())
 }
 
+/// Moves the GSI server to `port` on the saved bind address without
+/// restarting the app, then saves `port` as `[server] port`. Fails, leaving
+/// the server where it was, when the new address cannot be bound.
+#[tauri::command]
+pub async fn restart_gsi_server(
+    port: u16,
+    state: tauri::State<'_, TauriAppState>,
+) -> Result<String, ConfigErrorDto> {
+    if port == 0 {
+        return Err(ConfigErrorDto::invalid(
+            "Server port must be greater than 0",
+        ));
+    }
+    let bind_ip = lock_or_recover(&state.settings).server.bind_ip();
+    let addr = std::net::SocketAddr::new(bind_ip, port);
+    rebind_gsi_server(addr).await?;
+
+    let mut settings = lock_or_recover(&state.settings);
+    if settings.server.port != port {
+        settings.server.port = port;
+        settings.save().map_err(|e| ConfigErrorDto {
+            message: format!("Listening on {}, but saving the port failed: {}", addr, e),
+            ..ConfigErrorDto::from(e)
+        })?;
+    }
+    Ok(addr.to_string())
+}
+
+/// Lists overlapping key bindings in the given (possibly unsaved) config
+#[tauri::command]
+pub fn get_keybinding_conflicts(config: Settings) -> Vec<String> {
+    config.keybinding_conflicts()
+}
+
+/// Tiny's named timing presets, keyed by `timing_preset` value
+#[tauri::command]
+pub fn get_tiny_timing_presets() -> BTreeMap<&'static str, TinyComboTiming> {
+    TINY_TIMING_PRESETS.into_iter().collect()
+}
+
 /// Updates a hero-specific config section
 #[tauri::command]
 pub fn update_hero_config(
     hero: String,
     updates: serde_json::Value,
+    app_handle: tauri::AppHandle,
     state: tauri::State<'_, TauriAppState>,
-) -> Result<(), String> {
-    let mut settings = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?;
+) -> Result<(), ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
 
-    let mut config_value =
-        serde_json::to_value(&*settings).map_err(|e| format!("Serialize error: {}", e))?;
+    let mut config_value = serde_json::to_value(&*settings)
+        .map_err(|e| ConfigErrorDto::internal(format!("Serialize error: {}", e)))?;
 
     let heroes_section = config_value
         .get_mut("heroes")
         .and_then(|h| h.as_object_mut())
-        .ok_or("Missing heroes section")?;
+        .ok_or_else(|| ConfigErrorDto::internal("Missing heroes section"))?;
 
     if let Some(hero_section) = heroes_section.get_mut(&hero) {
         if let (Some(existing_obj), Some(update_obj)) =
@@ -130,30 +229,89 @@ pub fn update_hero_config(
             }
         }
     } else {
-        return Err(format!("Unknown hero: {}", hero));
-    }
-
-    let new_settings: Settings =
-        serde_json::from_value(config_value).map_err(|e| format!("Deserialize error: {}", e))?;
-
-    validate_settings(&new_settings)?;
-    new_settings
-        .save()
-        .map_err(|e| format!("Failed to write config: {}", e))?;
-
-    *settings = new_settings;
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
-    let snapshot = KeyboardSnapshot::from_runtime(&settings, &app);
-    drop(app);
-    let mut keyboard_snapshot = state
-        .keyboard_snapshot
-        .write()
-        .map_err(|e| format!("Failed to lock keyboard snapshot: {}", e))?;
-    *keyboard_snapshot = snapshot;
+        return Err(ConfigErrorDto::invalid(format!("Unknown hero: {}", hero)));
+    }
+
+    let new_settings: Settings = serde_json::from_value(config_value)
+        .map_err(|e| ConfigErrorDto::invalid(format!("Deserialize error: {}", e)))?;
+
+    validate_settings(&new_settings).map_err(ConfigErrorDto::invalid)?;
+    new_settings.save()?;
+
+    drop(settings);
+    install_settings(&app_handle, &state, new_settings)?;
     info!("Hero config '{}' updated and persisted", hero);
 
     Ok(())
 }
+
+/// Lists saved config profiles
+#[tauri::command]
+pub fn list_profiles() -> Result<Vec<String>, ConfigErrorDto> {
+    Ok(Settings::list_profiles())
+}
+
+/// Saves the current hero/threshold sections as a named profile
+#[tauri::command]
+pub fn save_profile(
+    name: String,
+    state: tauri::State<'_, TauriAppState>,
+) -> Result<(), ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
+    settings
+        .save_as_profile(&name)
+        .map_err(ConfigErrorDto::from)
+}
+
+/// Activates a profile (empty name for none) and returns the reloaded config
+#[tauri::command]
+pub fn switch_profile(
+    name: String,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, TauriAppState>,
+) -> Result<Settings, ConfigErrorDto> {
+    let new_settings = Settings::switch_profile(&name)?;
+
+    install_settings(&app_handle, &state, new_settings.clone())?;
+    info!("Switched config profile to '{}'", name);
+
+    Ok(new_settings)
+}
+
+/// Serializes the current config as TOML for sharing
+#[tauri::command]
+pub fn export_config(state: tauri::State<'_, TauriAppState>) -> Result<String, ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
+    settings.to_shared_toml().map_err(ConfigErrorDto::from)
+}
+
+/// Parses and validates a shared config without applying it
+#[tauri::command]
+pub fn preview_config_import(
+    contents: String,
+    state: tauri::State<'_, TauriAppState>,
+) -> Result<Settings, ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
+    let imported = Settings::from_shared_toml(&contents, &settings)?;
+    validate_settings(&imported).map_err(ConfigErrorDto::invalid)?;
+    Ok(imported)
+}
+
+/// Applies a shared config, persisting it and refreshing runtime state
+#[tauri::command]
+pub fn import_config(
+    contents: String,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, TauriAppState>,
+) -> Result<Settings, ConfigErrorDto> {
+    let settings = lock_or_recover(&state.settings);
+    let imported = Settings::from_shared_toml(&contents, &settings)?;
+    drop(settings);
+    validate_settings(&imported).map_err(ConfigErrorDto::invalid)?;
+    imported.save()?;
+
+    install_settings(&app_handle, &state, imported.clone())?;
+    info!("Imported shared config");
+
+    Ok(imported)
+}
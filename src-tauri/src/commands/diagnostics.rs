@@ -1,14 +1,18 @@
-use crate::ipc_types::{DiagnosticsDto, QueueMetricsDto, SyntheticInputDto};
+use crate::ipc_types::{
+    AutomationMetricsDto, DiagnosticsDto, ElevationStatusDto, QueueMetricsDto, SyntheticInputDto,
+};
 use crate::TauriAppState;
 use dota2_scripts::actions::SOUL_RING_STATE;
+use dota2_scripts::diagnostics::elevation;
+use dota2_scripts::input::simulation::synthetic_input_metrics;
+use dota2_scripts::state::poison::{lock_or_recover, lock_or_reset};
+use dota2_scripts::state::HeroType;
+use tracing::info;
 
 /// Returns diagnostics: GSI metrics, synthetic input, keyboard state
 #[tauri::command]
 pub fn get_diagnostics(state: tauri::State<'_, TauriAppState>) -> Result<DiagnosticsDto, String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let app = lock_or_recover(&state.app_state);
 
     Ok(DiagnosticsDto {
         gsi_connected: app.has_recent_gsi_activity(),
@@ -16,49 +20,92 @@ pub fn get_diagnostics(state: tauri::State<'_, TauriAppState>) -> Result<Diagnos
         queue_metrics: QueueMetricsDto {
             events_processed: app.metrics.events_processed,
             events_dropped: app.metrics.events_dropped,
+            events_deduplicated: app.metrics.events_deduplicated,
             current_queue_depth: app.metrics.current_queue_depth,
             max_queue_depth: 10,
         },
         synthetic_input: {
             let snap = state.executor_metrics.snapshot();
+            let input = synthetic_input_metrics();
             SyntheticInputDto {
                 queue_depth: snap.queue_depth as usize,
                 total_queued: snap.total_queued,
                 peak_depth: 0,
                 completions: snap.completions,
                 drops: snap.drops,
+                failures: input.failed_total,
+                consecutive_failures: input.consecutive_failures,
+                reinitializations: input.reinit_total,
             }
         },
         soul_ring_state: {
-            match SOUL_RING_STATE.lock() {
-                Ok(sr) => {
-                    if !sr.available {
-                        "unavailable".to_string()
-                    } else if !sr.can_cast {
-                        "cooldown".to_string()
-                    } else {
-                        "ready".to_string()
-                    }
-                }
-                Err(_) => "unknown".to_string(),
+            let sr = lock_or_reset(&SOUL_RING_STATE);
+            if !sr.available {
+                "unavailable".to_string()
+            } else if !sr.can_cast {
+                "cooldown".to_string()
+            } else {
+                "ready".to_string()
             }
         },
         blocked_keys: {
             let mut keys = Vec::new();
-            if app.sf_enabled.lock().map(|v| *v).unwrap_or(false) {
+            if *lock_or_recover(&app.sf_enabled) {
                 keys.extend(["Q", "W", "E"].iter().map(|s| s.to_string()));
             }
-            if app.od_enabled.lock().map(|v| *v).unwrap_or(false) {
+            if *lock_or_recover(&app.od_enabled) {
                 keys.push("R".to_string());
             }
-            if let Ok(sr) = SOUL_RING_STATE.lock() {
-                if sr.available && sr.can_cast {
-                    if let Some(key) = sr.slot_key {
-                        keys.push(format!("SoulRing({})", key));
-                    }
+            let sr = lock_or_reset(&SOUL_RING_STATE);
+            if sr.available && sr.can_cast {
+                if let Some(key) = sr.slot_key {
+                    keys.push(format!("SoulRing({})", key));
                 }
             }
             keys
         },
+        automation: {
+            let metrics = lock_or_recover(&app.automation_metrics);
+            AutomationMetricsDto {
+                combos_triggered: metrics
+                    .combos_triggered
+                    .iter()
+                    .map(|(hero_name, count)| {
+                        let name = HeroType::from_hero_name(hero_name)
+                            .map(|hero| hero.to_display_name().to_string())
+                            .unwrap_or_else(|| hero_name.clone());
+                        (name, *count)
+                    })
+                    .collect(),
+                defensive_items_used: metrics.defensive_items_used.clone(),
+                healing_items_used: metrics.healing_items_used,
+                danger_events: metrics.danger_events,
+                armlet_toggles: metrics.armlet_toggles,
+            }
+        },
+    })
+}
+
+/// Returns whether the app runs with administrator privileges
+#[tauri::command]
+pub fn get_elevation_status() -> Result<ElevationStatusDto, String> {
+    Ok(match elevation::is_elevated() {
+        Some(elevated) => ElevationStatusDto {
+            supported: true,
+            elevated,
+        },
+        None => ElevationStatusDto {
+            supported: false,
+            elevated: false,
+        },
     })
 }
+
+/// Relaunches the app through the UAC prompt and exits this instance
+#[tauri::command]
+pub fn relaunch_elevated(app: tauri::AppHandle) -> Result<(), String> {
+    elevation::relaunch_elevated()?;
+    info!("Elevated instance launched, exiting");
+    app.exit(0);
+    Ok(())
+}
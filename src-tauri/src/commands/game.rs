@@ -1,13 +1,10 @@
 use crate::ipc_types::GameStateDto;
 use crate::TauriAppState;
 use dota2_scripts::actions::danger_detector;
-#[cfg(test)]
-use dota2_scripts::models::GsiWebhookEvent;
+use dota2_scripts::state::poison::lock_or_recover;
 #[cfg(test)]
 use dota2_scripts::state::AppState;
 #[cfg(test)]
-use std::fs;
-#[cfg(test)]
 use std::time::{Duration, SystemTime};
 
 fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
@@ -24,6 +21,8 @@ fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
         GameStateDto {
             hero_name: app.selected_hero.map(|h| h.to_display_name().to_string()),
             hero_level: event.hero.level,
+            hero_facet: event.hero.facet,
+            hero_talents: event.hero.picked_talent_slots(),
             hp_percent: event.hero.health_percent,
             mana_percent: event.hero.mana_percent,
             in_danger: danger_detector::is_in_danger(),
@@ -31,6 +30,7 @@ fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
             alive: event.hero.alive,
             stunned: event.hero.stunned,
             silenced: event.hero.silenced,
+            broken: event.hero.is_broken(),
             respawn_timer: if event.hero.respawn_seconds > 0 {
                 Some(event.hero.respawn_seconds)
             } else {
@@ -38,11 +38,14 @@ fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
             },
             rune_timer,
             game_time: event.map.clock_time,
+            game_in_progress: event.map.game_in_progress(),
         }
     } else {
         GameStateDto {
             hero_name: None,
             hero_level: 0,
+            hero_facet: 0,
+            hero_talents: Vec::new(),
             hp_percent: 100,
             mana_percent: 100,
             in_danger: false,
@@ -50,9 +53,11 @@ fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
             alive: true,
             stunned: false,
             silenced: false,
+            broken: false,
             respawn_timer: None,
             rune_timer: None,
             game_time: 0,
+            game_in_progress: false,
         }
     }
 }
@@ -60,10 +65,7 @@ fn build_game_state_dto(app: &dota2_scripts::state::AppState) -> GameStateDto {
 /// Returns current game state from the latest GSI event
 #[tauri::command]
 pub fn get_game_state(state: tauri::State<'_, TauriAppState>) -> Result<GameStateDto, String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let app = lock_or_recover(&state.app_state);
 
     Ok(build_game_state_dto(&app))
 }
@@ -71,22 +73,16 @@ pub fn get_game_state(state: tauri::State<'_, TauriAppState>) -> Result<GameStat
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
-
-    fn load_huskar_event() -> GsiWebhookEvent {
-        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("..")
-            .join("tests")
-            .join("fixtures")
-            .join("huskar_event.json");
-        let json_data = fs::read_to_string(fixture_path).expect("Failed to read fixture");
-        serde_json::from_str(&json_data).expect("Failed to deserialize fixture")
-    }
+    use crate::load_fixture_event;
+    use dota2_scripts::config::Settings;
 
     #[test]
     fn game_state_is_disconnected_when_no_recent_gsi_activity() {
         let mut app = AppState::default();
-        app.update_from_gsi(load_huskar_event());
+        app.update_from_gsi(
+            load_fixture_event("huskar_event.json"),
+            &Settings::default(),
+        );
         app.last_gsi_activity_at = Some(SystemTime::now() - Duration::from_secs(10));
 
         let dto = build_game_state_dto(&app);
@@ -98,7 +94,10 @@ mod tests {
     #[test]
     fn game_state_stays_connected_with_recent_gsi_activity() {
         let mut app = AppState::default();
-        app.update_from_gsi(load_huskar_event());
+        app.update_from_gsi(
+            load_fixture_event("huskar_event.json"),
+            &Settings::default(),
+        );
 
         let dto = build_game_state_dto(&app);
 
@@ -1,13 +1,11 @@
 use crate::ipc_types::MinimapStatusDto;
 use crate::TauriAppState;
+use dota2_scripts::state::poison::lock_or_recover;
 
 /// Returns current minimap capture status
 #[tauri::command]
 pub fn get_minimap_status(state: tauri::State<'_, TauriAppState>) -> Result<MinimapStatusDto, String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let app = lock_or_recover(&state.app_state);
 
     let dto = if let Some(ref snapshot) = app.minimap_capture {
         MinimapStatusDto {
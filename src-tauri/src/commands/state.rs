@@ -2,15 +2,13 @@ use crate::ipc_types::AppStateDto;
 use crate::TauriAppState;
 use dota2_scripts::actions::armlet;
 use dota2_scripts::input::keyboard::KeyboardSnapshot;
+use dota2_scripts::state::poison::{lock_or_recover, write_or_recover};
 use dota2_scripts::state::HeroType;
 
 /// Returns current app state (selected hero, enabled flags)
 #[tauri::command]
 pub fn get_app_state(state: tauri::State<'_, TauriAppState>) -> Result<AppStateDto, String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let app = lock_or_recover(&state.app_state);
 
     Ok(AppStateDto {
         selected_hero: app.selected_hero.map(|h| h.to_display_name().to_string()),
@@ -18,6 +16,8 @@ pub fn get_app_state(state: tauri::State<'_, TauriAppState>) -> Result<AppStateD
         standalone_enabled: app.standalone_enabled,
         armlet_roshan_armed: armlet::is_roshan_mode_armed(),
         app_version: env!("CARGO_PKG_VERSION").to_string(),
+        crashes: crate::ipc_types::crash_report_dtos(),
+        gsi_listener: crate::ipc_types::gsi_listener_dto(),
     })
 }
 
@@ -27,13 +27,10 @@ pub fn set_gsi_enabled(
     enabled: bool,
     state: tauri::State<'_, TauriAppState>,
 ) -> Result<(), String> {
-    let mut app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let mut app = lock_or_recover(&state.app_state);
     app.gsi_enabled = enabled;
-    refresh_keyboard_snapshot(&state, &app)?;
-    Ok(())
+    refresh_keyboard_snapshot(&state, &app);
+    remember_arm_state(&state, &app)
 }
 
 /// Toggles standalone script on/off
@@ -42,13 +39,10 @@ pub fn set_standalone_enabled(
     enabled: bool,
     state: tauri::State<'_, TauriAppState>,
 ) -> Result<(), String> {
-    let mut app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let mut app = lock_or_recover(&state.app_state);
     app.standalone_enabled = enabled;
-    refresh_keyboard_snapshot(&state, &app)?;
-    Ok(())
+    refresh_keyboard_snapshot(&state, &app);
+    remember_arm_state(&state, &app)
 }
 
 #[tauri::command]
@@ -60,64 +54,69 @@ pub fn set_armlet_roshan_mode_armed(
     Ok(())
 }
 
+/// Clears cooldowns, trackers and latches left over from a previous game
+#[tauri::command]
+pub fn reset_automation_state(state: tauri::State<'_, TauriAppState>) -> Result<(), String> {
+    dota2_scripts::state::reset::reset_all_state();
+    state.dispatcher.reset();
+    Ok(())
+}
+
 /// Manually selects a hero (or clears selection with null)
 #[tauri::command]
 pub fn select_hero(
     hero: Option<String>,
     state: tauri::State<'_, TauriAppState>,
 ) -> Result<(), String> {
-    let mut app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let mut app = lock_or_recover(&state.app_state);
 
     let hero_type = match hero {
-        Some(name) => {
-            let game_name = match name.as_str() {
-                "Broodmother" => "npc_dota_hero_broodmother",
-                "Huskar" => "npc_dota_hero_huskar",
-                "Largo" => "npc_dota_hero_largo",
-                "Legion Commander" => "npc_dota_hero_legion_commander",
-                "Meepo" => "npc_dota_hero_meepo",
-                "Outworld Destroyer" => "npc_dota_hero_obsidian_destroyer",
-                "Shadow Fiend" => "npc_dota_hero_nevermore",
-                "Tiny" => "npc_dota_hero_tiny",
-                _ => return Err(format!("Unknown hero: {}", name)),
-            };
-            HeroType::from_hero_name(game_name)
-        }
+        Some(name) => Some(
+            HeroType::from_display_name(&name)
+                .ok_or_else(|| format!("Unknown hero: {}", name))?,
+        ),
         None => None,
     };
 
     app.selected_hero = hero_type;
 
     if let Some(ht) = hero_type {
-        *app.sf_enabled.lock().unwrap() = ht == HeroType::ShadowFiend;
-        *app.od_enabled.lock().unwrap() = ht == HeroType::OutworldDestroyer;
+        *lock_or_recover(&app.sf_enabled) = ht == HeroType::ShadowFiend;
+        *lock_or_recover(&app.od_enabled) = ht == HeroType::OutworldDestroyer;
+        *lock_or_recover(&app.largo_enabled) = ht == HeroType::Largo;
     } else {
-        *app.sf_enabled.lock().unwrap() = false;
-        *app.od_enabled.lock().unwrap() = false;
+        *lock_or_recover(&app.sf_enabled) = false;
+        *lock_or_recover(&app.od_enabled) = false;
+        *lock_or_recover(&app.largo_enabled) = false;
     }
 
-    refresh_keyboard_snapshot(&state, &app)?;
+    refresh_keyboard_snapshot(&state, &app);
     Ok(())
 }
 
-fn refresh_keyboard_snapshot(
+/// Persist the switches for the next launch when `[startup]
+/// restore_last_arm_state` is on.
+fn remember_arm_state(
     state: &tauri::State<'_, TauriAppState>,
     app: &dota2_scripts::state::AppState,
 ) -> Result<(), String> {
-    let settings = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?;
-    let snapshot = KeyboardSnapshot::from_runtime(&settings, app);
-    drop(settings);
+    let mut settings = lock_or_recover(&state.settings);
+    if !settings.startup.restore_last_arm_state {
+        return Ok(());
+    }
+    settings.startup.last_gsi_enabled = app.gsi_enabled;
+    settings.startup.last_standalone_enabled = app.standalone_enabled;
+    settings
+        .save()
+        .map_err(|e| format!("Failed to save arm state: {}", e))
+}
 
-    let mut keyboard_snapshot = state
-        .keyboard_snapshot
-        .write()
-        .map_err(|e| format!("Failed to lock keyboard snapshot: {}", e))?;
-    *keyboard_snapshot = snapshot;
-    Ok(())
+/// Rebuild the keyboard hook's snapshot from the saved settings and `app`.
+/// Takes the settings lock, so callers must not hold it.
+pub(crate) fn refresh_keyboard_snapshot(
+    state: &tauri::State<'_, TauriAppState>,
+    app: &dota2_scripts::state::AppState,
+) {
+    let snapshot = KeyboardSnapshot::from_runtime(&lock_or_recover(&state.settings), app);
+    *write_or_recover(&state.keyboard_snapshot) = snapshot;
 }
@@ -1,22 +1,35 @@
 use crate::ipc_types::UpdateStateDto;
 use crate::TauriAppState;
+use dota2_scripts::config::error::ErrorCategory;
+use dota2_scripts::state::poison::lock_or_recover;
 use dota2_scripts::state::UpdateCheckState;
-use dota2_scripts::update::{ApplyUpdateResult, UpdateCheckResult};
+use dota2_scripts::update::{ApplyUpdateResult, UpdateCheckResult, UpdateError};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+fn failed_dto(error: &UpdateError) -> UpdateStateDto {
+    UpdateStateDto::Error {
+        message: error.to_string(),
+        category: error.category(),
+        retryable: error.retryable(),
+    }
+}
+
+/// The blocking update task panicked or was cancelled.
+fn join_failed_dto(error: tokio::task::JoinError) -> UpdateStateDto {
+    UpdateStateDto::Error {
+        message: format!("Task join error: {}", error),
+        category: ErrorCategory::Internal,
+        retryable: false,
+    }
+}
+
 /// Returns current update check state
 #[tauri::command]
 pub fn get_update_state(state: tauri::State<'_, TauriAppState>) -> Result<UpdateStateDto, String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
+    let app = lock_or_recover(&state.app_state);
 
-    let update_state = app
-        .update_state
-        .lock()
-        .map_err(|e| format!("Failed to lock update state: {}", e))?;
+    let update_state = lock_or_recover(&app.update_state);
 
     let dto = match &*update_state {
         UpdateCheckState::Idle => UpdateStateDto::Idle,
@@ -29,8 +42,10 @@ pub fn get_update_state(state: tauri::State<'_, TauriAppState>) -> Result<Update
             release_notes: release_notes.clone(),
         },
         UpdateCheckState::Downloading => UpdateStateDto::Downloading,
-        UpdateCheckState::Error(msg) => UpdateStateDto::Error {
-            message: msg.clone(),
+        UpdateCheckState::Error { message, category } => UpdateStateDto::Error {
+            message: message.clone(),
+            category: *category,
+            retryable: category.retryable(),
         },
         UpdateCheckState::UpToDate => UpdateStateDto::UpToDate,
     };
@@ -44,28 +59,20 @@ pub async fn check_for_updates(
     state: tauri::State<'_, TauriAppState>,
 ) -> Result<UpdateStateDto, String> {
     let update_state_arc: Arc<Mutex<UpdateCheckState>> = {
-        let app = state
-            .app_state
-            .lock()
-            .map_err(|e| format!("Failed to lock app state: {}", e))?;
+        let app = lock_or_recover(&state.app_state);
         app.update_state.clone()
     };
-    let include_prereleases = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?
-        .updates
-        .include_prereleases;
+    let include_prereleases = lock_or_recover(&state.settings).updates.include_prereleases;
 
     {
-        let mut us = update_state_arc.lock().unwrap();
+        let mut us = lock_or_recover(&update_state_arc);
         *us = UpdateCheckState::Checking;
     }
 
     let update_state_clone = update_state_arc.clone();
     let result = tokio::task::spawn_blocking(move || {
         let check_result = dota2_scripts::update::check_for_update(include_prereleases);
-        let mut us = update_state_clone.lock().unwrap();
+        let mut us = lock_or_recover(&update_state_clone);
         match check_result {
             UpdateCheckResult::Available(info) => {
                 let dto = UpdateStateDto::Available {
@@ -82,17 +89,14 @@ pub async fn check_for_updates(
                 *us = UpdateCheckState::UpToDate;
                 UpdateStateDto::UpToDate
             }
-            UpdateCheckResult::Error(msg) => {
-                let dto = UpdateStateDto::Error {
-                    message: msg.clone(),
-                };
-                *us = UpdateCheckState::Error(msg);
-                dto
+            UpdateCheckResult::Error(e) => {
+                *us = UpdateCheckState::failed(&e);
+                failed_dto(&e)
             }
         }
     })
     .await
-    .map_err(|e| format!("Task join error: {}", e))?;
+    .unwrap_or_else(join_failed_dto);
 
     Ok(result)
 }
@@ -102,29 +106,21 @@ pub async fn check_for_updates(
 pub async fn apply_update(
     state: tauri::State<'_, TauriAppState>,
 ) -> Result<UpdateStateDto, String> {
-    let include_prereleases = state
-        .settings
-        .lock()
-        .map_err(|e| format!("Failed to lock settings: {}", e))?
-        .updates
-        .include_prereleases;
+    let include_prereleases = lock_or_recover(&state.settings).updates.include_prereleases;
     let update_state_arc: Arc<Mutex<UpdateCheckState>> = {
-        let app = state
-            .app_state
-            .lock()
-            .map_err(|e| format!("Failed to lock app state: {}", e))?;
+        let app = lock_or_recover(&state.app_state);
         app.update_state.clone()
     };
 
     {
-        let mut us = update_state_arc.lock().unwrap();
+        let mut us = lock_or_recover(&update_state_arc);
         *us = UpdateCheckState::Downloading;
     }
 
     let update_state_clone = update_state_arc.clone();
     let (result, should_exit) = tokio::task::spawn_blocking(move || {
         let apply_result = dota2_scripts::update::apply_update(include_prereleases);
-        let mut us = update_state_clone.lock().unwrap();
+        let mut us = lock_or_recover(&update_state_clone);
         match apply_result {
             ApplyUpdateResult::Success { new_version: _ } => {
                 *us = UpdateCheckState::UpToDate;
@@ -134,19 +130,18 @@ pub async fn apply_update(
                 *us = UpdateCheckState::UpToDate;
                 (UpdateStateDto::UpToDate, false)
             }
-            ApplyUpdateResult::Error(msg) => {
-                let dto = UpdateStateDto::Error {
-                    message: msg.clone(),
-                };
-                *us = UpdateCheckState::Error(msg);
-                (dto, false)
+            ApplyUpdateResult::Error(e) => {
+                *us = UpdateCheckState::failed(&e);
+                (failed_dto(&e), false)
             }
         }
     })
     .await
-    .map_err(|e| format!("Task join error: {}", e))?;
+    .unwrap_or_else(|error| (join_failed_dto(error), false));
 
     if should_exit {
+        // Stop the GSI listener first so the relaunched app can bind the port.
+        dota2_scripts::state::shutdown::request_shutdown();
         std::thread::spawn(|| {
             std::thread::sleep(Duration::from_millis(500));
             std::process::exit(0);
@@ -156,13 +151,34 @@ pub async fn apply_update(
     Ok(result)
 }
 
+/// Returns the version a rollback would restore, if a backup exists
+#[tauri::command]
+pub fn get_rollback_version() -> Result<Option<String>, String> {
+    Ok(dota2_scripts::update::rollback_available())
+}
+
+/// Restores the executable replaced by the last update and restarts.
+/// Rejects with an `UpdateStateDto::Error` carrying the failure's category.
+#[tauri::command]
+pub async fn rollback_update() -> Result<String, UpdateStateDto> {
+    let version = tokio::task::spawn_blocking(dota2_scripts::update::rollback)
+        .await
+        .map_err(join_failed_dto)?
+        .map_err(|e| failed_dto(&e))?;
+
+    dota2_scripts::state::shutdown::request_shutdown();
+    std::thread::spawn(|| {
+        std::thread::sleep(Duration::from_millis(500));
+        std::process::exit(0);
+    });
+
+    Ok(version)
+}
+
 /// Dismisses update banner (resets to Idle)
 #[tauri::command]
 pub fn dismiss_update(state: tauri::State<'_, TauriAppState>) -> Result<(), String> {
-    let app = state
-        .app_state
-        .lock()
-        .map_err(|e| format!("Failed to lock app state: {}", e))?;
-    *app.update_state.lock().unwrap() = UpdateCheckState::Idle;
+    let app = lock_or_recover(&state.app_state);
+    *lock_or_recover(&app.update_state) = UpdateCheckState::Idle;
     Ok(())
 }
@@ -1,8 +1,10 @@
-use crate::ipc_types::{ActivityEntryDto, AppStateDto, GameStateDto};
+use crate::ipc_types::{ActivityEntryDto, AppStateDto, CooldownEntryDto, GameStateDto};
 use crate::TauriAppState;
 use dota2_scripts::actions::activity;
 use dota2_scripts::actions::armlet;
 use dota2_scripts::actions::danger_detector;
+use dota2_scripts::input::combo_recorder;
+use dota2_scripts::state::poison::lock_or_recover;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager};
@@ -13,10 +15,13 @@ static ACTIVITY_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 pub fn start_game_state_emitter(app: AppHandle) {
     let tauri_state = app.state::<TauriAppState>();
     let app_state = tauri_state.app_state.clone();
+    let settings = tauri_state.settings.clone();
 
     tauri::async_runtime::spawn(async move {
         let mut last_emitted_state: Option<GameStateDto> = None;
         let mut last_emitted_app_state: Option<AppStateDto> = None;
+        let mut last_emitted_cooldowns: Option<Vec<CooldownEntryDto>> = None;
+        let mut saved_recordings = combo_recorder::saved_recordings();
 
         loop {
             tokio::time::sleep(Duration::from_millis(200)).await;
@@ -24,14 +29,7 @@ pub fn start_game_state_emitter(app: AppHandle) {
             // Emit game state if changed
             {
                 let dto = {
-                    let state = match app_state.lock() {
-                        Ok(s) => s,
-                        Err(_) => {
-                            drain_and_emit_activities(&app);
-                            continue;
-                        }
-                    };
-
+                    let state = lock_or_recover(&app_state);
                     let dto = build_game_state_dto(&state);
                     if last_emitted_state.as_ref() != Some(&dto) {
                         Some(dto)
@@ -48,14 +46,7 @@ pub fn start_game_state_emitter(app: AppHandle) {
 
             {
                 let dto = {
-                    let state = match app_state.lock() {
-                        Ok(s) => s,
-                        Err(_) => {
-                            drain_and_emit_activities(&app);
-                            continue;
-                        }
-                    };
-
+                    let state = lock_or_recover(&app_state);
                     let dto = build_app_state_dto(&state);
                     if last_emitted_app_state.as_ref() != Some(&dto) {
                         Some(dto)
@@ -70,6 +61,32 @@ pub fn start_game_state_emitter(app: AppHandle) {
                 }
             }
 
+            // Emit overlay cooldowns if the overlay is enabled and they changed
+            {
+                let overlay = lock_or_recover(&settings).overlay.clone();
+                let entries = overlay.enabled.then(|| {
+                    build_overlay_cooldowns(&lock_or_recover(&app_state), overlay.show_abilities)
+                });
+
+                if let Some(entries) = entries {
+                    if last_emitted_cooldowns.as_ref() != Some(&entries) {
+                        let _ = app.emit("overlay_cooldowns", &entries);
+                        last_emitted_cooldowns = Some(entries);
+                    }
+                } else {
+                    last_emitted_cooldowns = None;
+                }
+            }
+
+            // A recorded combo was saved to the config behind the UI's back
+            {
+                let saved = combo_recorder::saved_recordings();
+                if saved != saved_recordings {
+                    saved_recordings = saved;
+                    let _ = app.emit("config_changed", ());
+                }
+            }
+
             // Drain and emit activity events
             drain_and_emit_activities(&app);
         }
@@ -85,6 +102,8 @@ fn build_app_state_dto(state: &dota2_scripts::state::AppState) -> AppStateDto {
         standalone_enabled: state.standalone_enabled,
         armlet_roshan_armed: armlet::is_roshan_mode_armed(),
         app_version: env!("CARGO_PKG_VERSION").to_string(),
+        crashes: crate::ipc_types::crash_report_dtos(),
+        gsi_listener: crate::ipc_types::gsi_listener_dto(),
     }
 }
 
@@ -111,11 +130,25 @@ fn drain_and_emit_activities(app: &AppHandle) {
             category: entry.category.as_str().to_string(),
             message: entry.message,
             details: entry.details,
+            kind: entry.kind.map(str::to_string),
         };
         let _ = app.emit("activity_event", &dto);
     }
 }
 
+/// Overlay rows from the latest GSI event; empty once GSI goes quiet.
+fn build_overlay_cooldowns(
+    state: &dota2_scripts::state::AppState,
+    show_abilities: bool,
+) -> Vec<CooldownEntryDto> {
+    match state.last_event.as_ref() {
+        Some(event) if state.has_recent_gsi_activity() => {
+            crate::overlay::build_cooldown_entries(event, show_abilities)
+        }
+        _ => Vec::new(),
+    }
+}
+
 fn build_game_state_dto(state: &dota2_scripts::state::AppState) -> GameStateDto {
     if state.has_recent_gsi_activity() {
         let event = state
@@ -132,6 +165,8 @@ fn build_game_state_dto(state: &dota2_scripts::state::AppState) -> GameStateDto
                 .selected_hero
                 .map(|h| h.to_display_name().to_string()),
             hero_level: event.hero.level,
+            hero_facet: event.hero.facet,
+            hero_talents: event.hero.picked_talent_slots(),
             hp_percent: event.hero.health_percent,
             mana_percent: event.hero.mana_percent,
             in_danger: danger_detector::is_in_danger(),
@@ -139,6 +174,7 @@ fn build_game_state_dto(state: &dota2_scripts::state::AppState) -> GameStateDto
             alive: event.hero.alive,
             stunned: event.hero.stunned,
             silenced: event.hero.silenced,
+            broken: event.hero.is_broken(),
             respawn_timer: if event.hero.respawn_seconds > 0 {
                 Some(event.hero.respawn_seconds)
             } else {
@@ -146,11 +182,14 @@ fn build_game_state_dto(state: &dota2_scripts::state::AppState) -> GameStateDto
             },
             rune_timer,
             game_time: event.map.clock_time,
+            game_in_progress: event.map.game_in_progress(),
         }
     } else {
         GameStateDto {
             hero_name: None,
             hero_level: 0,
+            hero_facet: 0,
+            hero_talents: Vec::new(),
             hp_percent: 100,
             mana_percent: 100,
             in_danger: false,
@@ -158,9 +197,11 @@ fn build_game_state_dto(state: &dota2_scripts::state::AppState) -> GameStateDto
             alive: true,
             stunned: false,
             silenced: false,
+            broken: false,
             respawn_timer: None,
             rune_timer: None,
             game_time: 0,
+            game_in_progress: false,
         }
     }
 }
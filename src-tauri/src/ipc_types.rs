@@ -1,5 +1,8 @@
+use dota2_scripts::config::error::{ConfigError, ErrorCategory};
+use dota2_scripts::gsi::server::BindError;
 use serde::Serialize;
 use std::cmp::PartialEq;
+use std::collections::BTreeMap;
 
 /// Matches frontend GameState in src-ui/src/types/game.ts
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -7,6 +10,10 @@ use std::cmp::PartialEq;
 pub struct GameStateDto {
     pub hero_name: Option<String>,
     pub hero_level: u32,
+    /// GSI `hero.facet`; 0 when not in game.
+    pub hero_facet: u32,
+    /// 1-based GSI talent slots picked so far.
+    pub hero_talents: Vec<u32>,
     pub hp_percent: u32,
     pub mana_percent: u32,
     pub in_danger: bool,
@@ -14,9 +21,24 @@ pub struct GameStateDto {
     pub alive: bool,
     pub stunned: bool,
     pub silenced: bool,
+    /// GSI `hero.break`: passives are disabled.
+    pub broken: bool,
     pub respawn_timer: Option<u32>,
     pub rune_timer: Option<i32>,
     pub game_time: i32,
+    /// GSI `map.game_state` is `DOTA_GAMERULES_STATE_GAME_IN_PROGRESS`.
+    pub game_in_progress: bool,
+}
+
+/// Matches frontend CooldownEntry in src-ui/src/types/game.ts
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CooldownEntryDto {
+    pub name: String,
+    /// `"item"` or `"ability"`
+    pub kind: String,
+    pub cooldown: u32,
+    pub charges: Option<u32>,
 }
 
 /// Matches frontend AppState-related fields
@@ -28,6 +50,57 @@ pub struct AppStateDto {
     pub standalone_enabled: bool,
     pub armlet_roshan_armed: bool,
     pub app_version: String,
+    /// Subsystems stopped by a panic since startup, oldest first.
+    pub crashes: Vec<CrashReportDto>,
+    pub gsi_listener: GsiListenerDto,
+}
+
+/// Matches frontend GsiListener in src-ui/src/types/game.ts
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GsiListenerDto {
+    /// `ip:port` the GSI server is listening on; `None` while it is not.
+    pub address: Option<String>,
+    pub error: Option<String>,
+    /// Free port to offer when the configured one is taken.
+    pub suggested_port: Option<u16>,
+}
+
+/// Live GSI server address for [`AppStateDto::gsi_listener`].
+pub fn gsi_listener_dto() -> GsiListenerDto {
+    let status = dota2_scripts::gsi::server::listener_status();
+    GsiListenerDto {
+        address: status.addr.map(|addr| addr.to_string()),
+        error: status.error,
+        suggested_port: status.suggested_port,
+    }
+}
+
+/// Matches frontend CrashReport in src-ui/src/types/game.ts
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReportDto {
+    pub subsystem: String,
+    pub message: String,
+    pub location: String,
+}
+
+impl From<dota2_scripts::diagnostics::panic_report::CrashReport> for CrashReportDto {
+    fn from(report: dota2_scripts::diagnostics::panic_report::CrashReport) -> Self {
+        Self {
+            subsystem: report.subsystem,
+            message: report.message,
+            location: report.location,
+        }
+    }
+}
+
+/// Crash reports for [`AppStateDto::crashes`].
+pub fn crash_report_dtos() -> Vec<CrashReportDto> {
+    dota2_scripts::diagnostics::panic_report::crashes()
+        .into_iter()
+        .map(CrashReportDto::from)
+        .collect()
 }
 
 /// Matches frontend QueueMetrics in src-ui/src/types/game.ts
@@ -36,6 +109,7 @@ pub struct AppStateDto {
 pub struct QueueMetricsDto {
     pub events_processed: u64,
     pub events_dropped: u64,
+    pub events_deduplicated: u64,
     pub current_queue_depth: usize,
     pub max_queue_depth: usize,
 }
@@ -49,6 +123,25 @@ pub struct SyntheticInputDto {
     pub peak_depth: usize,
     pub completions: u64,
     pub drops: u64,
+    /// Actions the input backend reported as failed.
+    pub failures: u64,
+    /// Failures since the last successful action; non-zero means input is
+    /// not reaching the game right now.
+    pub consecutive_failures: u32,
+    /// Times the input backend was rebuilt after repeated failures.
+    pub reinitializations: u64,
+}
+
+/// Matches frontend AutomationMetrics in src-ui/src/types/game.ts
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationMetricsDto {
+    /// Keyed by hero display name
+    pub combos_triggered: BTreeMap<String, u64>,
+    pub defensive_items_used: BTreeMap<String, u64>,
+    pub healing_items_used: u64,
+    pub danger_events: u64,
+    pub armlet_toggles: u64,
 }
 
 /// Matches frontend DiagnosticsState in src-ui/src/types/game.ts
@@ -61,6 +154,16 @@ pub struct DiagnosticsDto {
     pub synthetic_input: SyntheticInputDto,
     pub soul_ring_state: String,
     pub blocked_keys: Vec<String>,
+    pub automation: AutomationMetricsDto,
+}
+
+/// Matches frontend ElevationStatus in src-ui/src/types/game.ts
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ElevationStatusDto {
+    /// `false` on platforms where elevation does not apply
+    pub supported: bool,
+    pub elevated: bool,
 }
 
 /// Matches frontend UpdateCheckState in src-ui/src/types/game.ts
@@ -79,12 +182,59 @@ pub enum UpdateStateDto {
     },
     #[serde(rename = "downloading")]
     Downloading,
+    /// `retryable` is false when trying again cannot help (no permission,
+    /// ZIP install, ...).
     #[serde(rename = "error")]
-    Error { message: String },
+    Error {
+        message: String,
+        category: ErrorCategory,
+        retryable: bool,
+    },
     #[serde(rename = "upToDate")]
     UpToDate,
 }
 
+/// Error returned by the config commands, with the same `category` and
+/// `retryable` as `UpdateStateDto::Error`. Matches frontend ConfigCommandError
+/// in src-ui/src/types/config.ts
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ConfigErrorDto {
+    pub message: String,
+    pub category: ErrorCategory,
+    pub retryable: bool,
+}
+
+impl ConfigErrorDto {
+    pub fn new(category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            category,
+            retryable: category.retryable(),
+        }
+    }
+
+    /// A rejected request, e.g. a value out of range or an unknown section.
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::new(ErrorCategory::Invalid, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCategory::Internal, message)
+    }
+}
+
+impl From<ConfigError> for ConfigErrorDto {
+    fn from(error: ConfigError) -> Self {
+        Self::new(error.category(), error.to_string())
+    }
+}
+
+impl From<BindError> for ConfigErrorDto {
+    fn from(error: BindError) -> Self {
+        Self::new(error.category(), error.to_string())
+    }
+}
+
 /// Activity entry emitted to frontend
 #[derive(Debug, Clone, Serialize)]
 pub struct ActivityEntryDto {
@@ -94,6 +244,9 @@ pub struct ActivityEntryDto {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    /// Action kind for entries from `push_action` (e.g. `defensive_item`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
 }
 
 /// Meepo hero-specific observed runtime state
@@ -137,6 +290,8 @@ mod tests {
         let dto = GameStateDto {
             hero_name: Some("Shadow Fiend".to_string()),
             hero_level: 25,
+            hero_facet: 2,
+            hero_talents: vec![2, 3],
             hp_percent: 85,
             mana_percent: 70,
             in_danger: false,
@@ -144,15 +299,21 @@ mod tests {
             alive: true,
             stunned: false,
             silenced: false,
+            broken: true,
             respawn_timer: None,
             rune_timer: Some(45),
             game_time: 1234,
+            game_in_progress: true,
         };
         let json = serde_json::to_value(&dto).unwrap();
         assert_eq!(json["heroName"], "Shadow Fiend");
+        assert_eq!(json["heroFacet"], 2);
+        assert_eq!(json["heroTalents"], serde_json::json!([2, 3]));
         assert_eq!(json["hpPercent"], 85);
         assert_eq!(json["inDanger"], false);
+        assert_eq!(json["broken"], true);
         assert_eq!(json["runeTimer"], 45);
+        assert_eq!(json["gameInProgress"], true);
         assert!(json.get("hero_name").is_none());
     }
 
@@ -174,6 +335,29 @@ mod tests {
         let up_to_date = UpdateStateDto::UpToDate;
         let json = serde_json::to_value(&up_to_date).unwrap();
         assert_eq!(json["kind"], "upToDate");
+
+        let error = UpdateStateDto::Error {
+            message: "Access is denied.".to_string(),
+            category: ErrorCategory::Permission,
+            retryable: false,
+        };
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["kind"], "error");
+        assert_eq!(json["category"], "permission");
+        assert_eq!(json["retryable"], false);
+    }
+
+    #[test]
+    fn config_error_dto_keeps_the_config_error_category() {
+        let error = ConfigErrorDto::from(ConfigError::ProfileNotFound("carry".to_string()));
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["message"], "Profile 'carry' does not exist");
+        assert_eq!(json["category"], "not_found");
+        assert_eq!(json["retryable"], false);
+
+        let rejected = ConfigErrorDto::invalid("Server port must be greater than 0");
+        assert_eq!(rejected.category, ErrorCategory::Invalid);
+        assert!(!rejected.retryable);
     }
 
     #[test]
@@ -203,6 +387,7 @@ mod tests {
             queue_metrics: QueueMetricsDto {
                 events_processed: 100,
                 events_dropped: 2,
+                events_deduplicated: 40,
                 current_queue_depth: 3,
                 max_queue_depth: 10,
             },
@@ -212,13 +397,25 @@ mod tests {
                 peak_depth: 5,
                 completions: 48,
                 drops: 2,
+                failures: 0,
+                consecutive_failures: 0,
+                reinitializations: 0,
             },
             soul_ring_state: "ready".to_string(),
             blocked_keys: vec!["q".to_string(), "w".to_string()],
+            automation: AutomationMetricsDto {
+                combos_triggered: BTreeMap::from([("Legion Commander".to_string(), 3)]),
+                defensive_items_used: BTreeMap::from([("item_black_king_bar".to_string(), 1)]),
+                healing_items_used: 4,
+                danger_events: 2,
+                armlet_toggles: 9,
+            },
         };
         let json = serde_json::to_value(&dto).unwrap();
         assert_eq!(json["gsiConnected"], true);
         assert_eq!(json["queueMetrics"]["eventsProcessed"], 100);
         assert_eq!(json["syntheticInput"]["peakDepth"], 5);
+        assert_eq!(json["automation"]["combosTriggered"]["Legion Commander"], 3);
+        assert_eq!(json["automation"]["armletToggles"], 9);
     }
 }
@@ -1,6 +1,7 @@
 pub mod commands;
 pub mod events;
 pub mod ipc_types;
+pub mod overlay;
 
 use dota2_scripts::actions::executor::{ActionExecutor, ExecutorMetrics};
 use dota2_scripts::actions::heroes::{LargoScript, MeepoScript};
@@ -11,9 +12,11 @@ use dota2_scripts::input::keyboard::{
     start_keyboard_listener, HotkeyEvent, KeyboardListenerConfig, KeyboardSnapshot,
 };
 use dota2_scripts::models::Hero;
+use dota2_scripts::state::poison::lock_or_recover;
 use dota2_scripts::state::{AppState, HeroType, UpdateCheckState};
 use dota2_scripts::update::{check_for_update, UpdateCheckResult};
 use std::sync::{Arc, Mutex, RwLock};
+use tauri::Manager;
 use tracing::info;
 
 /// Shared state managed by Tauri, accessible from all commands
@@ -22,27 +25,44 @@ pub struct TauriAppState {
     pub settings: Arc<Mutex<Settings>>,
     pub keyboard_snapshot: Arc<RwLock<KeyboardSnapshot>>,
     pub executor_metrics: Arc<ExecutorMetrics>,
+    pub dispatcher: Arc<ActionDispatcher>,
 }
 
 pub fn run() {
+    // Initialize logging with config level or environment variable before
+    // loading settings, so load warnings reach the log file
+    let logging = Settings::load_logging();
+    let _log_guard = dota2_scripts::diagnostics::logging::init(&logging);
+    dota2_scripts::diagnostics::panic_report::install(&logging);
+
     // Load settings
     let settings = Arc::new(Mutex::new(Settings::load()));
 
-    // Initialize logging with config level or environment variable
-    let log_level = std::env::var("RUST_LOG")
-        .unwrap_or_else(|_| settings.lock().unwrap().logging.level.clone());
-    tracing_subscriber::fmt().with_env_filter(log_level).init();
-
     info!("Starting Dota 2 Script Automation (Tauri)...");
-    info!("Server port: {}", settings.lock().unwrap().server.port);
+    dota2_scripts::diagnostics::elevation::log_elevation_status();
+    info!(
+        "Server address: {}",
+        lock_or_recover(&settings).server.socket_addr()
+    );
+    dota2_scripts::input::simulation::apply_input_settings(&lock_or_recover(&settings));
 
-    // Initialize shared state
-    let app_state = AppState::new();
+    // Initialize shared state; [startup] decides whether automation is armed
+    let app_state = AppState::new_from_startup(&lock_or_recover(&settings));
+    {
+        let state = lock_or_recover(&app_state);
+        if !state.gsi_enabled && !state.standalone_enabled {
+            info!("Starting disarmed; enable automation from the dashboard");
+            dota2_scripts::actions::activity::push_activity(
+                dota2_scripts::actions::activity::ActivityCategory::System,
+                "Automation is off until enabled on the dashboard",
+            );
+        }
+    }
 
     // Build the initial keyboard snapshot before starting the listener
     let initial_snapshot = {
-        let settings_guard = settings.lock().unwrap();
-        let state_guard = app_state.lock().unwrap();
+        let settings_guard = lock_or_recover(&settings);
+        let state_guard = lock_or_recover(&app_state);
         Arc::new(RwLock::new(KeyboardSnapshot::from_runtime(
             &settings_guard,
             &state_guard,
@@ -61,42 +81,48 @@ pub fn run() {
     let hotkey_rx = start_keyboard_listener(keyboard_config);
 
     // Start GSI server in background
-    let port = settings.lock().unwrap().server.port;
+    let gsi_addr = lock_or_recover(&settings).server.socket_addr();
     let gsi_app_state = app_state.clone();
     let gsi_dispatcher = dispatcher.clone();
     let gsi_settings = settings.clone();
     tauri::async_runtime::spawn(async move {
-        start_gsi_server(port, gsi_app_state, gsi_dispatcher, gsi_settings).await;
+        start_gsi_server(gsi_addr, gsi_app_state, gsi_dispatcher, gsi_settings).await;
     });
 
     // Start update check in background (if enabled)
     {
-        let settings_guard = settings.lock().unwrap();
+        let settings_guard = lock_or_recover(&settings);
         let check_on_startup = settings_guard.updates.check_on_startup;
         let include_prereleases = settings_guard.updates.include_prereleases;
         drop(settings_guard);
 
         if check_on_startup {
-            let update_state = app_state.lock().unwrap().update_state.clone();
-            *update_state.lock().unwrap() = UpdateCheckState::Checking;
+            let update_state = lock_or_recover(&app_state).update_state.clone();
+            *lock_or_recover(&update_state) = UpdateCheckState::Checking;
 
             std::thread::spawn(move || match check_for_update(include_prereleases) {
                 UpdateCheckResult::Available(update_info) => {
-                    *update_state.lock().unwrap() = UpdateCheckState::Available {
+                    *lock_or_recover(&update_state) = UpdateCheckState::Available {
                         version: update_info.version,
                         release_notes: update_info.release_notes,
                     };
                 }
                 UpdateCheckResult::UpToDate => {
-                    *update_state.lock().unwrap() = UpdateCheckState::UpToDate;
+                    *lock_or_recover(&update_state) = UpdateCheckState::UpToDate;
                 }
-                UpdateCheckResult::Error(msg) => {
-                    *update_state.lock().unwrap() = UpdateCheckState::Error(msg);
+                UpdateCheckResult::Error(e) => {
+                    *lock_or_recover(&update_state) = UpdateCheckState::failed(&e);
                 }
             });
         }
     }
 
+    // Re-check periodically when updates.check_interval_minutes > 0
+    dota2_scripts::update::start_periodic_update_check(
+        settings.clone(),
+        lock_or_recover(&app_state).update_state.clone(),
+    );
+
     // Start minimap capture worker in background
     let minimap_settings = settings.clone();
     let minimap_state = app_state.clone();
@@ -110,8 +136,14 @@ pub fn run() {
     // Start hotkey event handler in background
     let hotkey_app_state = app_state.clone();
     let hotkey_dispatcher = dispatcher.clone();
+    let hotkey_settings = settings.clone();
     std::thread::spawn(move || {
-        handle_hotkey_events(hotkey_rx, hotkey_app_state, hotkey_dispatcher);
+        handle_hotkey_events(
+            hotkey_rx,
+            hotkey_app_state,
+            hotkey_dispatcher,
+            hotkey_settings,
+        );
     });
 
     // Build and run Tauri application
@@ -122,9 +154,14 @@ pub fn run() {
             settings: settings.clone(),
             keyboard_snapshot: initial_snapshot.clone(),
             executor_metrics,
+            dispatcher,
         })
         .setup(|app| {
             let handle = app.handle().clone();
+            let overlay_config = lock_or_recover(&app.state::<TauriAppState>().settings)
+                .overlay
+                .clone();
+            overlay::sync_overlay_window(&handle, &overlay_config);
             events::start_game_state_emitter(handle);
             Ok(())
         })
@@ -132,22 +169,48 @@ pub fn run() {
             commands::config::get_config,
             commands::config::update_config,
             commands::config::update_hero_config,
+            commands::config::get_keybinding_conflicts,
+            commands::config::get_tiny_timing_presets,
+            commands::config::restart_gsi_server,
+            commands::config::list_profiles,
+            commands::config::save_profile,
+            commands::config::switch_profile,
+            commands::config::export_config,
+            commands::config::preview_config_import,
+            commands::config::import_config,
             commands::state::get_app_state,
             commands::state::set_gsi_enabled,
             commands::state::set_standalone_enabled,
             commands::state::set_armlet_roshan_mode_armed,
+            commands::state::reset_automation_state,
             commands::state::select_hero,
             commands::game::get_game_state,
             commands::diagnostics::get_diagnostics,
+            commands::diagnostics::get_elevation_status,
+            commands::diagnostics::relaunch_elevated,
             commands::updates::get_update_state,
             commands::updates::check_for_updates,
             commands::updates::apply_update,
             commands::updates::dismiss_update,
+            commands::updates::get_rollback_version,
+            commands::updates::rollback_update,
             commands::meepo::get_meepo_state,
             commands::minimap::get_minimap_status,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| match event {
+            // The cooldown overlay must not keep the app alive on its own
+            tauri::RunEvent::WindowEvent {
+                label,
+                event: tauri::WindowEvent::Destroyed,
+                ..
+            } if label == "main" => app.exit(0),
+            tauri::RunEvent::ExitRequested { .. } | tauri::RunEvent::Exit => {
+                dota2_scripts::state::shutdown::request_shutdown();
+            }
+            _ => {}
+        });
 }
 
 /// Processes hotkey events from the keyboard listener and dispatches actions.
@@ -155,24 +218,15 @@ fn handle_hotkey_events(
     hotkey_rx: std::sync::mpsc::Receiver<HotkeyEvent>,
     app_state: Arc<Mutex<AppState>>,
     dispatcher: Arc<ActionDispatcher>,
+    settings: Arc<Mutex<Settings>>,
 ) {
     while let Ok(event) = hotkey_rx.recv() {
         match event {
             HotkeyEvent::ComboTrigger => {
-                let state = app_state.lock().unwrap();
+                let state = lock_or_recover(&app_state);
                 if state.standalone_enabled {
-                    if let Some(hero_type) = state.selected_hero {
-                        let hero_name = match hero_type {
-                            HeroType::Huskar => Hero::Huskar.to_game_name(),
-                            HeroType::Largo => Hero::Largo.to_game_name(),
-                            HeroType::LegionCommander => Hero::LegionCommander.to_game_name(),
-                            HeroType::Meepo => Hero::Meepo.to_game_name(),
-                            HeroType::OutworldDestroyer => {
-                                Hero::ObsidianDestroyer.to_game_name()
-                            }
-                            HeroType::ShadowFiend => Hero::Nevermore.to_game_name(),
-                            HeroType::Tiny => Hero::Tiny.to_game_name(),
-                        };
+                    if let Some(hero_type) = state.standalone_hero() {
+                        let hero_name = hero_type.to_game_name();
                         info!("Triggering standalone combo for {}", hero_name);
                         drop(state);
                         dispatcher.dispatch_standalone_trigger(hero_name);
@@ -183,10 +237,21 @@ fn handle_hotkey_events(
                     info!("Standalone scripts disabled");
                 }
             }
+            HotkeyEvent::HeroComboTrigger(hero_type) => {
+                let state = lock_or_recover(&app_state);
+                if state.standalone_enabled {
+                    let hero_name = hero_type.to_game_name();
+                    info!("Triggering standalone combo for {}", hero_name);
+                    drop(state);
+                    dispatcher.dispatch_standalone_trigger(hero_name);
+                } else {
+                    info!("Standalone scripts disabled");
+                }
+            }
             HotkeyEvent::MeepoFarmToggle => {
-                let state = app_state.lock().unwrap();
+                let state = lock_or_recover(&app_state);
                 if state.standalone_enabled
-                    && state.selected_hero == Some(HeroType::Meepo)
+                    && state.standalone_hero() == Some(HeroType::Meepo)
                 {
                     drop(state);
                     if let Some(script) =
@@ -207,6 +272,19 @@ fn handle_hotkey_events(
                     if armed { "armed" } else { "disarmed" }
                 );
             }
+            HotkeyEvent::ArmletEmergencyToggle => {
+                let last_event = lock_or_recover(&app_state).last_event.clone();
+                if let Some(event) = last_event {
+                    let settings = lock_or_recover(&settings).clone();
+                    dota2_scripts::actions::armlet::emergency_toggle(&event, &settings);
+                } else {
+                    info!("Emergency armlet hotkey ignored: no GSI event received yet");
+                }
+            }
+            HotkeyEvent::ComboRecorderToggle => {
+                let hero = lock_or_recover(&app_state).standalone_hero();
+                dota2_scripts::input::combo_recorder::toggle(hero, &settings);
+            }
             HotkeyEvent::LargoQ => {
                 dispatch_largo_song(&app_state, &dispatcher, |largo| {
                     largo.select_song_manually(
@@ -243,8 +321,8 @@ fn dispatch_largo_song(
     dispatcher: &Arc<ActionDispatcher>,
     action: impl FnOnce(&LargoScript),
 ) {
-    let state = app_state.lock().unwrap();
-    if state.standalone_enabled && state.selected_hero == Some(HeroType::Largo) {
+    let state = lock_or_recover(&app_state);
+    if state.standalone_enabled && state.standalone_hero() == Some(HeroType::Largo) {
         drop(state);
         if let Some(script) = dispatcher.hero_scripts.get(Hero::Largo.to_game_name()) {
             if let Some(largo_script) = script.as_any().downcast_ref::<LargoScript>() {
@@ -253,3 +331,16 @@ fn dispatch_largo_song(
         }
     }
 }
+
+/// Deserialize a GSI fixture from the workspace `tests/fixtures/`, e.g.
+/// `huskar_event.json`.
+#[cfg(test)]
+pub(crate) fn load_fixture_event(name: &str) -> dota2_scripts::models::GsiWebhookEvent {
+    let fixture_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    let json_data = std::fs::read_to_string(fixture_path).expect("Failed to read GSI fixture");
+    serde_json::from_str(&json_data).expect("Failed to deserialize GSI fixture")
+}
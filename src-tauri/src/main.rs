@@ -1,5 +1,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    if std::env::args().any(|arg| arg == dota2_scripts::diagnostics::DOCTOR_FLAG) {
+        std::process::exit(dota2_scripts::diagnostics::run_cli());
+    }
+
     dota2_scripts_tauri::run();
 }
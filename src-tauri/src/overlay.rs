@@ -0,0 +1,171 @@
+//! Always-on-top cooldown overlay window (`[overlay]`).
+//!
+//! A second webview (`src-ui/overlay.html`) listens for `overlay_cooldowns`,
+//! which `events.rs` emits from the latest GSI event while the overlay is
+//! enabled. The window is transparent and, on Windows, click-through, so it
+//! is placed with `[overlay] x`/`y` rather than dragged.
+
+use crate::ipc_types::CooldownEntryDto;
+use crate::TauriAppState;
+use dota2_scripts::config::OverlayConfig;
+use dota2_scripts::models::GsiWebhookEvent;
+use dota2_scripts::state::poison::lock_or_recover;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, LogicalPosition, Manager, WebviewUrl, WebviewWindowBuilder};
+use tracing::{info, warn};
+
+pub const OVERLAY_WINDOW_LABEL: &str = "overlay";
+const OVERLAY_WIDTH: f64 = 220.0;
+const OVERLAY_HEIGHT: f64 = 320.0;
+
+/// Set while a spawned build of the overlay window is in flight, so a second
+/// sync before the window exists does not start another build.
+static OVERLAY_CREATING: AtomicBool = AtomicBool::new(false);
+
+/// Open, move or close the overlay window to match `config`.
+pub fn sync_overlay_window(app: &AppHandle, config: &OverlayConfig) {
+    let position = LogicalPosition::new(f64::from(config.x), f64::from(config.y));
+    match app.get_webview_window(OVERLAY_WINDOW_LABEL) {
+        Some(window) if config.enabled => {
+            if let Err(e) = window.set_position(position) {
+                warn!("Failed to move cooldown overlay: {}", e);
+            }
+        }
+        Some(window) => {
+            if let Err(e) = window.close() {
+                warn!("Failed to close cooldown overlay: {}", e);
+            }
+        }
+        None if config.enabled => {
+            if OVERLAY_CREATING.swap(true, Ordering::AcqRel) {
+                return;
+            }
+            // Window creation waits on the main thread, which runs sync
+            // commands such as `update_config`, so build it off that thread.
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = open_overlay_window(&app, position);
+                OVERLAY_CREATING.store(false, Ordering::Release);
+                match result {
+                    Ok(()) => {
+                        info!(
+                            "Cooldown overlay opened at ({}, {})",
+                            position.x, position.y
+                        );
+                        // A save that landed during the build was skipped
+                        // above; apply the current settings now.
+                        let current = lock_or_recover(&app.state::<TauriAppState>().settings)
+                            .overlay
+                            .clone();
+                        sync_overlay_window(&app, &current);
+                    }
+                    Err(e) => warn!("Failed to open cooldown overlay: {}", e),
+                }
+            });
+        }
+        None => {}
+    }
+}
+
+fn open_overlay_window(app: &AppHandle, position: LogicalPosition<f64>) -> tauri::Result<()> {
+    let builder = WebviewWindowBuilder::new(
+        app,
+        OVERLAY_WINDOW_LABEL,
+        WebviewUrl::App("overlay.html".into()),
+    )
+    .title("Cooldowns")
+    .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
+    .position(position.x, position.y)
+    .decorations(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .resizable(false)
+    .focused(false)
+    .shadow(false);
+    #[cfg(not(target_os = "macos"))]
+    let builder = builder.transparent(true);
+
+    let _window = builder.build()?;
+    #[cfg(windows)]
+    _window.set_ignore_cursor_events(true)?;
+    Ok(())
+}
+
+/// Overlay rows for `event`: castable inventory and neutral items, then
+/// (with `show_abilities`) learned non-passive abilities.
+pub fn build_cooldown_entries(
+    event: &GsiWebhookEvent,
+    show_abilities: bool,
+) -> Vec<CooldownEntryDto> {
+    let mut entries: Vec<CooldownEntryDto> = event
+        .items
+        .all_slots()
+        .into_iter()
+        .filter(|(_, item)| item.name != "empty" && !item.passive.unwrap_or(false))
+        .map(|(_, item)| CooldownEntryDto {
+            name: item
+                .name
+                .strip_prefix("item_")
+                .unwrap_or(&item.name)
+                .to_string(),
+            kind: "item".to_string(),
+            cooldown: item.cooldown.unwrap_or(0),
+            charges: item.charge_count(),
+        })
+        .collect();
+
+    if show_abilities {
+        entries.extend(
+            (0..6)
+                .filter_map(|index| event.abilities.get_by_index(index))
+                .filter(|ability| ability.level > 0 && !ability.passive && !ability.name.is_empty())
+                .map(|ability| CooldownEntryDto {
+                    name: ability.name.clone(),
+                    kind: "ability".to_string(),
+                    cooldown: ability.cooldown,
+                    charges: None,
+                }),
+        );
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_fixture_event;
+
+    #[test]
+    fn cooldown_entries_skip_empty_and_passive_slots() {
+        let mut event = load_fixture_event("huskar_event.json");
+        event.items.slot0.name = "item_black_king_bar".to_string();
+        event.items.slot0.passive = Some(false);
+        event.items.slot0.cooldown = Some(42);
+        event.items.slot1.name = "empty".to_string();
+        event.items.slot2.name = "item_vanguard".to_string();
+        event.items.slot2.passive = Some(true);
+
+        let entries = build_cooldown_entries(&event, false);
+
+        assert_eq!(
+            entries.first(),
+            Some(&CooldownEntryDto {
+                name: "black_king_bar".to_string(),
+                kind: "item".to_string(),
+                cooldown: 42,
+                charges: event.items.slot0.charge_count(),
+            })
+        );
+        assert!(entries.iter().all(|e| e.kind == "item"));
+        assert!(!entries
+            .iter()
+            .any(|e| e.name == "empty" || e.name == "vanguard"));
+
+        let with_abilities = build_cooldown_entries(&event, true);
+        assert!(with_abilities
+            .iter()
+            .filter(|e| e.kind == "ability")
+            .all(|e| !e.name.is_empty()));
+    }
+}
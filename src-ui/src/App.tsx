@@ -3,6 +3,11 @@ import { BrowserRouter, Routes, Route } from "react-router-dom";
 import { Sidebar } from "./components/layout/Sidebar";
 import { StatusHeader } from "./components/layout/StatusHeader";
 import { UpdateBanner } from "./components/layout/UpdateBanner";
+import { ElevationBanner } from "./components/layout/ElevationBanner";
+import { CrashBanner } from "./components/layout/CrashBanner";
+import { GsiListenerBanner } from "./components/layout/GsiListenerBanner";
+import { SafeModeBanner } from "./components/layout/SafeModeBanner";
+import { HotkeyChangeGuard } from "./components/layout/HotkeyChangeGuard";
 import { ActivityTicker } from "./components/layout/ActivityTicker";
 import { useRuneAlert } from "./hooks/useRuneAlert";
 import { useConfigStore } from "./stores/configStore";
@@ -24,16 +29,20 @@ import MinimapIntelligence from "./pages/MinimapIntelligence";
 export default function App() {
   useEffect(() => {
     useConfigStore.getState().loadConfig();
+    const configUnlistenPromise = useConfigStore.getState().startListening();
     useUIStore.getState().loadInitialState();
     const uiUnlistenPromise = useUIStore.getState().startListening();
     const gameUnlistenPromise = useGameStore.getState().startListening();
     const activityUnlistenPromise = useActivityStore.getState().startListening();
     useUpdateStore.getState().loadInitialState();
+    const stopUpdateWatch = useUpdateStore.getState().startWatching();
 
     return () => {
+      configUnlistenPromise.then((unlisten) => unlisten());
       uiUnlistenPromise.then((unlisten) => unlisten());
       gameUnlistenPromise.then((unlisten) => unlisten());
       activityUnlistenPromise.then((unlisten) => unlisten());
+      stopUpdateWatch();
     };
   }, []);
 
@@ -59,6 +68,8 @@ export default function App() {
           <StatusHeader
             heroName={game.heroName ?? undefined}
             heroLevel={game.heroLevel}
+            heroFacet={game.heroFacet}
+            heroTalents={game.heroTalents}
             hpPercent={game.hpPercent}
             manaPercent={game.manaPercent}
             inDanger={game.inDanger}
@@ -67,9 +78,14 @@ export default function App() {
             runeTimer={game.runeTimer}
             stunned={game.stunned}
             silenced={game.silenced}
+            broken={game.broken}
             alive={game.alive}
             respawnTimer={game.respawnTimer}
           />
+          <CrashBanner />
+          <GsiListenerBanner />
+          <SafeModeBanner />
+          <ElevationBanner />
           <UpdateBanner />
           <main className="flex-1 overflow-y-auto page-transition">
             <Routes>
@@ -87,6 +103,7 @@ export default function App() {
           </main>
           <ActivityTicker entries={tickerEntries} />
         </div>
+        <HotkeyChangeGuard />
       </div>
     </BrowserRouter>
   );
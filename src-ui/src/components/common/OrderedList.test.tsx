@@ -0,0 +1,25 @@
+import { render, screen } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { OrderedList } from "./OrderedList";
+
+describe("OrderedList", () => {
+  it("renders items in order", () => {
+    render(<OrderedList label="Order" items={["bkb", "satanic"]} onChange={() => {}} />);
+    const rows = screen.getAllByRole("listitem");
+    expect(rows[0]).toHaveTextContent("bkb");
+    expect(rows[1]).toHaveTextContent("satanic");
+  });
+
+  it("moves an item up", async () => {
+    const onChange = vi.fn();
+    render(<OrderedList label="Order" items={["bkb", "satanic", "ghost"]} onChange={onChange} />);
+    await userEvent.click(screen.getByRole("button", { name: "move ghost up" }));
+    expect(onChange).toHaveBeenCalledWith(["bkb", "ghost", "satanic"]);
+  });
+
+  it("disables moving past the ends", () => {
+    render(<OrderedList label="Order" items={["bkb", "satanic"]} onChange={() => {}} />);
+    expect(screen.getByRole("button", { name: "move bkb up" })).toBeDisabled();
+    expect(screen.getByRole("button", { name: "move satanic down" })).toBeDisabled();
+  });
+});
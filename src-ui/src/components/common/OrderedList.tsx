@@ -0,0 +1,58 @@
+import { ChevronDown, ChevronUp } from "lucide-react";
+
+interface OrderedListProps {
+  label: string;
+  items: string[];
+  onChange: (items: string[]) => void;
+  format?: (item: string) => string;
+  disabled?: boolean;
+}
+
+export function OrderedList({ label, items, onChange, format = (item) => item, disabled = false }: OrderedListProps) {
+  const move = (index: number, offset: number) => {
+    const target = index + offset;
+    if (target < 0 || target >= items.length) return;
+    const next = [...items];
+    [next[index], next[target]] = [next[target], next[index]];
+    onChange(next);
+  };
+
+  return (
+    <div className="space-y-2">
+      <label className="text-xs text-subtle">{label}</label>
+      <ol className="space-y-1">
+        {items.map((item, i) => (
+          <li
+            key={item}
+            className="flex items-center gap-2 rounded-md border border-border bg-elevated px-2.5 py-1 text-xs text-content"
+          >
+            <span className="w-4 text-muted">{i + 1}.</span>
+            <span className="flex-1">{format(item)}</span>
+            {!disabled && (
+              <>
+                <button
+                  type="button"
+                  onClick={() => move(i, -1)}
+                  disabled={i === 0}
+                  aria-label={`move ${item} up`}
+                  className="rounded p-0.5 text-muted hover:text-content disabled:opacity-30"
+                >
+                  <ChevronUp className="h-3 w-3" />
+                </button>
+                <button
+                  type="button"
+                  onClick={() => move(i, 1)}
+                  disabled={i === items.length - 1}
+                  aria-label={`move ${item} down`}
+                  className="rounded p-0.5 text-muted hover:text-content disabled:opacity-30"
+                >
+                  <ChevronDown className="h-3 w-3" />
+                </button>
+              </>
+            )}
+          </li>
+        ))}
+      </ol>
+    </div>
+  );
+}
@@ -5,6 +5,7 @@ export { Slider } from "./Slider";
 export { NumberInput } from "./NumberInput";
 export { KeyInput } from "./KeyInput";
 export { TagList } from "./TagList";
+export { OrderedList } from "./OrderedList";
 export { HPBar } from "./HPBar";
 export { ManaBar } from "./ManaBar";
 export { DangerBadge } from "./DangerBadge";
@@ -0,0 +1,26 @@
+import { Dropdown } from "../common/Dropdown";
+import type { AttackMove } from "../../types/config";
+
+// Mirrors AttackMove in src/config/settings.rs.
+const OPTIONS: { value: AttackMove; label: string }[] = [
+  { value: "off", label: "Off" },
+  { value: "before", label: "Before combo" },
+  { value: "after", label: "After combo" },
+];
+
+interface AttackMoveDropdownProps {
+  value: AttackMove;
+  onChange: (value: AttackMove) => void;
+}
+
+/** Attack-move at the cursor (Attack key + left-click) around the standalone combo. */
+export function AttackMoveDropdown({ value, onChange }: AttackMoveDropdownProps) {
+  return (
+    <Dropdown
+      label="Attack-Move"
+      value={value}
+      options={OPTIONS}
+      onChange={(v) => onChange(v as AttackMove)}
+    />
+  );
+}
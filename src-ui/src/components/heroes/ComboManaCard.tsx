@@ -0,0 +1,40 @@
+import { Card } from "../common/Card";
+import { NumberInput } from "../common/NumberInput";
+
+interface ComboManaCardProps {
+  abilities: { name: string; label: string }[];
+  costs: Record<string, number>;
+  onChange: (costs: Record<string, number>) => void;
+}
+
+/** Per-ability mana costs for the pre-combo mana check; 0 falls back to GSI inference. */
+export function ComboManaCard({ abilities, costs, onChange }: ComboManaCardProps) {
+  const setCost = (name: string, cost: number) => {
+    const next = { ...costs };
+    if (cost > 0) {
+      next[name] = cost;
+    } else {
+      delete next[name];
+    }
+    onChange(next);
+  };
+
+  return (
+    <Card title="Mana Check" collapsible>
+      <p className="text-xs text-muted">
+        The combo is skipped with a &quot;not enough mana&quot; warning when the ready abilities cost more than your
+        mana (plus Soul Ring). Leave a cost at 0 to rely on Dota reporting the ability uncastable.
+      </p>
+      {abilities.map(({ name, label }) => (
+        <NumberInput
+          key={name}
+          label={label}
+          value={costs[name] ?? 0}
+          onChange={(v) => setCost(name, v)}
+          min={0}
+          suffix="mana"
+        />
+      ))}
+    </Card>
+  );
+}
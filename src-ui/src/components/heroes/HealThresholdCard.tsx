@@ -0,0 +1,36 @@
+import { Card } from "../common/Card";
+import { NumberInput } from "../common/NumberInput";
+import { Toggle } from "../common/Toggle";
+import { useConfigStore } from "../../stores/configStore";
+import type { HeroType } from "../../types/game";
+
+/** Per-hero `survivability_hp_threshold_override`; unset falls back to `[common]`. */
+export function HealThresholdCard({ hero }: { hero: HeroType }) {
+  const override = useConfigStore((s) => s.config.heroes[hero].survivability_hp_threshold_override);
+  const common = useConfigStore((s) => s.config.common.survivability_hp_threshold);
+  const update = useConfigStore((s) => s.updateHeroConfig);
+
+  return (
+    <Card title="Heal Threshold" collapsible>
+      <Toggle
+        label="Override Common Threshold"
+        checked={override != null}
+        onChange={(v) => update(hero, { survivability_hp_threshold_override: v ? common : null })}
+      />
+      {override != null && (
+        <NumberInput
+          label="Heal Below"
+          value={override}
+          onChange={(v) => update(hero, { survivability_hp_threshold_override: v })}
+          min={0}
+          max={100}
+          suffix="%"
+        />
+      )}
+      <p className="text-xs text-muted">
+        Out of danger, healing items fire below this HP instead of the {common}% common threshold. Lane phase and
+        in-danger thresholds still apply.
+      </p>
+    </Card>
+  );
+}
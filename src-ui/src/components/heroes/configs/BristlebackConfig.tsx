@@ -0,0 +1,49 @@
+import { Card } from "../../common/Card";
+import { Toggle } from "../../common/Toggle";
+import { Slider } from "../../common/Slider";
+import { NumberInput } from "../../common/NumberInput";
+import { KeyInput } from "../../common/KeyInput";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
+import { useConfigStore } from "../../../stores/configStore";
+
+export default function BristlebackConfig() {
+  const config = useConfigStore((s) => s.config.heroes.bristleback);
+  const update = useConfigStore((s) => s.updateHeroConfig);
+  const set = (updates: Partial<typeof config>) => update("bristleback", updates);
+
+  return (
+    <>
+      <div className="space-y-4">
+        <Card title="Standalone Combo">
+          <KeyInput label="Standalone Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+          <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
+          <KeyInput label="Viscous Nasal Goo Key" value={config.viscous_nasal_goo_key} onChange={(v) => set({ viscous_nasal_goo_key: v })} />
+          <p className="text-xs text-muted">
+            Casts Viscous Nasal Goo and Quill Spray while either is ready. Goo is quick-cast at the unit under the cursor.
+          </p>
+        </Card>
+
+        <Card title="Quill Spray">
+          <Toggle label="Auto Quill Spray" checked={config.auto_quill_spray} onChange={(v) => set({ auto_quill_spray: v })} />
+          <KeyInput label="Quill Spray Key" value={config.quill_spray_key} onChange={(v) => set({ quill_spray_key: v })} />
+          <Slider label="Mana Floor" value={config.quill_min_mana_percent} min={0} max={100} onChange={(v) => set({ quill_min_mana_percent: v })} suffix="%" />
+          <NumberInput label="Combat Window" value={config.quill_combat_window_ms} onChange={(v) => set({ quill_combat_window_ms: v })} min={0} max={10000} suffix="ms" />
+          <p className="text-xs text-muted">
+            Casts Quill Spray whenever it is ready to keep Warpath stacked, but only within the combat window of
+            losing HP or right-clicking / pressing the attack key (0 = always). Holds while smoked and while
+            running home, so quills do not give away a retreat.
+          </p>
+        </Card>
+      </div>
+
+      <div className="space-y-4">
+        <Card title="Armlet Override" collapsible>
+          <p className="text-xs text-muted">
+            Configure armlet override thresholds on the Armlet page.
+          </p>
+        </Card>
+      </div>
+    </>
+  );
+}
@@ -1,7 +1,9 @@
 import { Card } from "../../common/Card";
 import { Toggle } from "../../common/Toggle";
 import { KeyInput } from "../../common/KeyInput";
+import { NumberInput } from "../../common/NumberInput";
 import { TagList } from "../../common/TagList";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
 import { useConfigStore } from "../../../stores/configStore";
 
 export default function BroodmotherConfig() {
@@ -17,6 +19,8 @@ export default function BroodmotherConfig() {
           <KeyInput label="Spider Control Group Key" value={config.spider_control_group_key} onChange={(v) => set({ spider_control_group_key: v })} />
           <KeyInput label="Reselect Hero Key" value={config.reselect_hero_key} onChange={(v) => set({ reselect_hero_key: v })} />
           <KeyInput label="Standalone Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+          <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
         </Card>
 
         <Card title="Auto-Items (Space+Right-Click)">
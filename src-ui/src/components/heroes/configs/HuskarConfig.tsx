@@ -21,11 +21,29 @@ export default function HuskarConfig() {
       <div className="space-y-4">
         <Card title="Keybindings">
           <KeyInput label="Standalone Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
           <KeyInput label="Berserker Blood Key" value={config.berserker_blood_key} onChange={(v) => set({ berserker_blood_key: v })} />
         </Card>
 
         <Card title="Berserker Blood">
           <NumberInput label="Cleanse Delay" value={config.berserker_blood_delay_ms} onChange={(v) => set({ berserker_blood_delay_ms: v })} suffix="ms" />
+          <Toggle
+            label="Re-arm While Still Debuffed"
+            checked={config.berserker_blood_rearm}
+            onChange={(value) => set({ berserker_blood_rearm: value })}
+          />
+          <Toggle
+            label="Only Cleanse After a Disable"
+            checked={config.berserker_blood_require_disable}
+            onChange={(value) => set({ berserker_blood_require_disable: value })}
+          />
+          <NumberInput
+            label="Armlet Threshold Bonus While Broken"
+            value={config.break_armlet_threshold_bonus}
+            onChange={(v) => set({ break_armlet_threshold_bonus: v })}
+            min={0}
+            suffix="HP"
+          />
         </Card>
 
         <Card title="Roshan Spears">
@@ -1,24 +1,65 @@
 import { Card } from "../../common/Card";
 import { KeyInput } from "../../common/KeyInput";
+import { NumberInput } from "../../common/NumberInput";
+import { TagList } from "../../common/TagList";
+import { Toggle } from "../../common/Toggle";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
+import { ComboManaCard } from "../ComboManaCard";
 import { useConfigStore } from "../../../stores/configStore";
+import { useGameStore } from "../../../stores/gameStore";
+
+const FACETS = ["1", "2"];
 
 export default function LegionCommanderConfig() {
   const config = useConfigStore((s) => s.config.heroes.legion_commander);
   const update = useConfigStore((s) => s.updateHeroConfig);
   const set = (updates: Partial<typeof config>) => update("legion_commander", updates);
+  const detectedFacet = useGameStore((s) => s.game.heroFacet);
+
+  const setFacetItems = (facet: string, items: string[]) => {
+    const next = { ...config.facet_self_buff_items };
+    if (items.length > 0) {
+      next[facet] = items;
+    } else {
+      delete next[facet];
+    }
+    set({ facet_self_buff_items: next });
+  };
 
   return (
     <>
       <div className="space-y-4">
         <Card title="Keybindings">
           <KeyInput label="Standalone Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+          <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
+          <Toggle label="BKB Right Before Duel" checked={config.auto_bkb_before_duel} onChange={(v) => set({ auto_bkb_before_duel: v })} />
+          <NumberInput label="Post-Blink Delay" value={config.post_blink_delay_ms} onChange={(v) => set({ post_blink_delay_ms: v })} min={0} suffix="ms" />
+        </Card>
+
+        <Card title="Facet Item Order">
+          <p className="text-xs text-muted">
+            Replaces Blade Mail → Mjollnir → BKB for the given facet. Leave empty to keep the default order.
+            {detectedFacet > 0 && ` Detected facet: ${detectedFacet}.`}
+          </p>
+          {FACETS.map((facet) => (
+            <TagList
+              key={facet}
+              label={`Facet ${facet}`}
+              items={config.facet_self_buff_items[facet] ?? []}
+              onChange={(items) => setFacetItems(facet, items)}
+            />
+          ))}
         </Card>
 
         <Card title="Combo Sequence">
           <div className="space-y-1 text-xs text-subtle">
             <p className="font-medium text-content">Combo Order:</p>
             <div className="flex flex-wrap gap-1">
-              {["W (Press The Attack)", "Blade Mail", "Mjollnir", "BKB", "Blink", "Orchid/Bloodthorn", "R (Duel)", "Q (Overwhelming Odds)"].map((step, i) => (
+              {(config.auto_bkb_before_duel
+                ? ["W (Press The Attack)", "Blade Mail", "Mjollnir", "Blink", "Orchid/Bloodthorn", "BKB", "R (Duel)", "Q (Overwhelming Odds)"]
+                : ["W (Press The Attack)", "Blade Mail", "Mjollnir", "BKB", "Blink", "Orchid/Bloodthorn", "R (Duel)", "Q (Overwhelming Odds)"]
+              ).map((step, i) => (
                 <span key={i} className="rounded bg-elevated px-2 py-0.5 font-mono">
                   {i > 0 && "→ "}{step}
                 </span>
@@ -30,6 +71,15 @@ export default function LegionCommanderConfig() {
       </div>
 
       <div className="space-y-4">
+        <ComboManaCard
+          abilities={[
+            { name: "legion_commander_press_the_attack", label: "Press The Attack" },
+            { name: "legion_commander_duel", label: "Duel" },
+            { name: "legion_commander_overwhelming_odds", label: "Overwhelming Odds" },
+          ]}
+          costs={config.combo_mana_costs}
+          onChange={(v) => set({ combo_mana_costs: v })}
+        />
         <Card title="Armlet Override" collapsible>
           <p className="text-xs text-muted">
             Configure armlet override thresholds on the Armlet page.
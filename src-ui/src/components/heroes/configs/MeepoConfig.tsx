@@ -5,6 +5,7 @@ import { Slider } from "../../common/Slider";
 import { NumberInput } from "../../common/NumberInput";
 import { KeyInput } from "../../common/KeyInput";
 import { TagList } from "../../common/TagList";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
 import { useConfigStore } from "../../../stores/configStore";
 import { isTauri } from "../../../lib/tauri";
 import type { MeepoObservedState } from "../../../types/game";
@@ -112,6 +113,8 @@ export default function MeepoConfig() {
         <Card title="Keybindings">
           <div className="grid grid-cols-2 gap-3">
             <KeyInput label="Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+            <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+            <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
             <KeyInput label="Earthbind" value={config.earthbind_key} onChange={(v) => set({ earthbind_key: v })} />
             <KeyInput label="Poof" value={config.poof_key} onChange={(v) => set({ poof_key: v })} />
             <KeyInput label="Dig" value={config.dig_key} onChange={(v) => set({ dig_key: v })} />
@@ -130,6 +133,7 @@ export default function MeepoConfig() {
             <NumberInput label="Poof Presses" value={config.poof_press_count} onChange={(v) => set({ poof_press_count: v })} />
             <NumberInput label="Poof Interval" value={config.poof_press_interval_ms} onChange={(v) => set({ poof_press_interval_ms: v })} suffix="ms" />
           </div>
+          <Toggle label="BKB Before Poof" checked={config.auto_bkb_before_poof} onChange={(v) => set({ auto_bkb_before_poof: v })} />
         </Card>
       </div>
 
@@ -4,6 +4,7 @@ import { Slider } from "../../common/Slider";
 import { NumberInput } from "../../common/NumberInput";
 import { KeyInput } from "../../common/KeyInput";
 import { TagList } from "../../common/TagList";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
 import { useConfigStore } from "../../../stores/configStore";
 
 export default function OutworldDestroyerConfig() {
@@ -17,6 +18,8 @@ export default function OutworldDestroyerConfig() {
         <Card title="Keybindings">
           <div className="grid grid-cols-2 gap-3">
             <KeyInput label="Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+            <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+            <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
             <KeyInput label="Objurgation" value={config.objurgation_key} onChange={(v) => set({ objurgation_key: v })} />
             <KeyInput label="Arcane Orb" value={config.arcane_orb_key} onChange={(v) => set({ arcane_orb_key: v })} />
             <KeyInput label="Astral Imprisonment" value={config.astral_imprisonment_key} onChange={(v) => set({ astral_imprisonment_key: v })} />
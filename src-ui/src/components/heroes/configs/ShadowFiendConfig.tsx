@@ -1,6 +1,7 @@
 import { Card } from "../../common/Card";
 import { Toggle } from "../../common/Toggle";
 import { NumberInput } from "../../common/NumberInput";
+import { KeyInput } from "../../common/KeyInput";
 import { useConfigStore } from "../../../stores/configStore";
 
 export default function ShadowFiendConfig() {
@@ -14,8 +15,14 @@ export default function ShadowFiendConfig() {
         <Card title="Raze Intercept">
           <Toggle label="Enable Raze Intercept" checked={config.raze_intercept_enabled} onChange={(v) => set({ raze_intercept_enabled: v })} />
           <NumberInput label="Raze Delay" value={config.raze_delay_ms} onChange={(v) => set({ raze_delay_ms: v })} suffix="ms" />
+          <Toggle label="Cast on Key Release" checked={config.cast_on_key_release} onChange={(v) => set({ cast_on_key_release: v })} />
+          <div className="grid grid-cols-3 gap-3">
+            <KeyInput label="Close Raze" value={config.close_raze_key} onChange={(v) => set({ close_raze_key: v })} />
+            <KeyInput label="Medium Raze" value={config.medium_raze_key} onChange={(v) => set({ medium_raze_key: v })} />
+            <KeyInput label="Far Raze" value={config.far_raze_key} onChange={(v) => set({ far_raze_key: v })} />
+          </div>
           <p className="text-xs text-muted">
-            Intercepts Q/W/E to face cursor direction before razing.
+            Intercepts {[config.close_raze_key, config.medium_raze_key, config.far_raze_key].join("/").toUpperCase()} to face cursor direction before razing{config.cast_on_key_release ? "; hold to aim, release to raze" : ""}.
           </p>
         </Card>
       </div>
@@ -1,24 +1,113 @@
+import { useEffect, useState } from "react";
 import { Card } from "../../common/Card";
+import { Dropdown } from "../../common/Dropdown";
 import { KeyInput } from "../../common/KeyInput";
+import { NumberInput } from "../../common/NumberInput";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
+import { ComboManaCard } from "../ComboManaCard";
 import { useConfigStore } from "../../../stores/configStore";
+import { isTauri } from "../../../lib/tauri";
+import type { TinyConfig as TinyConfigType } from "../../../types/config";
+
+type TinyComboTiming = Pick<
+  TinyConfigType,
+  "toss_catch_window_ms" | "avalanche_spam_count" | "toss_spam_count" | "post_blink_delay_ms"
+>;
 
 export default function TinyConfig() {
   const config = useConfigStore((s) => s.config.heroes.tiny);
   const update = useConfigStore((s) => s.updateHeroConfig);
   const set = (updates: Partial<typeof config>) => update("tiny", updates);
 
+  // Preset values live in TINY_TIMING_PRESETS in src/config/settings.rs.
+  const [presets, setPresets] = useState<Record<string, TinyComboTiming>>({});
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    import("@tauri-apps/api/core")
+      .then(({ invoke }) => invoke<Record<string, TinyComboTiming>>("get_tiny_timing_presets"))
+      .then(setPresets)
+      .catch((e) => console.error("Failed to load Tiny timing presets:", e));
+  }, []);
+
+  const isCustom = config.timing_preset === "custom";
+  const timing = presets[config.timing_preset] ?? config;
+
   return (
     <>
       <div className="space-y-4">
         <Card title="Keybindings">
           <KeyInput label="Standalone Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+          <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
+        </Card>
+
+        <Card title="Combo Timing">
+          <Dropdown
+            label="Timing Preset"
+            value={config.timing_preset}
+            options={[
+              { value: "low_ping", label: "Low ping" },
+              { value: "medium_ping", label: "Medium ping" },
+              { value: "high_ping", label: "High ping" },
+              { value: "custom", label: "Custom" },
+            ]}
+            onChange={(v) => set({ timing_preset: v as TinyConfigType["timing_preset"] })}
+          />
+          <NumberInput
+            label="Post-Blink Delay"
+            value={timing.post_blink_delay_ms}
+            onChange={(v) => set({ post_blink_delay_ms: v })}
+            suffix="ms"
+            disabled={!isCustom}
+          />
+          <NumberInput
+            label="Toss Catch Window"
+            value={timing.toss_catch_window_ms}
+            onChange={(v) => set({ toss_catch_window_ms: v })}
+            suffix="ms"
+            disabled={!isCustom}
+          />
+          <div className="grid grid-cols-2 gap-3">
+            <NumberInput
+              label="Avalanche Presses"
+              value={timing.avalanche_spam_count}
+              onChange={(v) => set({ avalanche_spam_count: v })}
+              disabled={!isCustom}
+            />
+            <NumberInput
+              label="Toss Presses"
+              value={timing.toss_spam_count}
+              onChange={(v) => set({ toss_spam_count: v })}
+              disabled={!isCustom}
+            />
+          </div>
+          <NumberInput
+            label="Extra Toss Talent Slot"
+            value={config.extra_toss_talent}
+            onChange={(v) => set({ extra_toss_talent: v })}
+            min={0}
+            max={8}
+          />
+          <p className="text-xs text-muted">
+            Toss flies for about 1.3s. Keep the catch window longer than that so the target lands in
+            Avalanche before Tree Grab; a shorter window wastes the combo. Set the talent slot (1-8, 0 = off)
+            of Tiny&apos;s extra Toss charge talent to toss twice once it is picked.
+          </p>
         </Card>
 
         <Card title="Combo Sequence">
           <div className="space-y-1 text-xs text-subtle">
             <p className="font-medium text-content">Combo Order:</p>
             <div className="flex flex-wrap gap-1">
-              {["Blink", "Avalanche (W + Soul Ring)", "W ×3", "Toss (Q) ×4", "Tree Grab (D) ×3"].map((step, i) => (
+              {[
+                `Blink, wait ${timing.post_blink_delay_ms}ms`,
+                "Avalanche (W + Soul Ring)",
+                `W ×${timing.avalanche_spam_count}`,
+                `Toss (Q) ×${timing.toss_spam_count}${config.extra_toss_talent > 0 ? " (×2 with talent)" : ""}`,
+                `Wait ${timing.toss_catch_window_ms}ms`,
+                "Tree Grab (D) ×3",
+              ].map((step, i) => (
                 <span key={i} className="rounded bg-elevated px-2 py-0.5 font-mono">
                   {i > 0 && "→ "}{step}
                 </span>
@@ -30,6 +119,14 @@ export default function TinyConfig() {
       </div>
 
       <div className="space-y-4">
+        <ComboManaCard
+          abilities={[
+            { name: "tiny_avalanche", label: "Avalanche" },
+            { name: "tiny_toss", label: "Toss" },
+          ]}
+          costs={config.combo_mana_costs}
+          onChange={(v) => set({ combo_mana_costs: v })}
+        />
         <Card title="Armlet Override" collapsible>
           <p className="text-xs text-muted">
             Configure armlet override thresholds on the Armlet page.
@@ -39,4 +136,3 @@ export default function TinyConfig() {
     </>
   );
 }
-
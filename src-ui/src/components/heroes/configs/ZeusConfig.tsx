@@ -0,0 +1,58 @@
+import { Card } from "../../common/Card";
+import { Toggle } from "../../common/Toggle";
+import { NumberInput } from "../../common/NumberInput";
+import { KeyInput } from "../../common/KeyInput";
+import { AttackMoveDropdown } from "../AttackMoveDropdown";
+import { useConfigStore } from "../../../stores/configStore";
+
+export default function ZeusConfig() {
+  const config = useConfigStore((s) => s.config.heroes.zeus);
+  const update = useConfigStore((s) => s.updateHeroConfig);
+  const set = (updates: Partial<typeof config>) => update("zeus", updates);
+
+  return (
+    <>
+      <div className="space-y-4">
+        <Card title="Keybindings">
+          <KeyInput label="Thundergod's Wrath Combo Key" value={config.standalone_key} onChange={(v) => set({ standalone_key: v })} />
+          <NumberInput label="Retrigger Cooldown" value={config.combo_retrigger_cooldown_ms} onChange={(v) => set({ combo_retrigger_cooldown_ms: v })} min={0} max={5000} suffix="ms" />
+          <AttackMoveDropdown value={config.attack_move} onChange={(v) => set({ attack_move: v })} />
+          <div className="grid grid-cols-3 gap-3">
+            <KeyInput label="Arc Lightning" value={config.arc_lightning_key} onChange={(v) => set({ arc_lightning_key: v })} />
+            <KeyInput label="Lightning Bolt" value={config.lightning_bolt_key} onChange={(v) => set({ lightning_bolt_key: v })} />
+            <KeyInput label="Thundergod's Wrath" value={config.thundergods_wrath_key} onChange={(v) => set({ thundergods_wrath_key: v })} />
+          </div>
+        </Card>
+
+        <Card title="Spam Combo">
+          <Toggle label="Spam While Attacking" checked={config.auto_spam_while_attacking} onChange={(v) => set({ auto_spam_while_attacking: v })} />
+          <NumberInput label="Attack Window" value={config.attack_window_ms} onChange={(v) => set({ attack_window_ms: v })} min={100} max={5000} suffix="ms" />
+          <Toggle label="Spam Lightning Bolt" checked={config.spam_lightning_bolt} onChange={(v) => set({ spam_lightning_bolt: v })} />
+          <NumberInput label="Mana Reserve" value={config.mana_reserve} onChange={(v) => set({ mana_reserve: v })} min={0} />
+          <p className="text-xs text-muted">
+            Casts Arc Lightning on cooldown while you right-click or press the attack key within the attack window,
+            plus Lightning Bolt when enabled. The combo key casts Thundergod&apos;s Wrath, then the spam: GSI does not
+            report enemy HP, so press it when enemies are low. The spam stops at or below the mana reserve (0 = off)
+            so Wrath can still be cast. Arc Lightning and Lightning Bolt need quick-cast enabled in Dota.
+          </p>
+        </Card>
+
+        <Card title="Randomization" collapsible>
+          <NumberInput label="Timing Jitter" value={config.randomization.jitter_ms} onChange={(v) => set({ randomization: { ...config.randomization, jitter_ms: v } })} min={0} suffix="ms" />
+          <Toggle label="Shuffle Bolt and Arc" checked={config.randomization.shuffle_unordered} onChange={(v) => set({ randomization: { ...config.randomization, shuffle_unordered: v } })} />
+          <p className="text-xs text-muted">
+            Varies each spam so casts are not evenly spaced, and casts Lightning Bolt or Arc Lightning first at random when both are ready.
+          </p>
+        </Card>
+      </div>
+
+      <div className="space-y-4">
+        <Card title="Armlet Override" collapsible>
+          <p className="text-xs text-muted">
+            Configure armlet override thresholds on the Armlet page.
+          </p>
+        </Card>
+      </div>
+    </>
+  );
+}
@@ -3,6 +3,7 @@ import type { ComponentType } from "react";
 
 const configs: Record<HeroType, () => Promise<{ default: ComponentType }>> = {
   meepo: () => import("./MeepoConfig"),
+  bristleback: () => import("./BristlebackConfig"),
   broodmother: () => import("./BroodmotherConfig"),
   huskar: () => import("./HuskarConfig"),
   largo: () => import("./LargoConfig"),
@@ -10,6 +11,7 @@ const configs: Record<HeroType, () => Promise<{ default: ComponentType }>> = {
   outworld_destroyer: () => import("./OutworldDestroyerConfig"),
   shadow_fiend: () => import("./ShadowFiendConfig"),
   tiny: () => import("./TinyConfig"),
+  zeus: () => import("./ZeusConfig"),
 };
 
 export default configs;
@@ -0,0 +1,33 @@
+import { act, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { CrashBanner } from "./CrashBanner";
+import { useUIStore } from "../../stores/uiStore";
+
+describe("CrashBanner", () => {
+  afterEach(() => {
+    act(() => {
+      useUIStore.setState({ crashes: [] });
+    });
+  });
+
+  it("names each crashed subsystem once", () => {
+    act(() => {
+      useUIStore.setState({
+        crashes: [
+          { subsystem: "largo-beat-worker", message: "poisoned", location: "src/actions/heroes/largo.rs:300" },
+          { subsystem: "gsi::handler", message: "boom", location: "src/gsi/handler.rs:250" },
+          { subsystem: "largo-beat-worker", message: "again", location: "src/actions/heroes/largo.rs:300" },
+        ],
+      });
+    });
+
+    render(<CrashBanner />);
+
+    expect(screen.getByText(/largo-beat-worker, gsi::handler crashed/)).toBeInTheDocument();
+  });
+
+  it("stays hidden until something crashes", () => {
+    render(<CrashBanner />);
+    expect(screen.queryByText(/crashed/)).not.toBeInTheDocument();
+  });
+});
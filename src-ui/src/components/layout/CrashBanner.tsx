@@ -0,0 +1,19 @@
+import { useUIStore } from "../../stores/uiStore";
+
+export function CrashBanner() {
+  const crashes = useUIStore((s) => s.crashes);
+
+  if (crashes.length === 0) return null;
+
+  const subsystems = [...new Set(crashes.map((crash) => crash.subsystem))].join(", ");
+  const latest = crashes[crashes.length - 1];
+
+  return (
+    <div className="border-b border-danger bg-danger/15 px-4 py-2" title={`${latest.location}: ${latest.message}`}>
+      <span className="text-sm font-medium text-danger">
+        💥 {subsystems} crashed and stopped — restart the app. A panic report was written to the logs folder;
+        please attach it to a bug report.
+      </span>
+    </div>
+  );
+}
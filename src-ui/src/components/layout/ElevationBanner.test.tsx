@@ -0,0 +1,39 @@
+import { act, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { ElevationBanner } from "./ElevationBanner";
+import { useUIStore } from "../../stores/uiStore";
+
+describe("ElevationBanner", () => {
+  afterEach(() => {
+    act(() => {
+      useUIStore.setState({ elevation: null });
+    });
+  });
+
+  it("warns with a relaunch button when the app is not elevated", () => {
+    act(() => {
+      useUIStore.setState({ elevation: { supported: true, elevated: false } });
+    });
+
+    render(<ElevationBanner />);
+
+    expect(screen.getByText(/Not running as administrator/i)).toBeInTheDocument();
+    expect(
+      screen.getByRole("button", { name: /Relaunch as Administrator/i }),
+    ).toBeInTheDocument();
+  });
+
+  it("stays hidden when elevated or when elevation does not apply", () => {
+    act(() => {
+      useUIStore.setState({ elevation: { supported: true, elevated: true } });
+    });
+    const { rerender } = render(<ElevationBanner />);
+    expect(screen.queryByText(/Not running as administrator/i)).not.toBeInTheDocument();
+
+    act(() => {
+      useUIStore.setState({ elevation: { supported: false, elevated: false } });
+    });
+    rerender(<ElevationBanner />);
+    expect(screen.queryByText(/Not running as administrator/i)).not.toBeInTheDocument();
+  });
+});
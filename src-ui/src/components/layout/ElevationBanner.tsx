@@ -0,0 +1,21 @@
+import { useUIStore } from "../../stores/uiStore";
+import { Button } from "../common/Button";
+
+export function ElevationBanner() {
+  const elevation = useUIStore((s) => s.elevation);
+  const relaunchElevated = useUIStore((s) => s.relaunchElevated);
+
+  if (!elevation || !elevation.supported || elevation.elevated) return null;
+
+  return (
+    <div className="flex items-center justify-between gap-4 border-b border-danger bg-danger/15 px-4 py-2">
+      <span className="text-sm font-medium text-danger">
+        ⚠️ Not running as administrator — keys sent to Dota 2 may be ignored if the game runs
+        elevated.
+      </span>
+      <Button variant="danger" onClick={relaunchElevated} className="h-7 shrink-0 px-3 text-xs">
+        Relaunch as Administrator
+      </Button>
+    </div>
+  );
+}
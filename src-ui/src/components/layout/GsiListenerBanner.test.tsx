@@ -0,0 +1,41 @@
+import { act, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { GsiListenerBanner } from "./GsiListenerBanner";
+import { useUIStore } from "../../stores/uiStore";
+
+describe("GsiListenerBanner", () => {
+  afterEach(() => {
+    act(() => {
+      useUIStore.setState({ gsiListener: null });
+    });
+  });
+
+  it("explains a taken port and offers the free one", () => {
+    act(() => {
+      useUIStore.setState({
+        gsiListener: {
+          address: null,
+          error: "Port 3000 is already in use. Is another instance of the app running?",
+          suggestedPort: 3001,
+        },
+      });
+    });
+
+    render(<GsiListenerBanner />);
+
+    expect(screen.getByText(/Port 3000 is already in use/)).toBeInTheDocument();
+    expect(screen.getByRole("button", { name: "Use Port 3001" })).toBeInTheDocument();
+  });
+
+  it("stays hidden while the server is listening", () => {
+    act(() => {
+      useUIStore.setState({
+        gsiListener: { address: "127.0.0.1:3000", error: null, suggestedPort: null },
+      });
+    });
+
+    render(<GsiListenerBanner />);
+
+    expect(screen.queryByText(/GSI server not running/)).not.toBeInTheDocument();
+  });
+});
@@ -0,0 +1,33 @@
+import { useState } from "react";
+import { useUIStore } from "../../stores/uiStore";
+import { Button } from "../common/Button";
+
+export function GsiListenerBanner() {
+  const gsiListener = useUIStore((s) => s.gsiListener);
+  const restartGsiServer = useUIStore((s) => s.restartGsiServer);
+  const [retryError, setRetryError] = useState<string | null>(null);
+
+  if (!gsiListener || gsiListener.address || !gsiListener.error) return null;
+
+  const suggestedPort = gsiListener.suggestedPort;
+
+  return (
+    <div className="flex items-center justify-between gap-4 border-b border-danger bg-danger/15 px-4 py-2">
+      <span className="text-sm font-medium text-danger">
+        ⚠️ GSI server not running: {gsiListener.error} Change the GSI port in Settings → Server
+        {suggestedPort !== null && ` or switch to free port ${suggestedPort}`}, then update the port in
+        Dota's GSI cfg file.
+        {retryError && ` (${retryError})`}
+      </span>
+      {suggestedPort !== null && (
+        <Button
+          variant="danger"
+          onClick={async () => setRetryError(await restartGsiServer(suggestedPort))}
+          className="h-7 shrink-0 px-3 text-xs"
+        >
+          Use Port {suggestedPort}
+        </Button>
+      )}
+    </div>
+  );
+}
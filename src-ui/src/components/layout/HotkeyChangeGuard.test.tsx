@@ -0,0 +1,63 @@
+import { act, fireEvent, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { HotkeyChangeGuard } from "./HotkeyChangeGuard";
+import { useConfigStore } from "../../stores/configStore";
+import { useGameStore } from "../../stores/gameStore";
+
+function setGameInProgress(gameInProgress: boolean) {
+  act(() => {
+    useGameStore.getState().setGame({ gameInProgress });
+  });
+}
+
+describe("HotkeyChangeGuard", () => {
+  afterEach(() => {
+    setGameInProgress(false);
+    act(() => {
+      useConfigStore.getState().cancelHotkeyChange();
+      useConfigStore.getState().updateConfig("keybindings", { slot0: "z" });
+      useConfigStore.getState().updateConfig("overlay", { enabled: false });
+    });
+  });
+
+  it("saves keybindings silently outside a match", () => {
+    render(<HotkeyChangeGuard />);
+    act(() => {
+      useConfigStore.getState().updateConfig("keybindings", { slot0: "1" });
+    });
+
+    expect(screen.queryByRole("dialog")).not.toBeInTheDocument();
+    expect(useConfigStore.getState().config.keybindings.slot0).toBe("1");
+  });
+
+  it("holds keybinding edits until confirmed while a match is in progress", () => {
+    setGameInProgress(true);
+    render(<HotkeyChangeGuard />);
+    act(() => {
+      useConfigStore.getState().updateConfig("keybindings", { slot0: "1" });
+    });
+
+    expect(screen.getByRole("dialog")).toBeInTheDocument();
+    expect(useConfigStore.getState().config.keybindings.slot0).toBe("z");
+
+    fireEvent.click(screen.getByRole("button", { name: /Apply Now/i }));
+    expect(screen.queryByRole("dialog")).not.toBeInTheDocument();
+    expect(useConfigStore.getState().config.keybindings.slot0).toBe("1");
+  });
+
+  it("drops the edit on cancel and leaves other settings alone", () => {
+    setGameInProgress(true);
+    render(<HotkeyChangeGuard />);
+    act(() => {
+      useConfigStore.getState().updateHeroConfig("huskar", { standalone_key: "End" });
+    });
+    fireEvent.click(screen.getByRole("button", { name: /Cancel/i }));
+    expect(useConfigStore.getState().config.heroes.huskar.standalone_key).toBe("Home");
+
+    act(() => {
+      useConfigStore.getState().updateConfig("overlay", { enabled: true });
+    });
+    expect(screen.queryByRole("dialog")).not.toBeInTheDocument();
+    expect(useConfigStore.getState().config.overlay.enabled).toBe(true);
+  });
+});
@@ -0,0 +1,41 @@
+import { useConfigStore } from "../../stores/configStore";
+import { Button } from "../common/Button";
+
+/** Confirms a keybinding or hero key edit made while a match is in progress. */
+export function HotkeyChangeGuard() {
+  const pending = useConfigStore((s) => s.pendingHotkeyChange);
+  const confirm = useConfigStore((s) => s.confirmHotkeyChange);
+  const cancel = useConfigStore((s) => s.cancelHotkeyChange);
+
+  if (!pending) return null;
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/60"
+      onClick={cancel}
+    >
+      <div
+        role="dialog"
+        aria-label="Confirm hotkey change"
+        className="mx-4 w-full max-w-md rounded-lg border border-border bg-elevated shadow-2xl"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="border-b border-border px-6 py-4">
+          <h2 className="text-lg font-semibold text-content">Change keys mid-game?</h2>
+        </div>
+        <p className="px-6 py-4 text-sm text-subtle">
+          A match is in progress. New {pending.target.replace(/_/g, " ")} keys take effect
+          immediately, so combos and item automation will press the new keys from the next action.
+        </p>
+        <div className="flex items-center justify-end gap-3 border-t border-border px-6 py-4">
+          <Button variant="secondary" onClick={cancel} className="px-4 py-2 text-sm">
+            Cancel
+          </Button>
+          <Button variant="danger" onClick={confirm} className="px-4 py-2 text-sm">
+            Apply Now
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,44 @@
+import { act, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { SafeModeBanner } from "./SafeModeBanner";
+import { useConfigStore } from "../../stores/configStore";
+
+function setSafeMode(enabled: boolean) {
+  act(() => {
+    useConfigStore.setState((s) => ({ config: { ...s.config, safe_mode: { enabled } } }));
+  });
+}
+
+function setPracticeMode(enabled: boolean) {
+  act(() => {
+    useConfigStore.setState((s) => ({ config: { ...s.config, practice_mode: { enabled } } }));
+  });
+}
+
+describe("SafeModeBanner", () => {
+  afterEach(() => {
+    setSafeMode(false);
+    setPracticeMode(false);
+  });
+
+  it("shows while safe mode is on", () => {
+    setSafeMode(true);
+    render(<SafeModeBanner />);
+
+    expect(screen.getByText(/Safe mode/i)).toBeInTheDocument();
+    expect(screen.getByRole("button", { name: /Disable Safe Mode/i })).toBeInTheDocument();
+  });
+
+  it("shows practice mode when only practice mode is on", () => {
+    setPracticeMode(true);
+    render(<SafeModeBanner />);
+
+    expect(screen.getByText(/Practice mode/i)).toBeInTheDocument();
+    expect(screen.getByRole("button", { name: /Disable Practice Mode/i })).toBeInTheDocument();
+  });
+
+  it("stays hidden while safe mode is off", () => {
+    render(<SafeModeBanner />);
+    expect(screen.queryByText(/Safe mode/i)).not.toBeInTheDocument();
+  });
+});
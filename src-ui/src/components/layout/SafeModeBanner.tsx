@@ -0,0 +1,31 @@
+import { useConfigStore } from "../../stores/configStore";
+import { Button } from "../common/Button";
+
+export function SafeModeBanner() {
+  const safeMode = useConfigStore((s) => s.config.safe_mode.enabled);
+  const practiceMode = useConfigStore((s) => s.config.practice_mode.enabled);
+  const updateConfig = useConfigStore((s) => s.updateConfig);
+
+  if (!safeMode && !practiceMode) return null;
+
+  return (
+    <div className="flex items-center justify-between gap-4 border-b border-info bg-info/15 px-4 py-2">
+      <span className="text-sm font-medium text-info">
+        {safeMode
+          ? "🛡 Safe mode — automation runs but no keys or clicks are sent to Dota 2."
+          : "🎯 Practice mode — each action is explained in the Activity Log instead of sent to Dota 2."}
+      </span>
+      <Button
+        variant="secondary"
+        onClick={() =>
+          safeMode
+            ? updateConfig("safe_mode", { enabled: false })
+            : updateConfig("practice_mode", { enabled: false })
+        }
+        className="h-7 shrink-0 px-3 text-xs"
+      >
+        {safeMode ? "Disable Safe Mode" : "Disable Practice Mode"}
+      </Button>
+    </div>
+  );
+}
@@ -4,6 +4,7 @@ import { StatusHeader } from "./StatusHeader";
 const defaultProps = {
   stunned: false,
   silenced: false,
+  broken: false,
   alive: true,
   respawnTimer: null,
   appVersion: "0.14.0-rc.9",
@@ -21,6 +22,8 @@ describe("StatusHeader", () => {
       <StatusHeader
         heroName="Shadow Fiend"
         heroLevel={15}
+        heroFacet={2}
+        heroTalents={[1, 4]}
         hpPercent={72}
         manaPercent={55}
         inDanger={false}
@@ -30,6 +33,8 @@ describe("StatusHeader", () => {
     );
     expect(screen.getByText("Shadow Fiend")).toBeInTheDocument();
     expect(screen.getByText("Lv. 15")).toBeInTheDocument();
+    expect(screen.getByText("Facet 2")).toBeInTheDocument();
+    expect(screen.getByText("Talents 1 4")).toBeInTheDocument();
     expect(screen.getByText("72%")).toBeInTheDocument();
     expect(screen.getByText("55%")).toBeInTheDocument();
   });
@@ -49,6 +54,13 @@ describe("StatusHeader", () => {
     expect(screen.getByText("⚠ DANGER")).toBeInTheDocument();
   });
 
+  it("shows the Break badge while passives are disabled", () => {
+    render(
+      <StatusHeader heroName="Huskar" heroLevel={12} connected={true} {...defaultProps} broken={true} />,
+    );
+    expect(screen.getByText("💔 Broken")).toBeInTheDocument();
+  });
+
   it("renders disconnected state when GSI is stale", () => {
     render(<StatusHeader connected={false} {...defaultProps} />);
     expect(screen.getByText("Disconnected")).toBeInTheDocument();
@@ -6,6 +6,8 @@ import { Wifi, WifiOff } from "lucide-react";
 interface StatusHeaderProps {
   heroName?: string;
   heroLevel?: number;
+  heroFacet?: number;
+  heroTalents?: number[];
   hpPercent?: number;
   manaPercent?: number;
   inDanger?: boolean;
@@ -14,6 +16,7 @@ interface StatusHeaderProps {
   runeTimer?: number | null;
   stunned: boolean;
   silenced: boolean;
+  broken: boolean;
   alive: boolean;
   respawnTimer: number | null;
 }
@@ -21,6 +24,8 @@ interface StatusHeaderProps {
 export function StatusHeader({
   heroName,
   heroLevel,
+  heroFacet,
+  heroTalents = [],
   hpPercent,
   manaPercent,
   inDanger = false,
@@ -29,6 +34,7 @@ export function StatusHeader({
   runeTimer,
   stunned,
   silenced,
+  broken,
   alive,
   respawnTimer,
 }: StatusHeaderProps) {
@@ -53,6 +59,19 @@ export function StatusHeader({
             <span className="rounded bg-elevated px-1.5 py-0.5 font-mono text-xs text-subtle">
               Lv. {heroLevel}
             </span>
+            {!!heroFacet && (
+              <span className="rounded bg-elevated px-1.5 py-0.5 font-mono text-xs text-subtle">
+                Facet {heroFacet}
+              </span>
+            )}
+            {heroTalents.length > 0 && (
+              <span
+                className="rounded bg-elevated px-1.5 py-0.5 font-mono text-xs text-subtle"
+                title="Picked talent slots (1-2 level 10, 3-4 level 15, 5-6 level 20, 7-8 level 25)"
+              >
+                Talents {heroTalents.join(" ")}
+              </span>
+            )}
           </div>
           <div className="flex items-center gap-3 flex-1">
             <div className="w-32">
@@ -70,6 +89,11 @@ export function StatusHeader({
             )}
             {stunned && <span className="text-warning text-xs">⚡ Stunned</span>}
             {silenced && <span className="text-danger text-xs">🔇 Silenced</span>}
+            {broken && (
+              <span className="text-warning text-xs" title="Break: passives are disabled">
+                💔 Broken
+              </span>
+            )}
             {runeTimer != null && runeTimer <= 15 && (
               <span className="font-mono text-xs text-warning animate-pulse">
                 🔮 {runeTimer}s
@@ -1,5 +1,5 @@
-import { act, render, screen } from "@testing-library/react";
-import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { act, fireEvent, render, screen } from "@testing-library/react";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
 import { UpdateBanner } from "./UpdateBanner";
 import { useUpdateStore } from "../../stores/updateStore";
 
@@ -41,4 +41,40 @@ describe("UpdateBanner", () => {
       screen.getByText(/Update v0\.15\.0-rc\.2 available/i),
     ).toBeInTheDocument();
   });
+
+  it("shows a spinner while downloading and a retry on error", () => {
+    const original = useUpdateStore.getState().checkForUpdates;
+    const checkForUpdates = vi.fn(async () => {});
+    act(() => {
+      useUpdateStore.setState({ updateState: { kind: "downloading" }, checkForUpdates });
+    });
+    const { rerender } = render(<UpdateBanner />);
+    expect(screen.getByText(/Downloading update/i)).toBeInTheDocument();
+
+    act(() => {
+      useUpdateStore.setState({ updateState: { kind: "error", message: "network down" } });
+    });
+    rerender(<UpdateBanner />);
+    expect(screen.getByText(/network down/)).toBeInTheDocument();
+    fireEvent.click(screen.getByRole("button", { name: "Retry" }));
+    expect(checkForUpdates).toHaveBeenCalled();
+
+    act(() => {
+      useUpdateStore.setState({
+        updateState: {
+          kind: "error",
+          message: "Failed to write update.msi: Access is denied.",
+          category: "permission",
+          retryable: false,
+        },
+      });
+    });
+    rerender(<UpdateBanner />);
+    expect(screen.getByText(/can write to %LOCALAPPDATA%/)).toBeInTheDocument();
+    expect(screen.queryByRole("button", { name: "Retry" })).not.toBeInTheDocument();
+
+    act(() => {
+      useUpdateStore.setState({ checkForUpdates: original });
+    });
+  });
 });
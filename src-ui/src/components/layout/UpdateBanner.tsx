@@ -3,6 +3,19 @@ import ReactMarkdown from "react-markdown";
 import remarkGfm from "remark-gfm";
 import { useUpdateStore } from "../../stores/updateStore";
 import { Button } from "../common/Button";
+import type { ErrorCategory } from "../../types/game";
+
+/** What the user can do about errors that retrying will not fix. */
+function errorHint(category: ErrorCategory | undefined): string | null {
+  switch (category) {
+    case "permission":
+      return "Check that the app can write to %LOCALAPPDATA% and %TEMP%, then try again.";
+    case "unsupported":
+      return "Download the latest release from GitHub instead.";
+    default:
+      return null;
+  }
+}
 
 function ReleaseNotesModal({
   version,
@@ -50,7 +63,7 @@ function ReleaseNotesModal({
             Close
           </button>
           <Button onClick={onApply} className="px-4 py-2 text-sm">
-            Apply Update
+            Update &amp; Restart
           </Button>
         </div>
       </div>
@@ -58,12 +71,59 @@ function ReleaseNotesModal({
   );
 }
 
+function Spinner() {
+  return (
+    <span
+      aria-hidden="true"
+      className="inline-block h-3.5 w-3.5 animate-spin rounded-full border-2 border-gold border-t-transparent"
+    />
+  );
+}
+
 export function UpdateBanner() {
   const updateState = useUpdateStore((s) => s.updateState);
   const applyUpdate = useUpdateStore((s) => s.applyUpdate);
+  const checkForUpdates = useUpdateStore((s) => s.checkForUpdates);
   const dismissUpdate = useUpdateStore((s) => s.dismissUpdate);
   const [showNotes, setShowNotes] = useState(false);
 
+  if (updateState.kind === "downloading") {
+    return (
+      <div className="flex items-center gap-2 border-b border-border bg-elevated px-4 py-2">
+        <Spinner />
+        <span className="text-sm text-subtle">
+          Downloading update — the app will restart when the installer takes over…
+        </span>
+      </div>
+    );
+  }
+
+  if (updateState.kind === "error") {
+    const hint = errorHint(updateState.category);
+    return (
+      <div className="flex items-center justify-between gap-4 border-b border-border bg-elevated px-4 py-2">
+        <span className="text-sm text-danger">
+          Update error: {updateState.message}
+          {hint && <span className="ml-2 text-subtle">{hint}</span>}
+        </span>
+        <div className="flex items-center gap-2">
+          {updateState.retryable !== false && (
+            <Button onClick={checkForUpdates} className="h-7 px-3 text-xs">
+              Retry
+            </Button>
+          )}
+          <button
+            type="button"
+            onClick={dismissUpdate}
+            className="text-xs text-subtle hover:text-content"
+          >
+            Dismiss
+          </button>
+        </div>
+      </div>
+    );
+  }
+
   if (updateState.kind !== "available") return null;
 
   return (
@@ -85,14 +145,14 @@ export function UpdateBanner() {
         </div>
         <div className="flex items-center gap-2">
           <Button onClick={applyUpdate} className="h-7 px-3 text-xs">
-            Apply Update
+            Update Now
           </Button>
           <button
             type="button"
             onClick={dismissUpdate}
             className="text-xs text-subtle hover:text-content"
           >
-            Dismiss
+            Later
           </button>
         </div>
       </div>
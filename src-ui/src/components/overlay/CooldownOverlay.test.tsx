@@ -0,0 +1,32 @@
+import { render, screen } from "@testing-library/react";
+import { describe, expect, it, vi } from "vitest";
+import { CooldownOverlay } from "./CooldownOverlay";
+import type { CooldownEntry } from "../../types/game";
+
+const { entries } = vi.hoisted(() => ({
+  entries: [
+    { name: "black_king_bar", kind: "item", cooldown: 42, charges: null },
+    { name: "magic_wand", kind: "item", cooldown: 0, charges: 7 },
+    { name: "nevermore_requiem", kind: "ability", cooldown: 0, charges: null },
+  ] as CooldownEntry[],
+}));
+
+vi.mock("../../hooks/useTauriEvent", async () => {
+  const { useEffect } = await import("react");
+  return {
+    useTauriEvent: (_event: string, handler: (payload: CooldownEntry[]) => void) => {
+      useEffect(() => handler(entries), []);
+    },
+  };
+});
+
+describe("CooldownOverlay", () => {
+  it("lists remaining cooldowns and ready entries", async () => {
+    render(<CooldownOverlay />);
+
+    expect(await screen.findByText("42s")).toBeInTheDocument();
+    expect(screen.getByText("black king bar")).toBeInTheDocument();
+    expect(screen.getByText("(7)")).toBeInTheDocument();
+    expect(screen.getAllByText("ready")).toHaveLength(2);
+  });
+});
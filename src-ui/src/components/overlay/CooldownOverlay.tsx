@@ -0,0 +1,31 @@
+import { useState } from "react";
+import { useTauriEvent } from "../../hooks/useTauriEvent";
+import type { CooldownEntry } from "../../types/game";
+
+function displayName(name: string): string {
+  return name.replace(/_/g, " ");
+}
+
+/** Item/ability cooldowns from the latest GSI event, rendered in the overlay window. */
+export function CooldownOverlay() {
+  const [entries, setEntries] = useState<CooldownEntry[]>([]);
+  useTauriEvent<CooldownEntry[]>("overlay_cooldowns", setEntries);
+
+  if (entries.length === 0) return null;
+
+  return (
+    <ul className="m-1 space-y-0.5 rounded-md bg-base/70 p-1.5 font-mono text-xs">
+      {entries.map((entry) => (
+        <li key={`${entry.kind}:${entry.name}`} className="flex items-center justify-between gap-2">
+          <span className={`truncate ${entry.kind === "ability" ? "text-subtle" : "text-content"}`}>
+            {displayName(entry.name)}
+            {entry.charges !== null && <span className="text-muted"> ({entry.charges})</span>}
+          </span>
+          <span className={entry.cooldown > 0 ? "text-danger" : "text-success"}>
+            {entry.cooldown > 0 ? `${entry.cooldown}s` : "ready"}
+          </span>
+        </li>
+      ))}
+    </ul>
+  );
+}
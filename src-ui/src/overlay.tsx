@@ -0,0 +1,10 @@
+import { StrictMode } from "react";
+import { createRoot } from "react-dom/client";
+import { CooldownOverlay } from "./components/overlay/CooldownOverlay";
+import "./styles/global.css";
+
+createRoot(document.getElementById("root")!).render(
+  <StrictMode>
+    <CooldownOverlay />
+  </StrictMode>,
+);
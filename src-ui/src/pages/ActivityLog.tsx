@@ -1,7 +1,7 @@
 import { useRef, useEffect, useState } from "react";
 import { useActivityStore } from "../stores/activityStore";
 import { Button } from "../components/common/Button";
-import type { ActivityCategory } from "../types/activity";
+import type { ActionKind, ActivityCategory } from "../types/activity";
 
 const filters: { label: string; value: ActivityCategory | "all" }[] = [
   { label: "All", value: "all" },
@@ -11,6 +11,18 @@ const filters: { label: string; value: ActivityCategory | "all" }[] = [
   { label: "System", value: "system" },
 ];
 
+const actionKinds: { label: string; value: ActionKind | "all" }[] = [
+  { label: "All Actions", value: "all" },
+  { label: "Defensive Items", value: "defensive_item" },
+  { label: "Healing", value: "healing_item" },
+  { label: "Danger Items", value: "danger_item" },
+  { label: "Mana Items", value: "mana_item" },
+  { label: "Consumables", value: "consumable" },
+  { label: "Soul Ring", value: "soul_ring" },
+  { label: "Armlet", value: "armlet" },
+  { label: "Combos", value: "combo" },
+];
+
 const categoryColors: Record<string, string> = {
   action: "text-terminal",
   danger: "text-danger",
@@ -23,6 +35,8 @@ export default function ActivityLog() {
   const entries = useActivityStore((s) => s.filteredEntries());
   const filter = useActivityStore((s) => s.filter);
   const setFilter = useActivityStore((s) => s.setFilter);
+  const actionKind = useActivityStore((s) => s.actionKind);
+  const setActionKind = useActivityStore((s) => s.setActionKind);
   const clear = useActivityStore((s) => s.clear);
   const [paused, setPaused] = useState(false);
   const [expandedId, setExpandedId] = useState<string | null>(null);
@@ -68,6 +82,25 @@ export default function ActivityLog() {
         ))}
       </div>
 
+      {filter === "action" && (
+        <div className="mb-4 flex flex-wrap gap-2">
+          {actionKinds.map((k) => (
+            <button
+              key={k.value}
+              type="button"
+              onClick={() => setActionKind(k.value)}
+              className={`rounded-full px-3 py-1 text-xs font-medium transition-colors ${
+                actionKind === k.value
+                  ? "bg-info text-base"
+                  : "bg-elevated text-subtle hover:text-content"
+              }`}
+            >
+              {k.label}
+            </button>
+          ))}
+        </div>
+      )}
+
       <div className="flex-1 overflow-y-auto rounded-lg bg-terminal-bg p-4 font-mono text-xs">
         {entries.length === 0 ? (
           <p className="text-muted">No activity entries.</p>
@@ -93,4 +93,27 @@ describe("Armlet page Roshan controls", () => {
       });
     });
   });
+
+  it("adds a generic override for an unscripted hero", async () => {
+    invokeMock.mockResolvedValue(undefined);
+
+    render(
+      <MemoryRouter>
+        <Armlet />
+      </MemoryRouter>,
+    );
+
+    fireEvent.change(screen.getByLabelText("Hero name"), {
+      target: { value: "npc_dota_hero_Dragon_Knight" },
+    });
+    fireEvent.click(screen.getByRole("button", { name: "Add" }));
+    await vi.advanceTimersByTimeAsync(300);
+    await vi.waitFor(() => {
+      expect(invokeMock).toHaveBeenCalledWith("update_config", {
+        section: "armlet",
+        updates: { heroes: { dragon_knight: {} } },
+      });
+    });
+    expect(screen.getByText("dragon_knight")).toBeInTheDocument();
+  });
 });
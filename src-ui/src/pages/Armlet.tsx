@@ -1,3 +1,5 @@
+import { useState } from "react";
+import { Button } from "../components/common/Button";
 import { Card } from "../components/common/Card";
 import { Toggle } from "../components/common/Toggle";
 import { NumberInput } from "../components/common/NumberInput";
@@ -6,6 +8,7 @@ import { KeyInput } from "../components/common/KeyInput";
 import { useConfigStore } from "../stores/configStore";
 import { useUIStore } from "../stores/uiStore";
 import { HEROES } from "../types/game";
+import type { HeroArmletOverride } from "../types/config";
 import { Link } from "react-router-dom";
 
 export default function Armlet() {
@@ -17,6 +20,27 @@ export default function Armlet() {
     useConfigStore.getState().updateConfig("armlet", updates);
   const updateRoshan = (updates: Partial<typeof config.roshan>) =>
     update({ roshan: { ...config.roshan, ...updates } });
+  const updateStuckDetection = (updates: Partial<typeof config.stuck_detection>) =>
+    update({ stuck_detection: { ...config.stuck_detection, ...updates } });
+
+  const [newHeroName, setNewHeroName] = useState("");
+  const otherHeroOverrides = Object.entries(config.heroes ?? {});
+  const updateHeroOverride = (name: string, updates: Partial<HeroArmletOverride>) =>
+    update({ heroes: { ...config.heroes, [name]: { ...config.heroes[name], ...updates } } });
+  const removeHeroOverride = (name: string) => {
+    const { [name]: _removed, ...rest } = config.heroes;
+    update({ heroes: rest });
+  };
+  const addHeroOverride = () => {
+    const name = newHeroName
+      .trim()
+      .toLowerCase()
+      .replace(/^npc_dota_hero_/, "")
+      .replace(/\s+/g, "_");
+    if (!name || name in (config.heroes ?? {})) return;
+    update({ heroes: { ...config.heroes, [name]: {} } });
+    setNewHeroName("");
+  };
 
   const heroesWithOverrides = HEROES.filter((h) => {
     const heroConfig = heroes[h.id as keyof typeof heroes];
@@ -44,6 +68,30 @@ export default function Armlet() {
             <NumberInput label="Toggle Threshold" value={config.toggle_threshold} onChange={(v) => update({ toggle_threshold: v })} suffix="HP" />
             <NumberInput label="Predictive Offset" value={config.predictive_offset} onChange={(v) => update({ predictive_offset: v })} suffix="HP" />
             <NumberInput label="Toggle Cooldown" value={config.toggle_cooldown_ms} onChange={(v) => update({ toggle_cooldown_ms: v })} suffix="ms" />
+            <KeyInput
+              label="Emergency Toggle Key"
+              value={config.emergency_toggle_key}
+              onChange={(value) => update({ emergency_toggle_key: value })}
+            />
+            <p className="text-xs text-muted">Toggles armlet immediately, ignoring the toggle cooldown.</p>
+          </Card>
+
+          <Card title="Stuck Detection">
+            <p className="text-xs text-muted">
+              Forces another toggle when HP keeps falling below the threshold after a toggle. 0 frames disables it.
+            </p>
+            <NumberInput
+              label="Falling Frames"
+              value={config.stuck_detection.falling_frames}
+              onChange={(v) => updateStuckDetection({ falling_frames: v })}
+              min={0}
+            />
+            <NumberInput
+              label="Detection Window"
+              value={config.stuck_detection.window_ms}
+              onChange={(v) => updateStuckDetection({ window_ms: v })}
+              suffix="ms"
+            />
           </Card>
 
           <Card title="Roshan Mode">
@@ -121,6 +169,60 @@ export default function Armlet() {
               ))}
             </div>
           </Card>
+
+          <Card title="Other Heroes">
+            <p className="text-xs text-muted">
+              Heroes without a script (e.g. dragon_knight, sven) use the shared settings unless overridden here.
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="text"
+                value={newHeroName}
+                onChange={(e) => setNewHeroName(e.target.value)}
+                onKeyDown={(e) => e.key === "Enter" && addHeroOverride()}
+                placeholder="dragon_knight"
+                aria-label="Hero name"
+                className="h-8 w-full rounded-md border border-border bg-input px-3 font-mono text-sm
+                           text-content focus:border-border-accent focus:outline-none"
+              />
+              <Button variant="secondary" onClick={addHeroOverride} disabled={!newHeroName.trim()}>
+                Add
+              </Button>
+            </div>
+            {otherHeroOverrides.map(([name, heroOverride]) => (
+              <div key={name} className="space-y-2 rounded-md border border-border bg-base p-3">
+                <div className="flex items-center justify-between">
+                  <span className="font-mono text-sm text-content">{name}</span>
+                  <Button variant="secondary" onClick={() => removeHeroOverride(name)}>
+                    Remove
+                  </Button>
+                </div>
+                <Toggle
+                  label="Enable Armlet"
+                  checked={heroOverride.enabled ?? config.enabled}
+                  onChange={(v) => updateHeroOverride(name, { enabled: v })}
+                />
+                <NumberInput
+                  label="Toggle Threshold"
+                  value={heroOverride.toggle_threshold ?? config.toggle_threshold}
+                  onChange={(v) => updateHeroOverride(name, { toggle_threshold: v })}
+                  suffix="HP"
+                />
+                <NumberInput
+                  label="Predictive Offset"
+                  value={heroOverride.predictive_offset ?? config.predictive_offset}
+                  onChange={(v) => updateHeroOverride(name, { predictive_offset: v })}
+                  suffix="HP"
+                />
+                <NumberInput
+                  label="Toggle Cooldown"
+                  value={heroOverride.toggle_cooldown_ms ?? config.toggle_cooldown_ms}
+                  onChange={(v) => updateHeroOverride(name, { toggle_cooldown_ms: v })}
+                  suffix="ms"
+                />
+              </div>
+            ))}
+          </Card>
         </div>
       </div>
     </div>
@@ -1,9 +1,30 @@
 import { Card } from "../components/common/Card";
 import { Toggle } from "../components/common/Toggle";
 import { Slider } from "../components/common/Slider";
+import { NumberInput } from "../components/common/NumberInput";
 import { KeyInput } from "../components/common/KeyInput";
 import { TagList } from "../components/common/TagList";
+import { Dropdown } from "../components/common/Dropdown";
+import { OrderedList } from "../components/common/OrderedList";
 import { useConfigStore } from "../stores/configStore";
+import type { CastModifier, SelfCastMethod } from "../types/config";
+
+const DEFENSIVE_ITEM_LABELS: Record<string, string> = {
+  item_black_king_bar: "Black King Bar",
+  item_satanic: "Satanic",
+  item_blade_mail: "Blade Mail",
+  item_glimmer_cape: "Glimmer Cape",
+  item_ghost: "Ghost Scepter",
+  item_shivas_guard: "Shiva's Guard",
+};
+
+const SELF_CAST_ITEMS = ["item_glimmer_cape", "item_ghost"];
+
+const SELF_CAST_METHOD_OPTIONS = [
+  { value: "", label: "Single press" },
+  { value: "double_tap", label: "Double-tap" },
+  { value: "self_cast_modifier", label: "Self-cast modifier" },
+];
 
 export default function DangerDetection() {
   const danger = useConfigStore((s) => s.config.danger_detection);
@@ -12,6 +33,12 @@ export default function DangerDetection() {
     useConfigStore.getState().updateConfig("danger_detection", updates);
   const updateNeutral = (updates: Partial<typeof neutral>) =>
     useConfigStore.getState().updateConfig("neutral_items", updates);
+  const setSelfCastMethod = (item: string, method: SelfCastMethod | "") => {
+    const methods = { ...danger.self_cast_methods };
+    if (method) methods[item] = method;
+    else delete methods[item];
+    updateDanger({ self_cast_methods: methods });
+  };
 
   return (
     <div className="space-y-6 p-6">
@@ -30,6 +57,8 @@ export default function DangerDetection() {
           <Card title="Healing in Danger">
             <Slider label="Healing HP Threshold" value={danger.healing_threshold_in_danger} min={30} max={80} onChange={(v) => updateDanger({ healing_threshold_in_danger: v })} suffix="%" />
             <Slider label="Max Healing Items/Event" value={danger.max_healing_items_per_danger} min={1} max={5} onChange={(v) => updateDanger({ max_healing_items_per_danger: v })} />
+            <NumberInput label="Min Recent HP Loss" value={danger.healing_min_recent_loss_hp} min={0} onChange={(v) => updateDanger({ healing_min_recent_loss_hp: v })} suffix="HP" />
+            <p className="text-xs text-muted">HP that must actually have been lost within the clear delay before danger healing kicks in; otherwise the normal threshold applies. 0 turns the check off.</p>
             <div className="mt-2 text-xs text-muted">
               <p className="font-medium text-subtle">Priority: Cheese → Greater Faerie Fire → Enchanted Mango → Magic Wand → Faerie Fire</p>
             </div>
@@ -38,6 +67,8 @@ export default function DangerDetection() {
 
         <div className="space-y-4">
           <Card title="Defensive Items">
+            <NumberInput label="Max Defensive Items/Event" value={danger.max_defensive_items_per_danger} min={0} onChange={(v) => updateDanger({ max_defensive_items_per_danger: v })} />
+            <p className="text-xs text-muted">0 fires every ready item; the count resets when danger clears.</p>
             <Toggle label="Black King Bar" checked={danger.auto_bkb} onChange={(v) => updateDanger({ auto_bkb: v })} />
             <Toggle label="Satanic" checked={danger.auto_satanic} onChange={(v) => updateDanger({ auto_satanic: v })} />
             {danger.auto_satanic && (
@@ -47,6 +78,35 @@ export default function DangerDetection() {
             <Toggle label="Glimmer Cape" checked={danger.auto_glimmer_cape} onChange={(v) => updateDanger({ auto_glimmer_cape: v })} />
             <Toggle label="Ghost Scepter" checked={danger.auto_ghost_scepter} onChange={(v) => updateDanger({ auto_ghost_scepter: v })} />
             <Toggle label="Shiva's Guard" checked={danger.auto_shivas_guard} onChange={(v) => updateDanger({ auto_shivas_guard: v })} />
+            <OrderedList
+              label="Activation Order"
+              items={danger.defensive_item_priority}
+              format={(item) => DEFENSIVE_ITEM_LABELS[item] ?? item}
+              onChange={(v) => updateDanger({ defensive_item_priority: v })}
+            />
+          </Card>
+
+          <Card title="Self-Cast">
+            {SELF_CAST_ITEMS.map((item) => (
+              <Dropdown
+                key={item}
+                label={DEFENSIVE_ITEM_LABELS[item]}
+                value={danger.self_cast_methods[item] ?? ""}
+                options={SELF_CAST_METHOD_OPTIONS}
+                onChange={(v) => setSelfCastMethod(item, v as SelfCastMethod | "")}
+              />
+            ))}
+            <Dropdown
+              label="Self-Cast Modifier"
+              value={danger.self_cast_modifier}
+              options={[
+                { value: "Alt", label: "Alt" },
+                { value: "Ctrl", label: "Ctrl" },
+                { value: "Shift", label: "Shift" },
+              ]}
+              onChange={(v) => updateDanger({ self_cast_modifier: v as CastModifier })}
+            />
+            <p className="text-xs text-muted">Double-tap only self-casts when the second press lands on your hero. The modifier (Dota's self-cast key) always targets you but must match your in-game binding.</p>
           </Card>
 
           <Card title="Dispels">
@@ -60,6 +120,7 @@ export default function DangerDetection() {
             <Slider label="HP Threshold" value={neutral.hp_threshold} min={10} max={90} onChange={(v) => updateNeutral({ hp_threshold: v })} suffix="%" />
             <KeyInput label="Self-Cast Key" value={neutral.self_cast_key} onChange={(v) => updateNeutral({ self_cast_key: v })} />
             <TagList label="Allowed Items" items={neutral.allowed_items} onChange={(v) => updateNeutral({ allowed_items: v })} />
+            <Toggle label="Use in Auto-Items Combo" checked={neutral.use_in_auto_items} onChange={(v) => updateNeutral({ use_in_auto_items: v })} />
           </Card>
         </div>
       </div>
@@ -1,5 +1,7 @@
+import { Button } from "../components/common/Button";
 import { Card } from "../components/common/Card";
 import { useGameStore } from "../stores/gameStore";
+import { useUIStore } from "../stores/uiStore";
 
 function StatusDot({ active, label }: { active: boolean; label: string }) {
   return (
@@ -24,17 +26,34 @@ function MetricRow({ label, value }: { label: string; value: string | number })
   );
 }
 
+function formatCounts(counts: Record<string, number>, stripPrefix = "") {
+  const entries = Object.entries(counts);
+  if (entries.length === 0) return "None";
+  return entries
+    .map(([name, count]) => `${name.replace(stripPrefix, "")} ×${count}`)
+    .join(", ");
+}
+
 export default function Diagnostics() {
   const diag = useGameStore((s) => s.diagnostics);
+  const resetAutomationState = useUIStore((s) => s.resetAutomationState);
 
   return (
     <div className="space-y-6 p-6">
       <h2 className="text-xl font-semibold">Diagnostics</h2>
 
-      <div className="grid grid-cols-3 gap-4">
+      <div className="grid grid-cols-2 gap-4 lg:grid-cols-4">
         <StatusDot active={diag.gsiConnected} label="GSI Server" />
         <StatusDot active={diag.keyboardHookActive} label="Keyboard Hook" />
         <StatusDot active={diag.gsiConnected} label="Game State" />
+        <StatusDot
+          active={diag.syntheticInput.consecutiveFailures === 0}
+          label={
+            diag.syntheticInput.consecutiveFailures === 0
+              ? "Input Backend"
+              : `Input Backend failing (${diag.syntheticInput.consecutiveFailures} in a row)`
+          }
+        />
       </div>
 
       <div className="grid grid-cols-1 gap-6 lg:grid-cols-2">
@@ -42,6 +61,7 @@ export default function Diagnostics() {
           <Card title="GSI Pipeline">
             <MetricRow label="Events Processed" value={diag.queueMetrics.eventsProcessed} />
             <MetricRow label="Events Dropped" value={diag.queueMetrics.eventsDropped} />
+            <MetricRow label="Unchanged Events Skipped" value={diag.queueMetrics.eventsDeduplicated} />
             <MetricRow label="Queue Depth" value={`${diag.queueMetrics.currentQueueDepth} / ${diag.queueMetrics.maxQueueDepth}`} />
           </Card>
 
@@ -58,6 +78,29 @@ export default function Diagnostics() {
             <MetricRow label="Peak Depth" value={diag.syntheticInput.peakDepth} />
             <MetricRow label="Completions" value={diag.syntheticInput.completions} />
             <MetricRow label="Drops" value={diag.syntheticInput.drops} />
+            <MetricRow label="Failures" value={diag.syntheticInput.failures} />
+            <MetricRow label="Backend Reinitializations" value={diag.syntheticInput.reinitializations} />
+          </Card>
+
+          <Card title="Automation">
+            <MetricRow label="Combos Fired" value={formatCounts(diag.automation.combosTriggered)} />
+            <MetricRow
+              label="Defensive Items"
+              value={formatCounts(diag.automation.defensiveItemsUsed, "item_")}
+            />
+            <MetricRow label="Healing Items" value={diag.automation.healingItemsUsed} />
+            <MetricRow label="Danger Events" value={diag.automation.dangerEvents} />
+            <MetricRow label="Armlet Toggles" value={diag.automation.armletToggles} />
+          </Card>
+
+          <Card title="Recovery">
+            <p className="mb-3 text-xs text-subtle">
+              Clears cooldowns, danger and HP tracking, Soul Ring and armlet state, and hero
+              trackers left over from a previous game. Settings and toggles are kept.
+            </p>
+            <Button variant="secondary" onClick={resetAutomationState}>
+              Reset Automation State
+            </Button>
           </Card>
         </div>
       </div>
@@ -2,6 +2,7 @@ import { useParams, Navigate } from "react-router-dom";
 import { Suspense, lazy, useMemo } from "react";
 import { HEROES, type HeroType } from "../types/game";
 import { HeroPage } from "../components/heroes/HeroPage";
+import { HealThresholdCard } from "../components/heroes/HealThresholdCard";
 import configs from "../components/heroes/configs";
 
 export default function HeroDetail() {
@@ -26,6 +27,7 @@ export default function HeroDetail() {
       >
         <ConfigComponent />
       </Suspense>
+      <HealThresholdCard hero={hero.id} />
     </HeroPage>
   );
 }
@@ -4,11 +4,117 @@ import { NumberInput } from "../components/common/NumberInput";
 import { KeyInput } from "../components/common/KeyInput";
 import { Dropdown } from "../components/common/Dropdown";
 import { Button } from "../components/common/Button";
-import { useConfigStore } from "../stores/configStore";
+import { TagList } from "../components/common/TagList";
+import { useEffect, useState } from "react";
+import { configErrorMessage, useConfigStore } from "../stores/configStore";
+import { useUpdateStore } from "../stores/updateStore";
+import { useUIStore } from "../stores/uiStore";
+import type { UpdateCheckState } from "../types/game";
+import type { InputBackend } from "../types/config";
+
+function updateStatusText(state: UpdateCheckState): string | null {
+  switch (state.kind) {
+    case "checking":
+      return "Checking for updates…";
+    case "available":
+      return `v${state.version} is available`;
+    case "downloading":
+      return "Downloading update…";
+    case "upToDate":
+      return "You're on the latest version";
+    case "error":
+      return `Update check failed: ${state.message}`;
+    default:
+      return null;
+  }
+}
 
 export default function Settings() {
   const config = useConfigStore((s) => s.config);
   const updateConfig = useConfigStore((s) => s.updateConfig);
+  const rollbackVersion = useUpdateStore((s) => s.rollbackVersion);
+  const loadRollbackVersion = useUpdateStore((s) => s.loadRollbackVersion);
+  const rollback = useUpdateStore((s) => s.rollback);
+  const updateState = useUpdateStore((s) => s.updateState);
+  const checkForUpdates = useUpdateStore((s) => s.checkForUpdates);
+  const updateStatus = updateStatusText(updateState);
+  const [confirmRollback, setConfirmRollback] = useState(false);
+  const profiles = useConfigStore((s) => s.profiles);
+  const loadProfiles = useConfigStore((s) => s.loadProfiles);
+  const saveProfile = useConfigStore((s) => s.saveProfile);
+  const switchProfile = useConfigStore((s) => s.switchProfile);
+  const [newProfileName, setNewProfileName] = useState("");
+  const [profileError, setProfileError] = useState<string | null>(null);
+  const exportConfig = useConfigStore((s) => s.exportConfig);
+  const previewImport = useConfigStore((s) => s.previewImport);
+  const importConfig = useConfigStore((s) => s.importConfig);
+  const [pendingImport, setPendingImport] = useState<string | null>(null);
+  const [shareStatus, setShareStatus] = useState<{ error: boolean; message: string } | null>(null);
+  const keybindingConflicts = useConfigStore((s) => s.keybindingConflicts);
+  const checkKeybindings = useConfigStore((s) => s.checkKeybindings);
+  const gsiListener = useUIStore((s) => s.gsiListener);
+  const restartGsiServer = useUIStore((s) => s.restartGsiServer);
+  const [restartError, setRestartError] = useState<string | null>(null);
+  const configuredAddress = `${config.server.bind_address.trim()}:${config.server.port}`;
+  const listenerMoved = gsiListener !== null && gsiListener.address !== configuredAddress;
+
+  useEffect(() => {
+    loadRollbackVersion();
+    loadProfiles();
+  }, [loadRollbackVersion, loadProfiles]);
+
+  useEffect(() => {
+    checkKeybindings();
+  }, [config, checkKeybindings]);
+
+  const runProfileAction = async (action: () => Promise<void>) => {
+    setProfileError(null);
+    try {
+      await action();
+    } catch (e) {
+      setProfileError(configErrorMessage(e));
+    }
+  };
+
+  const copyConfig = async () => {
+    try {
+      await navigator.clipboard.writeText(await exportConfig());
+      setShareStatus({ error: false, message: "Config copied to clipboard" });
+    } catch (e) {
+      setShareStatus({ error: true, message: configErrorMessage(e) });
+    }
+  };
+
+  const readImport = async () => {
+    setPendingImport(null);
+    try {
+      const contents = await navigator.clipboard.readText();
+      await previewImport(contents);
+      setPendingImport(contents);
+      setShareStatus(null);
+    } catch (e) {
+      setShareStatus({ error: true, message: configErrorMessage(e) });
+    }
+  };
+
+  const applyImport = async () => {
+    if (pendingImport === null) return;
+    try {
+      await importConfig(pendingImport);
+      setShareStatus({ error: false, message: "Imported config applied" });
+    } catch (e) {
+      setShareStatus({ error: true, message: configErrorMessage(e) });
+    }
+    setPendingImport(null);
+  };
+
+  const saveNewProfile = () =>
+    runProfileAction(async () => {
+      const name = newProfileName.trim();
+      await saveProfile(name);
+      await switchProfile(name);
+      setNewProfileName("");
+    });
 
   return (
     <div className="space-y-6 p-6">
@@ -16,13 +122,132 @@ export default function Settings() {
 
       <div className="grid grid-cols-1 gap-6 lg:grid-cols-2">
         <div className="space-y-4">
+          <Card title="Input Safety">
+            <Toggle
+              label="Safe Mode (log input instead of sending it)"
+              checked={config.safe_mode.enabled}
+              onChange={(v) => updateConfig("safe_mode", { enabled: v })}
+            />
+            <p className="text-xs text-info">
+              GSI, danger detection and this UI keep running; every key press and click is only
+              written to the log.
+            </p>
+            <Toggle
+              label="Practice Mode (explain actions instead of sending them)"
+              checked={config.practice_mode.enabled}
+              onChange={(v) => updateConfig("practice_mode", { enabled: v })}
+            />
+            <p className="text-xs text-info">
+              Each action the automation would take shows up in the Activity Log with its reason,
+              e.g. "Would use satanic: HP 35% &lt;= 40% Satanic threshold in danger".
+            </p>
+            <Toggle
+              label="Only Send Input While Dota 2 Is Focused"
+              checked={config.input.only_input_when_dota_focused}
+              onChange={(v) => updateConfig("input", { only_input_when_dota_focused: v })}
+            />
+            <NumberInput
+              label="Min Time Between Actions"
+              value={config.input.min_action_interval_ms}
+              onChange={(v) => updateConfig("input", { min_action_interval_ms: v })}
+              min={0}
+              max={500}
+              suffix="ms"
+            />
+            <Dropdown
+              label="Input Backend"
+              value={config.simulation.backend}
+              options={[
+                { value: "enigo", label: "Enigo (default)" },
+                { value: "scancode", label: "Scancode (SendInput, Windows)" },
+              ]}
+              onChange={(v) => updateConfig("simulation", { backend: v as InputBackend })}
+            />
+            <p className="text-xs text-info">
+              Try Scancode if Dota has focus but ignores the keys this app sends.
+            </p>
+          </Card>
+
           <Card title="Server">
+            <p className="text-xs text-subtle">
+              {gsiListener?.address
+                ? `Listening on ${gsiListener.address}`
+                : gsiListener?.error
+                  ? `Not listening: ${gsiListener.error}`
+                  : "GSI server is not running"}
+            </p>
             <NumberInput
               label="GSI Port"
               value={config.server.port}
-              onChange={(v) => updateConfig("server", { port: v })}
+              onChange={(v) => {
+                setRestartError(null);
+                updateConfig("server", { port: v });
+              }}
+              min={1}
+              max={65535}
+            />
+            <Dropdown
+              label="Bind Address"
+              value={config.server.bind_address}
+              options={[
+                { value: "127.0.0.1", label: "127.0.0.1 (this PC only)" },
+                { value: "0.0.0.0", label: "0.0.0.0 (all network interfaces)" },
+                ...(["127.0.0.1", "0.0.0.0"].includes(config.server.bind_address)
+                  ? []
+                  : [{ value: config.server.bind_address, label: config.server.bind_address }]),
+              ]}
+              onChange={(v) => updateConfig("server", { bind_address: v })}
+            />
+            {config.server.bind_address !== "127.0.0.1" && (
+              <p className="text-xs text-warning">
+                ⚠ Any device that can reach this port can send fake game state. Only use on a trusted network.
+              </p>
+            )}
+            {listenerMoved && (
+              <div className="flex items-center gap-2">
+                <Button onClick={async () => setRestartError(await restartGsiServer(config.server.port))}>
+                  Listen on {configuredAddress}
+                </Button>
+                <span className="text-xs text-subtle">Update the port in your GSI cfg file too.</span>
+              </div>
+            )}
+            {restartError && <p className="text-xs text-danger">{restartError}</p>}
+            <Toggle
+              label="Skip Unchanged GSI Events"
+              checked={config.server.dedup_identical_events}
+              onChange={(v) => updateConfig("server", { dedup_identical_events: v })}
             />
-            <p className="text-xs text-warning">⚠ Restart required after changing port.</p>
+          </Card>
+
+          <Card title="Cooldown Overlay">
+            <Toggle
+              label="Show Cooldown Overlay"
+              checked={config.overlay.enabled}
+              onChange={(v) => updateConfig("overlay", { enabled: v })}
+            />
+            <div className="grid grid-cols-2 gap-3">
+              <NumberInput
+                label="Position X"
+                value={config.overlay.x}
+                onChange={(v) => updateConfig("overlay", { x: v })}
+                suffix="px"
+              />
+              <NumberInput
+                label="Position Y"
+                value={config.overlay.y}
+                onChange={(v) => updateConfig("overlay", { y: v })}
+                suffix="px"
+              />
+            </div>
+            <Toggle
+              label="Include Abilities"
+              checked={config.overlay.show_abilities}
+              onChange={(v) => updateConfig("overlay", { show_abilities: v })}
+            />
+            <p className="text-xs text-info">
+              Always-on-top window over the game listing item and ability cooldowns from GSI. It
+              ignores the mouse on Windows, so move it with X/Y. Run Dota in borderless windowed mode.
+            </p>
           </Card>
 
           <Card title="Keybindings">
@@ -35,7 +260,18 @@ export default function Settings() {
               <KeyInput label="Slot 6" value={config.keybindings.slot5} onChange={(v) => updateConfig("keybindings", { slot5: v })} />
             </div>
             <KeyInput label="Neutral Slot" value={config.keybindings.neutral0} onChange={(v) => updateConfig("keybindings", { neutral0: v })} />
+            <KeyInput label="TP Slot" value={config.keybindings.teleport0} onChange={(v) => updateConfig("keybindings", { teleport0: v })} />
             <KeyInput label="Combo Trigger" value={config.keybindings.combo_trigger} onChange={(v) => updateConfig("keybindings", { combo_trigger: v })} />
+            <Toggle
+              label="Second Trigger Press Aborts Combo"
+              checked={config.keybindings.combo_toggle_abort}
+              onChange={(v) => updateConfig("keybindings", { combo_toggle_abort: v })}
+            />
+            {keybindingConflicts.map((conflict) => (
+              <p key={conflict} className="text-xs text-warning">
+                ⚠ Keybinding conflict: {conflict}
+              </p>
+            ))}
           </Card>
 
           <Card title="Common">
@@ -45,10 +281,126 @@ export default function Settings() {
               onChange={(v) => updateConfig("common", { survivability_hp_threshold: v })}
               suffix="%"
             />
+            <NumberInput
+              label="Min Wand/Stick Charges"
+              value={config.common.wand_min_charges}
+              onChange={(v) => updateConfig("common", { wand_min_charges: v })}
+              min={0}
+            />
+            <Toggle
+              label="Hold Noisy Automation While Smoked"
+              checked={config.common.respect_smoke}
+              onChange={(v) => updateConfig("common", { respect_smoke: v })}
+            />
+            <Toggle
+              label="Hold Largo Beats and Quill Spray While Retreating"
+              checked={config.common.hold_offense_while_retreating}
+              onChange={(v) => updateConfig("common", { hold_offense_while_retreating: v })}
+            />
+            <Toggle
+              label="Survivability Items for Heroes Without a Script"
+              checked={config.common.generic_hero_survivability}
+              onChange={(v) => updateConfig("common", { generic_hero_survivability: v })}
+            />
+            <Toggle
+              label="ALT+Click to Face Before Direction Casts"
+              checked={config.common.face_direction_with_alt}
+              onChange={(v) => updateConfig("common", { face_direction_with_alt: v })}
+            />
+            <NumberInput
+              label="Combo Max GSI Age"
+              value={config.common.combo_max_event_age_ms}
+              onChange={(v) => updateConfig("common", { combo_max_event_age_ms: v })}
+              min={0}
+              suffix="ms"
+            />
+            <NumberInput
+              label="Combo Fallback Settle"
+              value={config.common.combo_fallback_settle_ms}
+              onChange={(v) => updateConfig("common", { combo_fallback_settle_ms: v })}
+              min={0}
+              suffix="ms"
+            />
+            <Toggle
+              label="Hold Position After Combo"
+              checked={config.common.combo_hold_after !== null}
+              onChange={(v) => updateConfig("common", { combo_hold_after: v ? "s" : null })}
+            />
+            {config.common.combo_hold_after !== null && (
+              <KeyInput
+                label="Stop/Hold Key"
+                value={config.common.combo_hold_after}
+                onChange={(v) => updateConfig("common", { combo_hold_after: v.toLowerCase() })}
+              />
+            )}
+            <KeyInput
+              label="Attack Key"
+              value={config.common.attack_key}
+              onChange={(v) => updateConfig("common", { attack_key: v.toLowerCase() })}
+            />
           </Card>
         </div>
 
         <div className="space-y-4">
+          <Card title="Profiles">
+            <p className="text-xs text-muted">
+              Profiles hold hero, danger, armlet, item and common settings. Server, keybindings,
+              logging and update settings stay shared.
+            </p>
+            <Dropdown
+              label="Active Profile"
+              value={config.profiles.active}
+              options={[
+                { value: "", label: "Default (no profile)" },
+                ...profiles.map((name) => ({ value: name, label: name })),
+              ]}
+              onChange={(v) => runProfileAction(() => switchProfile(v))}
+            />
+            <div className="flex items-center gap-2">
+              <input
+                type="text"
+                value={newProfileName}
+                onChange={(e) => setNewProfileName(e.target.value)}
+                onKeyDown={(e) => e.key === "Enter" && newProfileName.trim() && saveNewProfile()}
+                placeholder="carry"
+                aria-label="Profile name"
+                className="h-8 w-full rounded-md border border-border bg-input px-3 font-mono text-sm
+                           text-content focus:border-border-accent focus:outline-none"
+              />
+              <Button variant="secondary" onClick={saveNewProfile} disabled={!newProfileName.trim()}>
+                Save As
+              </Button>
+            </div>
+            {profileError && <p className="text-xs text-danger">{profileError}</p>}
+          </Card>
+
+          <Card title="Share Config">
+            <div className="grid grid-cols-2 gap-3">
+              <Button variant="secondary" onClick={copyConfig}>
+                Copy to Clipboard
+              </Button>
+              <Button variant="secondary" onClick={readImport}>
+                Import from Clipboard
+              </Button>
+            </div>
+            {pendingImport !== null && (
+              <div className="space-y-2">
+                <p className="text-xs text-warning">
+                  ⚠ Replace your current settings with the imported config? Server settings are kept.
+                </p>
+                <div className="grid grid-cols-2 gap-3">
+                  <Button onClick={applyImport}>Apply Import</Button>
+                  <Button variant="secondary" onClick={() => setPendingImport(null)}>
+                    Cancel
+                  </Button>
+                </div>
+              </div>
+            )}
+            {shareStatus && (
+              <p className={`text-xs ${shareStatus.error ? "text-danger" : "text-subtle"}`}>{shareStatus.message}</p>
+            )}
+          </Card>
+
           <Card title="Rune Alerts">
             <Toggle label="Enable Rune Alerts" checked={config.rune_alerts.enabled} onChange={(v) => updateConfig("rune_alerts", { enabled: v })} />
             <NumberInput label="Alert Lead Time" value={config.rune_alerts.alert_lead_seconds} onChange={(v) => updateConfig("rune_alerts", { alert_lead_seconds: v })} suffix="s" />
@@ -56,9 +408,81 @@ export default function Settings() {
             <Toggle label="Audio Alert" checked={config.rune_alerts.audio_enabled} onChange={(v) => updateConfig("rune_alerts", { audio_enabled: v })} />
           </Card>
 
+          <Card title="Auto-Consume">
+            <Toggle label="Consume Upgrade Items" checked={config.consumables.auto_consume} onChange={(v) => updateConfig("consumables", { auto_consume: v })} />
+            <TagList label="Allowed Items" items={config.consumables.allowed_items} onChange={(v) => updateConfig("consumables", { allowed_items: v })} />
+            <p className="text-xs text-subtle">
+              Double-taps listed items to use them on your hero. Moon Shard waits for a full inventory; Cheese is never consumed.
+            </p>
+          </Card>
+
+          <Card title="Combo Recorder">
+            <Toggle label="Enable Combo Recorder" checked={config.combo_recorder.enabled} onChange={(v) => updateConfig("combo_recorder", { enabled: v })} />
+            <KeyInput label="Record Start/Stop Key" value={config.combo_recorder.toggle_key} onChange={(v) => updateConfig("combo_recorder", { toggle_key: v })} />
+            <NumberInput label="Max Step Delay" value={config.combo_recorder.max_step_delay_ms} onChange={(v) => updateConfig("combo_recorder", { max_step_delay_ms: v })} min={0} suffix="ms" />
+            <p className="text-xs text-subtle">
+              Press the key, play your combo, press it again. The recording replaces the current hero's standalone combo.
+            </p>
+            {Object.entries(config.combo_recorder.recordings).map(([hero, steps]) => {
+              const randomization = config.combo_recorder.randomization[hero] ?? { jitter_ms: 0, shuffle_unordered: false };
+              const updateRandomization = (partial: Partial<typeof randomization>) =>
+                updateConfig("combo_recorder", {
+                  randomization: { ...config.combo_recorder.randomization, [hero]: { ...randomization, ...partial } },
+                });
+              return (
+                <div key={hero} className="space-y-2 rounded-md border border-border bg-base p-3">
+                  <div className="flex items-center justify-between">
+                    <span className="font-mono text-sm text-content">
+                      {hero} · {steps.length} steps
+                    </span>
+                    <Button
+                      variant="secondary"
+                      onClick={() => {
+                        const { [hero]: _removed, ...rest } = config.combo_recorder.recordings;
+                        const { [hero]: _removedRandomization, ...restRandomization } = config.combo_recorder.randomization;
+                        updateConfig("combo_recorder", { recordings: rest, randomization: restRandomization });
+                      }}
+                    >
+                      Remove
+                    </Button>
+                  </div>
+                  <NumberInput label="Timing Jitter" value={randomization.jitter_ms} onChange={(v) => updateRandomization({ jitter_ms: v })} min={0} suffix="ms" />
+                  <Toggle label="Shuffle Unordered Steps" checked={randomization.shuffle_unordered} onChange={(v) => updateRandomization({ shuffle_unordered: v })} />
+                </div>
+              );
+            })}
+          </Card>
+
+          <Card title="Notifications">
+            <Toggle label="Announce Key Item Purchases" checked={config.notifications.item_acquired} onChange={(v) => updateConfig("notifications", { item_acquired: v })} />
+            <p className="text-xs text-subtle">
+              Adds an activity entry when an item one of your automations uses lands in the inventory.
+            </p>
+          </Card>
+
+          <Card title="Startup">
+            <Toggle label="Enable Automation on Launch" checked={config.startup.arm_on_launch} onChange={(v) => updateConfig("startup", { arm_on_launch: v })} />
+            <Toggle label="Remember Last On/Off State" checked={config.startup.restore_last_arm_state} onChange={(v) => updateConfig("startup", { restore_last_arm_state: v })} />
+            <p className="text-xs text-subtle">
+              With both off, GSI automation and the standalone script start disabled until you turn them on from the Dashboard.
+            </p>
+          </Card>
+
           <Card title="Application">
             <Toggle label="Check for Updates on Startup" checked={config.updates.check_on_startup} onChange={(v) => updateConfig("updates", { check_on_startup: v })} />
             <Toggle label="Include Pre-releases" checked={config.updates.include_prereleases} onChange={(v) => updateConfig("updates", { include_prereleases: v })} />
+            <NumberInput label="Background Check Interval" value={config.updates.check_interval_minutes} onChange={(v) => updateConfig("updates", { check_interval_minutes: v })} min={0} suffix="min" />
+            <Button
+              variant="secondary"
+              className="w-full"
+              onClick={checkForUpdates}
+              disabled={updateState.kind === "checking" || updateState.kind === "downloading"}
+            >
+              Check for Updates Now
+            </Button>
+            {updateStatus && (
+              <p className={`text-xs ${updateState.kind === "error" ? "text-danger" : "text-subtle"}`}>{updateStatus}</p>
+            )}
             <Dropdown
               label="Log Level"
               value={config.logging.level}
@@ -70,6 +494,30 @@ export default function Settings() {
               ]}
               onChange={(v) => updateConfig("logging", { level: v as "debug" | "info" | "warn" | "error" })}
             />
+            <Toggle label="Write Log Files" checked={config.logging.file_enabled} onChange={(v) => updateConfig("logging", { file_enabled: v })} />
+            <p className="text-xs text-subtle">
+              Daily log files in {config.logging.dir}/ to attach to bug reports. Takes effect after a restart.
+            </p>
+            {rollbackVersion && !confirmRollback && (
+              <Button variant="secondary" className="w-full" onClick={() => setConfirmRollback(true)}>
+                Roll Back to v{rollbackVersion}
+              </Button>
+            )}
+            {rollbackVersion && confirmRollback && (
+              <div className="space-y-2">
+                <p className="text-xs text-warning">
+                  ⚠ Restore v{rollbackVersion}? The app will close and restart on the previous version.
+                </p>
+                <div className="grid grid-cols-2 gap-3">
+                  <Button variant="danger" onClick={rollback}>
+                    Roll Back
+                  </Button>
+                  <Button variant="secondary" onClick={() => setConfirmRollback(false)}>
+                    Cancel
+                  </Button>
+                </div>
+              </div>
+            )}
           </Card>
 
           <Card title="Advanced" collapsible defaultOpen={false}>
@@ -0,0 +1,29 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import { useActivityStore } from "./activityStore";
+
+describe("activityStore action kind filter", () => {
+  beforeEach(() => {
+    useActivityStore.setState({ entries: [], filter: "all", actionKind: "all" });
+    const { addEntry } = useActivityStore.getState();
+    addEntry({ id: "1", timestamp: "00:00:01.000", category: "action", message: "Armlet toggled", kind: "armlet" });
+    addEntry({
+      id: "2",
+      timestamp: "00:00:02.000",
+      category: "action",
+      message: "Defensive item activated: black_king_bar (danger)",
+      kind: "defensive_item",
+    });
+    addEntry({ id: "3", timestamp: "00:00:03.000", category: "danger", message: "⚠ Danger detected — HP 20%" });
+  });
+
+  it("narrows actions by kind and resets the kind when the category changes", () => {
+    const store = useActivityStore.getState();
+    store.setFilter("action");
+    store.setActionKind("defensive_item");
+    expect(useActivityStore.getState().filteredEntries().map((e) => e.id)).toEqual(["2"]);
+
+    useActivityStore.getState().setFilter("danger");
+    expect(useActivityStore.getState().actionKind).toBe("all");
+    expect(useActivityStore.getState().filteredEntries().map((e) => e.id)).toEqual(["3"]);
+  });
+});
@@ -1,11 +1,13 @@
 import { create } from "zustand";
-import type { ActivityEntry, ActivityCategory } from "../types/activity";
+import type { ActionKind, ActivityEntry, ActivityCategory } from "../types/activity";
 import { isTauri } from "../lib/tauri";
 
 interface ActivityStore {
   entries: ActivityEntry[];
   filter: ActivityCategory | "all";
+  actionKind: ActionKind | "all";
   setFilter: (filter: ActivityCategory | "all") => void;
+  setActionKind: (kind: ActionKind | "all") => void;
   addEntry: (entry: ActivityEntry) => void;
   clear: () => void;
   filteredEntries: () => ActivityEntry[];
@@ -15,15 +17,20 @@ interface ActivityStore {
 export const useActivityStore = create<ActivityStore>((set, get) => ({
   entries: [],
   filter: "all",
-  setFilter: (filter) => set({ filter }),
+  actionKind: "all",
+  setFilter: (filter) => set({ filter, actionKind: "all" }),
+  setActionKind: (actionKind) => set({ actionKind }),
   addEntry: (entry) =>
     set((state) => ({ entries: [...state.entries.slice(-499), entry] })),
   clear: () => set({ entries: [] }),
   filteredEntries: () => {
-    const { entries, filter } = get();
-    return filter === "all"
-      ? entries
-      : entries.filter((e) => e.category === filter);
+    const { entries, filter, actionKind } = get();
+    if (filter === "all") return entries;
+    return entries.filter(
+      (e) =>
+        e.category === filter &&
+        (filter !== "action" || actionKind === "all" || e.kind === actionKind),
+    );
   },
   startListening: async () => {
     if (!isTauri()) return () => {};
@@ -1,12 +1,22 @@
 import { create } from "zustand";
-import type { Settings } from "../types/config";
+import type { ConfigCommandError, Settings } from "../types/config";
 import { mockConfig } from "./mockData";
 import { isTauri } from "../lib/tauri";
+import { useGameStore } from "./gameStore";
+
+/** A hotkey edit held back while a match is in progress until it is confirmed. */
+export interface PendingHotkeyChange {
+  /** Section or hero whose keys would change, for the confirmation prompt. */
+  target: string;
+  apply: () => void;
+}
 
 interface ConfigStore {
   config: Settings;
   loaded: boolean;
   loadConfig: () => Promise<void>;
+  /** Reload `config` whenever the backend changes it (e.g. a recorded combo was saved). */
+  startListening: () => Promise<() => void>;
   updateConfig: <K extends keyof Settings>(
     section: K,
     updates: Partial<Settings[K]>,
@@ -15,6 +25,33 @@ interface ConfigStore {
     hero: K,
     updates: Partial<Settings["heroes"][K]>,
   ) => void;
+  /** Saved profile names (`config/profiles/*.toml`). */
+  profiles: string[];
+  loadProfiles: () => Promise<void>;
+  saveProfile: (name: string) => Promise<void>;
+  /** Activate a profile ("" for none) and replace `config` with the reloaded one. */
+  switchProfile: (name: string) => Promise<void>;
+  /** Current config as TOML for sharing. */
+  exportConfig: () => Promise<string>;
+  /** Parse and validate shared TOML without applying it; throws the parse error. */
+  previewImport: (contents: string) => Promise<void>;
+  importConfig: (contents: string) => Promise<void>;
+  /** Overlapping key bindings (item slots, Q/W/E/R/D/F, hotkeys) in `config`. */
+  keybindingConflicts: string[];
+  checkKeybindings: () => Promise<void>;
+  /** Keybinding or hero key edit waiting on confirmation (game in progress). */
+  pendingHotkeyChange: PendingHotkeyChange | null;
+  confirmHotkeyChange: () => void;
+  cancelHotkeyChange: () => void;
+}
+
+/** Message of a rejected config command (a `ConfigCommandError`) or any other thrown value. */
+export function configErrorMessage(e: unknown): string {
+  if (e instanceof Error) return e.message;
+  if (typeof e === "object" && e !== null && "message" in e) {
+    return (e as ConfigCommandError).message;
+  }
+  return String(e);
 }
 
 // Debounce timers per section
@@ -53,9 +90,22 @@ function debouncedPersistHero(hero: string, updates: Record<string, unknown>) {
   }, DEBOUNCE_MS);
 }
 
-export const useConfigStore = create<ConfigStore>((set) => ({
+/** Rebinding a key mid-match can break a combo mid-fight, so ask first. */
+function needsHotkeyConfirmation(hotkeyChange: boolean): boolean {
+  return hotkeyChange && useGameStore.getState().game.gameInProgress;
+}
+
+/** Hero fields that are keys (`standalone_key`, raze keys, ability keys). */
+function touchesHeroKeys(updates: Record<string, unknown>): boolean {
+  return Object.keys(updates).some((field) => field.endsWith("_key"));
+}
+
+export const useConfigStore = create<ConfigStore>((set, get) => ({
   config: mockConfig,
   loaded: false,
+  profiles: [],
+  keybindingConflicts: [],
+  pendingHotkeyChange: null,
 
   loadConfig: async () => {
     if (!isTauri()) {
@@ -72,28 +122,116 @@ export const useConfigStore = create<ConfigStore>((set) => ({
     }
   },
 
-  updateConfig: (section, updates) => {
-    set((state) => {
-      const newConfig = {
-        ...state.config,
-        [section]: { ...state.config[section], ...updates },
-      };
-      debouncedPersist(section, updates as Record<string, unknown>);
-      return { config: newConfig };
+  startListening: async () => {
+    if (!isTauri()) return () => {};
+
+    const { listen } = await import("@tauri-apps/api/event");
+
+    return listen("config_changed", () => {
+      get().loadConfig();
     });
   },
 
+  loadProfiles: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      set({ profiles: await invoke<string[]>("list_profiles") });
+    } catch (e) {
+      console.error("Failed to list profiles:", e);
+    }
+  },
+
+  saveProfile: async (name) => {
+    if (!isTauri()) return;
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke("save_profile", { name });
+    await get().loadProfiles();
+  },
+
+  switchProfile: async (name) => {
+    if (!isTauri()) return;
+    const { invoke } = await import("@tauri-apps/api/core");
+    const config = await invoke<Settings>("switch_profile", { name });
+    set({ config });
+  },
+
+  exportConfig: async () => {
+    if (!isTauri()) return "";
+    const { invoke } = await import("@tauri-apps/api/core");
+    return invoke<string>("export_config");
+  },
+
+  previewImport: async (contents) => {
+    if (!isTauri()) return;
+    const { invoke } = await import("@tauri-apps/api/core");
+    await invoke<Settings>("preview_config_import", { contents });
+  },
+
+  importConfig: async (contents) => {
+    if (!isTauri()) return;
+    const { invoke } = await import("@tauri-apps/api/core");
+    const config = await invoke<Settings>("import_config", { contents });
+    set({ config });
+  },
+
+  checkKeybindings: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      const keybindingConflicts = await invoke<string[]>("get_keybinding_conflicts", {
+        config: get().config,
+      });
+      set({ keybindingConflicts });
+    } catch (e) {
+      console.error("Failed to check keybindings:", e);
+    }
+  },
+
+  updateConfig: (section, updates) => {
+    const apply = () =>
+      set((state) => {
+        const newConfig = {
+          ...state.config,
+          [section]: { ...state.config[section], ...updates },
+        };
+        debouncedPersist(section, updates as Record<string, unknown>);
+        return { config: newConfig };
+      });
+
+    if (needsHotkeyConfirmation(section === "keybindings")) {
+      set({ pendingHotkeyChange: { target: "keybindings", apply } });
+      return;
+    }
+    apply();
+  },
+
   updateHeroConfig: (hero, updates) => {
-    set((state) => {
-      const newConfig = {
-        ...state.config,
-        heroes: {
-          ...state.config.heroes,
-          [hero]: { ...state.config.heroes[hero], ...updates },
-        },
-      };
-      debouncedPersistHero(hero, updates as Record<string, unknown>);
-      return { config: newConfig };
-    });
+    const apply = () =>
+      set((state) => {
+        const newConfig = {
+          ...state.config,
+          heroes: {
+            ...state.config.heroes,
+            [hero]: { ...state.config.heroes[hero], ...updates },
+          },
+        };
+        debouncedPersistHero(hero, updates as Record<string, unknown>);
+        return { config: newConfig };
+      });
+
+    if (needsHotkeyConfirmation(touchesHeroKeys(updates as Record<string, unknown>))) {
+      set({ pendingHotkeyChange: { target: String(hero), apply } });
+      return;
+    }
+    apply();
   },
+
+  confirmHotkeyChange: () => {
+    const pending = get().pendingHotkeyChange;
+    set({ pendingHotkeyChange: null });
+    pending?.apply();
+  },
+
+  cancelHotkeyChange: () => set({ pendingHotkeyChange: null }),
 }));
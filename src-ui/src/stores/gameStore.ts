@@ -14,6 +14,8 @@ export const useGameStore = create<GameStore>((set) => ({
   game: {
     heroName: null,
     heroLevel: 0,
+    heroFacet: 0,
+    heroTalents: [],
     hpPercent: 100,
     manaPercent: 100,
     inDanger: false,
@@ -21,9 +23,11 @@ export const useGameStore = create<GameStore>((set) => ({
     alive: true,
     stunned: false,
     silenced: false,
+    broken: false,
     respawnTimer: null,
     runeTimer: null,
     gameTime: 0,
+    gameInProgress: false,
   },
   diagnostics: {
     gsiConnected: false,
@@ -31,6 +35,7 @@ export const useGameStore = create<GameStore>((set) => ({
     queueMetrics: {
       eventsProcessed: 0,
       eventsDropped: 0,
+      eventsDeduplicated: 0,
       currentQueueDepth: 0,
       maxQueueDepth: 10,
     },
@@ -40,9 +45,19 @@ export const useGameStore = create<GameStore>((set) => ({
       peakDepth: 0,
       completions: 0,
       drops: 0,
+      failures: 0,
+      consecutiveFailures: 0,
+      reinitializations: 0,
     },
     soulRingState: "ready",
     blockedKeys: [],
+    automation: {
+      combosTriggered: {},
+      defensiveItemsUsed: {},
+      healingItemsUsed: 0,
+      dangerEvents: 0,
+      armletToggles: 0,
+    },
   },
 
   setGame: (partial) =>
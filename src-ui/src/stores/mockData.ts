@@ -2,16 +2,17 @@ import type { Settings } from "../types/config";
 import type { ActivityEntry } from "../types/activity";
 
 export const mockConfig: Settings = {
-  server: { port: 3000 },
+  server: { port: 3000, bind_address: "127.0.0.1", dedup_identical_events: true },
   keybindings: {
     slot0: "z", slot1: "x", slot2: "c", slot3: "v", slot4: "b", slot5: "n",
-    neutral0: "0", combo_trigger: "Home",
+    neutral0: "0", teleport0: "t", combo_trigger: "Home", combo_toggle_abort: false,
   },
-  logging: { level: "info" },
-  common: { survivability_hp_threshold: 30 },
+  logging: { level: "info", file_enabled: true, dir: "C:\\Users\\player\\AppData\\Local\\dota2-scripts\\logs" },
+  common: { survivability_hp_threshold: 30, wand_min_charges: 1, respect_smoke: true, hold_offense_while_retreating: true, generic_hero_survivability: true, combo_hold_after: null, attack_key: "a", face_direction_with_alt: true, combo_max_event_age_ms: 2000, combo_fallback_settle_ms: 500 },
   armlet: {
     enabled: true, cast_modifier: "Alt", toggle_threshold: 320,
-    predictive_offset: 30, toggle_cooldown_ms: 250,
+    predictive_offset: 30, toggle_cooldown_ms: 250, emergency_toggle_key: "",
+    stuck_detection: { falling_frames: 2, window_ms: 1500 },
     roshan: {
       enabled: false,
       toggle_key: "Insert",
@@ -21,13 +22,16 @@ export const mockConfig: Settings = {
       min_sample_damage: 80,
       stale_reset_ms: 6000,
     },
+    heroes: {},
   },
   heroes: {
     huskar: {
       armlet_toggle_threshold: 120, armlet_predictive_offset: 150,
       armlet_toggle_cooldown_ms: 300, berserker_blood_key: "e",
-      berserker_blood_delay_ms: 300, standalone_key: "Home",
-      armlet: {},
+      berserker_blood_delay_ms: 300, berserker_blood_rearm: true,
+      berserker_blood_require_disable: false, break_armlet_threshold_bonus: 150,
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0,
+      survivability_hp_threshold_override: null, armlet: {},
       roshan_spears: {
         enabled: false,
         burning_spear_key: "w",
@@ -35,15 +39,29 @@ export const mockConfig: Settings = {
         reenable_buffer_hp: 100,
       },
     },
-    legion_commander: { standalone_key: "Home", armlet: {} },
+    legion_commander: {
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off", facet_self_buff_items: {}, auto_bkb_before_duel: false,
+      combo_mana_costs: {}, post_blink_delay_ms: 100, survivability_hp_threshold_override: null, armlet: {},
+    },
     shadow_fiend: {
       raze_intercept_enabled: true, raze_delay_ms: 10,
+      close_raze_key: "q", medium_raze_key: "w", far_raze_key: "e", cast_on_key_release: false,
       auto_bkb_on_ultimate: true, auto_d_on_ultimate: true,
-      standalone_key: "Home", armlet: {},
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, survivability_hp_threshold_override: null, armlet: {},
+    },
+    tiny: {
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off",
+      timing_preset: "custom",
+      toss_catch_window_ms: 1400,
+      avalanche_spam_count: 3,
+      toss_spam_count: 4,
+      post_blink_delay_ms: 100,
+      extra_toss_talent: 0,
+      combo_mana_costs: {},
+      survivability_hp_threshold_override: null, armlet: {},
     },
-    tiny: { standalone_key: "Home", armlet: {} },
     outworld_destroyer: {
-      standalone_key: "Home", objurgation_key: "w", arcane_orb_key: "q",
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off", objurgation_key: "w", arcane_orb_key: "q",
       astral_imprisonment_key: "e", auto_objurgation_on_danger: true,
       objurgation_hp_threshold_percent: 55, objurgation_min_mana_percent: 25,
       objurgation_trigger_cooldown_ms: 1500, ultimate_intercept_enabled: true,
@@ -52,7 +70,7 @@ export const mockConfig: Settings = {
       astral_self_cast_enabled: true, astral_self_cast_key: "F5",
       combo_items: ["sheepstick", "bloodthorn"], combo_item_spam_count: 3,
       combo_item_delay_ms: 30, post_ultimate_arcane_orb_presses: 3,
-      arcane_orb_press_interval_ms: 50, armlet: {},
+      arcane_orb_press_interval_ms: 50, survivability_hp_threshold_override: null, armlet: {},
     },
     largo: {
       amphibian_rhapsody_enabled: true, auto_toggle_on_danger: true,
@@ -60,26 +78,26 @@ export const mockConfig: Settings = {
       beat_interval_ms: 995, beat_correction_ms: 30,
       beat_correction_every_n_beats: 5, q_ability_key: "q",
       w_ability_key: "w", e_ability_key: "e", r_ability_key: "r",
-      standalone_key: "Home", armlet: {},
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, survivability_hp_threshold_override: null, armlet: {},
     },
     broodmother: {
       spider_micro_enabled: true, spider_control_group_key: "F3",
-      reselect_hero_key: "1", attack_key: "a", standalone_key: "Space",
+      reselect_hero_key: "1", standalone_key: "Space", combo_retrigger_cooldown_ms: 0, attack_move: "off",
       auto_items_enabled: true, auto_items_modifier: "Space",
       auto_items: ["orchid", "bloodthorn", "diffusal_blade", "disperser", "nullifier", "abyssal_blade"],
       auto_abilities: [
         { index: 0, key: "q", hp_threshold: 80 },
         { index: 3, key: "r" },
       ],
-      auto_abilities_first: false, armlet: {},
+      auto_abilities_first: false, survivability_hp_threshold_override: null, armlet: {},
     },
     meepo: {
-      standalone_key: "Home", earthbind_key: "q", poof_key: "w",
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off", earthbind_key: "q", poof_key: "w",
       dig_key: "e", megameepo_key: "r", post_blink_delay_ms: 80,
       combo_items: ["sheepstick", "disperser"], combo_item_spam_count: 3,
       combo_item_delay_ms: 30, earthbind_press_count: 2,
       earthbind_press_interval_ms: 50, poof_press_count: 3,
-      poof_press_interval_ms: 50, auto_dig_on_danger: true,
+      poof_press_interval_ms: 50, auto_bkb_before_poof: false, auto_dig_on_danger: true,
       dig_hp_threshold_percent: 32, auto_megameepo_on_danger: true,
       megameepo_hp_threshold_percent: 45, defensive_trigger_cooldown_ms: 1500,
       farm_assist: {
@@ -89,22 +107,43 @@ export const mockConfig: Settings = {
         suspend_after_manual_combo_ms: 2500, poof_press_count: 3,
         poof_press_interval_ms: 50,
       },
-      armlet: {},
+      survivability_hp_threshold_override: null, armlet: {},
+    },
+    zeus: {
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off", arc_lightning_key: "q", lightning_bolt_key: "w",
+      thundergods_wrath_key: "r", spam_lightning_bolt: false, auto_spam_while_attacking: true,
+      attack_window_ms: 1000,
+      mana_reserve: 0, randomization: { jitter_ms: 0, shuffle_unordered: false },
+      survivability_hp_threshold_override: null, armlet: {},
+    },
+    bristleback: {
+      standalone_key: "Home", combo_retrigger_cooldown_ms: 0, attack_move: "off", viscous_nasal_goo_key: "q",
+      auto_quill_spray: true, quill_spray_key: "w",
+      quill_min_mana_percent: 30, quill_combat_window_ms: 3000, survivability_hp_threshold_override: null, armlet: {},
     },
   },
   danger_detection: {
     enabled: true, hp_threshold_percent: 70, rapid_loss_hp: 100,
     time_window_ms: 500, clear_delay_seconds: 3,
     healing_threshold_in_danger: 50, max_healing_items_per_danger: 3,
+    healing_min_recent_loss_hp: 0,
+    max_defensive_items_per_danger: 0,
     auto_bkb: true, auto_satanic: true, satanic_hp_threshold: 40,
     auto_blade_mail: true, auto_glimmer_cape: true,
     auto_ghost_scepter: true, auto_shivas_guard: true,
+    defensive_item_priority: [
+      "item_black_king_bar", "item_satanic", "item_blade_mail",
+      "item_glimmer_cape", "item_ghost", "item_shivas_guard",
+    ],
+    self_cast_methods: { item_glimmer_cape: "double_tap" },
+    self_cast_modifier: "Alt",
     auto_manta_on_silence: true, auto_lotus_on_silence: true,
   },
   neutral_items: {
     enabled: false, self_cast_key: "0", log_discoveries: false,
     use_in_danger: true, hp_threshold: 50,
     allowed_items: ["essence_ring", "minotaur_horn", "metamorphic_mandible"],
+    use_in_auto_items: false,
   },
   soul_ring: {
     enabled: true, min_mana_percent: 100, min_health_percent: 20,
@@ -112,7 +151,7 @@ export const mockConfig: Settings = {
     ability_keys: ["q", "w", "e", "r", "d", "f"],
     intercept_item_keys: true,
   },
-  updates: { check_on_startup: true, include_prereleases: false },
+  updates: { check_on_startup: true, include_prereleases: false, check_interval_minutes: 0 },
   rune_alerts: {
     enabled: true, alert_lead_seconds: 10,
     interval_seconds: 120, audio_enabled: true,
@@ -138,15 +177,25 @@ export const mockConfig: Settings = {
     green_min_saturation: 35.0,
     green_min_value: 25.0,
   },
+  profiles: { active: "" },
+  safe_mode: { enabled: false },
+  practice_mode: { enabled: false },
+  input: { only_input_when_dota_focused: true, min_action_interval_ms: 0 },
+  simulation: { backend: "enigo" },
+  overlay: { enabled: false, x: 40, y: 200, show_abilities: true },
+  notifications: { item_acquired: true },
+  consumables: { auto_consume: false, allowed_items: ["item_aghanims_shard", "item_moon_shard"] },
+  combo_recorder: { enabled: false, toggle_key: "F8", max_step_delay_ms: 1000, recordings: {}, randomization: {} },
+  startup: { arm_on_launch: false, restore_last_arm_state: false, last_gsi_enabled: false, last_standalone_enabled: false },
 };
 
 export const mockActivityLog: ActivityEntry[] = [
   { id: "1", timestamp: "14:32:01.234", category: "system", message: "GSI server started on port 3000" },
   { id: "2", timestamp: "14:32:05.112", category: "system", message: "Hero detected: Shadow Fiend" },
-  { id: "3", timestamp: "14:33:12.456", category: "action", message: "Soul Ring → Raze (Q)" },
+  { id: "3", timestamp: "14:33:12.456", category: "action", message: "Soul Ring combo triggered", kind: "soul_ring" },
   { id: "4", timestamp: "14:33:15.789", category: "danger", message: "⚠ Danger detected — HP 28%" },
-  { id: "5", timestamp: "14:33:15.820", category: "action", message: "Auto-BKB activated" },
-  { id: "6", timestamp: "14:33:16.100", category: "action", message: "Satanic activated (HP 22%)" },
+  { id: "5", timestamp: "14:33:15.820", category: "action", message: "Defensive item activated: black_king_bar (danger)", kind: "defensive_item" },
+  { id: "6", timestamp: "14:33:16.100", category: "action", message: "Defensive item activated: satanic (danger)", kind: "defensive_item" },
   { id: "7", timestamp: "14:34:00.000", category: "system", message: "🔮 Rune spawning in 10s" },
-  { id: "8", timestamp: "14:35:22.333", category: "action", message: "Armlet toggled ON" },
+  { id: "8", timestamp: "14:35:22.333", category: "action", message: "Armlet toggled", kind: "armlet" },
 ];
@@ -86,4 +86,35 @@ describe("uiStore armlet Roshan mode", () => {
 
     unlisten();
   });
+
+  it("loads elevation status alongside the app state snapshot", async () => {
+    invokeMock.mockImplementation(async (command: string) => {
+      if (command === "get_elevation_status") {
+        return { supported: true, elevated: false };
+      }
+      return {
+        selectedHero: null,
+        gsiEnabled: true,
+        standaloneEnabled: false,
+        appVersion: "0.15.0",
+        armletRoshanArmed: false,
+      };
+    });
+
+    await useUIStore.getState().loadInitialState();
+
+    expect(useUIStore.getState().elevation).toEqual({ supported: true, elevated: false });
+  });
+
+  it("returns the message of a rejected GSI server restart", async () => {
+    invokeMock.mockRejectedValueOnce({
+      message: "Server port must be greater than 0",
+      category: "invalid",
+      retryable: false,
+    });
+
+    await expect(useUIStore.getState().restartGsiServer(0)).resolves.toBe(
+      "Server port must be greater than 0",
+    );
+  });
 });
@@ -1,5 +1,7 @@
 import { create } from "zustand";
 import { isTauri } from "../lib/tauri";
+import { configErrorMessage, useConfigStore } from "./configStore";
+import type { CrashReport, ElevationStatus, GsiListener } from "../types/game";
 
 interface UIStore {
   sidebarCollapsed: boolean;
@@ -8,10 +10,17 @@ interface UIStore {
   standaloneEnabled: boolean;
   appVersion: string;
   armletRoshanArmed: boolean;
+  elevation: ElevationStatus | null;
+  crashes: CrashReport[];
+  gsiListener: GsiListener | null;
   setGsiEnabled: (enabled: boolean) => void;
   setStandaloneEnabled: (enabled: boolean) => void;
   setArmletRoshanArmed: (armed: boolean) => void;
   loadInitialState: () => Promise<void>;
+  relaunchElevated: () => Promise<void>;
+  resetAutomationState: () => Promise<void>;
+  /** Moves the GSI server to `port` and saves it; resolves to an error message on failure. */
+  restartGsiServer: (port: number) => Promise<string | null>;
   startListening: () => Promise<() => void>;
 }
 
@@ -22,6 +31,9 @@ export const useUIStore = create<UIStore>((set) => ({
   standaloneEnabled: false,
   appVersion: "0.1.0",
   armletRoshanArmed: false,
+  elevation: null,
+  crashes: [],
+  gsiListener: null,
 
   setGsiEnabled: (enabled) => {
     set({ gsiEnabled: enabled });
@@ -60,18 +72,57 @@ export const useUIStore = create<UIStore>((set) => ({
         standaloneEnabled: boolean;
         armletRoshanArmed: boolean;
         appVersion: string;
+        crashes?: CrashReport[];
+        gsiListener?: GsiListener;
       }>("get_app_state");
       set({
         gsiEnabled: state.gsiEnabled,
         standaloneEnabled: state.standaloneEnabled,
         armletRoshanArmed: state.armletRoshanArmed,
         appVersion: state.appVersion,
+        crashes: state.crashes ?? [],
+        gsiListener: state.gsiListener ?? null,
       });
+      const elevation = await invoke<ElevationStatus>("get_elevation_status");
+      set({ elevation });
     } catch (e) {
       console.error("Failed to load app state:", e);
     }
   },
 
+  relaunchElevated: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      await invoke("relaunch_elevated");
+    } catch (e) {
+      console.error("Failed to relaunch elevated:", e);
+    }
+  },
+
+  resetAutomationState: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      await invoke("reset_automation_state");
+    } catch (e) {
+      console.error("Failed to reset automation state:", e);
+    }
+  },
+
+  restartGsiServer: async (port) => {
+    if (!isTauri()) return null;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      const address = await invoke<string>("restart_gsi_server", { port });
+      set({ gsiListener: { address, error: null, suggestedPort: null } });
+      useConfigStore.setState((s) => ({ config: { ...s.config, server: { ...s.config.server, port } } }));
+      return null;
+    } catch (e) {
+      return configErrorMessage(e);
+    }
+  },
+
   startListening: async () => {
     if (!isTauri()) return () => {};
 
@@ -82,12 +133,16 @@ export const useUIStore = create<UIStore>((set) => ({
       standaloneEnabled: boolean;
       armletRoshanArmed: boolean;
       appVersion: string;
+      crashes?: CrashReport[];
+      gsiListener?: GsiListener;
     }>("app_state_update", (event) => {
       set({
         gsiEnabled: event.payload.gsiEnabled,
         standaloneEnabled: event.payload.standaloneEnabled,
         armletRoshanArmed: event.payload.armletRoshanArmed,
         appVersion: event.payload.appVersion,
+        crashes: event.payload.crashes ?? [],
+        gsiListener: event.payload.gsiListener ?? null,
       });
     });
 
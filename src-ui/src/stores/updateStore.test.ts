@@ -90,3 +90,24 @@ describe("updateStore startup refresh", () => {
     });
   });
 });
+
+describe("updateStore rollback", () => {
+  beforeEach(() => {
+    invokeMock.mockReset();
+    useUpdateStore.setState({ updateState: { kind: "idle" } });
+  });
+
+  it("keeps the category of a rejected rollback", async () => {
+    const rejected = {
+      kind: "error",
+      message: "No previous version is available to roll back to",
+      category: "not_found",
+      retryable: false,
+    };
+    invokeMock.mockRejectedValueOnce(rejected);
+
+    await useUpdateStore.getState().rollback();
+
+    expect(useUpdateStore.getState().updateState).toEqual(rejected);
+  });
+});
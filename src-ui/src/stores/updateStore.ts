@@ -4,6 +4,8 @@ import { isTauri } from "../lib/tauri";
 
 const STARTUP_UPDATE_REFRESH_INTERVAL_MS = 750;
 const STARTUP_UPDATE_REFRESH_ATTEMPTS = 20;
+/** How often to pick up results of the backend's periodic update check. */
+const BACKGROUND_UPDATE_POLL_INTERVAL_MS = 60_000;
 
 interface UpdateStore {
   updateState: UpdateCheckState;
@@ -12,23 +14,42 @@ interface UpdateStore {
   applyUpdate: () => Promise<void>;
   dismissUpdate: () => void;
   loadInitialState: () => Promise<void>;
+  /** Poll for updates found by the periodic check; returns a stop function. */
+  startWatching: () => () => void;
+  /** Version the last in-app update replaced, when it can be restored. */
+  rollbackVersion: string | null;
+  loadRollbackVersion: () => Promise<void>;
+  rollback: () => Promise<void>;
 }
 
 function shouldRefreshStartupUpdateState(state: UpdateCheckState): boolean {
   return state.kind === "idle" || state.kind === "checking";
 }
 
+function canShowBackgroundUpdate(state: UpdateCheckState): boolean {
+  return state.kind === "idle" || state.kind === "upToDate" || state.kind === "error";
+}
+
 function wait(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
 
+/** State for a rejected update command: the categorized error the backend sent, or the thrown value. */
+function rejectedUpdateState(e: unknown): UpdateCheckState {
+  if (typeof e === "object" && e !== null && (e as UpdateCheckState).kind === "error") {
+    return e as UpdateCheckState;
+  }
+  return { kind: "error", message: e instanceof Error ? e.message : String(e) };
+}
+
 async function fetchUpdateState(): Promise<UpdateCheckState> {
   const { invoke } = await import("@tauri-apps/api/core");
   return invoke<UpdateCheckState>("get_update_state");
 }
 
-export const useUpdateStore = create<UpdateStore>((set) => ({
+export const useUpdateStore = create<UpdateStore>((set, get) => ({
   updateState: { kind: "idle" },
+  rollbackVersion: null,
 
   setUpdateState: (updateState) => set({ updateState }),
 
@@ -53,6 +74,21 @@ export const useUpdateStore = create<UpdateStore>((set) => ({
     }
   },
 
+  startWatching: () => {
+    if (!isTauri()) return () => {};
+    const timer = setInterval(async () => {
+      try {
+        const state = await fetchUpdateState();
+        if (state.kind === "available" && canShowBackgroundUpdate(get().updateState)) {
+          set({ updateState: state });
+        }
+      } catch (e) {
+        console.error("Failed to poll update state:", e);
+      }
+    }, BACKGROUND_UPDATE_POLL_INTERVAL_MS);
+    return () => clearInterval(timer);
+  },
+
   checkForUpdates: async () => {
     if (!isTauri()) return;
     set({ updateState: { kind: "checking" } });
@@ -61,12 +97,7 @@ export const useUpdateStore = create<UpdateStore>((set) => ({
       const result = await invoke<UpdateCheckState>("check_for_updates");
       set({ updateState: result });
     } catch (e) {
-      set({
-        updateState: {
-          kind: "error",
-          message: e instanceof Error ? e.message : String(e),
-        },
-      });
+      set({ updateState: rejectedUpdateState(e) });
     }
   },
 
@@ -78,12 +109,27 @@ export const useUpdateStore = create<UpdateStore>((set) => ({
       const result = await invoke<UpdateCheckState>("apply_update");
       set({ updateState: result });
     } catch (e) {
-      set({
-        updateState: {
-          kind: "error",
-          message: e instanceof Error ? e.message : String(e),
-        },
-      });
+      set({ updateState: rejectedUpdateState(e) });
+    }
+  },
+
+  loadRollbackVersion: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      set({ rollbackVersion: await invoke<string | null>("get_rollback_version") });
+    } catch (e) {
+      console.error("Failed to load rollback version:", e);
+    }
+  },
+
+  rollback: async () => {
+    if (!isTauri()) return;
+    try {
+      const { invoke } = await import("@tauri-apps/api/core");
+      await invoke<string>("rollback_update");
+    } catch (e) {
+      set({ updateState: rejectedUpdateState(e) });
     }
   },
 
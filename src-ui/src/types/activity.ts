@@ -1,9 +1,21 @@
 export type ActivityCategory = "action" | "danger" | "warning" | "error" | "system";
 
+/** Kind of an `action` entry, from the backend's `ActionEvent`. */
+export type ActionKind =
+  | "defensive_item"
+  | "healing_item"
+  | "danger_item"
+  | "mana_item"
+  | "consumable"
+  | "soul_ring"
+  | "armlet"
+  | "combo";
+
 export interface ActivityEntry {
   id: string;
   timestamp: string;
   category: ActivityCategory;
   message: string;
   details?: string;
+  kind?: ActionKind;
 }
@@ -1,10 +1,15 @@
+import type { ErrorCategory } from "./game";
+
 export interface ServerConfig {
   port: number;
+  bind_address: string;
+  dedup_identical_events: boolean;
 }
 
 export interface UpdateConfig {
   check_on_startup: boolean;
   include_prereleases: boolean;
+  check_interval_minutes: number;
 }
 
 export interface KeybindingsConfig {
@@ -15,15 +20,33 @@ export interface KeybindingsConfig {
   slot4: string;
   slot5: string;
   neutral0: string;
+  teleport0: string;
   combo_trigger: string;
+  combo_toggle_abort: boolean;
 }
 
 export interface LoggingConfig {
   level: "debug" | "info" | "warn" | "error";
+  file_enabled: boolean;
+  dir: string;
 }
 
+/** When a standalone combo issues an attack-move at the cursor. */
+export type AttackMove = "off" | "before" | "after";
+export type SelfCastMethod = "double_tap" | "self_cast_modifier";
+export type CastModifier = "Alt" | "Ctrl" | "Shift";
+
 export interface CommonConfig {
   survivability_hp_threshold: number;
+  wand_min_charges: number;
+  respect_smoke: boolean;
+  hold_offense_while_retreating: boolean;
+  generic_hero_survivability: boolean;
+  combo_hold_after: string | null;
+  attack_key: string;
+  face_direction_with_alt: boolean;
+  combo_max_event_age_ms: number;
+  combo_fallback_settle_ms: number;
 }
 
 export interface ArmletConfig {
@@ -32,7 +55,18 @@ export interface ArmletConfig {
   toggle_threshold: number;
   predictive_offset: number;
   toggle_cooldown_ms: number;
+  /** Empty disables the emergency hotkey */
+  emergency_toggle_key: string;
+  stuck_detection: ArmletStuckDetectionConfig;
   roshan: ArmletRoshanConfig;
+  /** Overrides for heroes without their own config section, keyed by name without `npc_dota_hero_` */
+  heroes: Record<string, HeroArmletOverride>;
+}
+
+export interface ArmletStuckDetectionConfig {
+  /** 0 disables the forced re-toggle */
+  falling_frames: number;
+  window_ms: number;
 }
 
 export interface ArmletRoshanConfig {
@@ -65,27 +99,55 @@ export interface HuskarConfig {
   armlet_toggle_cooldown_ms: number;
   berserker_blood_key: string;
   berserker_blood_delay_ms: number;
+  berserker_blood_rearm: boolean;
+  berserker_blood_require_disable: boolean;
+  break_armlet_threshold_bonus: number;
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
   roshan_spears: HuskarRoshanSpearsConfig;
 }
 
 export interface LegionCommanderConfig {
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
+  facet_self_buff_items: Record<string, string[]>;
+  auto_bkb_before_duel: boolean;
+  combo_mana_costs: Record<string, number>;
+  post_blink_delay_ms: number;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
 export interface ShadowFiendConfig {
   raze_intercept_enabled: boolean;
   raze_delay_ms: number;
+  close_raze_key: string;
+  medium_raze_key: string;
+  far_raze_key: string;
+  cast_on_key_release: boolean;
   auto_bkb_on_ultimate: boolean;
   auto_d_on_ultimate: boolean;
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
 export interface TinyConfig {
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
+  timing_preset: "low_ping" | "medium_ping" | "high_ping" | "custom";
+  toss_catch_window_ms: number;
+  avalanche_spam_count: number;
+  toss_spam_count: number;
+  post_blink_delay_ms: number;
+  extra_toss_talent: number;
+  combo_mana_costs: Record<string, number>;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
@@ -99,13 +161,15 @@ export interface BroodmotherConfig {
   spider_micro_enabled: boolean;
   spider_control_group_key: string;
   reselect_hero_key: string;
-  attack_key: string;
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
   auto_items_enabled: boolean;
   auto_items_modifier: string;
   auto_items: string[];
   auto_abilities: AutoAbilityConfig[];
   auto_abilities_first: boolean;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
@@ -122,6 +186,8 @@ export interface LargoConfig {
   e_ability_key: string;
   r_ability_key: string;
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
@@ -140,6 +206,8 @@ export interface MeepoFarmAssistConfig {
 
 export interface MeepoConfig {
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
   earthbind_key: string;
   poof_key: string;
   dig_key: string;
@@ -152,17 +220,21 @@ export interface MeepoConfig {
   earthbind_press_interval_ms: number;
   poof_press_count: number;
   poof_press_interval_ms: number;
+  auto_bkb_before_poof: boolean;
   auto_dig_on_danger: boolean;
   dig_hp_threshold_percent: number;
   auto_megameepo_on_danger: boolean;
   megameepo_hp_threshold_percent: number;
   defensive_trigger_cooldown_ms: number;
   farm_assist: MeepoFarmAssistConfig;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
 export interface OutworldDestroyerConfig {
   standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
   objurgation_key: string;
   arcane_orb_key: string;
   astral_imprisonment_key: string;
@@ -182,6 +254,36 @@ export interface OutworldDestroyerConfig {
   combo_item_delay_ms: number;
   post_ultimate_arcane_orb_presses: number;
   arcane_orb_press_interval_ms: number;
+  survivability_hp_threshold_override: number | null;
+  armlet: HeroArmletOverride;
+}
+
+export interface BristlebackConfig {
+  standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
+  viscous_nasal_goo_key: string;
+  auto_quill_spray: boolean;
+  quill_spray_key: string;
+  quill_min_mana_percent: number;
+  quill_combat_window_ms: number;
+  survivability_hp_threshold_override: number | null;
+  armlet: HeroArmletOverride;
+}
+
+export interface ZeusConfig {
+  standalone_key: string;
+  combo_retrigger_cooldown_ms: number;
+  attack_move: AttackMove;
+  arc_lightning_key: string;
+  lightning_bolt_key: string;
+  thundergods_wrath_key: string;
+  spam_lightning_bolt: boolean;
+  auto_spam_while_attacking: boolean;
+  attack_window_ms: number;
+  mana_reserve: number;
+  randomization: ComboRandomizationConfig;
+  survivability_hp_threshold_override: number | null;
   armlet: HeroArmletOverride;
 }
 
@@ -194,6 +296,8 @@ export interface HeroesConfig {
   largo: LargoConfig;
   broodmother: BroodmotherConfig;
   meepo: MeepoConfig;
+  zeus: ZeusConfig;
+  bristleback: BristlebackConfig;
 }
 
 export interface DangerDetectionConfig {
@@ -204,6 +308,8 @@ export interface DangerDetectionConfig {
   clear_delay_seconds: number;
   healing_threshold_in_danger: number;
   max_healing_items_per_danger: number;
+  healing_min_recent_loss_hp: number;
+  max_defensive_items_per_danger: number;
   auto_bkb: boolean;
   auto_satanic: boolean;
   satanic_hp_threshold: number;
@@ -211,6 +317,9 @@ export interface DangerDetectionConfig {
   auto_glimmer_cape: boolean;
   auto_ghost_scepter: boolean;
   auto_shivas_guard: boolean;
+  defensive_item_priority: string[];
+  self_cast_methods: Record<string, SelfCastMethod>;
+  self_cast_modifier: CastModifier;
   auto_manta_on_silence: boolean;
   auto_lotus_on_silence: boolean;
 }
@@ -222,6 +331,7 @@ export interface NeutralItemConfig {
   use_in_danger: boolean;
   hp_threshold: number;
   allowed_items: string[];
+  use_in_auto_items: boolean;
 }
 
 export interface SoulRingConfig {
@@ -252,6 +362,73 @@ export interface MinimapCaptureConfig {
   artifact_output_dir: string;
 }
 
+export interface ProfilesConfig {
+  active: string;
+}
+
+export interface SafeModeConfig {
+  enabled: boolean;
+}
+
+export interface PracticeModeConfig {
+  enabled: boolean;
+}
+
+export interface InputConfig {
+  only_input_when_dota_focused: boolean;
+  min_action_interval_ms: number;
+}
+
+/** Mirrors InputBackend in src/config/settings.rs. */
+export type InputBackend = "enigo" | "scancode";
+
+export interface SimulationConfig {
+  backend: InputBackend;
+}
+
+export interface StartupConfig {
+  arm_on_launch: boolean;
+  restore_last_arm_state: boolean;
+  last_gsi_enabled: boolean;
+  last_standalone_enabled: boolean;
+}
+
+export interface NotificationsConfig {
+  item_acquired: boolean;
+}
+
+export interface ConsumablesConfig {
+  auto_consume: boolean;
+  allowed_items: string[];
+}
+
+export interface RecordedComboStep {
+  /** Key character ("q"), or "left_click" / "right_click". */
+  input: string;
+  delay_ms: number;
+  unordered: boolean;
+}
+
+export interface ComboRandomizationConfig {
+  jitter_ms: number;
+  shuffle_unordered: boolean;
+}
+
+export interface ComboRecorderConfig {
+  enabled: boolean;
+  toggle_key: string;
+  max_step_delay_ms: number;
+  recordings: Record<string, RecordedComboStep[]>;
+  randomization: Record<string, ComboRandomizationConfig>;
+}
+
+export interface OverlayConfig {
+  enabled: boolean;
+  x: number;
+  y: number;
+  show_abilities: boolean;
+}
+
 export interface MinimapAnalysisConfig {
   enabled: boolean;
   baseline_frames: number;
@@ -282,4 +459,21 @@ export interface Settings {
   rune_alerts: RuneAlertConfig;
   minimap_capture: MinimapCaptureConfig;
   minimap_analysis: MinimapAnalysisConfig;
+  profiles: ProfilesConfig;
+  safe_mode: SafeModeConfig;
+  practice_mode: PracticeModeConfig;
+  input: InputConfig;
+  simulation: SimulationConfig;
+  overlay: OverlayConfig;
+  notifications: NotificationsConfig;
+  consumables: ConsumablesConfig;
+  combo_recorder: ComboRecorderConfig;
+  startup: StartupConfig;
+}
+
+/** Rejection from the config commands; mirrors ConfigErrorDto in src-tauri/src/ipc_types.rs. */
+export interface ConfigCommandError {
+  message: string;
+  category: ErrorCategory;
+  retryable: boolean;
 }
@@ -1,4 +1,5 @@
 export type HeroType =
+  | "bristleback"
   | "broodmother"
   | "huskar"
   | "largo"
@@ -6,7 +7,8 @@ export type HeroType =
   | "meepo"
   | "outworld_destroyer"
   | "shadow_fiend"
-  | "tiny";
+  | "tiny"
+  | "zeus";
 
 export interface HeroInfo {
   id: HeroType;
@@ -17,6 +19,7 @@ export interface HeroInfo {
 }
 
 export const HEROES: HeroInfo[] = [
+  { id: "bristleback", displayName: "Bristleback", internalName: "npc_dota_hero_bristleback", icon: "🦔", role: "Carry / Durable" },
   { id: "broodmother", displayName: "Broodmother", internalName: "npc_dota_hero_broodmother", icon: "🕷️", role: "Pusher / Carry" },
   { id: "huskar", displayName: "Huskar", internalName: "npc_dota_hero_huskar", icon: "🔥", role: "Carry / Durable" },
   { id: "largo", displayName: "Largo", internalName: "npc_dota_hero_largo", icon: "🎵", role: "Support / Healer" },
@@ -25,19 +28,34 @@ export const HEROES: HeroInfo[] = [
   { id: "outworld_destroyer", displayName: "Outworld Destroyer", internalName: "npc_dota_hero_obsidian_destroyer", icon: "🌀", role: "Carry / Nuker" },
   { id: "shadow_fiend", displayName: "Shadow Fiend", internalName: "npc_dota_hero_nevermore", icon: "👻", role: "Carry / Nuker" },
   { id: "tiny", displayName: "Tiny", internalName: "npc_dota_hero_tiny", icon: "🪨", role: "Initiator / Nuker" },
+  { id: "zeus", displayName: "Zeus", internalName: "npc_dota_hero_zuus", icon: "⚡", role: "Nuker" },
 ];
 
+/** Mirrors ErrorCategory in src/config/error.rs. */
+export type ErrorCategory =
+  | "network"
+  | "permission"
+  | "not_found"
+  | "invalid"
+  | "unsupported"
+  | "conflict"
+  | "internal";
+
 export type UpdateCheckState =
   | { kind: "idle" }
   | { kind: "checking" }
   | { kind: "available"; version: string; releaseNotes?: string }
   | { kind: "downloading" }
-  | { kind: "error"; message: string }
+  /** `category`/`retryable` are absent when the command itself failed. */
+  | { kind: "error"; message: string; category?: ErrorCategory; retryable?: boolean }
   | { kind: "upToDate" };
 
 export interface GameState {
   heroName: string | null;
   heroLevel: number;
+  heroFacet: number;
+  /** 1-based GSI talent slots picked so far (1-2 level 10 … 7-8 level 25). */
+  heroTalents: number[];
   hpPercent: number;
   manaPercent: number;
   inDanger: boolean;
@@ -45,18 +63,43 @@ export interface GameState {
   alive: boolean;
   stunned: boolean;
   silenced: boolean;
+  /** GSI `hero.break`: passives are disabled. */
+  broken: boolean;
   respawnTimer: number | null;
   runeTimer: number | null;
   gameTime: number;
+  /** GSI reports `DOTA_GAMERULES_STATE_GAME_IN_PROGRESS`. */
+  gameInProgress: boolean;
+}
+
+/** One row of the cooldown overlay (`overlay_cooldowns` event). */
+export interface CooldownEntry {
+  /** GSI item name without the `item_` prefix, or the ability name. */
+  name: string;
+  kind: "item" | "ability";
+  /** Seconds remaining; 0 when ready. */
+  cooldown: number;
+  charges: number | null;
 }
 
 export interface QueueMetrics {
   eventsProcessed: number;
   eventsDropped: number;
+  eventsDeduplicated: number;
   currentQueueDepth: number;
   maxQueueDepth: number;
 }
 
+export interface AutomationMetrics {
+  /** Keyed by hero display name */
+  combosTriggered: Record<string, number>;
+  /** Keyed by item name, e.g. "item_black_king_bar" */
+  defensiveItemsUsed: Record<string, number>;
+  healingItemsUsed: number;
+  dangerEvents: number;
+  armletToggles: number;
+}
+
 export interface DiagnosticsState {
   gsiConnected: boolean;
   keyboardHookActive: boolean;
@@ -67,9 +110,34 @@ export interface DiagnosticsState {
     peakDepth: number;
     completions: number;
     drops: number;
+    failures: number;
+    /** Non-zero while the input backend is failing */
+    consecutiveFailures: number;
+    reinitializations: number;
   };
   soulRingState: "ready" | "triggered" | "cooldown";
   blockedKeys: string[];
+  automation: AutomationMetrics;
+}
+
+/** Where the GSI server is listening; `address` is null while it is not. */
+export interface GsiListener {
+  address: string | null;
+  error: string | null;
+  /** Free port near a taken one, offered as a fix. */
+  suggestedPort: number | null;
+}
+
+/** A background subsystem stopped by a panic; details are in the log folder. */
+export interface CrashReport {
+  subsystem: string;
+  message: string;
+  location: string;
+}
+
+export interface ElevationStatus {
+  supported: boolean;
+  elevated: boolean;
 }
 
 export interface MeepoObservedState {
@@ -1,7 +1,9 @@
+use crate::state::poison::lock_or_recover;
 use std::collections::VecDeque;
 use std::sync::LazyLock;
-use std::sync::Mutex;
+use std::sync::{Mutex, TryLockError};
 use std::time::SystemTime;
+use tracing::info;
 
 /// Maximum entries retained in the buffer before oldest are dropped.
 const MAX_BUFFER_SIZE: usize = 200;
@@ -28,6 +30,78 @@ impl ActivityCategory {
     }
 }
 
+/// Something the automation did, pushed from the action site so the UI can
+/// show and filter a concise "what the bot did" feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionEvent {
+    /// Defensive item fired while in danger.
+    DefensiveItem { item: String },
+    HealingItem { item: String },
+    /// Neutral item from the danger automation list.
+    DangerItem { item: String },
+    /// Item from the low-mana automation list.
+    ManaItem { item: String },
+    /// Upgrade item from the auto-consume list, used on the hero.
+    Consumable { item: String },
+    SoulRing,
+    ArmletToggle,
+    /// Standalone combo trigger, by hero game name.
+    Combo { hero: String },
+}
+
+impl ActionEvent {
+    /// Stable identifier the UI filters on.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ActionEvent::DefensiveItem { .. } => "defensive_item",
+            ActionEvent::HealingItem { .. } => "healing_item",
+            ActionEvent::DangerItem { .. } => "danger_item",
+            ActionEvent::ManaItem { .. } => "mana_item",
+            ActionEvent::Consumable { .. } => "consumable",
+            ActionEvent::SoulRing => "soul_ring",
+            ActionEvent::ArmletToggle => "armlet",
+            ActionEvent::Combo { .. } => "combo",
+        }
+    }
+
+    /// What the action does, phrased to follow "Would" in practice mode.
+    pub fn intent(&self) -> String {
+        let short = |item: &str| item.trim_start_matches("item_").to_string();
+        match self {
+            ActionEvent::DefensiveItem { item }
+            | ActionEvent::HealingItem { item }
+            | ActionEvent::DangerItem { item }
+            | ActionEvent::ManaItem { item } => format!("use {}", short(item)),
+            ActionEvent::Consumable { item } => format!("consume {}", short(item)),
+            ActionEvent::SoulRing => "use Soul Ring".to_string(),
+            ActionEvent::ArmletToggle => "toggle armlet".to_string(),
+            ActionEvent::Combo { hero } => format!("fire {} combo", hero_label(hero)),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        let short = |item: &str| item.trim_start_matches("item_").to_string();
+        match self {
+            ActionEvent::DefensiveItem { item } => {
+                format!("Defensive item activated: {} (danger)", short(item))
+            }
+            ActionEvent::HealingItem { item } => format!("Healing item used: {}", short(item)),
+            ActionEvent::DangerItem { item } => {
+                format!("Danger automation used: {}", short(item))
+            }
+            ActionEvent::ManaItem { item } => format!("Mana automation used: {}", short(item)),
+            ActionEvent::Consumable { item } => format!("Consumed {}", short(item)),
+            ActionEvent::SoulRing => "Soul Ring combo triggered".to_string(),
+            ActionEvent::ArmletToggle => "Armlet toggled".to_string(),
+            ActionEvent::Combo { hero } => format!("Fired {} combo", hero_label(hero)),
+        }
+    }
+}
+
+fn hero_label(hero: &str) -> String {
+    hero.trim_start_matches("npc_dota_hero_").replace('_', " ")
+}
+
 /// A single activity event produced by the backend.
 #[derive(Debug, Clone)]
 pub struct ActivityEntry {
@@ -35,6 +109,8 @@ pub struct ActivityEntry {
     pub category: ActivityCategory,
     pub message: String,
     pub details: Option<String>,
+    /// [`ActionEvent::kind`] for entries pushed with [`push_action`].
+    pub kind: Option<&'static str>,
 }
 
 static ACTIVITY_BUFFER: LazyLock<Mutex<VecDeque<ActivityEntry>>> =
@@ -42,7 +118,8 @@ static ACTIVITY_BUFFER: LazyLock<Mutex<VecDeque<ActivityEntry>>> =
 
 /// Push an activity event into the global buffer.
 pub fn push_activity(category: ActivityCategory, message: impl Into<String>) {
-    if let Ok(mut buf) = ACTIVITY_BUFFER.lock() {
+    {
+        let mut buf = lock_or_recover(&ACTIVITY_BUFFER);
         if buf.len() >= MAX_BUFFER_SIZE {
             buf.pop_front();
         }
@@ -51,17 +128,41 @@ pub fn push_activity(category: ActivityCategory, message: impl Into<String>) {
             category,
             message: message.into(),
             details: None,
+            kind: None,
         });
     }
 }
 
+/// [`push_activity`] that gives up instead of waiting when the buffer is
+/// locked. For the panic hook, where a panic raised while this thread holds
+/// the buffer would otherwise deadlock. Returns whether the entry was pushed.
+pub fn try_push_activity(category: ActivityCategory, message: impl Into<String>) -> bool {
+    let mut buf = match ACTIVITY_BUFFER.try_lock() {
+        Ok(buf) => buf,
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        Err(TryLockError::WouldBlock) => return false,
+    };
+    if buf.len() >= MAX_BUFFER_SIZE {
+        buf.pop_front();
+    }
+    buf.push_back(ActivityEntry {
+        timestamp: SystemTime::now(),
+        category,
+        message: message.into(),
+        details: None,
+        kind: None,
+    });
+    true
+}
+
 /// Push an activity event with optional details.
 pub fn push_activity_with_details(
     category: ActivityCategory,
     message: impl Into<String>,
     details: impl Into<String>,
 ) {
-    if let Ok(mut buf) = ACTIVITY_BUFFER.lock() {
+    {
+        let mut buf = lock_or_recover(&ACTIVITY_BUFFER);
         if buf.len() >= MAX_BUFFER_SIZE {
             buf.pop_front();
         }
@@ -70,17 +171,45 @@ pub fn push_activity_with_details(
             category,
             message: message.into(),
             details: Some(details.into()),
+            kind: None,
+        });
+    }
+}
+
+/// Push an [`ActionEvent`] as an `Action` entry tagged with its kind.
+///
+/// `reason` is why the action fired (e.g. "HP 35% < 40% threshold in
+/// danger"). It goes in the entry's details, or into the message itself when
+/// `practice` is set (callers pass `simulation::practice_mode_enabled()`),
+/// since the action is never sent then.
+pub fn push_action(event: ActionEvent, reason: impl Into<String>, practice: bool) {
+    let reason = reason.into();
+    let (message, details) = if practice {
+        let message = format!("Would {}: {}", event.intent(), reason);
+        info!("🎯 {}", message);
+        (message, None)
+    } else {
+        (event.message(), Some(reason))
+    };
+
+    {
+        let mut buf = lock_or_recover(&ACTIVITY_BUFFER);
+        if buf.len() >= MAX_BUFFER_SIZE {
+            buf.pop_front();
+        }
+        buf.push_back(ActivityEntry {
+            timestamp: SystemTime::now(),
+            category: ActivityCategory::Action,
+            message,
+            details,
+            kind: Some(event.kind()),
         });
     }
 }
 
 /// Drain all pending activity entries from the buffer.
-/// Returns an empty vec if the buffer is empty or the lock is poisoned.
 pub fn drain_activities() -> Vec<ActivityEntry> {
-    match ACTIVITY_BUFFER.lock() {
-        Ok(mut buf) => buf.drain(..).collect(),
-        Err(_) => Vec::new(),
-    }
+    lock_or_recover(&ACTIVITY_BUFFER).drain(..).collect()
 }
 
 #[cfg(test)]
@@ -132,6 +261,41 @@ mod tests {
         assert!(entries.is_empty());
     }
 
+    #[test]
+    fn action_events_carry_kind_and_reason_and_explain_in_practice_mode() {
+        let _guard = shared_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        drain_activities();
+        let bkb = ActionEvent::DefensiveItem {
+            item: "item_black_king_bar".to_string(),
+        };
+        push_action(bkb.clone(), "HP 30% in danger".to_string(), false);
+        push_action(
+            ActionEvent::Combo {
+                hero: "npc_dota_hero_legion_commander".to_string(),
+            },
+            "trigger key pressed".to_string(),
+            false,
+        );
+        push_action(bkb, "HP 30% in danger".to_string(), true);
+
+        let entries: Vec<_> = drain_activities()
+            .into_iter()
+            .filter(|e| e.message.contains("black_king_bar") || e.message.contains("legion"))
+            .collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].category, ActivityCategory::Action);
+        assert_eq!(entries[0].kind, Some("defensive_item"));
+        assert_eq!(entries[0].message, "Defensive item activated: black_king_bar (danger)");
+        assert_eq!(entries[0].details.as_deref(), Some("HP 30% in danger"));
+        assert_eq!(entries[1].kind, Some("combo"));
+        assert_eq!(entries[1].message, "Fired legion commander combo");
+        assert_eq!(entries[2].kind, Some("defensive_item"));
+        assert_eq!(entries[2].message, "Would use black_king_bar: HP 30% in danger");
+    }
+
     #[test]
     fn test_buffer_overflow() {
         let _guard = shared_test_lock()
@@ -1,7 +1,13 @@
-use crate::config::settings::{ArmletRoshanConfig, EffectiveArmletConfig};
+use crate::actions::activity::{push_action, ActionEvent};
+use crate::actions::common::find_item_slot;
+use crate::config::settings::{
+    ArmletRoshanConfig, ArmletStuckDetectionConfig, EffectiveArmletConfig,
+};
 use crate::config::Settings;
-use crate::input::simulation::{armlet_chord, ModifierKey};
-use crate::models::GsiWebhookEvent;
+use crate::input::simulation::{armlet_chord, practice_mode_enabled, ModifierKey};
+use crate::models::{GsiWebhookEvent, Item};
+use crate::state::automation_metrics::record_armlet_toggle;
+use crate::state::poison::{lock_or_recover, lock_or_reset};
 use lazy_static::lazy_static;
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -11,7 +17,8 @@ use tracing::{debug, info, trace, warn};
 
 lazy_static! {
     static ref ARMLET_LAST_TOGGLE: Mutex<Option<Instant>> = Mutex::new(None);
-    static ref ARMLET_CRITICAL_HP: Mutex<Option<u32>> = Mutex::new(None);
+    static ref ARMLET_STUCK_TRACKER: Mutex<ArmletStuckTracker> =
+        Mutex::new(ArmletStuckTracker::default());
     static ref ARMLET_ROSHAN_STATE: Mutex<ArmletRoshanState> =
         Mutex::new(ArmletRoshanState::default());
 }
@@ -171,9 +178,10 @@ pub fn is_roshan_mode_armed() -> bool {
 pub fn set_roshan_mode_armed(armed: bool) -> bool {
     let previous = ARMLET_ROSHAN_MODE_ARMED.swap(armed, Ordering::SeqCst);
     if previous != armed {
-        if let Ok(mut state) = ARMLET_ROSHAN_STATE.lock() {
-            clear_roshan_learning_state_with_reason(&mut state, RoshanResetReason::ModeToggled);
-        }
+        clear_roshan_learning_state_with_reason(
+            &mut lock_or_reset(&ARMLET_ROSHAN_STATE),
+            RoshanResetReason::ModeToggled,
+        );
         info!(
             "Armlet Roshan mode {} (reason: {})",
             if armed { "armed" } else { "disarmed" },
@@ -188,7 +196,15 @@ pub fn toggle_roshan_mode() -> bool {
     set_roshan_mode_armed(!is_roshan_mode_armed())
 }
 
-fn parse_cast_modifier(raw: &str) -> Option<ModifierKey> {
+/// Clear the toggle cooldown, stuck-armlet watch and Roshan learning.
+/// Roshan mode stays armed: that is a user toggle, not runtime state.
+pub fn reset() {
+    *lock_or_recover(&ARMLET_LAST_TOGGLE) = None;
+    lock_or_reset(&ARMLET_STUCK_TRACKER).reset();
+    *lock_or_reset(&ARMLET_ROSHAN_STATE) = ArmletRoshanState::default();
+}
+
+pub(crate) fn parse_cast_modifier(raw: &str) -> Option<ModifierKey> {
     match raw.trim().to_ascii_lowercase().as_str() {
         "alt" => Some(ModifierKey::Alt),
         "ctrl" | "control" => Some(ModifierKey::Control),
@@ -219,7 +235,7 @@ fn plan_dual_trigger_sequence(
     ]
 }
 
-fn execute_dual_trigger(slot_key: char, cast_modifier: ModifierKey) {
+fn execute_dual_trigger(slot_key: char, cast_modifier: ModifierKey, reason: String) {
     let started = Instant::now();
     let sequence = plan_dual_trigger_sequence(slot_key, cast_modifier);
     debug!(
@@ -228,6 +244,8 @@ fn execute_dual_trigger(slot_key: char, cast_modifier: ModifierKey) {
     );
 
     armlet_chord(slot_key, cast_modifier);
+    record_armlet_toggle();
+    push_action(ActionEvent::ArmletToggle, reason, practice_mode_enabled());
 
     debug!(
         "Armlet dual-trigger finished in {}ms via dedicated worker chord",
@@ -235,14 +253,69 @@ fn execute_dual_trigger(slot_key: char, cast_modifier: ModifierKey) {
     );
 }
 
-fn next_critical_retry_health(health: u32, threshold: u32) -> Option<u32> {
-    if health < threshold / 2 {
-        Some(health)
-    } else {
-        None
+/// Watches HP after the last toggle attempt to catch an armlet stuck on,
+/// i.e. a toggle that did not restore HP. Keyed on HP still falling below
+/// the threshold rather than on a fraction of it, so it behaves the same for
+/// small and large HP pools.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct ArmletStuckTracker {
+    /// When the watched toggle attempt was sent; `None` while idle.
+    toggled_at_ms: Option<u64>,
+    last_health: u32,
+    falling_frames: u32,
+}
+
+impl ArmletStuckTracker {
+    fn watch(&mut self, now_ms: u64, health: u32) {
+        *self = Self {
+            toggled_at_ms: Some(now_ms),
+            last_health: health,
+            falling_frames: 0,
+        };
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_watching(&self) -> bool {
+        self.toggled_at_ms.is_some()
+    }
+
+    /// Feed one GSI frame and report whether a forced re-toggle is due.
+    fn observe(
+        &mut self,
+        now_ms: u64,
+        health: u32,
+        threshold: u32,
+        config: &ArmletStuckDetectionConfig,
+    ) -> bool {
+        let Some(toggled_at_ms) = self.toggled_at_ms else {
+            return false;
+        };
+        if config.falling_frames == 0 || now_ms.saturating_sub(toggled_at_ms) > config.window_ms {
+            self.reset();
+            return false;
+        }
+
+        if health < threshold && health < self.last_health {
+            self.falling_frames += 1;
+        } else {
+            self.falling_frames = 0;
+        }
+        self.last_health = health;
+
+        self.falling_frames >= config.falling_frames
     }
 }
 
+fn armlet_clock_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
 fn elapsed_since_toggle_ms(last_toggle: Option<Instant>) -> Option<u64> {
     last_toggle.map(|last_time| last_time.elapsed().as_millis() as u64)
 }
@@ -275,34 +348,21 @@ fn cooldown_remaining_ms(last_toggle: Option<Instant>, cooldown_ms: u64) -> u64
 }
 
 fn should_force_critical_retry_for_elapsed(
-    health: u32,
-    threshold: u32,
-    last_critical: Option<u32>,
+    stuck_retry_due: bool,
     elapsed_since_last_toggle_ms: Option<u64>,
     cooldown_ms: u64,
 ) -> bool {
-    match last_critical {
-        Some(last_critical) => {
-            health < threshold / 2
-                && health <= last_critical
-                && cooldown_ready_for_elapsed(elapsed_since_last_toggle_ms, cooldown_ms)
-        }
-        None => false,
-    }
+    stuck_retry_due && cooldown_ready_for_elapsed(elapsed_since_last_toggle_ms, cooldown_ms)
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
 fn should_force_critical_retry(
-    health: u32,
-    threshold: u32,
-    last_critical: Option<u32>,
+    stuck_retry_due: bool,
     last_toggle: Option<Instant>,
     cooldown_ms: u64,
 ) -> bool {
     should_force_critical_retry_for_elapsed(
-        health,
-        threshold,
-        last_critical,
+        stuck_retry_due,
         elapsed_since_toggle_ms(last_toggle),
         cooldown_ms,
     )
@@ -313,7 +373,7 @@ fn evaluate_armlet_decision(
     threshold: u32,
     predictive_offset: u32,
     is_stunned: bool,
-    last_critical: Option<u32>,
+    stuck_retry_due: bool,
     elapsed_since_last_toggle_ms: Option<u64>,
     cooldown_ms: u64,
 ) -> ArmletEvaluation {
@@ -322,9 +382,7 @@ fn evaluate_armlet_decision(
         cooldown_remaining_for_elapsed(elapsed_since_last_toggle_ms, cooldown_ms);
 
     if should_force_critical_retry_for_elapsed(
-        health,
-        threshold,
-        last_critical,
+        stuck_retry_due,
         elapsed_since_last_toggle_ms,
         cooldown_ms,
     ) {
@@ -528,18 +586,24 @@ fn simulate_armlet_replay(
     config: &EffectiveArmletConfig,
 ) -> ArmletReplayReport {
     let mut report = ArmletReplayReport::default();
-    let mut last_critical = None;
+    let mut stuck_tracker = ArmletStuckTracker::default();
     let mut last_toggle_at_ms = None;
 
     for sample in samples {
         let elapsed_since_last_toggle_ms =
             last_toggle_at_ms.map(|last_toggle_at| sample.at_ms.saturating_sub(last_toggle_at));
+        let stuck_retry_due = stuck_tracker.observe(
+            sample.at_ms,
+            sample.health,
+            config.toggle_threshold,
+            &config.stuck_detection,
+        );
         let evaluation = evaluate_armlet_decision(
             sample.health,
             config.toggle_threshold,
             config.predictive_offset,
             sample.stunned,
-            last_critical,
+            stuck_retry_due,
             elapsed_since_last_toggle_ms,
             config.toggle_cooldown_ms,
         );
@@ -556,12 +620,12 @@ fn simulate_armlet_replay(
             ArmletDecision::Toggle | ArmletDecision::ToggleRoshan => {
                 report.normal_toggles += 1;
                 last_toggle_at_ms = Some(sample.at_ms);
-                last_critical = next_critical_retry_health(sample.health, config.toggle_threshold);
+                stuck_tracker.watch(sample.at_ms, sample.health);
             }
             ArmletDecision::CriticalRetry => {
                 report.critical_retries += 1;
                 last_toggle_at_ms = Some(sample.at_ms);
-                last_critical = None;
+                stuck_tracker.watch(sample.at_ms, sample.health);
             }
             ArmletDecision::SkipCooldown => {
                 report.cooldown_blocks += 1;
@@ -570,7 +634,7 @@ fn simulate_armlet_replay(
                 report.stun_blocks += 1;
             }
             ArmletDecision::SkipSafe => {
-                last_critical = None;
+                stuck_tracker.reset();
             }
         }
     }
@@ -727,49 +791,66 @@ fn maybe_log_roshan_skip_context(
     }
 }
 
+/// Resolved toggle threshold, raised by the hero's Break bonus while its
+/// passives are off.
+fn toggle_threshold(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    resolved: &EffectiveArmletConfig,
+) -> u32 {
+    if event.hero.is_broken() {
+        resolved.toggle_threshold + settings.break_armlet_threshold_bonus(&event.hero.name)
+    } else {
+        resolved.toggle_threshold
+    }
+}
+
 pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
     if !event.hero.is_alive() {
-        if let Ok(mut roshan_state) = ARMLET_ROSHAN_STATE.lock() {
-            clear_roshan_learning_state_with_reason(&mut roshan_state, RoshanResetReason::HeroDied);
-        }
+        clear_roshan_learning_state_with_reason(
+            &mut lock_or_reset(&ARMLET_ROSHAN_STATE),
+            RoshanResetReason::HeroDied,
+        );
         return;
     }
 
     let resolved = settings.resolve_armlet_config(&event.hero.name);
     if !resolved.enabled {
-        if let Ok(mut roshan_state) = ARMLET_ROSHAN_STATE.lock() {
-            clear_roshan_learning_state_with_reason(
-                &mut roshan_state,
-                RoshanResetReason::ArmletDisabled,
-            );
-        }
+        clear_roshan_learning_state_with_reason(
+            &mut lock_or_reset(&ARMLET_ROSHAN_STATE),
+            RoshanResetReason::ArmletDisabled,
+        );
         return;
     }
 
     let Some(slot_key) = find_armlet_slot_key(event, settings) else {
-        if let Ok(mut roshan_state) = ARMLET_ROSHAN_STATE.lock() {
-            clear_roshan_learning_state_with_reason(
-                &mut roshan_state,
-                RoshanResetReason::ArmletMissing,
-            );
-        }
+        clear_roshan_learning_state_with_reason(
+            &mut lock_or_reset(&ARMLET_ROSHAN_STATE),
+            RoshanResetReason::ArmletMissing,
+        );
         return;
     };
 
     let health = event.hero.health;
-    let threshold = resolved.toggle_threshold;
+    let threshold = toggle_threshold(event, settings, &resolved);
     let cooldown_ms = resolved.toggle_cooldown_ms;
     let cast_modifier = resolve_cast_modifier(&resolved);
 
-    let last_critical = *ARMLET_CRITICAL_HP.lock().unwrap();
-    let last_toggle_snapshot = *ARMLET_LAST_TOGGLE.lock().unwrap();
+    let now_ms = armlet_clock_ms();
+    let stuck_retry_due = lock_or_reset(&ARMLET_STUCK_TRACKER).observe(
+        now_ms,
+        health,
+        threshold,
+        &resolved.stuck_detection,
+    );
+    let last_toggle_snapshot = *lock_or_recover(&ARMLET_LAST_TOGGLE);
     let elapsed_since_last_toggle_ms = elapsed_since_toggle_ms(last_toggle_snapshot);
     let mut evaluation = evaluate_armlet_decision(
         health,
         threshold,
         resolved.predictive_offset,
         event.hero.is_stunned(),
-        last_critical,
+        stuck_retry_due,
         elapsed_since_last_toggle_ms,
         cooldown_ms,
     );
@@ -777,51 +858,125 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
     let roshan_active = resolved.roshan.enabled && is_roshan_mode_armed();
 
     if roshan_active {
-        let now_ms = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|duration| duration.as_millis() as u64)
-            .unwrap_or_default();
-
-        if let Ok(mut roshan_state) = ARMLET_ROSHAN_STATE.lock() {
-            let was_stunned_last_tick = roshan_state.was_stunned_last_tick;
-            record_roshan_health_sample(&mut roshan_state, now_ms, health, &resolved.roshan);
-
-            if matches!(
-                evaluation.decision,
-                ArmletDecision::Toggle | ArmletDecision::CriticalRetry
-            ) {
-                roshan_state.was_stunned_last_tick = event.hero.is_stunned();
-                if event.hero.is_stunned() {
-                    clear_roshan_recovery_defer(&mut roshan_state);
-                }
-            } else {
-                let roshan_recovery_action = evaluate_roshan_stun_recovery(
-                    &mut roshan_state,
-                    health,
-                    event.hero.is_stunned(),
-                    was_stunned_last_tick && !event.hero.is_stunned(),
-                    now_ms,
-                    &resolved.roshan,
-                );
-                let (predicted_damage, recent_sample_count) =
-                    roshan_prediction_summary(&roshan_state, now_ms, &resolved.roshan);
-
-                if evaluation.decision == ArmletDecision::SkipSafe {
-                    match roshan_recovery_action {
-                        RoshanRecoveryAction::ProtectNow => {
-                            info!(
-                                "Triggering immediate Roshan protection after stun recovery (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted hit: {:?}, samples: {})",
-                                health,
-                                threshold,
-                                resolved.predictive_offset,
-                                evaluation.trigger_point,
-                                evaluation.cooldown_remaining_ms,
-                                predicted_damage,
-                                recent_sample_count
-                            );
+        let mut roshan_state = lock_or_reset(&ARMLET_ROSHAN_STATE);
+        let was_stunned_last_tick = roshan_state.was_stunned_last_tick;
+        record_roshan_health_sample(&mut roshan_state, now_ms, health, &resolved.roshan);
+
+        if matches!(
+            evaluation.decision,
+            ArmletDecision::Toggle | ArmletDecision::CriticalRetry
+        ) {
+            roshan_state.was_stunned_last_tick = event.hero.is_stunned();
+            if event.hero.is_stunned() {
+                clear_roshan_recovery_defer(&mut roshan_state);
+            }
+        } else {
+            let roshan_recovery_action = evaluate_roshan_stun_recovery(
+                &mut roshan_state,
+                health,
+                event.hero.is_stunned(),
+                was_stunned_last_tick && !event.hero.is_stunned(),
+                now_ms,
+                &resolved.roshan,
+            );
+            let (predicted_damage, recent_sample_count) =
+                roshan_prediction_summary(&roshan_state, now_ms, &resolved.roshan);
+
+            if evaluation.decision == ArmletDecision::SkipSafe {
+                match roshan_recovery_action {
+                    RoshanRecoveryAction::ProtectNow => {
+                        info!(
+                            "Triggering immediate Roshan protection after stun recovery (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted hit: {:?}, samples: {})",
+                            health,
+                            threshold,
+                            resolved.predictive_offset,
+                            evaluation.trigger_point,
+                            evaluation.cooldown_remaining_ms,
+                            predicted_damage,
+                            recent_sample_count
+                        );
+                        evaluation.decision = ArmletDecision::ToggleRoshan;
+                    }
+                    RoshanRecoveryAction::AwaitNextHit { predicted_damage } => {
+                        maybe_log_roshan_skip_context(
+                            evaluation,
+                            health,
+                            threshold,
+                            resolved.predictive_offset,
+                            event.hero.is_stunned(),
+                            &roshan_state,
+                            RoshanRecoveryAction::AwaitNextHit { predicted_damage },
+                            now_ms,
+                            &resolved.roshan,
+                        );
+                        debug!(
+                            "Deferring Roshan toggle after stun recovery; waiting for next hit (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted damage: {}, samples: {})",
+                            health,
+                            threshold,
+                            resolved.predictive_offset,
+                            evaluation.trigger_point,
+                            evaluation.cooldown_remaining_ms,
+                            predicted_damage,
+                            recent_sample_count
+                        );
+                    }
+                    RoshanRecoveryAction::TriggerDeferredHit { observed_damage } => {
+                        info!(
+                            "Triggering deferred Roshan re-sync toggle after stun recovery hit (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, observed damage: {}, predicted hit: {:?}, samples: {})",
+                            health,
+                            threshold,
+                            resolved.predictive_offset,
+                            evaluation.trigger_point,
+                            evaluation.cooldown_remaining_ms,
+                            observed_damage,
+                            predicted_damage,
+                            recent_sample_count
+                        );
+                        evaluation.decision = ArmletDecision::ToggleRoshan;
+                    }
+                    RoshanRecoveryAction::None => {
+                        if let Some(trigger) =
+                            evaluate_roshan_trigger(health, now_ms, &roshan_state, &resolved.roshan)
+                        {
+                            match trigger {
+                                RoshanArmletTrigger::EmergencyFallback {
+                                    observed_damage,
+                                    lethal_zone,
+                                } => {
+                                    info!(
+                                        "Triggering armlet Roshan emergency fallback (HP: {} <= lethal zone: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, observed hit: {}, predicted hit: {:?}, samples: {})",
+                                        health,
+                                        lethal_zone,
+                                        threshold,
+                                        resolved.predictive_offset,
+                                        evaluation.trigger_point,
+                                        evaluation.cooldown_remaining_ms,
+                                        observed_damage,
+                                        predicted_damage,
+                                        recent_sample_count
+                                    );
+                                }
+                                RoshanArmletTrigger::LearnedHit {
+                                    predicted_damage,
+                                    lethal_zone,
+                                    sample_count,
+                                } => {
+                                    info!(
+                                        "Triggering armlet Roshan learned-hit protection (HP: {} <= lethal zone: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted hit: {}, samples: {})",
+                                        health,
+                                        lethal_zone,
+                                        threshold,
+                                        resolved.predictive_offset,
+                                        evaluation.trigger_point,
+                                        evaluation.cooldown_remaining_ms,
+                                        predicted_damage,
+                                        sample_count
+                                    );
+                                }
+                            }
+
                             evaluation.decision = ArmletDecision::ToggleRoshan;
-                        }
-                        RoshanRecoveryAction::AwaitNextHit { predicted_damage } => {
+                        } else {
                             maybe_log_roshan_skip_context(
                                 evaluation,
                                 health,
@@ -829,117 +984,34 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
                                 resolved.predictive_offset,
                                 event.hero.is_stunned(),
                                 &roshan_state,
-                                RoshanRecoveryAction::AwaitNextHit { predicted_damage },
+                                RoshanRecoveryAction::None,
                                 now_ms,
                                 &resolved.roshan,
                             );
-                            debug!(
-                                "Deferring Roshan toggle after stun recovery; waiting for next hit (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted damage: {}, samples: {})",
-                                health,
-                                threshold,
-                                resolved.predictive_offset,
-                                evaluation.trigger_point,
-                                evaluation.cooldown_remaining_ms,
-                                predicted_damage,
-                                recent_sample_count
-                            );
-                        }
-                        RoshanRecoveryAction::TriggerDeferredHit { observed_damage } => {
-                            info!(
-                                "Triggering deferred Roshan re-sync toggle after stun recovery hit (HP: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, observed damage: {}, predicted hit: {:?}, samples: {})",
-                                health,
-                                threshold,
-                                resolved.predictive_offset,
-                                evaluation.trigger_point,
-                                evaluation.cooldown_remaining_ms,
-                                observed_damage,
-                                predicted_damage,
-                                recent_sample_count
-                            );
-                            evaluation.decision = ArmletDecision::ToggleRoshan;
-                        }
-                        RoshanRecoveryAction::None => {
-                            if let Some(trigger) = evaluate_roshan_trigger(
-                                health,
-                                now_ms,
-                                &roshan_state,
-                                &resolved.roshan,
-                            ) {
-                                match trigger {
-                                    RoshanArmletTrigger::EmergencyFallback {
-                                        observed_damage,
-                                        lethal_zone,
-                                    } => {
-                                        info!(
-                                            "Triggering armlet Roshan emergency fallback (HP: {} <= lethal zone: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, observed hit: {}, predicted hit: {:?}, samples: {})",
-                                            health,
-                                            lethal_zone,
-                                            threshold,
-                                            resolved.predictive_offset,
-                                            evaluation.trigger_point,
-                                            evaluation.cooldown_remaining_ms,
-                                            observed_damage,
-                                            predicted_damage,
-                                            recent_sample_count
-                                        );
-                                    }
-                                    RoshanArmletTrigger::LearnedHit {
-                                        predicted_damage,
-                                        lethal_zone,
-                                        sample_count,
-                                    } => {
-                                        info!(
-                                            "Triggering armlet Roshan learned-hit protection (HP: {} <= lethal zone: {}, threshold: {}, offset: {}, trigger: {}, cooldown: {}ms, predicted hit: {}, samples: {})",
-                                            health,
-                                            lethal_zone,
-                                            threshold,
-                                            resolved.predictive_offset,
-                                            evaluation.trigger_point,
-                                            evaluation.cooldown_remaining_ms,
-                                            predicted_damage,
-                                            sample_count
-                                        );
-                                    }
-                                }
-
-                                evaluation.decision = ArmletDecision::ToggleRoshan;
-                            } else {
-                                maybe_log_roshan_skip_context(
-                                    evaluation,
-                                    health,
-                                    threshold,
-                                    resolved.predictive_offset,
-                                    event.hero.is_stunned(),
-                                    &roshan_state,
-                                    RoshanRecoveryAction::None,
-                                    now_ms,
-                                    &resolved.roshan,
-                                );
-                            }
                         }
                     }
-                } else {
-                    maybe_log_roshan_skip_context(
-                        evaluation,
-                        health,
-                        threshold,
-                        resolved.predictive_offset,
-                        event.hero.is_stunned(),
-                        &roshan_state,
-                        roshan_recovery_action,
-                        now_ms,
-                        &resolved.roshan,
-                    );
-                    trace!(
-                        "Roshan recovery state updated without immediate toggle decision: {:?}",
-                        roshan_recovery_action
-                    );
                 }
+            } else {
+                maybe_log_roshan_skip_context(
+                    evaluation,
+                    health,
+                    threshold,
+                    resolved.predictive_offset,
+                    event.hero.is_stunned(),
+                    &roshan_state,
+                    roshan_recovery_action,
+                    now_ms,
+                    &resolved.roshan,
+                );
+                trace!(
+                    "Roshan recovery state updated without immediate toggle decision: {:?}",
+                    roshan_recovery_action
+                );
             }
         }
-    } else if let Ok(mut roshan_state) = ARMLET_ROSHAN_STATE.lock() {
+    } else {
         clear_roshan_learning_state_with_reason(
-            &mut roshan_state,
+            &mut lock_or_reset(&ARMLET_ROSHAN_STATE),
             RoshanResetReason::RoshanModeDisarmed,
         );
     }
@@ -947,21 +1019,23 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
     match evaluation.decision {
         ArmletDecision::CriticalRetry => {
             warn!(
-                "Critical HP detected! HP: {} (likely armlet stuck on). Forcing emergency toggle.",
-                health
+                "HP still falling after armlet toggle (HP: {}, threshold: {}); armlet likely stuck on. Forcing re-toggle.",
+                health, threshold
             );
             debug!(
                 "Armlet emergency retry: hero={}, health={}, trigger={}, cooldown={}ms, modifier={:?}",
                 event.hero.name, health, evaluation.trigger_point, cooldown_ms, cast_modifier
             );
 
-            execute_dual_trigger(slot_key, cast_modifier);
+            execute_dual_trigger(
+                slot_key,
+                cast_modifier,
+                format!("HP {} still falling after a toggle (threshold {})", health, threshold),
+            );
 
-            let mut critical_hp = ARMLET_CRITICAL_HP.lock().unwrap();
-            *critical_hp = None;
-            drop(critical_hp);
+            lock_or_reset(&ARMLET_STUCK_TRACKER).watch(now_ms, health);
 
-            let mut last_toggle = ARMLET_LAST_TOGGLE.lock().unwrap();
+            let mut last_toggle = lock_or_recover(&ARMLET_LAST_TOGGLE);
             *last_toggle = Some(Instant::now());
         }
         ArmletDecision::ToggleRoshan => {
@@ -975,12 +1049,15 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
                 cast_modifier
             );
 
-            execute_dual_trigger(slot_key, cast_modifier);
-            let mut last_toggle = ARMLET_LAST_TOGGLE.lock().unwrap();
+            execute_dual_trigger(
+                slot_key,
+                cast_modifier,
+                format!("Roshan protection at HP {} (threshold {})", health, threshold),
+            );
+            let mut last_toggle = lock_or_recover(&ARMLET_LAST_TOGGLE);
             *last_toggle = Some(Instant::now());
 
-            let mut critical_hp = ARMLET_CRITICAL_HP.lock().unwrap();
-            *critical_hp = next_critical_retry_health(health, threshold);
+            lock_or_reset(&ARMLET_STUCK_TRACKER).watch(now_ms, health);
         }
         ArmletDecision::Toggle => {
             info!(
@@ -997,12 +1074,18 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
                 cast_modifier
             );
 
-            execute_dual_trigger(slot_key, cast_modifier);
-            let mut last_toggle = ARMLET_LAST_TOGGLE.lock().unwrap();
+            execute_dual_trigger(
+                slot_key,
+                cast_modifier,
+                format!(
+                    "HP {} < trigger {} (threshold {})",
+                    health, evaluation.trigger_point, threshold
+                ),
+            );
+            let mut last_toggle = lock_or_recover(&ARMLET_LAST_TOGGLE);
             *last_toggle = Some(Instant::now());
 
-            let mut critical_hp = ARMLET_CRITICAL_HP.lock().unwrap();
-            *critical_hp = next_critical_retry_health(health, threshold);
+            lock_or_reset(&ARMLET_STUCK_TRACKER).watch(now_ms, health);
         }
         ArmletDecision::SkipStunned => {
             debug!(
@@ -1024,32 +1107,72 @@ pub fn maybe_toggle(event: &GsiWebhookEvent, settings: &Settings) {
                 evaluation.trigger_point
             );
 
-            if let Ok(mut critical_hp) = ARMLET_CRITICAL_HP.try_lock() {
-                if critical_hp.is_some() {
-                    debug!("HP recovered to safe levels, resetting critical HP tracker");
-                    *critical_hp = None;
+            if let Ok(mut stuck_tracker) = ARMLET_STUCK_TRACKER.try_lock() {
+                if stuck_tracker.is_watching() {
+                    debug!("HP recovered to safe levels, resetting armlet stuck tracker");
+                    stuck_tracker.reset();
                 }
             }
         }
     }
 }
 
+/// Manual override from the emergency hotkey: double-tap the armlet slot now,
+/// bypassing the toggle cooldown and stun check.
+///
+/// Uses the last GSI event for the slot lookup. Returns whether a toggle was
+/// sent.
+pub fn emergency_toggle(event: &GsiWebhookEvent, settings: &Settings) -> bool {
+    if !event.hero.is_alive() {
+        info!("Emergency armlet hotkey ignored: hero is dead");
+        return false;
+    }
+
+    let Some(slot_key) = find_item_slot(event, settings, Item::Armlet) else {
+        info!("Emergency armlet hotkey ignored: no armlet in inventory");
+        return false;
+    };
+
+    let resolved = settings.resolve_armlet_config(&event.hero.name);
+    let cast_modifier = resolve_cast_modifier(&resolved);
+    let cooldown_remaining = cooldown_remaining_ms(
+        *lock_or_recover(&ARMLET_LAST_TOGGLE),
+        resolved.toggle_cooldown_ms,
+    );
+    warn!(
+        "Manual armlet override via hotkey (HP: {}, cooldown remaining: {}ms)",
+        event.hero.health, cooldown_remaining
+    );
+
+    execute_dual_trigger(slot_key, cast_modifier, "emergency armlet hotkey".to_string());
+    *lock_or_recover(&ARMLET_LAST_TOGGLE) = Some(Instant::now());
+    lock_or_reset(&ARMLET_STUCK_TRACKER).watch(armlet_clock_ms(), event.hero.health);
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         clear_roshan_learning_state, cooldown_ready, cooldown_remaining_ms,
         evaluate_armlet_decision, evaluate_roshan_stun_recovery, evaluate_roshan_trigger,
-        next_critical_retry_health, parse_cast_modifier, plan_dual_trigger_sequence,
+        parse_cast_modifier, plan_dual_trigger_sequence,
         record_roshan_health_sample, resolve_cast_modifier, should_force_critical_retry,
-        should_log_roshan_skip_context, simulate_armlet_replay, ArmletDecision, ArmletReplaySample,
-        ArmletRoshanConfig, ArmletRoshanState, ArmletTriggerStep, RoshanArmletTrigger,
+        should_log_roshan_skip_context, simulate_armlet_replay, toggle_threshold,
+        ArmletDecision, ArmletReplaySample,
+        ArmletRoshanConfig, ArmletRoshanState, ArmletStuckTracker, ArmletTriggerStep,
+        RoshanArmletTrigger,
         RoshanRecoveryAction, RoshanResetReason,
     };
     use crate::config::{
-        settings::{ArmletAutomationConfig, EffectiveArmletConfig, HeroArmletOverrideConfig},
+        settings::{
+            ArmletAutomationConfig, ArmletStuckDetectionConfig, EffectiveArmletConfig,
+            HeroArmletOverrideConfig,
+        },
         Settings,
     };
-    use crate::input::simulation::ModifierKey;
+    use super::{emergency_toggle, find_item_slot, Item, ARMLET_LAST_TOGGLE};
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, ModifierKey, RecordingInputSink};
     use std::time::{Duration, Instant};
 
     fn roshan_test_config() -> ArmletRoshanConfig {
@@ -1093,6 +1216,7 @@ mod tests {
             toggle_threshold: 320,
             predictive_offset: 30,
             toggle_cooldown_ms: 250,
+            stuck_detection: ArmletStuckDetectionConfig::default(),
             roshan: ArmletRoshanConfig::default(),
         };
 
@@ -1100,29 +1224,57 @@ mod tests {
     }
 
     #[test]
-    fn critical_retry_health_only_arms_for_very_low_hp() {
-        assert_eq!(next_critical_retry_health(100, 320), Some(100));
-        assert_eq!(next_critical_retry_health(220, 320), None);
+    fn stuck_tracker_needs_consecutive_falling_frames_below_threshold() {
+        let config = ArmletStuckDetectionConfig {
+            falling_frames: 2,
+            window_ms: 1_000,
+        };
+        let mut tracker = ArmletStuckTracker::default();
+        assert!(!tracker.observe(0, 100, 320, &config));
+
+        tracker.watch(0, 300);
+        assert!(!tracker.observe(100, 280, 320, &config));
+        assert!(!tracker.observe(200, 290, 320, &config));
+        assert!(!tracker.observe(300, 270, 320, &config));
+        assert!(tracker.observe(400, 250, 320, &config));
+    }
+
+    #[test]
+    fn stuck_tracker_ignores_frames_above_threshold_or_after_window() {
+        let config = ArmletStuckDetectionConfig {
+            falling_frames: 1,
+            window_ms: 500,
+        };
+        let mut tracker = ArmletStuckTracker::default();
+
+        tracker.watch(0, 900);
+        assert!(!tracker.observe(100, 800, 320, &config));
+
+        tracker.watch(0, 300);
+        assert!(!tracker.observe(600, 200, 320, &config));
+        assert!(!tracker.is_watching());
+
+        let disabled = ArmletStuckDetectionConfig {
+            falling_frames: 0,
+            ..config
+        };
+        tracker.watch(0, 300);
+        assert!(!tracker.observe(100, 200, 320, &disabled));
     }
 
     #[test]
     fn critical_retry_waits_for_cooldown_before_forcing_another_toggle() {
         let just_now = Some(Instant::now());
 
-        assert!(!should_force_critical_retry(1, 120, Some(1), just_now, 300,));
+        assert!(!should_force_critical_retry(true, just_now, 300));
     }
 
     #[test]
     fn critical_retry_can_fire_after_cooldown_when_hp_is_still_critical() {
         let cooled_down = Some(Instant::now() - Duration::from_millis(400));
 
-        assert!(should_force_critical_retry(
-            1,
-            120,
-            Some(1),
-            cooled_down,
-            300,
-        ));
+        assert!(should_force_critical_retry(true, cooled_down, 300));
+        assert!(!should_force_critical_retry(false, cooled_down, 300));
     }
 
     #[test]
@@ -1148,7 +1300,7 @@ mod tests {
 
     #[test]
     fn evaluate_armlet_decision_reports_cooldown_blocks_with_remaining_time() {
-        let evaluation = evaluate_armlet_decision(100, 120, 0, false, None, Some(150), 300);
+        let evaluation = evaluate_armlet_decision(100, 120, 0, false, false, Some(150), 300);
 
         assert_eq!(evaluation.decision, ArmletDecision::SkipCooldown);
         assert_eq!(evaluation.trigger_point, 120);
@@ -1185,6 +1337,7 @@ mod tests {
             toggle_threshold: 80,
             predictive_offset: 0,
             toggle_cooldown_ms: 150,
+            stuck_detection: ArmletStuckDetectionConfig::default(),
             roshan: ArmletRoshanConfig::default(),
         };
         let aggressive = EffectiveArmletConfig {
@@ -1234,6 +1387,10 @@ mod tests {
             toggle_threshold: 120,
             predictive_offset: 0,
             toggle_cooldown_ms: 300,
+            stuck_detection: ArmletStuckDetectionConfig {
+                falling_frames: 0,
+                ..ArmletStuckDetectionConfig::default()
+            },
             roshan: ArmletRoshanConfig::default(),
         };
         let fast = EffectiveArmletConfig {
@@ -1270,6 +1427,10 @@ mod tests {
             toggle_threshold: 120,
             predictive_offset: 0,
             toggle_cooldown_ms: 300,
+            stuck_detection: ArmletStuckDetectionConfig {
+                falling_frames: 1,
+                ..ArmletStuckDetectionConfig::default()
+            },
             roshan: ArmletRoshanConfig::default(),
         };
 
@@ -1280,6 +1441,43 @@ mod tests {
         assert_eq!(report.events[1].decision, ArmletDecision::CriticalRetry);
     }
 
+    #[test]
+    fn replay_waits_for_consecutive_falling_frames_before_critical_retry() {
+        let samples = [
+            ArmletReplaySample {
+                at_ms: 0,
+                health: 40,
+                stunned: false,
+            },
+            ArmletReplaySample {
+                at_ms: 150,
+                health: 30,
+                stunned: false,
+            },
+            ArmletReplaySample {
+                at_ms: 350,
+                health: 20,
+                stunned: true,
+            },
+        ];
+        let config = EffectiveArmletConfig {
+            enabled: true,
+            cast_modifier: "Alt".to_string(),
+            toggle_threshold: 120,
+            predictive_offset: 0,
+            toggle_cooldown_ms: 300,
+            stuck_detection: ArmletStuckDetectionConfig::default(),
+            roshan: ArmletRoshanConfig::default(),
+        };
+
+        let report = simulate_armlet_replay(&samples, &config);
+
+        assert_eq!(report.normal_toggles, 1);
+        assert_eq!(report.cooldown_blocks, 1);
+        assert_eq!(report.critical_retries, 1);
+        assert_eq!(report.events[2].decision, ArmletDecision::CriticalRetry);
+    }
+
     #[test]
     fn roshan_mode_triggers_on_first_large_hit_when_health_enters_emergency_margin() {
         let mut state = ArmletRoshanState::armed();
@@ -1621,6 +1819,7 @@ mod tests {
                             toggle_threshold: threshold,
                             predictive_offset: 0,
                             toggle_cooldown_ms: cooldown,
+                            stuck_detection: ArmletStuckDetectionConfig::default(),
                             roshan: ArmletRoshanConfig::default(),
                         },
                     );
@@ -1648,7 +1847,10 @@ mod tests {
             toggle_threshold: 350,
             predictive_offset: 40,
             toggle_cooldown_ms: 280,
+            emergency_toggle_key: String::new(),
+            stuck_detection: ArmletStuckDetectionConfig::default(),
             roshan: ArmletRoshanConfig::default(),
+            heroes: Default::default(),
         };
 
         let resolved = settings.resolve_armlet_config("npc_dota_hero_kunkka");
@@ -1700,4 +1902,111 @@ mod tests {
         assert_eq!(resolved.predictive_offset, 30);
         assert_eq!(resolved.toggle_cooldown_ms, 190);
     }
+
+    #[test]
+    fn resolve_armlet_config_applies_generic_override_for_unscripted_hero() {
+        let mut settings = Settings::default();
+        settings.armlet.toggle_threshold = 320;
+        settings.armlet.predictive_offset = 30;
+        settings.armlet.toggle_cooldown_ms = 250;
+        settings.armlet.heroes.insert(
+            "dragon_knight".to_string(),
+            HeroArmletOverrideConfig {
+                enabled: None,
+                toggle_threshold: Some(260),
+                predictive_offset: Some(80),
+                toggle_cooldown_ms: None,
+            },
+        );
+
+        let resolved = settings.resolve_armlet_config("npc_dota_hero_dragon_knight");
+
+        assert_eq!(resolved.toggle_threshold, 260);
+        assert_eq!(resolved.predictive_offset, 80);
+        assert_eq!(resolved.toggle_cooldown_ms, 250);
+
+        let untouched = settings.resolve_armlet_config("npc_dota_hero_sven");
+        assert_eq!(untouched.toggle_threshold, 320);
+    }
+
+    #[test]
+    fn generic_armlet_override_may_be_keyed_by_display_name() {
+        let mut settings = Settings::default();
+        settings.armlet.heroes.insert(
+            "windranger".to_string(),
+            HeroArmletOverrideConfig {
+                enabled: None,
+                toggle_threshold: Some(280),
+                predictive_offset: None,
+                toggle_cooldown_ms: None,
+            },
+        );
+
+        let resolved = settings.resolve_armlet_config("npc_dota_hero_windrunner");
+        assert_eq!(resolved.toggle_threshold, 280);
+    }
+
+    #[test]
+    fn generic_armlet_overrides_parse_from_toml() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [armlet.heroes.sven]
+            toggle_threshold = 300
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.armlet.heroes["sven"].toggle_threshold, Some(300));
+        assert_eq!(settings.armlet.toggle_cooldown_ms, 250);
+    }
+
+    #[test]
+    fn break_raises_only_huskars_toggle_threshold() {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let mut settings = Settings::default();
+        settings.heroes.huskar.break_armlet_threshold_bonus = 150;
+        let resolved = settings.resolve_armlet_config(&event.hero.name);
+        let base = resolved.toggle_threshold;
+
+        event.hero.is_break = false;
+        assert_eq!(toggle_threshold(&event, &settings, &resolved), base);
+        event.hero.is_break = true;
+        assert_eq!(toggle_threshold(&event, &settings, &resolved), base + 150);
+
+        event.hero.name = "npc_dota_hero_bristleback".to_string();
+        let resolved = settings.resolve_armlet_config(&event.hero.name);
+        assert_eq!(
+            toggle_threshold(&event, &settings, &resolved),
+            resolved.toggle_threshold
+        );
+    }
+
+    #[test]
+    fn emergency_toggle_fires_during_cooldown_and_skips_without_armlet() {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let settings = Settings::default();
+        let slot_key = find_item_slot(&event, &settings, Item::Armlet).unwrap();
+        *ARMLET_LAST_TOGGLE.lock().unwrap() = Some(Instant::now());
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        assert!(emergency_toggle(&event, &settings));
+        assert_eq!(recorder.key_clicks(), vec![slot_key, slot_key]);
+
+        let items = &mut event.items;
+        for item in [
+            &mut items.slot0,
+            &mut items.slot1,
+            &mut items.slot2,
+            &mut items.slot3,
+            &mut items.slot4,
+            &mut items.slot5,
+        ] {
+            if item.name == "item_armlet" {
+                item.name = "empty".to_string();
+            }
+        }
+        assert!(!emergency_toggle(&event, &settings));
+        assert_eq!(recorder.key_clicks().len(), 2);
+    }
 }
@@ -4,18 +4,20 @@
 //! Enabled per-hero with configurable modifier key, item list, and ability list.
 //!
 //! Usage: Hold modifier key (e.g., Space) + Right-click to:
-//! 1. Use all configured items that are off cooldown
+//! 1. Use all configured items that are off cooldown, then the equipped
+//!    neutral if `neutral_items.use_in_auto_items` allows it
 //! 2. Use all configured abilities (with optional HP threshold)
 //! 3. Right-click the target
 
 use crate::config::AutoAbilityConfig;
-use crate::input::simulation::{mouse_click, press_key};
+use crate::input::simulation::{press_key, right_click};
 use crate::models::GsiWebhookEvent;
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use std::sync::atomic::AtomicBool;
 use std::sync::Mutex;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 #[cfg(test)]
@@ -27,6 +29,9 @@ lazy_static! {
 
     /// Cache of the latest GSI event for item state
     pub static ref LATEST_GSI_EVENT: Mutex<Option<GsiWebhookEvent>> = Mutex::new(None);
+
+    /// When `LATEST_GSI_EVENT` was last refreshed
+    static ref LATEST_GSI_EVENT_AT: Mutex<Option<Instant>> = Mutex::new(None);
 }
 
 #[cfg(test)]
@@ -52,8 +57,89 @@ pub fn update_gsi_state(event: &GsiWebhookEvent) {
         UPDATE_GSI_STATE_CALLS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
     }
     
-    let mut cached = LATEST_GSI_EVENT.lock().unwrap();
+    let mut cached = lock_or_recover(&LATEST_GSI_EVENT);
     *cached = Some(event.clone());
+    *lock_or_recover(&LATEST_GSI_EVENT_AT) = Some(Instant::now());
+}
+
+/// The cached GSI event and how long ago it arrived.
+pub fn latest_gsi_event() -> Option<(GsiWebhookEvent, Duration)> {
+    let event = lock_or_recover(&LATEST_GSI_EVENT).clone()?;
+    let age = lock_or_recover(&LATEST_GSI_EVENT_AT).map_or(Duration::ZERO, |at| at.elapsed());
+    Some((event, age))
+}
+
+/// How old the cached event may be before its inventory is no longer trusted
+/// for slot keys. In game `map.clock_time` changes every second, so Dota posts
+/// at least once a second; an older cache means the feed has stalled and an
+/// inventory reshuffle may have gone unseen.
+pub const SLOT_KEY_MAX_EVENT_AGE: Duration = Duration::from_millis(1500);
+
+/// Event to read slot keys from right before a press, or `None` to skip it.
+///
+/// Combos plan from an event cloned when they start and then sleep between
+/// steps, so by the time an item is pressed the player may have moved it.
+/// The handler refreshes `LATEST_GSI_EVENT` before any hero cache, so the
+/// cached event is used when it is for the same hero and within
+/// [`SLOT_KEY_MAX_EVENT_AGE`]. `planned` was cloned from that same cache and
+/// is never fresher, so without such an event the press is skipped.
+pub fn slot_key_event(planned: &GsiWebhookEvent) -> Option<GsiWebhookEvent> {
+    pick_slot_key_event(planned, latest_gsi_event())
+}
+
+fn pick_slot_key_event(
+    planned: &GsiWebhookEvent,
+    latest: Option<(GsiWebhookEvent, Duration)>,
+) -> Option<GsiWebhookEvent> {
+    match latest {
+        Some((latest, age))
+            if age <= SLOT_KEY_MAX_EVENT_AGE && latest.hero.name == planned.hero.name =>
+        {
+            Some(latest)
+        }
+        Some((latest, age)) if age <= SLOT_KEY_MAX_EVENT_AGE => {
+            info!(
+                "🎯 Skipping item press: latest GSI event is for {}, not {}",
+                latest.hero.name, planned.hero.name
+            );
+            None
+        }
+        Some((_, age)) => {
+            info!(
+                "🎯 Skipping item press: latest GSI event is {}ms old, slot keys may not match the inventory",
+                age.as_millis()
+            );
+            None
+        }
+        None => {
+            info!("🎯 Skipping item press: no GSI event to read slot keys from");
+            None
+        }
+    }
+}
+
+/// Neutral slot included in the auto-items combo
+/// (`neutral_items.use_in_auto_items`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoNeutral {
+    /// `keybindings.neutral0`
+    pub key: char,
+    /// `neutral_items.allowed_items`
+    pub allowed_items: Vec<String>,
+}
+
+/// Neutral slot key if the equipped neutral is allowed and ready.
+fn find_neutral_key(event: &GsiWebhookEvent, neutral: &AutoNeutral) -> Option<char> {
+    let item = &event.items.neutral0;
+    if !neutral.allowed_items.contains(&item.name) {
+        return None;
+    }
+    if item.is_ready() {
+        debug!("🎯 Found castable neutral '{}' with key '{}'", item.name, neutral.key);
+        Some(neutral.key)
+    } else {
+        None
+    }
 }
 
 /// Find item slot key by item name (partial match)
@@ -72,11 +158,8 @@ fn find_item_key(event: &GsiWebhookEvent, slot_keys: &[char; 6], item_name: &str
 
     for (item, key) in slots {
         if item.name.contains(item_name) {
-            // Check if item is castable (not on cooldown, can be used)
-            let can_cast = item.can_cast.unwrap_or(false);
-            let cooldown = item.cooldown.unwrap_or(0);
-
-            if can_cast && cooldown == 0 {
+            // Castable, off cooldown and not out of charges
+            if item.is_ready() {
                 debug!(
                     "🎯 Found castable item '{}' in slot with key '{}'",
                     item.name, key
@@ -84,8 +167,11 @@ fn find_item_key(event: &GsiWebhookEvent, slot_keys: &[char; 6], item_name: &str
                 return Some(key);
             } else {
                 debug!(
-                    "🎯 Item '{}' found but not castable (can_cast={}, cd={})",
-                    item.name, can_cast, cooldown
+                    "🎯 Item '{}' found but not castable (can_cast={:?}, cd={:?}, charges={:?})",
+                    item.name,
+                    item.can_cast,
+                    item.cooldown,
+                    item.charge_count()
                 );
             }
         }
@@ -101,31 +187,58 @@ fn find_item_key(event: &GsiWebhookEvent, slot_keys: &[char; 6], item_name: &str
 /// * `item_names` - List of item names to try using
 /// * `auto_abilities` - List of abilities to auto-cast with optional HP thresholds
 /// * `abilities_first` - If true, cast abilities before items; if false, items first
+/// * `neutral` - Equipped neutral to include after the items, if enabled
 pub fn execute_auto_items(
     slot_keys: &[char; 6],
     item_names: &[String],
     auto_abilities: &[AutoAbilityConfig],
     abilities_first: bool,
+    neutral: Option<&AutoNeutral>,
 ) {
     // Get cached GSI state
-    let cached = LATEST_GSI_EVENT.lock().unwrap();
+    let cached = lock_or_recover(&LATEST_GSI_EVENT);
     let event = match cached.as_ref() {
         Some(e) => e.clone(),
         None => {
             debug!("🎯 No GSI state available for auto-items");
             // Still do the right-click even without item info
-            mouse_click();
+            right_click();
             return;
         }
     };
     drop(cached);
 
+    run_auto_items(
+        &event,
+        slot_keys,
+        item_names,
+        auto_abilities,
+        abilities_first,
+        neutral,
+        latest_gsi_event,
+    );
+}
+
+/// `latest_event` is consulted before each item press (see
+/// [`slot_key_event`]) so items moved mid-combo keep their right key.
+fn run_auto_items(
+    event: &GsiWebhookEvent,
+    slot_keys: &[char; 6],
+    item_names: &[String],
+    auto_abilities: &[AutoAbilityConfig],
+    abilities_first: bool,
+    neutral: Option<&AutoNeutral>,
+    latest_event: impl Fn() -> Option<(GsiWebhookEvent, Duration)>,
+) {
     let mut items_used = 0;
     let mut abilities_used = 0;
 
     // Helper closure to use items
     let use_items = |items_used: &mut u32| {
         for item_name in item_names {
+            let Some(event) = pick_slot_key_event(event, latest_event()) else {
+                continue;
+            };
             if let Some(key) = find_item_key(&event, slot_keys, item_name) {
                 info!("🎯 Using item '{}' (key: {})", item_name, key);
                 press_key(key);
@@ -133,6 +246,18 @@ pub fn execute_auto_items(
                 thread::sleep(Duration::from_millis(30));
             }
         }
+        let Some(neutral) = neutral else {
+            return;
+        };
+        let Some(event) = pick_slot_key_event(event, latest_event()) else {
+            return;
+        };
+        if let Some(key) = find_neutral_key(&event, neutral) {
+            info!("🎯 Using neutral '{}' (key: {})", event.items.neutral0.name, key);
+            press_key(key);
+            *items_used += 1;
+            thread::sleep(Duration::from_millis(30));
+        }
     };
 
     // Helper closure to use abilities
@@ -195,5 +320,102 @@ pub fn execute_auto_items(
             items_used, abilities_used
         );
     }
-    mouse_click();
+    right_click();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+    use crate::models::gsi_event::Item;
+
+    #[test]
+    fn allowed_ready_neutral_is_used_after_the_items() {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let ready = |name: &str| Item {
+            name: name.to_string(),
+            can_cast: Some(true),
+            cooldown: Some(0),
+            ..Item::default()
+        };
+        event.items.slot0 = ready("item_orchid");
+        event.items.neutral0 = ready("item_crippling_crossbow");
+
+        let slot_keys = ['z', 'x', 'c', 'v', 'b', 'n'];
+        let items = vec!["orchid".to_string()];
+        let neutral = AutoNeutral {
+            key: '0',
+            allowed_items: vec!["item_crippling_crossbow".to_string()],
+        };
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        let fresh = || Some((event.clone(), Duration::ZERO));
+        run_auto_items(
+            &event,
+            &slot_keys,
+            &items,
+            &[],
+            false,
+            Some(&neutral),
+            fresh,
+        );
+        run_auto_items(&event, &slot_keys, &items, &[], false, None, fresh);
+
+        let not_allowed = AutoNeutral {
+            allowed_items: Vec::new(),
+            ..neutral
+        };
+        run_auto_items(
+            &event,
+            &slot_keys,
+            &items,
+            &[],
+            false,
+            Some(&not_allowed),
+            fresh,
+        );
+
+        assert_eq!(recorder.key_clicks(), vec!['z', '0', 'z', 'z']);
+    }
+
+    #[test]
+    fn items_moved_mid_combo_are_pressed_on_their_new_slot() {
+        let mut planned = load_fixture_event("tests/fixtures/huskar_event.json");
+        planned.items.slot0 = Item {
+            name: "item_orchid".to_string(),
+            can_cast: Some(true),
+            cooldown: Some(0),
+            ..Item::default()
+        };
+        planned.items.slot2 = Item::default();
+        let mut reshuffled = planned.clone();
+        reshuffled.items.slot2 = planned.items.slot0.clone();
+        reshuffled.items.slot0 = Item::default();
+
+        let slot_keys = ['z', 'x', 'c', 'v', 'b', 'n'];
+        let items = vec!["orchid".to_string()];
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+
+        let fresh = || Some((reshuffled.clone(), Duration::from_millis(100)));
+        run_auto_items(&planned, &slot_keys, &items, &[], false, None, fresh);
+        let stale = || Some((reshuffled.clone(), SLOT_KEY_MAX_EVENT_AGE * 2));
+        run_auto_items(&planned, &slot_keys, &items, &[], false, None, stale);
+
+        let mut other_hero = reshuffled.clone();
+        other_hero.hero.name = "npc_dota_hero_tinker".to_string();
+        run_auto_items(&planned, &slot_keys, &items, &[], false, None, || {
+            Some((other_hero.clone(), Duration::ZERO))
+        });
+
+        run_auto_items(&planned, &slot_keys, &items, &[], false, None, || None);
+
+        assert_eq!(
+            recorder.key_clicks(),
+            vec!['c'],
+            "a stale, foreign or missing event skips the press"
+        );
+    }
 }
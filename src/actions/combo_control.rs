@@ -0,0 +1,367 @@
+//! Standalone combo lifecycle shared by every hero script.
+//!
+//! `ActionDispatcher` keeps one [`ComboState`] per hero. A trigger claims it
+//! with [`ComboState::try_begin`]; while that [`ComboRun`] is alive further
+//! triggers are either ignored or, with `keybindings.combo_toggle_abort`,
+//! request an abort that combo scripts poll with [`combo_aborted`] between
+//! steps.
+//!
+//! The dispatcher also mirrors the hero's hex/silence/mute/disarm flags from
+//! every GSI event, so a combo stops as soon as a disable that blocks its
+//! abilities lands mid-sequence. Hex and silence block abilities, hex and
+//! mute block items, and disarm blocks attacks.
+
+pub use crate::config::settings::AttackMove;
+use crate::config::Settings;
+use crate::models::gsi_event::Hero;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Arc;
+use tracing::info;
+
+const HEXED: u8 = 1;
+const MUTED: u8 = 1 << 1;
+const DISARMED: u8 = 1 << 2;
+const SILENCED: u8 = 1 << 3;
+
+/// Disable flags from the latest GSI event, see [`update_hero_disables`].
+static HERO_DISABLES: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Default)]
+pub struct ComboState {
+    running: AtomicBool,
+    abort: AtomicBool,
+    disable_logged: AtomicBool,
+}
+
+impl ComboState {
+    /// Claim the hero's combo slot, or `None` if a combo is already running.
+    pub fn try_begin(self: &Arc<Self>) -> Option<ComboRun> {
+        self.running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()?;
+        self.abort.store(false, Ordering::Release);
+        self.disable_logged.store(false, Ordering::Release);
+        Some(ComboRun(Arc::clone(self)))
+    }
+
+    /// Ask the running combo to stop at its next step. Returns `false` when
+    /// no combo is running.
+    pub fn request_abort(&self) -> bool {
+        if !self.running.load(Ordering::Acquire) {
+            return false;
+        }
+        self.abort.store(true, Ordering::Release);
+        true
+    }
+}
+
+/// An in-progress combo. Dropping it frees the hero's combo slot, so the
+/// slot is released even if the combo panics on the executor.
+pub struct ComboRun(Arc<ComboState>);
+
+impl ComboRun {
+    /// Run `combo` with this run's abort flag visible to [`combo_aborted`]
+    /// on the current thread.
+    pub fn run(self, combo: impl FnOnce()) {
+        CURRENT_COMBO.with(|current| *current.borrow_mut() = Some(Arc::clone(&self.0)));
+        combo();
+    }
+}
+
+impl Drop for ComboRun {
+    fn drop(&mut self) {
+        let _ = CURRENT_COMBO.try_with(|current| {
+            let mut current = current.borrow_mut();
+            if current.as_ref().is_some_and(|state| Arc::ptr_eq(state, &self.0)) {
+                *current = None;
+            }
+        });
+        self.0.abort.store(false, Ordering::Release);
+        self.0.running.store(false, Ordering::Release);
+    }
+}
+
+thread_local! {
+    static CURRENT_COMBO: RefCell<Option<Arc<ComboState>>> = const { RefCell::new(None) };
+}
+
+/// Press `common.combo_hold_after` once the combo body has returned, unless
+/// the combo was aborted (a disable or shutdown should not issue new orders).
+pub fn hold_after_combo(hold_key: Option<char>) {
+    let Some(key) = hold_key else {
+        return;
+    };
+    if combo_aborted() {
+        return;
+    }
+    crate::input::press_key(key);
+}
+
+/// Orders a dispatched standalone combo adds around the hero's own steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComboOrders {
+    pub attack_move: AttackMove,
+    /// `common.attack_key`.
+    pub attack_key: char,
+    /// `common.combo_hold_after`.
+    pub hold_key: Option<char>,
+}
+
+impl ComboOrders {
+    /// Orders configured for `hero` (a config key such as `"tiny"`).
+    pub fn for_hero(settings: &Settings, hero: &str) -> Self {
+        Self {
+            attack_move: settings
+                .get_combo_orders(hero)
+                .map(|orders| orders.attack_move)
+                .unwrap_or_default(),
+            attack_key: settings.common.attack_key,
+            hold_key: settings.common.combo_hold_after,
+        }
+    }
+
+    /// Run `combo` with the attack-move before or after it. The hold key is
+    /// pressed last, unless an attack-move follows the combo: stopping would
+    /// cancel the attack it just ordered.
+    pub fn wrap(self, combo: impl FnOnce()) {
+        if self.attack_move == AttackMove::Before {
+            attack_move(self.attack_key);
+        }
+        combo();
+        if self.attack_move == AttackMove::After {
+            attack_move(self.attack_key);
+        } else {
+            hold_after_combo(self.hold_key);
+        }
+    }
+}
+
+/// Attack-move at the cursor: the Attack key, then a left-click to place
+/// the order. Skipped when the combo was aborted or the hero is disarmed.
+pub fn attack_move(attack_key: char) {
+    if combo_aborted() || attack_blocked() {
+        return;
+    }
+    crate::input::press_key(attack_key);
+    crate::input::simulation::left_click();
+}
+
+/// Whether the standalone combo running on this thread was cancelled by a
+/// second trigger press, the hero got hexed or silenced, or the app is
+/// shutting down. Always `false` outside a dispatched combo.
+pub fn combo_aborted() -> bool {
+    CURRENT_COMBO.with(|current| {
+        let current = current.borrow();
+        let Some(state) = current.as_ref() else {
+            return false;
+        };
+        if state.abort.load(Ordering::Acquire) || crate::state::shutdown::is_shutting_down() {
+            return true;
+        }
+        let Some(reason) = ability_blocked_reason_from(HERO_DISABLES.load(Ordering::Acquire))
+        else {
+            return false;
+        };
+        if !state.disable_logged.swap(true, Ordering::AcqRel) {
+            info!("Stopping combo early: hero is {}", reason);
+        }
+        true
+    })
+}
+
+/// Record the hero's current disables. Called by the dispatcher for every
+/// GSI event before any automation runs.
+pub fn update_hero_disables(hero: &Hero) {
+    HERO_DISABLES.store(disables_from(hero), Ordering::Release);
+}
+
+/// Clear the recorded disables until the next GSI event.
+pub fn reset() {
+    HERO_DISABLES.store(0, Ordering::Release);
+}
+
+/// Why ability keys are pointless right now (hexed or silenced), if they are.
+pub fn ability_blocked_reason() -> Option<&'static str> {
+    ability_blocked_reason_from(HERO_DISABLES.load(Ordering::Acquire))
+}
+
+/// Why item keys are pointless right now (hexed or muted), if they are.
+pub fn item_blocked_reason() -> Option<&'static str> {
+    item_blocked_reason_from(HERO_DISABLES.load(Ordering::Acquire))
+}
+
+/// Whether attack steps (right-clicks) should be skipped.
+pub fn attack_blocked() -> bool {
+    HERO_DISABLES.load(Ordering::Acquire) & DISARMED != 0
+}
+
+fn disables_from(hero: &Hero) -> u8 {
+    let mut disables = 0;
+    if hero.hexed {
+        disables |= HEXED;
+    }
+    if hero.silenced {
+        disables |= SILENCED;
+    }
+    if hero.muted {
+        disables |= MUTED;
+    }
+    if hero.disarmed {
+        disables |= DISARMED;
+    }
+    disables
+}
+
+fn ability_blocked_reason_from(disables: u8) -> Option<&'static str> {
+    if disables & HEXED != 0 {
+        Some("hexed")
+    } else if disables & SILENCED != 0 {
+        Some("silenced")
+    } else {
+        None
+    }
+}
+
+fn item_blocked_reason_from(disables: u8) -> Option<&'static str> {
+    if disables & HEXED != 0 {
+        Some("hexed")
+    } else if disables & MUTED != 0 {
+        Some("muted")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+
+    #[test]
+    fn abort_is_only_visible_inside_the_running_combo() {
+        let state = Arc::new(ComboState::default());
+        assert!(!state.request_abort(), "nothing to abort before a combo starts");
+
+        let run = state.try_begin().expect("first trigger claims the slot");
+        assert!(state.try_begin().is_none());
+        assert!(state.request_abort());
+
+        let mut seen = None;
+        run.run(|| seen = Some(combo_aborted()));
+        assert_eq!(seen, Some(true));
+        assert!(!combo_aborted());
+
+        let next = state.try_begin().expect("slot is free after the run");
+        next.run(|| assert!(!combo_aborted(), "abort does not leak into the next combo"));
+    }
+
+    #[test]
+    fn hold_key_follows_finished_combos_but_not_aborted_ones() {
+        use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+
+        let recorder = RecordingInputSink::default();
+        let _sink = install_thread_input_sink(recorder.clone());
+        let state = Arc::new(ComboState::default());
+
+        state.try_begin().unwrap().run(|| hold_after_combo(Some('s')));
+        assert_eq!(recorder.key_clicks(), vec!['s']);
+
+        let run = state.try_begin().unwrap();
+        state.request_abort();
+        run.run(|| hold_after_combo(Some('s')));
+        state.try_begin().unwrap().run(|| hold_after_combo(None));
+        assert_eq!(recorder.key_clicks(), vec!['s']);
+    }
+
+    #[test]
+    fn attack_move_brackets_the_combo_and_replaces_the_hold_key_after_it() {
+        use crate::input::simulation::{
+            install_thread_input_sink, RecordingInputSink, SyntheticAction,
+        };
+
+        let recorder = RecordingInputSink::default();
+        let _sink = install_thread_input_sink(recorder.clone());
+        let state = Arc::new(ComboState::default());
+        let orders = |attack_move| ComboOrders {
+            attack_move,
+            attack_key: 'a',
+            hold_key: Some('s'),
+        };
+        let sent = || -> Vec<SyntheticAction> {
+            recorder
+                .actions()
+                .into_iter()
+                .map(|(action, _)| action)
+                .collect()
+        };
+
+        state
+            .try_begin()
+            .unwrap()
+            .run(|| orders(AttackMove::Before).wrap(|| crate::input::press_key('q')));
+        assert_eq!(
+            sent(),
+            vec![
+                SyntheticAction::KeyClick('a'),
+                SyntheticAction::LeftClick,
+                SyntheticAction::KeyClick('q'),
+                SyntheticAction::KeyClick('s'),
+            ]
+        );
+
+        state
+            .try_begin()
+            .unwrap()
+            .run(|| orders(AttackMove::After).wrap(|| crate::input::press_key('w')));
+        assert_eq!(
+            sent()[4..],
+            [
+                SyntheticAction::KeyClick('w'),
+                SyntheticAction::KeyClick('a'),
+                SyntheticAction::LeftClick,
+            ]
+        );
+    }
+
+    fn disables(configure: impl FnOnce(&mut Hero)) -> u8 {
+        let event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let mut hero = event.hero;
+        hero.hexed = false;
+        hero.silenced = false;
+        hero.muted = false;
+        hero.disarmed = false;
+        configure(&mut hero);
+        disables_from(&hero)
+    }
+
+    #[test]
+    fn hex_blocks_abilities_and_items() {
+        let hexed = disables(|hero| hero.hexed = true);
+        assert_eq!(ability_blocked_reason_from(hexed), Some("hexed"));
+        assert_eq!(item_blocked_reason_from(hexed), Some("hexed"));
+    }
+
+    #[test]
+    fn silence_blocks_abilities_but_not_items() {
+        let silenced = disables(|hero| hero.silenced = true);
+        assert_eq!(ability_blocked_reason_from(silenced), Some("silenced"));
+        assert_eq!(item_blocked_reason_from(silenced), None);
+    }
+
+    #[test]
+    fn mute_blocks_items_but_not_abilities() {
+        let muted = disables(|hero| hero.muted = true);
+        assert_eq!(ability_blocked_reason_from(muted), None);
+        assert_eq!(item_blocked_reason_from(muted), Some("muted"));
+    }
+
+    #[test]
+    fn disarm_only_blocks_attacks() {
+        let disarmed = disables(|hero| hero.disarmed = true);
+        assert_eq!(ability_blocked_reason_from(disarmed), None);
+        assert_eq!(item_blocked_reason_from(disarmed), None);
+        assert_ne!(disarmed & DISARMED, 0);
+        assert_eq!(disables(|_| {}), 0);
+    }
+}
@@ -1,17 +1,26 @@
-use crate::actions::activity::{push_activity, ActivityCategory};
+use crate::actions::activity::{push_action, push_activity, ActionEvent, ActivityCategory};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::item_automation::{
     hero_is_excluded, lookup_item_automation, try_acquire_global_lockout, CastMode,
     ItemAutomationSpec, SupportStatus, TriggerFamily,
 };
-use crate::config::Settings;
+use crate::actions::morph_tracker::ShiftDirection;
+use crate::config::settings::SelfCastMethod;
+use crate::config::{DangerDetectionConfig, Settings};
+use crate::input::simulation::{modifier_down, modifier_up, practice_mode_enabled, ModifierKey};
+use crate::models::gsi_event::Item as GsiItem;
 use crate::models::{GsiWebhookEvent, Item};
+use crate::state::automation_metrics::{record_defensive_item_used, record_healing_item_used};
+use crate::state::poison::lock_or_recover;
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 #[cfg(test)]
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 const SELF_CAST_DELAY_MS: u64 = 50;
 const ITEM_AUTOMATION_LOCKOUT_MS: u64 = 120;
@@ -21,9 +30,57 @@ lazy_static::lazy_static! {
     static ref LOW_MANA_CHECK_CALLS: AtomicUsize = AtomicUsize::new(0);
 }
 
+static SMOKE_QUIET: AtomicBool = AtomicBool::new(false);
+static PASSIVES_BROKEN: AtomicBool = AtomicBool::new(false);
+
+/// Backpack healing item last hinted about, see [`hint_backpack_healing_item`].
+static BACKPACK_HEALING_HINT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Whether non-essential automation should hold off because the hero is
+/// smoked (`common.respect_smoke`). Danger lifts the hold so emergency
+/// survival still fires; healing and danger-gated items never check this.
+/// Danger is judged on this frame, so callers that run before the hero
+/// script's [`danger_detector::update`] still see a fresh burst.
+///
+/// [`danger_detector::update`]: crate::actions::danger_detector::update
+pub fn smoke_quiet(event: &GsiWebhookEvent, settings: &Settings) -> bool {
+    let quiet = should_hold_for_smoke(
+        settings.common.respect_smoke,
+        event.hero.smoked,
+        crate::actions::danger_detector::in_danger_now(event, &settings.danger_detection),
+    );
+    if SMOKE_QUIET.swap(quiet, Ordering::Relaxed) != quiet {
+        if quiet {
+            info!("🌫️ Smoked: holding armlet, low-mana items and Largo beats");
+        } else {
+            info!("🌫️ Smoke hold lifted");
+        }
+    }
+    quiet
+}
+
+/// Log when Break starts and ends, for scripts whose hero leans on a
+/// passive. Scripts that need the state read `event.hero.is_broken()`.
+pub fn log_passives_broken(event: &GsiWebhookEvent) {
+    let broken = event.hero.is_alive() && event.hero.is_broken();
+    if PASSIVES_BROKEN.swap(broken, Ordering::Relaxed) != broken {
+        if broken {
+            info!("💔 Broken: passives are off, playing it safer");
+        } else {
+            info!("💔 Break ended");
+        }
+    }
+}
+
+fn should_hold_for_smoke(respect_smoke: bool, smoked: bool, in_danger: bool) -> bool {
+    respect_smoke && smoked && !in_danger
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct PlannedKeyPress {
     key: char,
+    /// Held around the key press (Dota's self-cast modifier).
+    modifier: Option<ModifierKey>,
     delay_after_ms: u64,
 }
 
@@ -31,26 +88,45 @@ impl PlannedKeyPress {
     const fn new(key: char, delay_after_ms: u64) -> Self {
         Self {
             key,
+            modifier: None,
             delay_after_ms,
         }
     }
+
+    const fn with_modifier(key: char, modifier: ModifierKey) -> Self {
+        Self {
+            key,
+            modifier: Some(modifier),
+            delay_after_ms: 0,
+        }
+    }
 }
 
-fn plan_item_key_sequence(item_name: &str, key: char) -> Vec<PlannedKeyPress> {
-    if item_name == "item_glimmer_cape" {
-        vec![
+fn plan_item_key_sequence(
+    item_name: &str,
+    key: char,
+    config: &DangerDetectionConfig,
+) -> Vec<PlannedKeyPress> {
+    match config.self_cast_methods.get(item_name) {
+        Some(SelfCastMethod::DoubleTap) => vec![
             PlannedKeyPress::new(key, SELF_CAST_DELAY_MS),
             PlannedKeyPress::new(key, 0),
-        ]
-    } else {
-        vec![PlannedKeyPress::new(key, 0)]
+        ],
+        Some(SelfCastMethod::SelfCastModifier) => vec![PlannedKeyPress::with_modifier(
+            key,
+            config.self_cast_modifier.into(),
+        )],
+        None => vec![PlannedKeyPress::new(key, 0)],
     }
 }
 
-fn plan_defensive_item_key_sequence(items: &[(String, char)]) -> Vec<PlannedKeyPress> {
+fn plan_defensive_item_key_sequence(
+    items: &[(String, char)],
+    config: &DangerDetectionConfig,
+) -> Vec<PlannedKeyPress> {
     items
         .iter()
-        .flat_map(|(item_name, key)| plan_item_key_sequence(item_name, *key))
+        .flat_map(|(item_name, key)| plan_item_key_sequence(item_name, *key, config))
         .collect()
 }
 
@@ -70,18 +146,156 @@ fn plan_automation_key_sequence(
 
 fn execute_key_sequence(sequence: Vec<PlannedKeyPress>) {
     for press in sequence {
-        crate::input::press_key(press.key);
+        match press.modifier {
+            Some(modifier) => {
+                modifier_down(modifier);
+                crate::input::press_key(press.key);
+                modifier_up(modifier);
+            }
+            None => crate::input::press_key(press.key),
+        }
         if press.delay_after_ms > 0 {
             std::thread::sleep(Duration::from_millis(press.delay_after_ms));
         }
     }
 }
 
+/// Whether the talent in GSI slot `slot` (1-8, see [`crate::models::gsi_event::Hero::talents`])
+/// has been picked. Slot 0 or anything above 8 is never picked, so configs
+/// can use 0 to mean "no talent".
+pub fn has_talent(event: &GsiWebhookEvent, slot: u32) -> bool {
+    slot.checked_sub(1)
+        .and_then(|index| event.hero.talents().get(index as usize).copied())
+        .unwrap_or(false)
+}
+
+/// Mana Soul Ring grants, counted toward a combo when it will fire first.
+const SOUL_RING_MANA: u32 = 150;
+
+/// Why a combo pressing `abilities` would fizzle for lack of mana, if it would.
+///
+/// Only learned abilities that are off cooldown count. Their `mana_costs`
+/// are summed and compared with the hero's mana (plus Soul Ring when it is
+/// enabled and ready). An ability with no configured cost is inferred short
+/// when GSI reports it uncastable while the hero isn't silenced, stunned or
+/// hexed, since mana is then the only thing stopping it.
+pub fn combo_mana_shortfall(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    abilities: &[&str],
+    mana_costs: &BTreeMap<String, u32>,
+) -> Option<String> {
+    let soul_ring_ready = settings.soul_ring.enabled
+        && event
+            .items
+            .all_slots()
+            .into_iter()
+            .any(|(_, item)| item.name == Item::SoulRing.to_game_name() && item.is_ready());
+    let available = event.hero.mana + if soul_ring_ready { SOUL_RING_MANA } else { 0 };
+    let blocked = event.hero.silenced || event.hero.stunned || event.hero.hexed;
+
+    let mut required = 0;
+    for name in abilities {
+        let Some(ability) = event.abilities.find_by_name(name) else {
+            continue;
+        };
+        if ability.level == 0 || ability.cooldown > 0 {
+            continue;
+        }
+        match mana_costs.get(*name) {
+            Some(cost) => required += cost,
+            None if !ability.can_cast && !blocked && !soul_ring_ready => {
+                return Some(format!("not enough mana for {}", name));
+            }
+            None => {}
+        }
+    }
+
+    (required > available)
+        .then(|| format!("not enough mana: need {}, have {}", required, available))
+}
+
+/// Check [`combo_mana_shortfall`] before a combo, reporting a skip to the log
+/// and the activity feed. Returns `true` when the combo should run.
+pub fn combo_has_mana(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    combo: &str,
+    abilities: &[&str],
+    mana_costs: &BTreeMap<String, u32>,
+) -> bool {
+    let Some(reason) = combo_mana_shortfall(event, settings, abilities, mana_costs) else {
+        return true;
+    };
+    warn!("{} combo skipped: {}", combo, reason);
+    push_activity(
+        ActivityCategory::Warning,
+        format!("{} combo skipped: {}", combo, reason),
+    );
+    false
+}
+
 /// Find the keybinding for a specific item in the hero's inventory
 pub fn find_item_slot(event: &GsiWebhookEvent, settings: &Settings, item: Item) -> Option<char> {
     find_item_slot_by_name(event, settings, item.to_game_name())
 }
 
+/// Press BKB, then `ability_key`, so a stun cannot interrupt the cast.
+///
+/// The ability is pressed whether or not [`auto_bkb`] used BKB. Returns
+/// whether BKB was pressed. Callers gate this on their own per-hero config
+/// flag.
+pub fn auto_bkb_before(ability_key: char, settings: &Settings, event: &GsiWebhookEvent) -> bool {
+    let used_bkb = auto_bkb(event, &settings.inventory_slot_keys());
+    if used_bkb {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    crate::input::simulation::press_key(ability_key);
+    used_bkb
+}
+
+/// Double-tap BKB, pressing the key `slot_keys` binds to its inventory slot.
+///
+/// Skipped when the hero is already magic-immune, muted or hexed, or BKB is
+/// missing or not castable. Returns whether BKB was pressed; the caller
+/// waits before its next cast.
+pub fn auto_bkb(event: &GsiWebhookEvent, slot_keys: &[char; 6]) -> bool {
+    let items = &event.items;
+    let bkb_key = [
+        &items.slot0,
+        &items.slot1,
+        &items.slot2,
+        &items.slot3,
+        &items.slot4,
+        &items.slot5,
+    ]
+    .into_iter()
+    .zip(slot_keys)
+    .find_map(|(item, key)| {
+        (item_name_matches_lookup(&item.name, Item::BlackKingBar.to_game_name())
+            && item.can_cast == Some(true))
+        .then_some(*key)
+    });
+
+    if event.hero.magicimmune {
+        debug!("Already magic-immune, skipping BKB");
+        return false;
+    }
+    if event.hero.muted || event.hero.hexed {
+        return false;
+    }
+    let Some(key) = bkb_key else {
+        return false;
+    };
+
+    info!("Using BKB ({})", key);
+    crate::input::simulation::press_key(key);
+    std::thread::sleep(Duration::from_millis(30));
+    crate::input::simulation::press_key(key);
+    true
+}
+
 fn item_name_matches_lookup(item_name: &str, lookup_name: &str) -> bool {
     if item_name.contains(lookup_name) {
         return true;
@@ -143,10 +357,70 @@ fn healing_threshold_for_event(event: &GsiWebhookEvent, settings: &Settings, in_
     if in_danger && settings.danger_detection.enabled {
         settings.danger_detection.healing_threshold_in_danger
     } else {
-        settings.common.survivability_hp_threshold
+        settings.survivability_hp_threshold(&event.hero.name)
+    }
+}
+
+/// Whether a danger state should drive healing: with
+/// `healing_min_recent_loss_hp` set, the hero must have actually lost that
+/// much HP recently, so a lingering danger flag while HP recovers does not
+/// burn consumables at the higher danger threshold.
+fn danger_healing_confirmed(config: &DangerDetectionConfig, recent_loss_hp: u32) -> bool {
+    recent_loss_hp >= config.healing_min_recent_loss_hp
+}
+
+/// Enabled flag of a defensive item, or `None` if it isn't one we automate.
+pub(crate) fn defensive_item_enabled(config: &DangerDetectionConfig, item_name: &str) -> Option<bool> {
+    match item_name {
+        "item_black_king_bar" => Some(config.auto_bkb),
+        "item_satanic" => Some(config.auto_satanic),
+        "item_blade_mail" => Some(config.auto_blade_mail),
+        "item_glimmer_cape" => Some(config.auto_glimmer_cape),
+        "item_ghost" => Some(config.auto_ghost_scepter),
+        "item_shivas_guard" => Some(config.auto_shivas_guard),
+        _ => None,
     }
 }
 
+/// How many more defensive items this danger event may fire, or `None` when
+/// `max_defensive_items_per_danger` is 0 (no limit).
+fn defensive_item_budget(max_items: u32, used: u32) -> Option<usize> {
+    (max_items > 0).then(|| max_items.saturating_sub(used) as usize)
+}
+
+/// Why a defensive item fires, for the activity feed.
+fn defensive_item_reason(
+    event: &GsiWebhookEvent,
+    item_name: &str,
+    satanic_threshold: u32,
+) -> String {
+    if item_name == "item_satanic" {
+        format!(
+            "HP {}% <= {}% Satanic threshold in danger",
+            event.hero.health_percent, satanic_threshold
+        )
+    } else {
+        format!("HP {}% in danger", event.hero.health_percent)
+    }
+}
+
+/// Defensive items in `defensive_item_priority` order, then any the list
+/// leaves out in the default order. Unknown and repeated names are skipped.
+fn ordered_defensive_items(config: &DangerDetectionConfig) -> Vec<(String, bool)> {
+    let default_order = DangerDetectionConfig::default().defensive_item_priority;
+    let mut ordered: Vec<(String, bool)> = Vec::new();
+
+    for item_name in config.defensive_item_priority.iter().chain(default_order.iter()) {
+        if ordered.iter().any(|(name, _)| name == item_name) {
+            continue;
+        }
+        if let Some(enabled) = defensive_item_enabled(config, item_name) {
+            ordered.push((item_name.clone(), enabled));
+        }
+    }
+    ordered
+}
+
 #[cfg_attr(not(test), allow(dead_code))]
 fn should_consider_defensive_items(event: &GsiWebhookEvent, settings: &Settings, in_danger: bool) -> bool {
     // Mirror the early gates in use_defensive_items_if_danger
@@ -190,6 +464,49 @@ fn should_consider_neutral_item(event: &GsiWebhookEvent, settings: &Settings, in
     false
 }
 
+/// Healing item sitting in the backpack, which cannot be cast until it is
+/// swapped into the main inventory.
+fn backpack_healing_item<'a>(
+    event: &'a GsiWebhookEvent,
+    healing_names: &[&str],
+) -> Option<&'a str> {
+    event
+        .items
+        .all_slots_including_backpack()
+        .into_iter()
+        .filter(|(slot, _)| matches!(*slot, "slot6" | "slot7" | "slot8"))
+        .map(|(_, item)| item.name.as_str())
+        .find(|name| healing_names.contains(name))
+}
+
+/// Whether pressing this healing item would heal. An empty Magic Wand or a
+/// used-up mango stack can still report `can_cast`; wand and stick also need
+/// `common.wand_min_charges`.
+fn healing_item_usable(item: &GsiItem, wand_min_charges: u32) -> bool {
+    let too_few_charges = matches!(item.name.as_str(), "item_magic_wand" | "item_magic_stick")
+        && matches!(item.charge_count(), Some(charges) if charges < wand_min_charges);
+    item.is_ready() && !too_few_charges
+}
+
+/// Log once per item that a healing item is stuck in the backpack.
+fn hint_backpack_healing_item(event: &GsiWebhookEvent, healing_names: &[&str]) {
+    let found = backpack_healing_item(event, healing_names);
+    let mut last_hint = lock_or_recover(&BACKPACK_HEALING_HINT);
+    if found != last_hint.as_deref() {
+        if let Some(name) = found {
+            info!("🎒 {} is in the backpack; swap it up to use it while low", name);
+        }
+        *last_hint = found.map(str::to_string);
+    }
+}
+
+/// Clear the smoke-hold, Break and backpack-hint log state.
+pub fn reset() {
+    SMOKE_QUIET.store(false, Ordering::Relaxed);
+    PASSIVES_BROKEN.store(false, Ordering::Relaxed);
+    *lock_or_recover(&BACKPACK_HEALING_HINT) = None;
+}
+
 fn current_time_millis() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -233,6 +550,7 @@ fn hero_uses_mana(event: &GsiWebhookEvent) -> bool {
 fn eligible_low_mana_item(
     event: &GsiWebhookEvent,
     settings: &Settings,
+    in_danger: bool,
 ) -> Option<(&'static ItemAutomationSpec, char)> {
     if !settings.mana_automation.enabled {
         return None;
@@ -240,6 +558,10 @@ fn eligible_low_mana_item(
     if !event.hero.is_alive() {
         return None;
     }
+    // Mid-fight the slot keys belong to survivability; top up mana afterwards.
+    if in_danger {
+        return None;
+    }
     if !hero_uses_mana(event) {
         return None;
     }
@@ -288,24 +610,6 @@ impl SurvivabilityActions {
         Self { settings, executor }
     }
 
-    /// Execute default GSI strategy (danger detection + survivability)
-    pub fn execute_default_strategy(&self, event: &GsiWebhookEvent) {
-        // PRIORITY 1: Update danger detection state
-        let in_danger = {
-            let settings = self.settings.lock().unwrap();
-            crate::actions::danger_detector::update(event, &settings.danger_detection)
-        };
-
-        // PRIORITY 2: Always check survivability first
-        self.check_and_use_healing_items_with_danger(event, in_danger);
-
-        // PRIORITY 3: Use defensive items if in danger
-        self.use_defensive_items_if_danger_with_snapshot(event, in_danger);
-
-        // PRIORITY 4: Use neutral items if in danger
-        self.use_neutral_item_if_danger_with_snapshot(event, in_danger);
-    }
-
     #[allow(dead_code)]
     /// Check if hero needs healing and use appropriate items
     pub fn check_and_use_healing_items(&self, event: &GsiWebhookEvent) {
@@ -321,8 +625,28 @@ impl SurvivabilityActions {
         if !event.hero.is_alive() {
             return;
         }
+        // Shifting to Agility drains HP on purpose; HP percent says nothing
+        // about damage until it stops. Shifting to Strength only adds HP, so
+        // a Morphling losing HP to enemies meanwhile still heals.
+        if crate::actions::morph_tracker::attribute_shift() == Some(ShiftDirection::ToAgility) {
+            debug!("Attribute Shift to Agility running; skipping HP-threshold healing");
+            return;
+        }
 
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
+        let in_danger = in_danger && {
+            let config = &settings.danger_detection;
+            let window = Duration::from_secs(config.clear_delay_seconds);
+            let recent_loss = crate::actions::danger_detector::recent_hp_loss(window);
+            let confirmed = danger_healing_confirmed(config, recent_loss);
+            if !confirmed {
+                debug!(
+                    "In danger but only {} HP lost in {}s; healing at the normal threshold",
+                    recent_loss, config.clear_delay_seconds
+                );
+            }
+            confirmed
+        };
         let threshold = healing_threshold_for_event(event, &settings, in_danger);
 
         // Check if HP is below threshold
@@ -342,12 +666,14 @@ impl SurvivabilityActions {
                 ("item_greater_faerie_fire", 350u32),
                 ("item_enchanted_mango", 175u32),
                 ("item_magic_wand", 100u32), // Approximate (15 per charge)
+                ("item_magic_stick", 50u32),
                 ("item_faerie_fire", 85u32),
             ]
         } else {
             vec![
                 ("item_cheese", 2000u32),
                 ("item_faerie_fire", 85u32),
+                ("item_magic_stick", 50u32),
                 ("item_magic_wand", 100u32),
                 ("item_enchanted_mango", 175u32),
                 ("item_greater_faerie_fire", 350u32),
@@ -359,9 +685,11 @@ impl SurvivabilityActions {
         } else {
             1 // Normal mode: only one item
         };
+        let wand_min_charges = settings.common.wand_min_charges;
         drop(settings); // Release lock
 
         let mut items_used = 0u32;
+        let healing_names: Vec<&str> = healing_items.iter().map(|(name, _)| *name).collect();
 
         // Search for healing items in inventory
         for (item_name, _heal_amount) in healing_items {
@@ -370,32 +698,41 @@ impl SurvivabilityActions {
             }
 
             for (slot, item) in event.items.all_slots() {
-                if item.name == item_name {
-                    // Check if item can be cast
-                    if let Some(can_cast) = item.can_cast {
-                        if can_cast {
-                            self.use_item(slot, &item.name);
-                            items_used += 1;
-                            break; // Move to next item type
-                        }
-                    }
+                if item.name == item_name && healing_item_usable(item, wand_min_charges) {
+                    let reason = format!(
+                        "HP {}% < {}% threshold{}",
+                        event.hero.health_percent,
+                        threshold,
+                        if in_danger { " in danger" } else { "" }
+                    );
+                    self.use_item(slot, &item.name, reason);
+                    items_used += 1;
+                    break; // Move to next item type
                 }
             }
         }
+
+        if items_used == 0 {
+            hint_backpack_healing_item(event, &healing_names);
+        }
     }
 
-    fn use_item(&self, slot: &str, item_name: &str) {
+    fn use_item(&self, slot: &str, item_name: &str, reason: String) {
         let key = {
-            let settings = self.settings.lock().unwrap();
+            let settings = lock_or_recover(&self.settings);
             settings.get_key_for_slot(slot)
         };
 
         if let Some(key) = key {
             info!("Using {} in {} (key: {})", item_name, slot, key);
-            push_activity(
-                ActivityCategory::Action,
-                format!("Healing item used: {}", item_name.replace("item_", "")),
+            push_action(
+                ActionEvent::HealingItem {
+                    item: item_name.to_string(),
+                },
+                reason,
+                practice_mode_enabled(),
             );
+            record_healing_item_used();
             crate::input::press_key(key);
         }
     }
@@ -413,8 +750,8 @@ impl SurvivabilityActions {
         in_danger: bool,
     ) {
         // Check danger state and gather config - release lock before item usage
-        let (_enabled, satanic_threshold, defensive_items_config) = {
-            let settings = self.settings.lock().unwrap();
+        let (danger_config, defensive_items_config) = {
+            let settings = lock_or_recover(&self.settings);
             let current_config = &settings.danger_detection;
 
             if !should_consider_defensive_items(event, &settings, in_danger) {
@@ -424,17 +761,21 @@ impl SurvivabilityActions {
             debug!("In danger - checking defensive items");
 
             // Gather config before releasing lock
-            let defensive_items = vec![
-                ("item_black_king_bar", current_config.auto_bkb),
-                ("item_satanic", current_config.auto_satanic),
-                ("item_blade_mail", current_config.auto_blade_mail),
-                ("item_glimmer_cape", current_config.auto_glimmer_cape),
-                ("item_ghost", current_config.auto_ghost_scepter),
-                ("item_shivas_guard", current_config.auto_shivas_guard),
-            ];
-
-            (true, current_config.satanic_hp_threshold, defensive_items)
+            let defensive_items = ordered_defensive_items(current_config);
+
+            (current_config.clone(), defensive_items)
         }; // Lock released here
+        let max_items = danger_config.max_defensive_items_per_danger;
+        let satanic_threshold = danger_config.satanic_hp_threshold;
+
+        let budget = defensive_item_budget(
+            max_items,
+            crate::actions::danger_detector::defensive_items_used(),
+        );
+        if budget == Some(0) {
+            debug!("Defensive item cap of {} reached for this danger event", max_items);
+            return;
+        }
 
         let mut ready_items = Vec::new();
 
@@ -443,17 +784,17 @@ impl SurvivabilityActions {
             if !enabled {
                 continue;
             }
+            if budget.is_some_and(|budget| ready_items.len() >= budget) {
+                break;
+            }
 
             // Satanic has its own HP threshold check
-            if item_name == "item_satanic" {
-                let hp_percent = (event.hero.health * 100) / event.hero.max_health;
-                if hp_percent > satanic_threshold {
-                    debug!(
-                        "Satanic not used: HP {}% > threshold {}%",
-                        hp_percent, satanic_threshold
-                    );
-                    continue;
-                }
+            if item_name == "item_satanic" && event.hero.health_percent > satanic_threshold {
+                debug!(
+                    "Satanic not used: HP {}% > threshold {}%",
+                    event.hero.health_percent, satanic_threshold
+                );
+                continue;
             }
 
             for (slot, item) in event.items.all_slots() {
@@ -463,16 +804,20 @@ impl SurvivabilityActions {
                         if can_cast {
                             debug!("Activating defensive item: {}", item_name);
                             let key = {
-                                let settings = self.settings.lock().unwrap();
+                                let settings = lock_or_recover(&self.settings);
                                 settings.get_key_for_slot(slot)
                             };
 
                             if let Some(key) = key {
                                 info!("Using {} in {} (key: {})", item.name, slot, key);
-                                push_activity(
-                                    ActivityCategory::Action,
-                                    format!("Defensive item activated: {}", item.name.replace("item_", "")),
+                                push_action(
+                                    ActionEvent::DefensiveItem {
+                                        item: item.name.clone(),
+                                    },
+                                    defensive_item_reason(event, &item_name, satanic_threshold),
+                                    practice_mode_enabled(),
                                 );
+                                record_defensive_item_used(&item.name);
                                 ready_items.push((item.name.clone(), key));
                             }
                             break; // Move to next item type
@@ -485,16 +830,18 @@ impl SurvivabilityActions {
         if ready_items.is_empty() {
             return;
         }
+        crate::actions::danger_detector::record_defensive_items_used(ready_items.len() as u32);
 
-        if let Some(glimmer_index) = ready_items
+        if let Some(self_cast_index) = ready_items
             .iter()
-            .position(|(item_name, _)| item_name == "item_glimmer_cape")
+            .position(|(item_name, _)| self_cast_method(&danger_config, item_name).is_some())
         {
-            for (_item_name, key) in &ready_items[..glimmer_index] {
+            for (_item_name, key) in &ready_items[..self_cast_index] {
                 crate::input::press_key(*key);
             }
 
-            let sequence = plan_defensive_item_key_sequence(&ready_items[glimmer_index..]);
+            let sequence =
+                plan_defensive_item_key_sequence(&ready_items[self_cast_index..], &danger_config);
             self.executor
                 .enqueue("common-defensive-self-cast-tail", move || {
                     execute_key_sequence(sequence);
@@ -523,7 +870,7 @@ impl SurvivabilityActions {
             return;
         }
 
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let Some(spec) = eligible_danger_neutral_spec(event, &settings, in_danger) else {
             return;
         };
@@ -545,12 +892,12 @@ impl SurvivabilityActions {
             "⚡ Using danger automation item: {} (HP: {}%)",
             neutral_item.name, event.hero.health_percent
         );
-        push_activity(
-            ActivityCategory::Action,
-            format!(
-                "Danger automation used: {}",
-                neutral_item.name.replace("item_", "")
-            ),
+        push_action(
+            ActionEvent::DangerItem {
+                item: neutral_item.name.clone(),
+            },
+            format!("HP {}% in danger", event.hero.health_percent),
+            practice_mode_enabled(),
         );
 
         // Release lock before input simulation
@@ -568,13 +915,18 @@ impl SurvivabilityActions {
             LOW_MANA_CHECK_CALLS.fetch_add(1, Ordering::SeqCst);
         }
 
-        let settings = self.settings.lock().unwrap();
-        let Some((spec, item_key)) = eligible_low_mana_item(event, &settings) else {
+        let in_danger = crate::actions::danger_detector::is_in_danger();
+        let settings = lock_or_recover(&self.settings);
+        let Some((spec, item_key)) = eligible_low_mana_item(event, &settings, in_danger) else {
             return;
         };
 
         let self_cast_key = settings.neutral_items.self_cast_key;
         let item_name = spec.item_name.to_string();
+        let reason = format!(
+            "mana {}% < {}% threshold",
+            event.hero.mana_percent, settings.mana_automation.mana_threshold_percent
+        );
         let lockout_key = format!("mana:{}", item_name);
         let now_ms = current_time_millis();
 
@@ -586,9 +938,12 @@ impl SurvivabilityActions {
         drop(settings);
 
         info!("💧 Using low-mana automation item: {}", item_name);
-        push_activity(
-            ActivityCategory::Action,
-            format!("Mana automation used: {}", item_name.replace("item_", "")),
+        push_action(
+            ActionEvent::ManaItem {
+                item: item_name.to_string(),
+            },
+            reason,
+            practice_mode_enabled(),
         );
 
         self.executor.enqueue("common-low-mana-item", move || {
@@ -610,11 +965,15 @@ pub fn low_mana_check_call_count_for_tests() -> usize {
 #[cfg(test)]
 mod tests {
     use super::{
-        find_item_slot, plan_automation_key_sequence, plan_defensive_item_key_sequence,
-        plan_item_key_sequence, PlannedKeyPress, SELF_CAST_DELAY_MS,
+        backpack_healing_item, combo_mana_shortfall, defensive_item_budget,
+        find_item_slot, healing_item_usable,
+        ordered_defensive_items, plan_automation_key_sequence, plan_defensive_item_key_sequence,
+        plan_item_key_sequence, should_hold_for_smoke, PlannedKeyPress, SELF_CAST_DELAY_MS,
     };
     use crate::actions::item_automation::CastMode;
-    use crate::config::Settings;
+    use crate::config::settings::{CastModifier, SelfCastMethod};
+    use crate::config::{DangerDetectionConfig, Settings};
+    use crate::input::simulation::ModifierKey;
     use crate::models::gsi_event::{Abilities, Ability, GsiWebhookEvent, Hero, Item as GsiItem, Items, Map};
     use crate::models::Item;
 
@@ -669,6 +1028,7 @@ mod tests {
             xp: 0,
             xpos: 0,
             ypos: 0,
+            extra: Default::default(),
         }
     }
 
@@ -706,15 +1066,27 @@ mod tests {
                 ability5: empty_ability(),
             },
             items,
-            map: Map { clock_time: 0 },
+            map: Map {
+                clock_time: 0,
+                game_state: None,
+                matchid: None,
+            },
             player: None,
         }
     }
 
+    #[test]
+    fn smoke_hold_applies_only_when_smoked_respected_and_safe() {
+        assert!(should_hold_for_smoke(true, true, false));
+        assert!(!should_hold_for_smoke(true, true, true), "danger lifts the hold");
+        assert!(!should_hold_for_smoke(false, true, false));
+        assert!(!should_hold_for_smoke(true, false, false));
+    }
+
     #[test]
     fn glimmer_plan_double_taps_for_self_cast() {
         assert_eq!(
-            plan_item_key_sequence("item_glimmer_cape", '4'),
+            plan_item_key_sequence("item_glimmer_cape", '4', &DangerDetectionConfig::default()),
             vec![
                 PlannedKeyPress::new('4', SELF_CAST_DELAY_MS),
                 PlannedKeyPress::new('4', 0),
@@ -725,7 +1097,7 @@ mod tests {
     #[test]
     fn non_self_cast_item_plan_is_single_press() {
         assert_eq!(
-            plan_item_key_sequence("item_black_king_bar", '3'),
+            plan_item_key_sequence("item_black_king_bar", '3', &DangerDetectionConfig::default()),
             vec![PlannedKeyPress::new('3', 0)]
         );
     }
@@ -738,7 +1110,7 @@ mod tests {
         ];
 
         assert_eq!(
-            plan_defensive_item_key_sequence(&items),
+            plan_defensive_item_key_sequence(&items, &DangerDetectionConfig::default()),
             vec![
                 PlannedKeyPress::new('4', SELF_CAST_DELAY_MS),
                 PlannedKeyPress::new('4', 0),
@@ -747,6 +1119,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn self_cast_modifier_method_holds_the_modifier_for_one_press() {
+        let mut config = DangerDetectionConfig::default();
+        config.self_cast_modifier = CastModifier::Ctrl;
+        config.self_cast_methods.insert(
+            "item_glimmer_cape".to_string(),
+            SelfCastMethod::SelfCastModifier,
+        );
+        config
+            .self_cast_methods
+            .insert("item_ghost".to_string(), SelfCastMethod::DoubleTap);
+        let items = vec![
+            ("item_glimmer_cape".to_string(), '4'),
+            ("item_ghost".to_string(), '5'),
+        ];
+
+        assert_eq!(
+            plan_defensive_item_key_sequence(&items, &config),
+            vec![
+                PlannedKeyPress::with_modifier('4', ModifierKey::Control),
+                PlannedKeyPress::new('5', SELF_CAST_DELAY_MS),
+                PlannedKeyPress::new('5', 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn combo_mana_check_sums_configured_costs_and_infers_the_rest() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        let mut event = base_event(empty_items());
+        event.hero.mana = 150;
+        let ability = |name: &str, can_cast: bool, cooldown: u32| Ability {
+            can_cast,
+            cooldown,
+            level: 1,
+            name: name.to_string(),
+            ..empty_ability()
+        };
+        event.abilities.ability0 = ability("tiny_avalanche", true, 0);
+        event.abilities.ability1 = ability("tiny_toss", true, 0);
+        let abilities = ["tiny_avalanche", "tiny_toss"];
+        let costs = std::collections::BTreeMap::from([
+            ("tiny_avalanche".to_string(), 100),
+            ("tiny_toss".to_string(), 90),
+        ]);
+
+        assert!(combo_mana_shortfall(&event, &settings, &abilities, &costs).is_some());
+        event.abilities.ability1.cooldown = 5;
+        assert_eq!(combo_mana_shortfall(&event, &settings, &abilities, &costs), None);
+
+        let no_costs = std::collections::BTreeMap::new();
+        event.abilities.ability0.can_cast = false;
+        assert!(combo_mana_shortfall(&event, &settings, &abilities, &no_costs).is_some());
+        event.hero.silenced = true;
+        assert_eq!(combo_mana_shortfall(&event, &settings, &abilities, &no_costs), None);
+    }
+
+    #[test]
+    fn defensive_items_follow_configured_priority_then_default_order() {
+        let mut settings = Settings::default();
+        settings.danger_detection.auto_blade_mail = false;
+        settings.danger_detection.defensive_item_priority = vec![
+            "item_glimmer_cape".to_string(),
+            "item_unknown".to_string(),
+            "item_blade_mail".to_string(),
+            "item_glimmer_cape".to_string(),
+        ];
+
+        let names = ordered_defensive_items(&settings.danger_detection);
+        assert_eq!(
+            names,
+            vec![
+                ("item_glimmer_cape".to_string(), true),
+                ("item_blade_mail".to_string(), false),
+                ("item_black_king_bar".to_string(), false),
+                ("item_satanic".to_string(), true),
+                ("item_ghost".to_string(), true),
+                ("item_shivas_guard".to_string(), true),
+            ]
+        );
+
+        settings.danger_detection.defensive_item_priority.clear();
+        let defaults: Vec<String> = ordered_defensive_items(&settings.danger_detection)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(defaults[0], "item_black_king_bar");
+        assert_eq!(defaults[5], "item_shivas_guard");
+    }
+
+    #[test]
+    fn defensive_item_budget_counts_down_per_danger_event() {
+        assert_eq!(defensive_item_budget(0, 5), None);
+        assert_eq!(defensive_item_budget(1, 0), Some(1));
+        assert_eq!(defensive_item_budget(2, 1), Some(1));
+        assert_eq!(defensive_item_budget(1, 3), Some(0));
+    }
+
     #[test]
     fn automation_plan_for_self_cast_waits_before_tail() {
         assert_eq!(
@@ -774,6 +1245,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn backpack_healing_item_is_found_only_in_backpack_slots() {
+        let mango = || GsiItem {
+            name: "item_enchanted_mango".to_string(),
+            ..Default::default()
+        };
+        let names = ["item_faerie_fire", "item_enchanted_mango"];
+
+        let mut items = empty_items();
+        items.slot7 = mango();
+        assert_eq!(
+            backpack_healing_item(&base_event(items), &names),
+            Some("item_enchanted_mango")
+        );
+
+        let mut items = empty_items();
+        items.slot2 = mango();
+        assert_eq!(backpack_healing_item(&base_event(items), &names), None);
+    }
+
+    #[test]
+    fn wand_needs_minimum_charges_and_empty_mango_is_skipped() {
+        let item = |name: &str, charges: Option<u32>| GsiItem {
+            name: name.to_string(),
+            can_cast: Some(true),
+            cooldown: Some(0),
+            charges,
+            ..Default::default()
+        };
+
+        assert!(!healing_item_usable(&item("item_magic_wand", Some(0)), 1));
+        assert!(healing_item_usable(&item("item_magic_wand", Some(1)), 1));
+        assert!(!healing_item_usable(&item("item_magic_stick", Some(4)), 5));
+        assert!(healing_item_usable(&item("item_magic_wand", Some(5)), 5));
+        let mango = |charges| item("item_enchanted_mango", Some(charges));
+        assert!(!healing_item_usable(&mango(0), 5));
+        assert!(healing_item_usable(&mango(1), 5));
+        assert!(healing_item_usable(&item("item_cheese", None), 5));
+    }
+
     #[test]
     fn blink_lookup_accepts_arcane_blink_variant() {
         let settings = Settings::default();
@@ -795,14 +1306,13 @@ mod snapshot_tests {
     use std::sync::{Arc, Mutex};
 
     use crate::actions::executor::ActionExecutor;
-    use crate::actions::item_automation::reset_global_lockouts_for_tests;
-    use crate::config::Settings;
+    use crate::config::{DangerDetectionConfig, Settings};
     use crate::models::gsi_event::{Abilities, Ability, GsiWebhookEvent, Hero, Item, Items, Map};
 
     use super::{
-        acquire_item_trigger_lockout, eligible_danger_neutral_spec, eligible_low_mana_item,
-        healing_threshold_for_event, should_consider_defensive_items, should_consider_neutral_item,
-        SurvivabilityActions,
+        acquire_item_trigger_lockout, danger_healing_confirmed, eligible_danger_neutral_spec,
+        eligible_low_mana_item, healing_threshold_for_event, should_consider_defensive_items,
+        should_consider_neutral_item, SurvivabilityActions,
     };
 
     fn empty_ability() -> Ability {
@@ -856,6 +1366,7 @@ mod snapshot_tests {
             xp: 0,
             xpos: 0,
             ypos: 0,
+            extra: Default::default(),
         }
     }
 
@@ -897,7 +1408,11 @@ mod snapshot_tests {
             hero,
             abilities: empty_abilities(),
             items,
-            map: Map { clock_time: 0 },
+            map: Map {
+                clock_time: 0,
+                game_state: None,
+                matchid: None,
+            },
             player: None,
         }
     }
@@ -922,6 +1437,38 @@ mod snapshot_tests {
         );
     }
 
+    #[test]
+    fn danger_healing_needs_recent_loss_only_when_configured() {
+        let mut config = DangerDetectionConfig::default();
+        assert!(danger_healing_confirmed(&config, 0));
+
+        config.healing_min_recent_loss_hp = 150;
+        assert!(!danger_healing_confirmed(&config, 0));
+        assert!(!danger_healing_confirmed(&config, 149));
+        assert!(danger_healing_confirmed(&config, 150));
+    }
+
+    #[test]
+    fn hero_hp_threshold_override_replaces_common_threshold_out_of_danger() {
+        let mut settings = Settings::default();
+        settings.heroes.huskar.survivability_hp_threshold_override = Some(20);
+        let mut event = base_event(hero_with_health(100, 100), empty_items());
+        event.map.clock_time = 900;
+        event.hero.name = "npc_dota_hero_huskar".to_string();
+
+        assert_eq!(healing_threshold_for_event(&event, &settings, false), 20);
+        assert_eq!(
+            healing_threshold_for_event(&event, &settings, true),
+            settings.danger_detection.healing_threshold_in_danger
+        );
+
+        event.hero.name = "npc_dota_hero_nevermore".to_string();
+        assert_eq!(
+            healing_threshold_for_event(&event, &settings, false),
+            settings.common.survivability_hp_threshold
+        );
+    }
+
     #[test]
     fn lane_phase_healing_threshold_overrides_danger_before_cutoff() {
         let settings = Settings::default();
@@ -1058,7 +1605,7 @@ mod snapshot_tests {
 
     #[test]
     fn danger_neutral_gate_respects_global_lockout() {
-        reset_global_lockouts_for_tests();
+        crate::actions::item_automation::reset();
 
         assert!(acquire_item_trigger_lockout(
             "danger:item_jidi_pollen_bag",
@@ -1099,10 +1646,14 @@ mod snapshot_tests {
         hero.mana_percent = 20;
 
         let event = base_event(hero, items);
-        let (spec, slot_key) = eligible_low_mana_item(&event, &settings).unwrap();
+        let (spec, slot_key) = eligible_low_mana_item(&event, &settings, false).unwrap();
 
         assert_eq!(spec.item_name, "item_arcane_boots");
         assert_eq!(slot_key, settings.keybindings.slot0);
+        assert!(
+            eligible_low_mana_item(&event, &settings, true).is_none(),
+            "mana items wait until the hero is out of danger"
+        );
     }
 
     #[test]
@@ -1125,7 +1676,7 @@ mod snapshot_tests {
         hero.mana_percent = 20;
 
         let event = base_event(hero, items);
-        assert!(eligible_low_mana_item(&event, &settings).is_none());
+        assert!(eligible_low_mana_item(&event, &settings, false).is_none());
     }
 
     #[test]
@@ -1147,7 +1698,7 @@ mod snapshot_tests {
         hero.mana_percent = 20;
 
         let event = base_event(hero, items);
-        let (spec, slot_key) = eligible_low_mana_item(&event, &settings).unwrap();
+        let (spec, slot_key) = eligible_low_mana_item(&event, &settings, false).unwrap();
 
         assert_eq!(spec.item_name, "item_mana_draught");
         assert_eq!(slot_key, settings.keybindings.neutral0);
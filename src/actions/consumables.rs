@@ -0,0 +1,180 @@
+//! Auto-consume module
+//!
+//! Uses permanent upgrade items (Aghanim's Shard, Moon Shard) on the hero so
+//! they do not sit forgotten in the inventory. Only items in
+//! `[consumables] allowed_items` are touched, and Cheese never is: it is the
+//! emergency heal.
+
+use crate::actions::activity::{push_action, ActionEvent};
+use crate::actions::common::find_item_slot;
+use crate::actions::executor::ActionExecutor;
+use crate::actions::item_automation::try_acquire_global_lockout;
+use crate::config::Settings;
+use crate::models::{GsiWebhookEvent, Item};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Consumables kept for manual use even when listed in `allowed_items`.
+pub const NEVER_CONSUMED: &[&str] = &["item_cheese"];
+
+/// GSI lags the double-tap by a frame or two; do not press again while the
+/// item is still reported in the slot.
+const CONSUME_LOCKOUT_MS: u64 = 1500;
+
+/// Allow-listed consumable to use on this event, with its slot key.
+///
+/// Aghanim's Shard is used as soon as it is in a main slot. Moon Shard gives
+/// more attack speed held than consumed, so it is only used once every other
+/// main slot is taken and it is costing an item slot.
+fn consumable_to_use(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    in_danger: bool,
+) -> Option<(Item, char)> {
+    let config = &settings.consumables;
+    if !config.auto_consume || !event.hero.is_alive() {
+        return None;
+    }
+    // Mid-fight the slot keys belong to survivability; consume afterwards.
+    if in_danger {
+        return None;
+    }
+
+    let slots = event.items.all_slots();
+    let main_slots = &slots[..6];
+    for name in &config.allowed_items {
+        if NEVER_CONSUMED.contains(&name.as_str()) {
+            continue;
+        }
+        let Some(item) = Item::from_game_name(name) else {
+            debug!(
+                "Unknown consumable {:?} in [consumables] allowed_items",
+                name
+            );
+            continue;
+        };
+        let ready = main_slots
+            .iter()
+            .any(|(_, slot)| slot.name == item.to_game_name() && slot.can_cast != Some(false));
+        if !ready {
+            continue;
+        }
+        if item == Item::MoonShard && main_slots.iter().any(|(_, slot)| slot.name == "empty") {
+            continue;
+        }
+        if let Some(key) = find_item_slot(event, settings, item) {
+            return Some((item, key));
+        }
+    }
+
+    None
+}
+
+/// Consume allow-listed upgrade items (called every GSI event)
+pub fn check_and_consume(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    executor: &Arc<ActionExecutor>,
+) {
+    let in_danger = crate::actions::danger_detector::is_in_danger();
+    let Some((item, key)) = consumable_to_use(event, settings, in_danger) else {
+        return;
+    };
+
+    let item_name = item.to_game_name();
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    if !try_acquire_global_lockout(
+        &format!("consume:{}", item_name),
+        now_ms,
+        CONSUME_LOCKOUT_MS,
+    ) {
+        return;
+    }
+
+    push_action(
+        ActionEvent::Consumable {
+            item: item_name.to_string(),
+        },
+        format!("{} in inventory", item_name.trim_start_matches("item_")),
+        crate::input::simulation::practice_mode_enabled(),
+    );
+    executor.enqueue("consume-item", move || {
+        info!("💎 Consuming {}", item_name);
+        // Double-tap for self-cast
+        crate::input::simulation::press_key(key);
+        thread::sleep(Duration::from_millis(30));
+        crate::input::simulation::press_key(key);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+
+    fn event_with(items: &[&str]) -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        event.hero.alive = true;
+        let slots = [
+            &mut event.items.slot0,
+            &mut event.items.slot1,
+            &mut event.items.slot2,
+            &mut event.items.slot3,
+            &mut event.items.slot4,
+            &mut event.items.slot5,
+        ];
+        for (index, slot) in slots.into_iter().enumerate() {
+            slot.name = items.get(index).unwrap_or(&"empty").to_string();
+            slot.can_cast = Some(true);
+        }
+        event
+    }
+
+    #[test]
+    fn shard_is_consumed_but_cheese_never_is() {
+        let mut settings = Settings::default();
+        settings.consumables.allowed_items =
+            vec!["item_cheese".to_string(), "item_aghanims_shard".to_string()];
+
+        let event = event_with(&["item_cheese", "item_aghanims_shard"]);
+        // Opt-in: nothing is consumed until auto_consume is turned on.
+        assert_eq!(consumable_to_use(&event, &settings, false), None);
+
+        settings.consumables.auto_consume = true;
+        assert_eq!(
+            consumable_to_use(&event, &settings, false),
+            Some((Item::AghanimsShard, settings.keybindings.slot1))
+        );
+        assert_eq!(consumable_to_use(&event, &settings, true), None);
+
+        let event = event_with(&["item_cheese"]);
+        assert_eq!(consumable_to_use(&event, &settings, false), None);
+
+        settings.consumables.auto_consume = false;
+        let event = event_with(&["item_aghanims_shard"]);
+        assert_eq!(consumable_to_use(&event, &settings, false), None);
+    }
+
+    #[test]
+    fn moon_shard_waits_for_a_full_inventory() {
+        let mut settings = Settings::default();
+        settings.consumables.auto_consume = true;
+        let mut items = vec!["item_moon_shard", "item_butterfly", "item_satanic"];
+
+        assert_eq!(
+            consumable_to_use(&event_with(&items), &settings, false),
+            None
+        );
+
+        items.extend(["item_bfury", "item_black_king_bar", "item_assault"]);
+        assert_eq!(
+            consumable_to_use(&event_with(&items), &settings, false),
+            Some((Item::MoonShard, settings.keybindings.slot0))
+        );
+    }
+}
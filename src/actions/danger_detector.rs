@@ -1,9 +1,12 @@
 use crate::actions::activity::{push_activity, ActivityCategory};
 use crate::config::DangerDetectionConfig;
 use crate::models::GsiWebhookEvent;
+use crate::state::automation_metrics::record_danger_event;
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
+use std::collections::VecDeque;
 use std::sync::Mutex;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::info;
 
 lazy_static! {
@@ -14,9 +17,51 @@ lazy_static! {
 struct HpTracker {
     last_hp: Option<u32>,
     last_hp_percent: Option<u32>,
+    last_max_hp: Option<u32>,
     last_update: Option<Instant>,
     danger_detected: bool,
     danger_start_time: Option<Instant>,
+    /// Defensive items fired since this danger episode started.
+    defensive_items_used: u32,
+    /// `(when, hp)` of each HP drop between events, oldest first.
+    recent_losses: VecDeque<(Instant, u32)>,
+}
+
+/// Losses older than this are dropped from [`HpTracker::recent_losses`];
+/// longer than any sensible `clear_delay_seconds`.
+const RECENT_LOSS_RETENTION: Duration = Duration::from_secs(30);
+
+/// HP that leaves with a shrinking max HP pool (Morphling's Attribute Shift
+/// to Agility, selling a Heart): health percent is kept, so current HP drops
+/// by the same share of the lost max HP. It is not damage.
+fn pool_shrink_loss(last_hp: u32, last_max_hp: u32, max_hp: u32) -> u32 {
+    if max_hp >= last_max_hp || last_max_hp == 0 {
+        return 0;
+    }
+    ((last_max_hp - max_hp) as u64 * last_hp as u64 / last_max_hp as u64) as u32
+}
+
+/// Total HP lost at or after `now - window`.
+fn loss_within(losses: &VecDeque<(Instant, u32)>, now: Instant, window: Duration) -> u32 {
+    losses
+        .iter()
+        .filter(|(at, _)| now.saturating_duration_since(*at) <= window)
+        .map(|(_, hp)| *hp)
+        .sum()
+}
+
+/// Whether losing `hp_delta` HP over `time_delta_ms`, ending at
+/// `hp_percent`, counts as danger.
+fn is_dangerous_loss(
+    hp_delta: i32,
+    time_delta_ms: u128,
+    hp_percent: u32,
+    config: &DangerDetectionConfig,
+) -> bool {
+    let is_rapid_loss =
+        hp_delta > config.rapid_loss_hp as i32 && time_delta_ms < config.time_window_ms as u128;
+    let is_low_hp = hp_percent < config.hp_threshold_percent && hp_delta > 0;
+    is_rapid_loss || is_low_hp
 }
 
 /// Update danger detection state based on current GSI event
@@ -44,6 +89,7 @@ pub fn update(event: &GsiWebhookEvent, config: &DangerDetectionConfig) -> bool {
         if tracker.last_hp.is_none() {
             tracker.last_hp = Some(current_hp);
             tracker.last_hp_percent = Some(current_hp_percent);
+            tracker.last_max_hp = Some(max_hp);
             tracker.last_update = Some(now);
             return false;
         }
@@ -51,21 +97,31 @@ pub fn update(event: &GsiWebhookEvent, config: &DangerDetectionConfig) -> bool {
         let last_hp = tracker.last_hp.unwrap();
         let time_delta_ms = tracker.last_update.unwrap().elapsed().as_millis();
 
-        // Calculate HP change (positive = HP loss)
-        let hp_delta = last_hp as i32 - current_hp as i32;
+        // Calculate HP change (positive = HP loss), minus what a shrinking
+        // HP pool took with it
+        let pool_loss = pool_shrink_loss(last_hp, tracker.last_max_hp.unwrap_or(max_hp), max_hp);
+        let hp_delta = last_hp as i32 - current_hp as i32 - pool_loss as i32;
 
-        // Detection logic
-        let is_rapid_loss = hp_delta > config.rapid_loss_hp as i32
-            && time_delta_ms < config.time_window_ms as u128;
-        let is_low_hp = current_hp_percent < config.hp_threshold_percent && hp_delta > 0;
+        if hp_delta > 0 {
+            tracker.recent_losses.push_back((now, hp_delta as u32));
+        }
+        while tracker
+            .recent_losses
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > RECENT_LOSS_RETENTION)
+        {
+            tracker.recent_losses.pop_front();
+        }
 
-        let in_danger = is_rapid_loss || is_low_hp;
+        let in_danger = is_dangerous_loss(hp_delta, time_delta_ms, current_hp_percent, config);
 
         // State transitions
         if in_danger && !tracker.danger_detected {
             // Danger detected
             tracker.danger_detected = true;
             tracker.danger_start_time = Some(now);
+            tracker.defensive_items_used = 0;
+            record_danger_event();
             info!(
                 "⚠️ DANGER DETECTED! HP: {}/{} ({}%), lost {}HP in {}ms",
                 current_hp, max_hp, current_hp_percent, hp_delta, time_delta_ms
@@ -80,6 +136,7 @@ pub fn update(event: &GsiWebhookEvent, config: &DangerDetectionConfig) -> bool {
                 if danger_start.elapsed().as_secs() >= config.clear_delay_seconds {
                     tracker.danger_detected = false;
                     tracker.danger_start_time = None;
+                    tracker.defensive_items_used = 0;
                     info!("✓ Danger cleared - HP stabilized at {}HP ({}%)", current_hp, current_hp_percent);
                     push_activity(
                         ActivityCategory::Danger,
@@ -92,6 +149,7 @@ pub fn update(event: &GsiWebhookEvent, config: &DangerDetectionConfig) -> bool {
         // Update tracker
         tracker.last_hp = Some(current_hp);
         tracker.last_hp_percent = Some(current_hp_percent);
+        tracker.last_max_hp = Some(max_hp);
         tracker.last_update = Some(now);
 
         return tracker.danger_detected;
@@ -107,3 +165,99 @@ pub fn is_in_danger() -> bool {
     }
     false
 }
+
+/// Whether `event` puts the hero in danger, counting a loss [`update`] has
+/// not recorded yet. For checks that run before the hero script updates the
+/// tracker; leaves the tracker untouched.
+pub fn in_danger_now(event: &GsiWebhookEvent, config: &DangerDetectionConfig) -> bool {
+    if !config.enabled || !event.hero.is_alive() {
+        return false;
+    }
+    let tracker = lock_or_recover(&HP_TRACKER);
+    if tracker.danger_detected {
+        return true;
+    }
+    let (Some(last_hp), Some(last_update)) = (tracker.last_hp, tracker.last_update) else {
+        return false;
+    };
+    let max_hp = event.hero.max_health;
+    let pool_loss = pool_shrink_loss(last_hp, tracker.last_max_hp.unwrap_or(max_hp), max_hp);
+    let hp_delta = last_hp as i32 - event.hero.health as i32 - pool_loss as i32;
+    is_dangerous_loss(
+        hp_delta,
+        last_update.elapsed().as_millis(),
+        event.hero.health_percent,
+        config,
+    )
+}
+
+/// HP actually lost over the last `window`, regardless of the danger state.
+/// Blocks rather than reporting 0 while [`update`] holds the tracker, so a
+/// contended read never understates the damage.
+pub fn recent_hp_loss(window: Duration) -> u32 {
+    let tracker = lock_or_recover(&HP_TRACKER);
+    loss_within(&tracker.recent_losses, Instant::now(), window)
+}
+
+/// Defensive items fired during the current danger episode. Blocks rather
+/// than reporting 0 while the tracker is held, which would lift the
+/// per-episode cap.
+pub fn defensive_items_used() -> u32 {
+    lock_or_recover(&HP_TRACKER).defensive_items_used
+}
+
+/// Count `count` more defensive items against the current danger episode.
+/// The counter resets whenever danger starts or clears.
+pub fn record_defensive_items_used(count: u32) {
+    let mut tracker = lock_or_recover(&HP_TRACKER);
+    tracker.defensive_items_used = tracker.defensive_items_used.saturating_add(count);
+}
+
+/// Forget HP history and any danger episode in progress.
+pub fn reset() {
+    *lock_or_recover(&HP_TRACKER) = HpTracker::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_loss_only_counts_drops_inside_the_window() {
+        let start = Instant::now();
+        let now = start + Duration::from_secs(10);
+        let losses: VecDeque<(Instant, u32)> = [
+            (start + Duration::from_secs(5), 400),
+            (start + Duration::from_secs(8), 60),
+            (start + Duration::from_millis(9_700), 40),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(loss_within(&losses, now, Duration::from_secs(3)), 100);
+        assert_eq!(loss_within(&losses, now, Duration::from_secs(10)), 500);
+        assert_eq!(loss_within(&losses, now, Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn shrinking_max_hp_is_not_counted_as_damage() {
+        // Attribute Shift to Agility at 50% HP: 2000 -> 1900 max takes 50 HP.
+        assert_eq!(pool_shrink_loss(1000, 2000, 1900), 50);
+        assert_eq!(pool_shrink_loss(1000, 2000, 2100), 0);
+        assert_eq!(pool_shrink_loss(1000, 2000, 2000), 0);
+        assert_eq!(pool_shrink_loss(0, 0, 0), 0);
+    }
+
+    #[test]
+    fn dangerous_loss_is_a_burst_or_any_loss_at_low_hp() {
+        let config = DangerDetectionConfig::default();
+        let burst = config.rapid_loss_hp as i32 + 1;
+        let window = config.time_window_ms as u128;
+        let low = config.hp_threshold_percent - 1;
+
+        assert!(is_dangerous_loss(burst, window - 1, 90, &config));
+        assert!(!is_dangerous_loss(burst, window, 90, &config));
+        assert!(is_dangerous_loss(1, window, low, &config));
+        assert!(!is_dangerous_loss(0, 0, low, &config));
+    }
+}
@@ -1,20 +1,38 @@
+use crate::actions::activity::{
+    push_action, push_activity, push_activity_with_details, ActionEvent, ActivityCategory,
+};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::{
-    BroodmotherScript, HeroScript, HuskarScript, LargoScript, LegionCommanderScript,
-    MeepoScript, OutworldDestroyerScript, ShadowFiendScript, TinyScript,
+    BristlebackScript, BroodmotherScript, GenericHeroScript, HeroScript, HuskarScript, LargoScript,
+    LegionCommanderScript, MeepoScript, OutworldDestroyerScript, ShadowFiendScript, TinyScript,
+    ZeusScript,
 };
-use crate::actions::{armlet, common::SurvivabilityActions};
+use crate::actions::combo_control::{ComboOrders, ComboState};
+use crate::actions::common::{defensive_item_enabled, smoke_quiet, SurvivabilityActions};
+use crate::actions::armlet;
+use crate::actions::item_automation::lookup_item_automation;
 use crate::config::Settings;
+use crate::input::combo_recorder;
+use crate::input::simulation::practice_mode_enabled;
+use crate::models::gsi_event::Item;
 use crate::models::GsiWebhookEvent;
+use crate::state::automation_metrics::record_combo_triggered;
+use crate::state::poison::lock_or_recover;
+use crate::state::HeroType;
 use lazy_static::lazy_static;
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::sync::{Arc, Mutex};
-use tracing::{debug, warn};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
 
 lazy_static! {
     static ref DISCOVERED_NEUTRAL_ITEMS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    static ref EQUIPPED_NEUTRAL_ITEM: Mutex<String> = Mutex::new(String::from("empty"));
+    /// Item names in `slot0`-`slot5` as of the previous event; `None` until
+    /// the first event of a session seeds it.
+    static ref INVENTORY_ITEMS: Mutex<Option<Vec<String>>> = Mutex::new(None);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,16 +41,31 @@ enum StandaloneDispatchMode {
     Executor,
 }
 
+/// Combos that sleep between presses go to the executor so the hotkey thread
+/// is never held. Broodmother's spider move sleeps 30 ms twice and used to
+/// run inline.
 fn standalone_dispatch_mode(hero_name: &str) -> StandaloneDispatchMode {
     match hero_name {
         "npc_dota_hero_tiny"
+        | "npc_dota_hero_bristleback"
         | "npc_dota_hero_legion_commander"
         | "npc_dota_hero_obsidian_destroyer"
-        | "npc_dota_hero_meepo" => StandaloneDispatchMode::Executor,
+        | "npc_dota_hero_meepo"
+        | "npc_dota_hero_broodmother"
+        | "npc_dota_hero_zuus" => StandaloneDispatchMode::Executor,
         _ => StandaloneDispatchMode::Inline,
     }
 }
 
+fn combo_states_for(
+    hero_scripts: &HashMap<String, Arc<dyn HeroScript>>,
+) -> HashMap<String, Arc<ComboState>> {
+    hero_scripts
+        .keys()
+        .map(|name| (name.clone(), Arc::new(ComboState::default())))
+        .collect()
+}
+
 fn log_neutral_item_discovery(event: &GsiWebhookEvent, settings: &Settings) {
     // Skip if logging is disabled
     if !settings.neutral_items.log_discoveries {
@@ -47,7 +80,7 @@ fn log_neutral_item_discovery(event: &GsiWebhookEvent, settings: &Settings) {
     }
 
     // Check if we've already logged this item
-    let mut discovered = DISCOVERED_NEUTRAL_ITEMS.lock().unwrap();
+    let mut discovered = lock_or_recover(&DISCOVERED_NEUTRAL_ITEMS);
     if discovered.contains(&neutral_item.name) {
         return;
     }
@@ -86,8 +119,161 @@ fn log_neutral_item_discovery(event: &GsiWebhookEvent, settings: &Settings) {
     }
 }
 
+/// Activity entry for a neutral slot that now holds `current` instead of
+/// `previous`, warning when automation will not use it.
+fn neutral_change_notice(
+    previous: &str,
+    current: &str,
+    settings: &Settings,
+) -> Option<(ActivityCategory, String)> {
+    if previous == current || current == "empty" || !settings.neutral_items.enabled {
+        return None;
+    }
+
+    if !settings.neutral_items.allowed_items.iter().any(|item| item == current) {
+        Some((
+            ActivityCategory::Warning,
+            format!("Neutral item {current} equipped; not in allowed_items, automation won't use it"),
+        ))
+    } else if lookup_item_automation(current).is_none() {
+        Some((
+            ActivityCategory::Warning,
+            format!("Neutral item {current} equipped; allowed but has no automation support"),
+        ))
+    } else {
+        Some((
+            ActivityCategory::System,
+            format!("Neutral item {current} equipped"),
+        ))
+    }
+}
+
+fn track_neutral_item_change(event: &GsiWebhookEvent, settings: &Settings) {
+    let current = &event.items.neutral0.name;
+    let mut equipped = lock_or_recover(&EQUIPPED_NEUTRAL_ITEM);
+    if *equipped == *current {
+        return;
+    }
+    let notice = neutral_change_notice(&equipped, current, settings);
+    *equipped = current.clone();
+    drop(equipped);
+
+    if let Some((category, message)) = notice {
+        if category == ActivityCategory::Warning {
+            warn!("💎 {}", message);
+        } else {
+            info!("💎 {}", message);
+        }
+        push_activity(category, message);
+    }
+}
+
+/// What a newly acquired inventory item switches on, when its automation is enabled.
+fn acquisition_feature(item: &str, settings: &Settings) -> Option<&'static str> {
+    let dd = &settings.danger_detection;
+    if dd.enabled && defensive_item_enabled(dd, item) == Some(true) {
+        return Some(match item {
+            "item_black_king_bar" => "auto-BKB in danger now active",
+            "item_satanic" => "auto-Satanic in danger now active",
+            _ => "auto-use in danger now active",
+        });
+    }
+    match item {
+        "item_manta" if dd.auto_manta_on_silence => Some("silence dispel now active"),
+        "item_lotus_orb" if dd.auto_lotus_on_silence => Some("silence dispel now active"),
+        "item_armlet" if settings.armlet.enabled => Some("armlet toggling now active"),
+        "item_soul_ring" if settings.soul_ring.enabled => Some("Soul Ring automation now active"),
+        "item_blink" | "item_arcane_blink" | "item_overwhelming_blink" | "item_swift_blink" => {
+            Some("Blink combos now available")
+        }
+        _ => None,
+    }
+}
+
+/// `(message, details)` for each item in `current` inventory slots that was in
+/// none of the `previous` ones and enables some automation. Moving an item
+/// between inventory slots is not an acquisition; moving it up from the
+/// backpack is, since only then can automation use it. With no `previous`
+/// inventory yet, the items are what the hero already had and nothing fires.
+fn acquisition_notices(
+    previous: Option<&[String]>,
+    current: &[(&str, &Item)],
+    settings: &Settings,
+) -> Vec<(String, String)> {
+    let Some(previous) = previous else {
+        return Vec::new();
+    };
+    current
+        .iter()
+        .filter(|(_, item)| !previous.contains(&item.name))
+        .filter_map(|(slot, item)| {
+            let feature = acquisition_feature(&item.name, settings)?;
+            let details = match item.purchaser {
+                Some(purchaser) => format!("{slot}, purchased by player {purchaser}"),
+                None => slot.to_string(),
+            };
+            Some((
+                format!(
+                    "{} acquired: {}",
+                    item.name.trim_start_matches("item_"),
+                    feature
+                ),
+                details,
+            ))
+        })
+        .collect()
+}
+
+fn track_item_acquisitions(event: &GsiWebhookEvent, settings: &Settings) {
+    let current: Vec<(&str, &Item)> = event
+        .items
+        .all_slots()
+        .into_iter()
+        .filter(|(slot, _)| *slot != "neutral0")
+        .collect();
+    let mut previous = lock_or_recover(&INVENTORY_ITEMS);
+    let notices = if settings.notifications.item_acquired {
+        acquisition_notices(previous.as_deref(), &current, settings)
+    } else {
+        Vec::new()
+    };
+    *previous = Some(current.iter().map(|(_, item)| item.name.clone()).collect());
+    drop(previous);
+
+    for (message, details) in notices {
+        info!("🎒 {} ({})", message, details);
+        push_activity_with_details(ActivityCategory::System, message, details);
+    }
+}
+
+/// Forget the equipped neutral and inventory seen on the previous event, so
+/// the next event is handled like the first one of a session: the inventory
+/// is seeded again without announcements. Discovered neutrals are a
+/// persistent log and are kept.
+pub fn reset() {
+    *lock_or_recover(&EQUIPPED_NEUTRAL_ITEM) = String::from("empty");
+    *lock_or_recover(&INVENTORY_ITEMS) = None;
+}
+
+/// Whether a trigger at `now` comes less than `cooldown_ms` after the last
+/// combo the hero started. A cooldown of 0 never blocks.
+fn within_retrigger_cooldown(last_start: Option<Instant>, now: Instant, cooldown_ms: u64) -> bool {
+    cooldown_ms > 0
+        && last_start
+            .is_some_and(|last| now.duration_since(last) < Duration::from_millis(cooldown_ms))
+}
+
 pub struct ActionDispatcher {
     pub hero_scripts: HashMap<String, Arc<dyn HeroScript>>,
+    /// Per-hero combo state; a trigger that arrives while the hero's
+    /// previous combo is still running is ignored or, with
+    /// `keybindings.combo_toggle_abort`, aborts that combo.
+    combo_states: HashMap<String, Arc<ComboState>>,
+    /// When each hero's last standalone combo started, for
+    /// `combo_retrigger_cooldown_ms`.
+    last_combo_starts: Mutex<HashMap<String, Instant>>,
+    /// Runs for heroes that have no entry in `hero_scripts`.
+    default_script: GenericHeroScript,
     executor: Arc<ActionExecutor>,
     survivability: SurvivabilityActions,
 }
@@ -134,31 +320,65 @@ impl ActionDispatcher {
         let meepo = Arc::new(MeepoScript::new(settings.clone(), executor.clone()));
         hero_scripts.insert(meepo.hero_name().to_string(), meepo);
 
+        let zeus = Arc::new(ZeusScript::new(settings.clone(), executor.clone()));
+        hero_scripts.insert(zeus.hero_name().to_string(), zeus);
+
+        let bristleback = Arc::new(BristlebackScript::new(settings.clone(), executor.clone()));
+        hero_scripts.insert(bristleback.hero_name().to_string(), bristleback);
+
         Self {
+            combo_states: combo_states_for(&hero_scripts),
+            last_combo_starts: Mutex::default(),
             hero_scripts,
+            default_script: GenericHeroScript::new(settings.clone(), executor.clone()),
             executor: executor.clone(),
             survivability: SurvivabilityActions::new(settings, executor),
         }
     }
 
+    /// Forget the retrigger cooldowns and every hero script's own state.
+    /// Module statics are cleared by `reset_all_state()`; this covers what
+    /// lives on the dispatcher instance.
+    pub fn reset(&self) {
+        lock_or_recover(&self.last_combo_starts).clear();
+        for script in self.hero_scripts.values() {
+            script.reset();
+        }
+    }
+
     pub fn dispatch_gsi_event(&self, event: &GsiWebhookEvent) {
         // Shared keyboard/runtime caches are refreshed upstream in process_gsi_events().
         // Dispatcher only runs dispatch-local hooks and routes automation work.
-        let settings = self.survivability.settings.lock().unwrap();
+        crate::actions::combo_control::update_hero_disables(&event.hero);
+        crate::actions::position_tracker::update(event);
+        crate::actions::morph_tracker::update(event);
+
+        let settings = lock_or_recover(&self.survivability.settings);
 
         // Armlet is the most time-sensitive survivability action, so evaluate it first.
-        armlet::maybe_toggle(event, &settings);
+        // Toggling while smoked can give the gank away, so it waits for danger.
+        let smoke_quiet = smoke_quiet(event, &settings);
+        if !smoke_quiet {
+            armlet::maybe_toggle(event, &settings);
+        }
 
-        // Log neutral item discovery
+        // Log neutral item discovery and report neutral swaps
         log_neutral_item_discovery(event, &settings);
+        track_neutral_item_change(event, &settings);
+        track_item_acquisitions(event, &settings);
 
         // Check for silence dispel with Manta Style
         crate::actions::dispel::check_and_dispel_silence(event, &settings, &self.executor);
 
+        // Use Aghanim's Shard / Moon Shard from the auto-consume list
+        crate::actions::consumables::check_and_consume(event, &settings, &self.executor);
+
         drop(settings); // Release lock before further processing
 
         // Shared low-mana automation is global, unlike hero-specific survivability calls.
-        self.survivability.check_and_use_mana_items(event);
+        if !smoke_quiet {
+            self.survivability.check_and_use_mana_items(event);
+        }
 
         // Check if hero has a custom handler
         if let Some(hero_script) = self.hero_scripts.get(&event.hero.name) {
@@ -166,26 +386,104 @@ impl ActionDispatcher {
             debug!("Dispatching GSI event to {}", event.hero.name);
             hero_script.handle_gsi_event(event);
         } else {
-            // No custom handler, use default strategy (survivability + armlet)
+            // No custom handler, fall back to the generic survivability script
             debug!(
-                "No custom handler for {}, using default strategy",
+                "No custom handler for {}, using generic script",
                 event.hero.name
             );
-            self.survivability.execute_default_strategy(event);
+            self.default_script.handle_gsi_event(event);
         }
     }
 
+    /// Whether `hero_name`'s last combo started less than its
+    /// `combo_retrigger_cooldown_ms` ago.
+    fn retrigger_cooling_down(&self, hero_name: &str, now: Instant) -> bool {
+        let Some(hero) = HeroType::from_hero_name(hero_name) else {
+            return false;
+        };
+        let cooldown_ms = lock_or_recover(&self.survivability.settings)
+            .get_standalone_combo(hero.config_key())
+            .map_or(0, |combo| combo.combo_retrigger_cooldown_ms);
+        let last_start = lock_or_recover(&self.last_combo_starts)
+            .get(hero_name)
+            .copied();
+        within_retrigger_cooldown(last_start, now, cooldown_ms)
+    }
+
     pub fn dispatch_standalone_trigger(&self, hero_name: &str) {
         if let Some(hero_script) = self.hero_scripts.get(hero_name) {
+            // A bounced key press must not start or abort a second combo.
+            let now = Instant::now();
+            if self.retrigger_cooling_down(hero_name, now) {
+                debug!(
+                    "{} combo retriggered within its cooldown, ignoring",
+                    hero_name
+                );
+                return;
+            }
+
+            // Mashing the trigger key must not queue overlapping combos.
+            let state = self.combo_states.get(hero_name).cloned().unwrap_or_default();
+            let Some(run) = state.try_begin() else {
+                let toggle_abort = lock_or_recover(&self.survivability.settings)
+                    .keybindings
+                    .combo_toggle_abort;
+                if toggle_abort && state.request_abort() {
+                    info!("Aborting {} combo on second trigger press", hero_name);
+                } else {
+                    debug!("{} combo already in progress, ignoring trigger", hero_name);
+                }
+                return;
+            };
+
+            lock_or_recover(&self.last_combo_starts).insert(hero_name.to_string(), now);
             debug!("Dispatching standalone trigger to {}", hero_name);
+            record_combo_triggered(hero_name);
+            push_action(
+                ActionEvent::Combo {
+                    hero: hero_name.to_string(),
+                },
+                "standalone trigger pressed",
+                practice_mode_enabled(),
+            );
+            // A recorded combo replaces the hero's built-in one
+            let (recorded, orders) = {
+                let settings = lock_or_recover(&self.survivability.settings);
+                let hero_key = HeroType::from_hero_name(hero_name)
+                    .map(|hero| hero.config_key())
+                    .unwrap_or_default();
+                (
+                    combo_recorder::recording_for(&settings, hero_name),
+                    ComboOrders::for_hero(&settings, hero_key),
+                )
+            };
+            if let Some(steps) = recorded {
+                let hero_name = hero_name.to_string();
+                self.executor.enqueue("recorded-combo", move || {
+                    debug!(
+                        "Replaying {}-step recorded combo for {}",
+                        steps.len(),
+                        hero_name
+                    );
+                    run.run(|| {
+                        orders.wrap(|| {
+                            combo_recorder::replay(&steps);
+                        })
+                    });
+                });
+                return;
+            }
+
             match standalone_dispatch_mode(hero_name) {
-                StandaloneDispatchMode::Inline => hero_script.handle_standalone_trigger(),
+                StandaloneDispatchMode::Inline => run.run(|| hero_script.handle_standalone_trigger()),
                 StandaloneDispatchMode::Executor => {
+                    // Executor combos run to completion inside the trigger, so
+                    // the attack-move and hold key land after the last step.
                     let hero_name = hero_name.to_string();
                     let hero_script = Arc::clone(hero_script);
                     self.executor.enqueue("standalone-trigger", move || {
                         debug!("Executing standalone trigger on executor for {}", hero_name);
-                        hero_script.handle_standalone_trigger();
+                        run.run(|| orders.wrap(|| hero_script.handle_standalone_trigger()));
                     });
                 }
             }
@@ -195,13 +493,18 @@ impl ActionDispatcher {
 
 #[cfg(test)]
 mod tests {
-    use super::{standalone_dispatch_mode, ActionDispatcher, StandaloneDispatchMode};
+    use super::{
+        acquisition_notices, neutral_change_notice, standalone_dispatch_mode,
+        within_retrigger_cooldown, ActionDispatcher, StandaloneDispatchMode,
+    };
+    use crate::actions::activity::ActivityCategory;
+    use crate::actions::combo_control::combo_aborted;
     use crate::actions::common::{
         low_mana_check_call_count_for_tests, reset_low_mana_check_call_count_for_tests,
         SurvivabilityActions,
     };
     use crate::actions::executor::ActionExecutor;
-    use crate::actions::heroes::HeroScript;
+    use crate::actions::heroes::{GenericHeroScript, HeroScript};
     use crate::config::Settings;
     use crate::models::gsi_event::{Abilities, Ability, GsiWebhookEvent, Hero, Item, Items, Map};
     use std::any::Any;
@@ -236,6 +539,34 @@ mod tests {
         }
     }
 
+    /// Spins until the dispatcher aborts it, then reports whether it saw the abort.
+    struct AbortableStandaloneScript {
+        hero_name: &'static str,
+        started_tx: mpsc::Sender<()>,
+        finished_tx: mpsc::Sender<bool>,
+    }
+
+    impl HeroScript for AbortableStandaloneScript {
+        fn handle_gsi_event(&self, _event: &GsiWebhookEvent) {}
+
+        fn handle_standalone_trigger(&self) {
+            let _ = self.started_tx.send(());
+            let deadline = std::time::Instant::now() + Duration::from_secs(1);
+            while !combo_aborted() && std::time::Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(5));
+            }
+            let _ = self.finished_tx.send(combo_aborted());
+        }
+
+        fn hero_name(&self) -> &'static str {
+            self.hero_name
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
     struct NoopHeroScript {
         hero_name: &'static str,
     }
@@ -261,7 +592,10 @@ mod tests {
         hero_scripts.insert(script.hero_name().to_string(), script);
 
         ActionDispatcher {
+            combo_states: super::combo_states_for(&hero_scripts),
+            last_combo_starts: Mutex::default(),
             hero_scripts,
+            default_script: GenericHeroScript::new(settings.clone(), executor.clone()),
             executor: executor.clone(),
             survivability: SurvivabilityActions::new(settings, executor),
         }
@@ -285,6 +619,14 @@ mod tests {
             standalone_dispatch_mode("npc_dota_hero_meepo"),
             StandaloneDispatchMode::Executor
         );
+        assert_eq!(
+            standalone_dispatch_mode("npc_dota_hero_zuus"),
+            StandaloneDispatchMode::Executor
+        );
+        assert_eq!(
+            standalone_dispatch_mode("npc_dota_hero_bristleback"),
+            StandaloneDispatchMode::Executor
+        );
     }
 
     #[test]
@@ -336,6 +678,160 @@ mod tests {
             .expect("blocking script should finish after release");
     }
 
+    #[test]
+    fn broodmother_spider_move_leaves_the_trigger_thread() {
+        let (started_tx, started_rx) = mpsc::channel::<&'static str>();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (finished_tx, finished_rx) = mpsc::channel::<&'static str>();
+
+        let dispatcher = dispatcher_with_script(Arc::new(BlockingStandaloneScript {
+            hero_name: "npc_dota_hero_broodmother",
+            started_tx,
+            release_rx: Mutex::new(release_rx),
+            finished_tx,
+        }));
+
+        // Returns while the spider move is still blocked on the executor.
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_broodmother");
+        started_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("spider move should start");
+        assert!(finished_rx.try_recv().is_err());
+
+        let _ = release_tx.send(());
+        finished_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("spider move should finish after release");
+    }
+
+    #[test]
+    fn rapid_double_trigger_runs_one_combo_until_it_finishes() {
+        let (started_tx, started_rx) = mpsc::channel::<&'static str>();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (finished_tx, finished_rx) = mpsc::channel::<&'static str>();
+
+        let dispatcher = dispatcher_with_script(Arc::new(BlockingStandaloneScript {
+            hero_name: "npc_dota_hero_tiny",
+            started_tx,
+            release_rx: Mutex::new(release_rx),
+            finished_tx,
+        }));
+
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_tiny");
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_tiny");
+        started_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("first combo should start");
+
+        let _ = release_tx.send(());
+        finished_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("first combo should finish after release");
+        assert!(
+            started_rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "second trigger during the combo should be ignored"
+        );
+
+        // Once the combo is done, the next trigger runs again.
+        let _ = release_tx.send(());
+        let deadline = std::time::Instant::now() + Duration::from_secs(1);
+        loop {
+            dispatcher.dispatch_standalone_trigger("npc_dota_hero_tiny");
+            if started_rx.recv_timeout(Duration::from_millis(50)).is_ok() {
+                break;
+            }
+            assert!(
+                std::time::Instant::now() < deadline,
+                "trigger after the combo finished should run"
+            );
+        }
+    }
+
+    #[test]
+    fn retrigger_cooldown_blocks_only_inside_its_window() {
+        let start = std::time::Instant::now();
+        let later = |ms| start + Duration::from_millis(ms);
+
+        assert!(!within_retrigger_cooldown(None, later(10), 300));
+        assert!(within_retrigger_cooldown(Some(start), later(299), 300));
+        assert!(!within_retrigger_cooldown(Some(start), later(300), 300));
+        assert!(!within_retrigger_cooldown(Some(start), later(1), 0));
+    }
+
+    #[test]
+    fn trigger_after_a_finished_combo_waits_for_the_retrigger_cooldown() {
+        let (started_tx, started_rx) = mpsc::channel::<&'static str>();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (finished_tx, finished_rx) = mpsc::channel::<&'static str>();
+
+        let dispatcher = dispatcher_with_script(Arc::new(BlockingStandaloneScript {
+            hero_name: "npc_dota_hero_tiny",
+            started_tx,
+            release_rx: Mutex::new(release_rx),
+            finished_tx,
+        }));
+        dispatcher
+            .survivability
+            .settings
+            .lock()
+            .unwrap()
+            .heroes
+            .tiny
+            .standalone_combo
+            .combo_retrigger_cooldown_ms = 60_000;
+
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_tiny");
+        started_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("first combo should start");
+        let _ = release_tx.send(());
+        finished_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("first combo should finish after release");
+
+        let _ = release_tx.send(());
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_tiny");
+        assert!(
+            started_rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "trigger within the cooldown should be ignored"
+        );
+    }
+
+    #[test]
+    fn second_trigger_aborts_running_combo_when_toggle_abort_enabled() {
+        let (started_tx, started_rx) = mpsc::channel::<()>();
+        let (finished_tx, finished_rx) = mpsc::channel::<bool>();
+
+        let dispatcher = dispatcher_with_script(Arc::new(AbortableStandaloneScript {
+            hero_name: "npc_dota_hero_legion_commander",
+            started_tx,
+            finished_tx,
+        }));
+        dispatcher
+            .survivability
+            .settings
+            .lock()
+            .unwrap()
+            .keybindings
+            .combo_toggle_abort = true;
+
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_legion_commander");
+        started_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("combo should start");
+        dispatcher.dispatch_standalone_trigger("npc_dota_hero_legion_commander");
+
+        assert_eq!(
+            finished_rx.recv_timeout(Duration::from_millis(500)),
+            Ok(true),
+            "second press should abort the running combo"
+        );
+        assert!(
+            started_rx.recv_timeout(Duration::from_millis(50)).is_err(),
+            "the aborting press must not start a new combo"
+        );
+    }
+
     #[test]
     fn inline_standalone_dispatch_keeps_existing_behavior_for_other_heroes() {
         let (started_tx, started_rx) = mpsc::channel::<&'static str>();
@@ -393,7 +889,10 @@ mod tests {
         );
 
         let dispatcher = ActionDispatcher {
+            combo_states: super::combo_states_for(&hero_scripts),
+            last_combo_starts: Mutex::default(),
             hero_scripts,
+            default_script: GenericHeroScript::new(settings.clone(), executor.clone()),
             executor: executor.clone(),
             survivability: SurvivabilityActions::new(settings, executor),
         };
@@ -447,6 +946,7 @@ mod tests {
                 xp: 0,
                 xpos: 0,
                 ypos: 0,
+                extra: Default::default(),
             },
             abilities: Abilities {
                 ability0: empty_ability.clone(),
@@ -475,7 +975,11 @@ mod tests {
                 stash5: Item::default(),
                 teleport0: Item::default(),
             },
-            map: Map { clock_time: 0 },
+            map: Map {
+                clock_time: 0,
+                game_state: None,
+                matchid: None,
+            },
             player: None,
         };
 
@@ -483,4 +987,62 @@ mod tests {
 
         assert_eq!(low_mana_check_call_count_for_tests(), 1);
     }
+
+    #[test]
+    fn neutral_swaps_warn_when_automation_will_not_use_the_item() {
+        let mut settings = Settings::default();
+        settings.neutral_items.enabled = true;
+        settings.neutral_items.allowed_items =
+            vec!["item_essence_ring".to_string(), "item_unlisted_test".to_string()];
+
+        let category = |previous: &str, current: &str, settings: &Settings| {
+            neutral_change_notice(previous, current, settings).map(|(category, _)| category)
+        };
+        assert_eq!(
+            category("empty", "item_essence_ring", &settings),
+            Some(ActivityCategory::System)
+        );
+        assert_eq!(
+            category("item_essence_ring", "item_pogo_stick", &settings),
+            Some(ActivityCategory::Warning)
+        );
+        assert_eq!(
+            category("empty", "item_unlisted_test", &settings),
+            Some(ActivityCategory::Warning)
+        );
+        assert_eq!(category("item_essence_ring", "item_essence_ring", &settings), None);
+        assert_eq!(category("item_essence_ring", "empty", &settings), None);
+
+        settings.neutral_items.enabled = false;
+        assert_eq!(category("empty", "item_pogo_stick", &settings), None);
+    }
+
+    #[test]
+    fn acquisition_notices_fire_once_for_items_with_enabled_automation() {
+        let mut settings = Settings::default();
+        settings.danger_detection.auto_bkb = true;
+        let item = |name: &str, purchaser: Option<u32>| Item {
+            name: name.to_string(),
+            purchaser,
+            ..Default::default()
+        };
+        let bkb = item("item_black_king_bar", Some(3));
+        let wand = item("item_magic_wand", None);
+        let current = [("slot0", &wand), ("slot2", &bkb)];
+
+        assert_eq!(
+            acquisition_notices(Some(&["item_magic_wand".to_string()]), &current, &settings),
+            vec![(
+                "black_king_bar acquired: auto-BKB in danger now active".to_string(),
+                "slot2, purchased by player 3".to_string(),
+            )]
+        );
+        let previous = vec!["item_black_king_bar".to_string()];
+        assert!(acquisition_notices(Some(&previous), &current, &settings).is_empty());
+        // The first event seeds the inventory without announcing it.
+        assert!(acquisition_notices(None, &current, &settings).is_empty());
+
+        settings.danger_detection.auto_bkb = false;
+        assert!(acquisition_notices(Some(&[]), &current, &settings).is_empty());
+    }
 }
@@ -87,3 +87,8 @@ pub fn check_and_dispel_silence(
         }
     }
 }
+
+/// Re-allow a dispel for the current silence.
+pub fn reset() {
+    DISPEL_TRIGGERED.store(false, Ordering::SeqCst);
+}
@@ -1,3 +1,4 @@
+use crate::diagnostics::panic_report;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
@@ -212,18 +213,15 @@ fn run_ready_worker(rx: Receiver<ActionMessage>, metrics: Arc<ExecutorMetrics>)
     while let Ok(message) = rx.recv() {
         match message {
             ActionMessage::Run { label, job } => {
+                if crate::state::shutdown::is_shutting_down() {
+                    debug!("Shutting down; dropping action job: {}", label);
+                    continue;
+                }
                 debug!("Running action job: {}", label);
                 if let Err(panic_payload) =
-                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(job))
+                    panic_report::catch_panics(std::panic::AssertUnwindSafe(job))
                 {
-                    let panic_message = if let Some(message) = panic_payload.downcast_ref::<&str>()
-                    {
-                        *message
-                    } else if let Some(message) = panic_payload.downcast_ref::<String>() {
-                        message.as_str()
-                    } else {
-                        "unknown panic payload"
-                    };
+                    let panic_message = panic_report::payload_message(panic_payload.as_ref());
 
                     warn!(
                         "Action job {} panicked; executor will continue running: {}",
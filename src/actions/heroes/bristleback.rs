@@ -0,0 +1,253 @@
+//! Bristleback: keep Quill Spray going so Warpath stays stacked.
+//!
+//! Every GSI event casts Quill Spray when GSI reports it ready, mana is
+//! above `quill_min_mana_percent` and the hero is in a fight. GSI does not
+//! report nearby enemies, so "in a fight" means HP lost (`danger_detector`)
+//! or an attack order (`attack_order_age`) within `quill_combat_window_ms`;
+//! farming creeps that hit back counts too. Quilling reveals where the hero
+//! is, so it holds while smoked and, with `[common] hold_offense_while_retreating`,
+//! while `position_tracker` sees the hero running home. Armlet toggling is
+//! the shared dispatcher path, tuned by `[heroes.bristleback.armlet]`.
+//!
+//! The standalone trigger runs a [`PriorityComboEngine`] that casts Viscous
+//! Nasal Goo and Quill Spray while either is ready, for a fight the player
+//! starts by hand.
+
+use crate::actions::auto_items::latest_gsi_event;
+use crate::actions::common::{smoke_quiet, SurvivabilityActions};
+use crate::actions::executor::ActionExecutor;
+use crate::actions::heroes::traits::HeroScript;
+use crate::actions::item_automation::try_acquire_global_lockout;
+use crate::actions::priority_combo::{ComboEntry, PriorityComboEngine};
+use crate::config::{BristlebackConfig, Settings};
+use crate::input::keyboard::attack_order_age;
+use crate::input::simulation::press_key;
+use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::lock_or_recover;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, info};
+
+const QUILL_SPRAY_ABILITY_NAME: &str = "bristleback_quill_spray";
+const VISCOUS_NASAL_GOO_ABILITY_NAME: &str = "bristleback_viscous_nasal_goo";
+
+/// GSI shows the new cooldown a frame late; do not press again before it does.
+const QUILL_SPRAY_LOCKOUT_MS: u64 = 500;
+
+/// Whether the hero took damage or issued an attack order within
+/// `quill_combat_window_ms`. Always true with a window of 0.
+fn in_combat(config: &BristlebackConfig, hp_lost: u32, attack_age: Option<Duration>) -> bool {
+    if config.quill_combat_window_ms == 0 {
+        return true;
+    }
+    let window = Duration::from_millis(config.quill_combat_window_ms);
+    hp_lost > 0 || attack_age.is_some_and(|age| age <= window)
+}
+
+/// Key to press for Quill Spray on this event, if it should be cast.
+/// `quiet` is true while smoked or retreating; `in_combat` from [`in_combat`].
+fn quill_spray_key(
+    event: &GsiWebhookEvent,
+    config: &BristlebackConfig,
+    quiet: bool,
+    in_combat: bool,
+) -> Option<char> {
+    if !config.auto_quill_spray || quiet || !in_combat {
+        return None;
+    }
+    let hero = &event.hero;
+    if !hero.is_alive() || hero.is_stunned() || hero.silenced {
+        return None;
+    }
+    if hero.mana_percent < config.quill_min_mana_percent {
+        return None;
+    }
+    event
+        .abilities
+        .find_by_name(QUILL_SPRAY_ABILITY_NAME)
+        .filter(|ability| ability.level > 0 && ability.can_cast && ability.cooldown == 0)
+        .map(|_| config.quill_spray_key)
+}
+
+/// Spam engine for the standalone trigger: Goo first, so its slow lands
+/// before the quills, then Quill Spray.
+pub fn combo_engine(config: &BristlebackConfig) -> PriorityComboEngine {
+    PriorityComboEngine::new(vec![
+        ComboEntry::ability(VISCOUS_NASAL_GOO_ABILITY_NAME, config.viscous_nasal_goo_key),
+        ComboEntry::ability(QUILL_SPRAY_ABILITY_NAME, config.quill_spray_key),
+    ])
+}
+
+pub struct BristlebackScript {
+    settings: Arc<Mutex<Settings>>,
+    executor: Arc<ActionExecutor>,
+}
+
+impl BristlebackScript {
+    pub fn new(settings: Arc<Mutex<Settings>>, executor: Arc<ActionExecutor>) -> Self {
+        Self { settings, executor }
+    }
+
+    fn maybe_quill(&self, event: &GsiWebhookEvent, settings: &Settings) {
+        let retreating = settings.common.hold_offense_while_retreating
+            && crate::actions::position_tracker::is_retreating();
+        let quiet = retreating || smoke_quiet(event, settings);
+        if retreating && settings.heroes.bristleback.auto_quill_spray {
+            debug!("🏃 Retreating: holding Quill Spray");
+        }
+        let config = &settings.heroes.bristleback;
+        let window = Duration::from_millis(config.quill_combat_window_ms);
+        let fighting = in_combat(
+            config,
+            crate::actions::danger_detector::recent_hp_loss(window),
+            attack_order_age(),
+        );
+        let Some(key) = quill_spray_key(event, config, quiet, fighting) else {
+            return;
+        };
+
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        if !try_acquire_global_lockout("bristleback:quill_spray", now_ms, QUILL_SPRAY_LOCKOUT_MS) {
+            return;
+        }
+
+        self.executor.enqueue("bristleback-quill-spray", move || {
+            info!("🦔 Quill Spray ({})", key);
+            press_key(key);
+        });
+    }
+}
+
+impl HeroScript for BristlebackScript {
+    fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
+        let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
+        let settings = lock_or_recover(&self.settings);
+        let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
+        // Log Break: without its damage reduction Bristleback is far squishier
+        crate::actions::common::log_passives_broken(event);
+        self.maybe_quill(event, &settings);
+        drop(settings);
+        survivability.check_and_use_healing_items_with_danger(event, in_danger);
+        survivability.use_defensive_items_if_danger_with_snapshot(event, in_danger);
+        survivability.use_neutral_item_if_danger_with_snapshot(event, in_danger);
+    }
+
+    fn handle_standalone_trigger(&self) {
+        let settings = lock_or_recover(&self.settings).clone();
+        let steps = combo_engine(&settings.heroes.bristleback).run(&settings, latest_gsi_event);
+        info!("🦔 Bristleback combo finished after {} casts", steps);
+    }
+
+    fn hero_name(&self) -> &'static str {
+        Hero::Bristleback.to_game_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+    use crate::models::gsi_event::Ability;
+
+    fn quill_event(mana_percent: u32, cooldown: u32) -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        event.hero.alive = true;
+        event.hero.stunned = false;
+        event.hero.silenced = false;
+        event.hero.mana_percent = mana_percent;
+        event.abilities.ability1 = Ability {
+            ability_active: true,
+            can_cast: cooldown == 0,
+            cooldown,
+            level: 1,
+            name: QUILL_SPRAY_ABILITY_NAME.to_string(),
+            passive: false,
+            ultimate: false,
+        };
+        event
+    }
+
+    #[test]
+    fn quill_spray_fires_when_ready_above_the_mana_floor() {
+        let config = BristlebackConfig::default();
+
+        assert_eq!(
+            quill_spray_key(&quill_event(80, 0), &config, false, true),
+            Some('w')
+        );
+        assert_eq!(
+            quill_spray_key(&quill_event(80, 2), &config, false, true),
+            None
+        );
+        assert_eq!(
+            quill_spray_key(&quill_event(29, 0), &config, false, true),
+            None
+        );
+
+        let mut silenced = quill_event(80, 0);
+        silenced.hero.silenced = true;
+        assert_eq!(quill_spray_key(&silenced, &config, false, true), None);
+    }
+
+    #[test]
+    fn quill_spray_holds_while_quiet_out_of_combat_or_disabled() {
+        let mut config = BristlebackConfig::default();
+        assert_eq!(
+            quill_spray_key(&quill_event(80, 0), &config, true, true),
+            None
+        );
+        assert_eq!(
+            quill_spray_key(&quill_event(80, 0), &config, false, false),
+            None
+        );
+
+        config.auto_quill_spray = false;
+        assert_eq!(
+            quill_spray_key(&quill_event(80, 0), &config, false, true),
+            None
+        );
+    }
+
+    #[test]
+    fn combat_means_recent_damage_or_attack_order() {
+        let mut config = BristlebackConfig::default();
+        let recent = Some(Duration::from_millis(500));
+        let stale = Some(Duration::from_secs(10));
+
+        assert!(in_combat(&config, 120, None));
+        assert!(in_combat(&config, 0, recent));
+        assert!(!in_combat(&config, 0, stale));
+        assert!(!in_combat(&config, 0, None));
+
+        config.quill_combat_window_ms = 0;
+        assert!(in_combat(&config, 0, None));
+    }
+
+    #[test]
+    fn standalone_combo_casts_goo_then_quill_spray() {
+        let mut event = quill_event(80, 0);
+        event.abilities.ability0 = Ability {
+            name: VISCOUS_NASAL_GOO_ABILITY_NAME.to_string(),
+            ..event.abilities.ability1.clone()
+        };
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        combo_engine(&settings.heroes.bristleback)
+            .with_step_delay(0)
+            .with_recast_guard(60_000)
+            .with_max_steps(3)
+            .run(&settings, || Some((event.clone(), Duration::ZERO)));
+        assert_eq!(recorder.key_clicks(), vec!['q', 'w']);
+    }
+}
@@ -11,6 +11,7 @@ use crate::actions::heroes::traits::HeroScript;
 use crate::config::Settings;
 use crate::input::keyboard::{parse_key_string, simulate_key};
 use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use rdev::Key;
 use std::sync::atomic::AtomicBool;
@@ -25,6 +26,11 @@ lazy_static! {
     pub static ref BROODMOTHER_ACTIVE: AtomicBool = AtomicBool::new(false);
 }
 
+/// Forget the current hero until the next GSI event says it is Broodmother.
+pub fn reset() {
+    BROODMOTHER_ACTIVE.store(false, Ordering::SeqCst);
+}
+
 pub struct BroodmotherScript {
     settings: Arc<Mutex<Settings>>,
     executor: Arc<ActionExecutor>,
@@ -57,7 +63,7 @@ impl BroodmotherScript {
         }
 
         // Right click at current mouse position
-        crate::input::simulation::mouse_click();
+        crate::input::simulation::right_click();
         thread::sleep(Duration::from_millis(30));
 
         // Reselect hero
@@ -78,7 +84,7 @@ impl BroodmotherScript {
             thread::sleep(Duration::from_millis(30));
         }
 
-        crate::input::simulation::mouse_click();
+        crate::input::simulation::right_click();
         thread::sleep(Duration::from_millis(30));
 
         if let Some(key) = hero_key {
@@ -95,7 +101,7 @@ impl HeroScript for BroodmotherScript {
         // This handler is only called when playing Broodmother
 
         // Use common survivability actions (danger detection, healing, defensive items)
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         drop(settings);
@@ -105,7 +111,7 @@ impl HeroScript for BroodmotherScript {
     }
 
     fn handle_standalone_trigger(&self) {
-        let settings = self.settings.lock().unwrap().clone();
+        let settings = lock_or_recover(&self.settings).clone();
         Self::execute_spider_attack_move(&settings);
     }
 
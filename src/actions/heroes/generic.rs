@@ -0,0 +1,59 @@
+use crate::actions::common::SurvivabilityActions;
+use crate::actions::executor::ActionExecutor;
+use crate::actions::heroes::traits::HeroScript;
+use crate::config::Settings;
+use crate::models::GsiWebhookEvent;
+use crate::state::poison::lock_or_recover;
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+/// Routing name for the fallback script; never matches a Dota hero.
+pub const GENERIC_HERO_NAME: &str = "generic";
+
+/// Default script for heroes without a registered `HeroScript`.
+///
+/// Armlet is already handled by the dispatcher pre-hook, which reads `[armlet]`
+/// and any `[armlet.heroes.<name>]` override, so this script only runs the
+/// shared survivability pipeline the registered scripts use, unless
+/// `[common] generic_hero_survivability` is off.
+pub struct GenericHeroScript {
+    settings: Arc<Mutex<Settings>>,
+    executor: Arc<ActionExecutor>,
+}
+
+impl GenericHeroScript {
+    pub fn new(settings: Arc<Mutex<Settings>>, executor: Arc<ActionExecutor>) -> Self {
+        Self { settings, executor }
+    }
+}
+
+impl HeroScript for GenericHeroScript {
+    fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
+        let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
+        let (in_danger, enabled) = {
+            let settings = lock_or_recover(&self.settings);
+            (
+                crate::actions::danger_detector::update(event, &settings.danger_detection),
+                settings.common.generic_hero_survivability,
+            )
+        };
+        if !enabled {
+            return;
+        }
+        survivability.check_and_use_healing_items_with_danger(event, in_danger);
+        survivability.use_defensive_items_if_danger_with_snapshot(event, in_danger);
+        survivability.use_neutral_item_if_danger_with_snapshot(event, in_danger);
+    }
+
+    fn handle_standalone_trigger(&self) {
+        debug!("No standalone combo for heroes without a script");
+    }
+
+    fn hero_name(&self) -> &'static str {
+        GENERIC_HERO_NAME
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
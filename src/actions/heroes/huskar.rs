@@ -3,17 +3,100 @@ use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::traits::HeroScript;
 use crate::config::{settings::HuskarRoshanSpearsConfig, Settings};
 use crate::models::{gsi_event::Ability, GsiWebhookEvent, Hero};
+use crate::state::poison::{lock_or_recover, lock_or_reset};
 use lazy_static::lazy_static;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 lazy_static! {
-    static ref BERSERKER_BLOOD_DEBUFF_DETECTED: Mutex<Option<Instant>> = Mutex::new(None);
+    static ref BERSERKER_BLOOD_DEBUFF_DETECTED: Mutex<BerserkerBloodTracker> =
+        Mutex::new(BerserkerBloodTracker::default());
     static ref ROSHAN_SPEARS_STATE: Mutex<HuskarRoshanSpearsState> =
         Mutex::new(HuskarRoshanSpearsState::default());
 }
 
+/// Debuff window state for the Berserker Blood cleanse.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct BerserkerBloodTracker {
+    /// Start of the current delay window; `None` while not debuffed.
+    debuff_since: Option<Instant>,
+    /// A disable was seen at some point in the current window.
+    saw_disable: bool,
+    /// Cleanse already fired for this window (only used without re-arm).
+    cleansed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BerserkerBloodOptions {
+    delay: Duration,
+    rearm: bool,
+    require_disable: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BerserkerBloodAction {
+    Idle,
+    Wait,
+    /// Delay elapsed but the ability is not castable yet; keep the window.
+    NotReady,
+    /// Delay elapsed but no disable was seen and one is required.
+    SkipNoDisable,
+    Cleanse,
+}
+
+/// Advance the cleanse state machine by one GSI frame.
+///
+/// The delay window starts on the first debuffed frame even when Berserker
+/// Blood is on cooldown, so a debuff that lands mid-cooldown is cleansed as
+/// soon as the ability comes back. With `rearm`, a cleanse immediately opens
+/// a new window, so a debuff that survives or re-lands after the dispel is
+/// handled without waiting for `has_debuff` to clear first.
+fn plan_berserker_blood(
+    tracker: &mut BerserkerBloodTracker,
+    now: Instant,
+    has_debuff: bool,
+    disabled: bool,
+    ability_ready: bool,
+    options: BerserkerBloodOptions,
+) -> BerserkerBloodAction {
+    if !has_debuff {
+        *tracker = BerserkerBloodTracker::default();
+        return BerserkerBloodAction::Idle;
+    }
+
+    let Some(debuff_since) = tracker.debuff_since else {
+        *tracker = BerserkerBloodTracker {
+            debuff_since: Some(now),
+            saw_disable: disabled,
+            cleansed: false,
+        };
+        return BerserkerBloodAction::Wait;
+    };
+    tracker.saw_disable |= disabled;
+
+    if tracker.cleansed || now.saturating_duration_since(debuff_since) < options.delay {
+        return BerserkerBloodAction::Wait;
+    }
+    if options.require_disable && !tracker.saw_disable {
+        return BerserkerBloodAction::SkipNoDisable;
+    }
+    if !ability_ready {
+        return BerserkerBloodAction::NotReady;
+    }
+
+    if options.rearm {
+        *tracker = BerserkerBloodTracker {
+            debuff_since: Some(now),
+            saw_disable: false,
+            cleansed: false,
+        };
+    } else {
+        tracker.cleansed = true;
+    }
+    BerserkerBloodAction::Cleanse
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 struct HuskarRoshanSpearsState {
     disabled_by_app: bool,
@@ -142,7 +225,8 @@ fn evaluate_resolved_roshan_spears_gate(
 }
 
 fn clear_roshan_spears_ownership(reason: &str) {
-    if let Ok(mut state) = ROSHAN_SPEARS_STATE.lock() {
+    {
+        let mut state = lock_or_reset(&ROSHAN_SPEARS_STATE);
         if state.disabled_by_app {
             state.disabled_by_app = false;
             info!(
@@ -153,6 +237,12 @@ fn clear_roshan_spears_ownership(reason: &str) {
     }
 }
 
+/// Forget the Berserker Blood debuff window and Roshan spears ownership.
+pub fn reset() {
+    *lock_or_reset(&BERSERKER_BLOOD_DEBUFF_DETECTED) = BerserkerBloodTracker::default();
+    clear_roshan_spears_ownership("state reset");
+}
+
 fn log_roshan_spears_gate_event(
     action: &str,
     reason: &str,
@@ -199,17 +289,10 @@ fn log_roshan_spears_gate_event(
 }
 
 fn find_burning_spear_ability<'a>(event: &'a GsiWebhookEvent) -> Option<&'a Ability> {
-    [
-        &event.abilities.ability0,
-        &event.abilities.ability1,
-        &event.abilities.ability2,
-        &event.abilities.ability3,
-        &event.abilities.ability4,
-        &event.abilities.ability5,
-    ]
-    .iter()
-    .find(|ability| ability.name == "huskar_burning_spear" && ability.level > 0 && !ability.passive)
-    .copied()
+    event
+        .abilities
+        .find_by_name("huskar_burning_spear")
+        .filter(|ability| ability.level > 0 && !ability.passive)
 }
 
 fn emit_burning_spear_toggle(key: char) {
@@ -233,76 +316,68 @@ impl HuskarScript {
             return;
         }
 
-        // Check if hero has debuff
-        if !event.hero.has_debuff {
-            // Reset debuff tracker when no debuff
-            if let Ok(mut debuff_time) = BERSERKER_BLOOD_DEBUFF_DETECTED.try_lock() {
-                if debuff_time.is_some() {
-                    debug!("No debuffs detected, resetting berserker blood tracker");
-                    *debuff_time = None;
-                }
-            }
-            return;
-        }
-
-        // Find berserker blood ability
-        let berserker_ability = [
-            &event.abilities.ability0,
-            &event.abilities.ability1,
-            &event.abilities.ability2,
-            &event.abilities.ability3,
-        ]
-        .iter()
-        .find(|ability| ability.name == "huskar_berserkers_blood")
-        .copied();
-
-        let Some(ability) = berserker_ability else {
+        let Some(ability) = event.abilities.find_by_name("huskar_berserkers_blood") else {
             return;
         };
+        let ability_ready = ability.can_cast && ability.level > 0 && ability.cooldown == 0;
 
-        // Check if ability can be cast (not on cooldown and has levels)
-        if !ability.can_cast || ability.level == 0 || ability.cooldown > 0 {
-            debug!(
-                "Berserker Blood not ready: can_cast={}, level={}, cooldown={}",
-                ability.can_cast, ability.level, ability.cooldown
-            );
-            return;
-        }
+        let hero = &event.hero;
+        let disabled = hero.stunned || hero.silenced || hero.hexed || hero.muted || hero.disarmed;
 
-        let settings = self.settings.lock().unwrap();
-        let delay_ms = settings.heroes.huskar.berserker_blood_delay_ms;
-        let key = settings.heroes.huskar.berserker_blood_key;
+        let settings = lock_or_recover(&self.settings);
+        let huskar = &settings.heroes.huskar;
+        let options = BerserkerBloodOptions {
+            delay: Duration::from_millis(huskar.berserker_blood_delay_ms),
+            rearm: huskar.berserker_blood_rearm,
+            require_disable: huskar.berserker_blood_require_disable,
+        };
+        let key = huskar.berserker_blood_key;
         drop(settings);
 
-        if let Ok(mut debuff_time) = BERSERKER_BLOOD_DEBUFF_DETECTED.try_lock() {
-            match *debuff_time {
-                Some(first_debuff_time) => {
-                    // Debuff already detected, check if delay has passed
-                    if first_debuff_time.elapsed() >= Duration::from_millis(delay_ms) {
-                        info!(
-                            "Activating Berserker Blood to cleanse debuffs ({}ms delay elapsed)",
-                            delay_ms
-                        );
-                        crate::input::press_key(key);
-
-                        // Reset tracker after activation
-                        *debuff_time = None;
-                    } else {
-                        debug!(
-                            "Waiting for more debuffs... ({}ms elapsed)",
-                            first_debuff_time.elapsed().as_millis()
-                        );
-                    }
+        let Ok(mut tracker) = BERSERKER_BLOOD_DEBUFF_DETECTED.try_lock() else {
+            return;
+        };
+        let was_tracking = tracker.debuff_since.is_some();
+        let action = plan_berserker_blood(
+            &mut tracker,
+            Instant::now(),
+            hero.has_debuff,
+            disabled,
+            ability_ready,
+            options,
+        );
+        drop(tracker);
+
+        match action {
+            BerserkerBloodAction::Idle => {
+                if was_tracking {
+                    debug!("No debuffs detected, resetting berserker blood tracker");
                 }
-                None => {
-                    // First debuff detected, start tracking
+            }
+            BerserkerBloodAction::Wait => {
+                if !was_tracking {
                     info!(
                         "Debuff detected, starting {}ms timer for Berserker Blood",
-                        delay_ms
+                        options.delay.as_millis()
                     );
-                    *debuff_time = Some(Instant::now());
                 }
             }
+            BerserkerBloodAction::NotReady => {
+                debug!(
+                    "Berserker Blood not ready: can_cast={}, level={}, cooldown={}",
+                    ability.can_cast, ability.level, ability.cooldown
+                );
+            }
+            BerserkerBloodAction::SkipNoDisable => {
+                debug!("Debuff window has no disable; skipping Berserker Blood cleanse");
+            }
+            BerserkerBloodAction::Cleanse => {
+                info!(
+                    "Activating Berserker Blood to cleanse debuffs ({}ms delay elapsed)",
+                    options.delay.as_millis()
+                );
+                crate::input::press_key(key);
+            }
         }
     }
 
@@ -312,7 +387,7 @@ impl HuskarScript {
             return;
         }
 
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let config = settings.heroes.huskar.roshan_spears.clone();
         let resolved = settings.resolve_armlet_config(&event.hero.name);
         let effective_trigger = resolved
@@ -325,7 +400,7 @@ impl HuskarScript {
             roshan_spears_clear_reason(config.enabled, roshan_mode_armed, burning_spear_present);
         drop(settings);
 
-        let mut state = ROSHAN_SPEARS_STATE.lock().unwrap();
+        let mut state = lock_or_reset(&ROSHAN_SPEARS_STATE);
         let owned_by_app_before = state.disabled_by_app;
         let action = evaluate_roshan_spears_gate(
             event.hero.health,
@@ -446,10 +521,14 @@ impl HuskarScript {
 impl HeroScript for HuskarScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
         // PRIORITY 1: Update danger detection state
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         drop(settings);
 
+        // Break turns Berserker's Blood off; armlet::maybe_toggle already
+        // raised the toggle threshold, this only logs the state change
+        crate::actions::common::log_passives_broken(event);
+
         // PRIORITY 2: Create survivability actions for healing and defensive items
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
 
@@ -487,6 +566,86 @@ mod tests {
     use super::*;
     use crate::config::settings::HuskarRoshanSpearsConfig;
 
+    fn cleanse_options(rearm: bool, require_disable: bool) -> BerserkerBloodOptions {
+        BerserkerBloodOptions {
+            delay: Duration::from_millis(300),
+            rearm,
+            require_disable,
+        }
+    }
+
+    #[test]
+    fn berserker_blood_waits_for_delay_and_survives_cooldown() {
+        let mut tracker = BerserkerBloodTracker::default();
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let options = cleanse_options(false, false);
+
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(0), true, false, false, options),
+            BerserkerBloodAction::Wait
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(400), true, false, false, options),
+            BerserkerBloodAction::NotReady
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(500), true, false, true, options),
+            BerserkerBloodAction::Cleanse
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(2_000), true, false, true, options),
+            BerserkerBloodAction::Wait
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(2_100), false, false, true, options),
+            BerserkerBloodAction::Idle
+        );
+    }
+
+    #[test]
+    fn berserker_blood_rearms_while_still_debuffed() {
+        let mut tracker = BerserkerBloodTracker::default();
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let options = cleanse_options(true, false);
+
+        plan_berserker_blood(&mut tracker, at(0), true, false, true, options);
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(300), true, false, true, options),
+            BerserkerBloodAction::Cleanse
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(500), true, false, true, options),
+            BerserkerBloodAction::Wait
+        );
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(600), true, false, true, options),
+            BerserkerBloodAction::Cleanse
+        );
+    }
+
+    #[test]
+    fn berserker_blood_require_disable_remembers_disable_within_window() {
+        let mut tracker = BerserkerBloodTracker::default();
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let options = cleanse_options(false, true);
+
+        plan_berserker_blood(&mut tracker, at(0), true, false, true, options);
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(300), true, false, true, options),
+            BerserkerBloodAction::SkipNoDisable
+        );
+
+        let mut tracker = BerserkerBloodTracker::default();
+        plan_berserker_blood(&mut tracker, at(0), true, true, false, options);
+        assert_eq!(
+            plan_berserker_blood(&mut tracker, at(300), true, false, true, options),
+            BerserkerBloodAction::Cleanse
+        );
+    }
+
     #[test]
     fn roshan_spears_thresholds_match_configured_buffers() {
         let thresholds = roshan_spears_thresholds(
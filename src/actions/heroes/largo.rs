@@ -3,6 +3,8 @@ use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::traits::HeroScript;
 use crate::config::Settings;
 use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::{lock_or_recover, lock_or_reset, wait_or_reset, wait_timeout_or_reset};
+use crate::state::shutdown;
 use lazy_static::lazy_static;
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
@@ -39,6 +41,11 @@ struct UltimateState {
     pending_song: Option<Song>, // Queued song to switch to on next beat
     groovin_stacks: u8,
     has_aggs: bool,
+    /// Hero is smoked and `common.respect_smoke` holds beat key presses.
+    smoke_quiet: bool,
+    /// Hero is running home and `common.hold_offense_while_retreating` holds
+    /// beat key presses.
+    retreat_quiet: bool,
     beat_config: LargoBeatConfigSnapshot,
 }
 
@@ -48,21 +55,31 @@ struct LargoWorkerControl {
     emission_guard: Mutex<()>,
 }
 
+/// Idle, with the default beat config; the next GSI event brings the config
+/// back in line with settings.
+impl Default for UltimateState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            beat_start_time: None,
+            beat_count: 0,
+            schedule_generation: 0,
+            current_song: None,
+            previous_song: None,
+            pending_song: None,
+            groovin_stacks: 0,
+            has_aggs: false,
+            smoke_quiet: false,
+            retreat_quiet: false,
+            beat_config: LargoBeatConfigSnapshot::from_settings(&Settings::default()),
+        }
+    }
+}
+
 impl LargoWorkerControl {
     fn new() -> Self {
         Self {
-            state: Mutex::new(UltimateState {
-                active: false,
-                beat_start_time: None,
-                beat_count: 0,
-                schedule_generation: 0,
-                current_song: None,
-                previous_song: None,
-                pending_song: None,
-                groovin_stacks: 0,
-                has_aggs: false,
-                beat_config: LargoBeatConfigSnapshot::from_settings(&Settings::default()),
-            }),
+            state: Mutex::new(UltimateState::default()),
             wake_worker: Condvar::new(),
             emission_guard: Mutex::new(()),
         }
@@ -211,6 +228,22 @@ fn reset_song_schedule(state: &mut UltimateState) {
     state.groovin_stacks = 0;
 }
 
+/// Stop any tracked ultimate and forget the song schedule. The beat config
+/// snapshot is kept; it mirrors settings, not game state.
+pub fn reset() {
+    let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
+    let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
+    state.active = false;
+    cancel_planned_beats(&mut state);
+    reset_song_schedule(&mut state);
+    state.has_aggs = false;
+    state.smoke_quiet = false;
+    state.retreat_quiet = false;
+    drop(state);
+    drop(emission_guard);
+    LARGO_WORKER_CONTROL.notify_worker();
+}
+
 pub struct LargoScript {
     settings: Arc<Mutex<Settings>>,
     executor: Arc<ActionExecutor>,
@@ -219,16 +252,16 @@ pub struct LargoScript {
 impl LargoScript {
     pub fn new(settings: Arc<Mutex<Settings>>, executor: Arc<ActionExecutor>) -> Self {
         let config_snapshot = {
-            let settings_guard = settings.lock().unwrap();
+            let settings_guard = lock_or_recover(&settings);
             LargoBeatConfigSnapshot::from_settings(&settings_guard)
         };
         {
-            let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+            let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
             state.beat_config = config_snapshot;
         }
 
         // Start the beat monitoring thread once
-        let mut started = BEAT_THREAD_STARTED.lock().unwrap();
+        let mut started = lock_or_recover(&BEAT_THREAD_STARTED);
         if !*started {
             *started = true;
             drop(started);
@@ -243,12 +276,28 @@ impl LargoScript {
             .name("largo-beat-worker".to_string())
             .spawn(move || {
                 info!("🎵 Largo beat worker thread started");
+                // Take the state lock before notifying so the wake cannot land between
+                // the worker's shutdown check and its wait.
+                shutdown::on_shutdown(|| {
+                    let _state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
+                    LARGO_WORKER_CONTROL.wake_worker.notify_all();
+                });
 
                 loop {
-                    let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+                    let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
 
-                    while !state.active || state.current_song.is_none() {
-                        state = LARGO_WORKER_CONTROL.wake_worker.wait(state).unwrap();
+                    while !shutdown::is_shutting_down()
+                        && (!state.active || state.current_song.is_none())
+                    {
+                        state = wait_or_reset(
+                            &LARGO_WORKER_CONTROL.wake_worker,
+                            &LARGO_WORKER_CONTROL.state,
+                            state,
+                        );
+                    }
+                    if shutdown::is_shutting_down() {
+                        info!("🎵 Largo beat worker stopped");
+                        return;
                     }
 
                     let config = state.beat_config;
@@ -260,10 +309,12 @@ impl LargoScript {
                     );
 
                     if !wait_duration.is_zero() {
-                        let (guard, timeout_result) = LARGO_WORKER_CONTROL
-                            .wake_worker
-                            .wait_timeout(state, wait_duration)
-                            .unwrap();
+                        let (guard, timeout_result) = wait_timeout_or_reset(
+                            &LARGO_WORKER_CONTROL.wake_worker,
+                            &LARGO_WORKER_CONTROL.state,
+                            state,
+                            wait_duration,
+                        );
                         state = guard;
 
                         if !timeout_result.timed_out() {
@@ -326,13 +377,13 @@ impl LargoScript {
                     }
 
                     for key in plan.keys_to_press {
-                        let emission_guard = LARGO_WORKER_CONTROL.emission_guard.lock().unwrap();
+                        let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
                         let should_emit = {
-                            let state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+                            let state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
                             should_emit_planned_beat(
                                 captured_generation,
                                 state.schedule_generation,
-                                state.active,
+                                state.active && !state.smoke_quiet && !state.retreat_quiet,
                             )
                         };
 
@@ -354,7 +405,7 @@ impl LargoScript {
             return false;
         }
 
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         if !settings.heroes.largo.amphibian_rhapsody_enabled {
             return false;
         }
@@ -377,7 +428,7 @@ impl LargoScript {
 
     #[allow(dead_code)]
     fn should_toggle_ultimate_off(&self, event: &GsiWebhookEvent) -> bool {
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
 
         // Check mana threshold - disable if too low
         if event.hero.mana_percent <= settings.heroes.largo.mana_threshold_percent {
@@ -394,8 +445,8 @@ impl LargoScript {
     // Public method to immediately stop the beat loop when R is pressed
     // This prevents pressing Q/W/E during the window between R press and GSI confirmation
     pub fn deactivate_ultimate(&self) {
-        let emission_guard = LARGO_WORKER_CONTROL.emission_guard.lock().unwrap();
-        let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+        let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
+        let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
 
         if state.active {
             info!("🎵 Amphibian Rhapsody STOPPED (R key pressed - awaiting GSI confirmation)");
@@ -412,8 +463,8 @@ impl LargoScript {
     // The song is queued and will be switched on the next beat to maintain rhythm
     pub fn select_song_manually(&self, song: Song) {
         let mut should_wake_worker = false;
-        let emission_guard = LARGO_WORKER_CONTROL.emission_guard.lock().unwrap();
-        let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+        let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
+        let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
 
         // If ultimate is not active, don't allow song selection
         if !state.active {
@@ -474,7 +525,7 @@ impl LargoScript {
 
     #[allow(dead_code)]
     fn select_song(&self, event: &GsiWebhookEvent) -> Song {
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
 
         // Priority 1: Healing if HP low
         if event.hero.health_percent < settings.heroes.largo.heal_hp_threshold {
@@ -498,12 +549,12 @@ impl LargoScript {
 
     fn toggle_ultimate(&self, enable: bool) {
         let config_snapshot = {
-            let settings = self.settings.lock().unwrap();
+            let settings = lock_or_recover(&self.settings);
             LargoBeatConfigSnapshot::from_settings(&settings)
         };
 
-        let emission_guard = LARGO_WORKER_CONTROL.emission_guard.lock().unwrap();
-        let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+        let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
+        let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
         state.beat_config = config_snapshot;
         let r_key = state.beat_config.r_key;
         let mut should_press_r = false;
@@ -539,19 +590,31 @@ impl LargoScript {
 impl HeroScript for LargoScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
         // Update danger detection state
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         let config_snapshot = LargoBeatConfigSnapshot::from_settings(&settings);
+        let smoke_quiet = crate::actions::common::smoke_quiet(event, &settings);
+        let retreat_quiet = settings.common.hold_offense_while_retreating
+            && crate::actions::position_tracker::is_retreating();
         drop(settings);
 
         // Detect ultimate state from GSI ability names
         let is_ult_active = Self::is_ultimate_active_from_gsi(event);
         let has_aggs = has_dual_song_scepter(event);
 
-        let emission_guard = LARGO_WORKER_CONTROL.emission_guard.lock().unwrap();
-        let mut state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+        let emission_guard = lock_or_recover(&LARGO_WORKER_CONTROL.emission_guard);
+        let mut state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
         let mut should_wake_worker = false;
 
+        state.smoke_quiet = smoke_quiet;
+        if state.retreat_quiet != retreat_quiet {
+            state.retreat_quiet = retreat_quiet;
+            if retreat_quiet {
+                info!("🏃 Retreating: holding Largo beats");
+            } else {
+                debug!("🏃 No longer retreating: Largo beats resume");
+            }
+        }
         if state.beat_config != config_snapshot {
             state.beat_config = config_snapshot;
             should_wake_worker = true;
@@ -594,7 +657,7 @@ impl HeroScript for LargoScript {
         info!("🎸 Manual Amphibian Rhapsody toggle");
 
         // Toggle ultimate state
-        let state = LARGO_WORKER_CONTROL.state.lock().unwrap();
+        let state = lock_or_reset(&LARGO_WORKER_CONTROL.state);
         let currently_active = state.active;
         drop(state);
 
@@ -640,6 +703,8 @@ mod tests {
             pending_song: None,
             groovin_stacks: 0,
             has_aggs: false,
+            smoke_quiet: false,
+            retreat_quiet: false,
             beat_config: test_beat_config(),
         }
     }
@@ -696,6 +761,7 @@ mod tests {
                 xp: 0,
                 xpos: 0,
                 ypos: 0,
+                extra: Default::default(),
             },
             abilities: Abilities {
                 ability0: test_ability(SONG_FIGHT_SONG),
@@ -724,7 +790,11 @@ mod tests {
                 stash5: GsiItem::default(),
                 teleport0: GsiItem::default(),
             },
-            map: Map { clock_time: 0 },
+            map: Map {
+                clock_time: 0,
+                game_state: None,
+                matchid: None,
+            },
             player: None,
         }
     }
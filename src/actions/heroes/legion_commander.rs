@@ -1,10 +1,15 @@
 use crate::actions::heroes::HeroScript;
-use crate::actions::common::{find_item_slot, SurvivabilityActions};
+use crate::actions::combo_control::combo_aborted;
+use crate::actions::common::{
+    auto_bkb_before, combo_has_mana, find_item_slot, find_item_slot_by_name,
+    SurvivabilityActions,
+};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::soul_ring::press_ability_with_soul_ring;
 use crate::config::Settings;
 use crate::input::simulation::press_key;
 use crate::models::{GsiWebhookEvent, Hero, Item};
+use crate::state::poison::lock_or_recover;
 use std::any::Any;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -28,95 +33,157 @@ impl LegionCommanderScript {
 
     pub fn execute_combo(&self) {
         info!("Executing Legion Commander combo sequence...");
-        
-        let event = self.last_event.lock().unwrap();
-        if event.is_none() {
+
+        let Some(event) = lock_or_recover(&self.last_event).clone() else {
             info!("No GSI event available, cannot determine item slots");
             return;
+        };
+        // Snapshot settings so a second trigger press can read them while the combo runs.
+        let settings = lock_or_recover(&self.settings).clone();
+        let event = &event;
+
+        if !combo_has_mana(
+            event,
+            &settings,
+            "Legion Commander",
+            &[
+                "legion_commander_press_the_attack",
+                "legion_commander_duel",
+                "legion_commander_overwhelming_odds",
+            ],
+            &settings.heroes.legion_commander.combo_mana_costs,
+        ) {
+            return;
         }
-        
-        let event = event.as_ref().unwrap();
-        let settings = self.settings.lock().unwrap();
-        
+
         // 1. Press The Attack (W) - with Soul Ring on first press, then double tap
         info!("Using Press The Attack (W)");
         press_ability_with_soul_ring('w', &settings);
         thread::sleep(Duration::from_millis(30));
         press_key('w');
         thread::sleep(Duration::from_millis(220));
-        
-        // 3. Blade Mail (if present) - double tap
-        if let Some(key) = find_item_slot(event, &settings, Item::BladeMail) {
-            info!("Using Blade Mail ({})", key);
-            press_key(key);
-            thread::sleep(Duration::from_millis(30));
-            press_key(key);
-            thread::sleep(Duration::from_millis(50));
-        }
-        
-        // 4. Mjollnir (if present) - double tap
-        if let Some(key) = find_item_slot(event, &settings, Item::Mjollnir) {
-            info!("Using Mjollnir ({})", key);
-            press_key(key);
-            thread::sleep(Duration::from_millis(30));
-            press_key(key);
-            thread::sleep(Duration::from_millis(50));
-        }
-        
-        // 5. BKB (if present) - double tap
-        if let Some(key) = find_item_slot(event, &settings, Item::BlackKingBar) {
-            info!("Using BKB ({})", key);
-            press_key(key);
-            thread::sleep(Duration::from_millis(30));
-            press_key(key);
-            thread::sleep(Duration::from_millis(50));
+
+        // 3-5. Self-buff items (if present) - double tap
+        for (item_name, label) in self_buff_items(&settings, event.hero.facet) {
+            if Self::aborted() {
+                return;
+            }
+            if let Some(key) = find_item_slot_by_name(event, &settings, &item_name) {
+                info!("Using {} ({})", label, key);
+                press_key(key);
+                thread::sleep(Duration::from_millis(30));
+                press_key(key);
+                thread::sleep(Duration::from_millis(50));
+            }
         }
-        
+
         // 6. Blink (single tap)
+        if Self::aborted() {
+            return;
+        }
         if let Some(key) = find_item_slot(event, &settings, Item::Blink) {
             info!("Using Blink ({})", key);
             press_key(key);
-            thread::sleep(Duration::from_millis(100));
+            thread::sleep(Duration::from_millis(
+                settings.heroes.legion_commander.post_blink_delay_ms,
+            ));
         }
-        
+
         // 7. Orchid or Bloodthorn (spam 3-4 times to remove linkens)
         if let Some(key) = find_item_slot(event, &settings, Item::Orchid)
             .or_else(|| find_item_slot(event, &settings, Item::Bloodthorn))
         {
             info!("Using Orchid/Bloodthorn ({}) - spam for linkens", key);
             for _ in 0..10 {
+                if Self::aborted() {
+                    return;
+                }
                 press_key(key);
                 thread::sleep(Duration::from_millis(30));
             }
             thread::sleep(Duration::from_millis(50));
         }
-        
-        // 8. Duel (R) - spam to ensure cast
+
+        // 8. Duel (R) - spam to ensure cast, optionally behind BKB
         info!("Using Duel (R)");
-        for _ in 0..6 {
+        let mut duel_presses = 6;
+        if settings.heroes.legion_commander.auto_bkb_before_duel {
+            if Self::aborted() {
+                return;
+            }
+            auto_bkb_before('r', &settings, event);
+            duel_presses -= 1;
+            thread::sleep(Duration::from_millis(50));
+        }
+        for _ in 0..duel_presses {
+            if Self::aborted() {
+                return;
+            }
             press_key('r');
             thread::sleep(Duration::from_millis(50));
         }
-        
+
         // 9. Overwhelming Odds (Q) - spam after duel
         info!("Using Overwhelming Odds (Q)");
         for _ in 0..6 {
+            if Self::aborted() {
+                return;
+            }
             press_key('q');
             thread::sleep(Duration::from_millis(50));
         }
-        
+
         info!("Legion Commander combo complete");
     }
+
+    fn aborted() -> bool {
+        let aborted = combo_aborted();
+        if aborted {
+            info!("Legion Commander combo aborted");
+        }
+        aborted
+    }
+}
+
+/// Items pressed between Press The Attack and Blink, as `(lookup name, log label)`.
+///
+/// The order depends on the hero's facet when `facet_self_buff_items` has an
+/// entry for it; otherwise the default Blade Mail → Mjollnir → BKB is used.
+/// BKB is left out when `auto_bkb_before_duel` moves it in front of Duel.
+fn self_buff_items(settings: &Settings, facet: u32) -> Vec<(String, String)> {
+    let config = &settings.heroes.legion_commander;
+    let mut items: Vec<(String, String)> =
+        if let Some(items) = config.self_buff_items_for_facet(facet) {
+            info!("Using facet {} self-buff order: {:?}", facet, items);
+            items
+                .iter()
+                .map(|name| (name.clone(), name.clone()))
+                .collect()
+        } else {
+            [
+                (Item::BladeMail, "Blade Mail"),
+                (Item::Mjollnir, "Mjollnir"),
+                (Item::BlackKingBar, "BKB"),
+            ]
+            .into_iter()
+            .map(|(item, label)| (item.to_game_name().to_string(), label.to_string()))
+            .collect()
+        };
+
+    if config.auto_bkb_before_duel {
+        items.retain(|(name, _)| !name.contains("black_king_bar"));
+    }
+    items
 }
 
 impl HeroScript for LegionCommanderScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
         // Store the latest event for combo execution
-        *self.last_event.lock().unwrap() = Some(event.clone());
+        *lock_or_recover(&self.last_event) = Some(event.clone());
         
         // Use common survivability actions (danger detection, healing, defensive items)
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         drop(settings);
         survivability.check_and_use_healing_items_with_danger(event, in_danger);
@@ -136,3 +203,179 @@ impl HeroScript for LegionCommanderScript {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::combo_control::ComboState;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+    use crate::models::gsi_event::Item as GsiItem;
+
+    fn combo_event() -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+
+        let item = |name: &str| GsiItem {
+            name: name.to_string(),
+            ..GsiItem::default()
+        };
+        event.items.slot0 = item("item_blade_mail");
+        event.items.slot1 = item("item_blink");
+        event.items.slot2 = item("item_orchid");
+        event.items.slot3 = GsiItem::default();
+        event.items.slot4 = GsiItem::default();
+        event.items.slot5 = GsiItem::default();
+        event.items.neutral0 = GsiItem::default();
+        event
+    }
+
+    #[test]
+    fn combo_presses_keys_in_order_with_expected_counts() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        let script =
+            LegionCommanderScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        *script.last_event.lock().unwrap() = Some(combo_event());
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+
+        let mut expected = vec!['w', 'w', 'z', 'z', 'x'];
+        expected.extend(['c'; 10]);
+        expected.extend(['r'; 6]);
+        expected.extend(['q'; 6]);
+        assert_eq!(recorder.key_clicks(), expected);
+
+        let actions = recorder.actions();
+        assert!(actions.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    }
+
+    #[test]
+    fn auto_bkb_before_duel_moves_bkb_unless_magic_immune() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        settings.heroes.legion_commander.auto_bkb_before_duel = true;
+        let script =
+            LegionCommanderScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        let mut event = combo_event();
+        event.items.slot3 = GsiItem {
+            name: "item_black_king_bar".to_string(),
+            can_cast: Some(true),
+            ..GsiItem::default()
+        };
+        *script.last_event.lock().unwrap() = Some(event.clone());
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+
+        let mut expected = vec!['w', 'w', 'z', 'z', 'x'];
+        expected.extend(['c'; 10]);
+        expected.extend(['v', 'v']);
+        expected.extend(['r'; 6]);
+        expected.extend(['q'; 6]);
+        assert_eq!(recorder.key_clicks(), expected);
+
+        event.hero.magicimmune = true;
+        *script.last_event.lock().unwrap() = Some(event);
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+
+        assert!(!recorder.key_clicks().contains(&'v'));
+        assert_eq!(recorder.key_clicks().iter().filter(|&&key| key == 'r').count(), 6);
+    }
+
+    #[test]
+    fn facet_entry_reorders_self_buff_items() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        settings
+            .heroes
+            .legion_commander
+            .facet_self_buff_items
+            .insert("2".to_string(), vec!["orchid".to_string(), "blade_mail".to_string()]);
+        let script =
+            LegionCommanderScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        let mut event = combo_event();
+        event.hero.facet = 2;
+        *script.last_event.lock().unwrap() = Some(event.clone());
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+        assert_eq!(recorder.key_clicks()[..6], ['w', 'w', 'c', 'c', 'z', 'z']);
+
+        // Other facets keep the default order.
+        event.hero.facet = 1;
+        *script.last_event.lock().unwrap() = Some(event);
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+        assert_eq!(recorder.key_clicks()[..5], ['w', 'w', 'z', 'z', 'x']);
+    }
+
+    #[test]
+    fn aborted_combo_stops_after_current_step() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        let script =
+            LegionCommanderScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        *script.last_event.lock().unwrap() = Some(combo_event());
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        let state = Arc::new(ComboState::default());
+        let run = state.try_begin().unwrap();
+        assert!(state.request_abort());
+        run.run(|| script.execute_combo());
+
+        assert_eq!(recorder.key_clicks(), vec!['w', 'w']);
+    }
+
+    #[test]
+    fn combo_without_gsi_event_presses_nothing() {
+        let script = LegionCommanderScript::new(
+            Arc::new(Mutex::new(Settings::default())),
+            ActionExecutor::new(),
+        );
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+
+        assert!(recorder.actions().is_empty());
+    }
+
+    #[test]
+    fn combo_is_skipped_when_mana_is_short() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        settings
+            .heroes
+            .legion_commander
+            .combo_mana_costs
+            .insert("legion_commander_duel".to_string(), 75);
+        let mut event = combo_event();
+        event.hero.mana = 50;
+        event.abilities.ability5 = crate::models::gsi_event::Ability {
+            ability_active: true,
+            can_cast: false,
+            cooldown: 0,
+            level: 1,
+            name: "legion_commander_duel".to_string(),
+            passive: false,
+            ultimate: true,
+        };
+        let script =
+            LegionCommanderScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        *script.last_event.lock().unwrap() = Some(event);
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo();
+
+        assert!(recorder.actions().is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::actions::common::{find_item_slot, SurvivabilityActions};
+use crate::actions::combo_control::{attack_blocked, combo_aborted};
+use crate::actions::common::{auto_bkb_before, find_item_slot, SurvivabilityActions};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::meepo_macro::{
     evaluate_farm_pulse, suspend_for_manual_combo, toggle_meepo_macro, MeepoFarmPulseDecision,
@@ -8,8 +9,9 @@ use crate::actions::heroes::traits::HeroScript;
 use crate::actions::heroes::meepo_state::{latest_meepo_observed_state, refresh_meepo_observed_state};
 use crate::config::settings::MeepoConfig;
 use crate::config::Settings;
-use crate::input::simulation::{mouse_click, press_key};
+use crate::input::simulation::{press_key, right_click};
 use crate::models::{GsiWebhookEvent, Hero, Item};
+use crate::state::poison::lock_or_recover;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -19,11 +21,10 @@ const DIG_ABILITY_NAME: &str = "meepo_petrify";
 const MEGAMEEPO_ABILITY_NAME: &str = "meepo_megameepo";
 
 fn ability_is_ready(event: &GsiWebhookEvent, ability_name: &str) -> bool {
-    (0..=5).any(|index| {
-        event.abilities.get_by_index(index).is_some_and(|ability| {
-            ability.name == ability_name && ability.level > 0 && ability.can_cast
-        })
-    })
+    event
+        .abilities
+        .find_by_name(ability_name)
+        .is_some_and(|ability| ability.level > 0 && ability.can_cast)
 }
 
 fn should_cast_dig(
@@ -152,6 +153,10 @@ impl MeepoScript {
         }
 
         for item_name in &meepo.combo_items {
+            if combo_aborted() {
+                info!("Meepo combo aborted");
+                return;
+            }
             if let Some(key) = find_combo_item_slot_key(event, settings, item_name) {
                 info!("Using combo item '{}' ({})", item_name, key);
                 for index in 0..meepo.combo_item_spam_count {
@@ -165,6 +170,11 @@ impl MeepoScript {
             }
         }
 
+        if combo_aborted() {
+            info!("Meepo combo aborted");
+            return;
+        }
+
         info!("Casting Earthbind ({})", meepo.earthbind_key);
         press_key_repeatedly(
             meepo.earthbind_key,
@@ -172,12 +182,21 @@ impl MeepoScript {
             meepo.earthbind_press_interval_ms,
         );
 
+        if combo_aborted() {
+            info!("Meepo combo aborted");
+            return;
+        }
+
         info!("Casting Poof ({})", meepo.poof_key);
-        press_key_repeatedly(
-            meepo.poof_key,
-            meepo.poof_press_count,
-            meepo.poof_press_interval_ms,
-        );
+        let mut poof_presses = meepo.poof_press_count;
+        if meepo.auto_bkb_before_poof && poof_presses > 0 {
+            auto_bkb_before(meepo.poof_key, settings, event);
+            poof_presses -= 1;
+            if poof_presses > 0 {
+                thread::sleep(Duration::from_millis(meepo.poof_press_interval_ms));
+            }
+        }
+        press_key_repeatedly(meepo.poof_key, poof_presses, meepo.poof_press_interval_ms);
     }
 
     fn maybe_trigger_defensive_cast(
@@ -187,7 +206,7 @@ impl MeepoScript {
         in_danger: bool,
     ) {
         let action = {
-            let mut last_trigger = self.last_defensive_trigger.lock().unwrap();
+            let mut last_trigger = lock_or_recover(&self.last_defensive_trigger);
 
             if should_cast_dig(event, config, *last_trigger, in_danger) {
                 *last_trigger = Some(Instant::now());
@@ -227,14 +246,14 @@ impl MeepoScript {
         self.executor.enqueue("meepo-farm-pulse", move || {
             info!("Executing Meepo farm-assist pulse");
             press_key_repeatedly(poof_key, pulse_count, interval_ms);
-            if right_click_after_poof {
-                mouse_click();
+            if right_click_after_poof && !attack_blocked() {
+                right_click();
             }
         });
     }
 
     pub fn toggle_farm_assist(&self) -> MeepoMacroMode {
-        let config = { self.settings.lock().unwrap().heroes.meepo.farm_assist.clone() };
+        let config = { lock_or_recover(&self.settings).heroes.meepo.farm_assist.clone() };
         let snapshot_available = latest_meepo_observed_state().is_some();
         let mode = toggle_meepo_macro(config.enabled, snapshot_available);
 
@@ -259,13 +278,13 @@ impl MeepoScript {
 impl HeroScript for MeepoScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
         {
-            let mut latest_event = self.latest_event.lock().unwrap();
+            let mut latest_event = lock_or_recover(&self.latest_event);
             *latest_event = Some(event.clone());
         }
 
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
         let (in_danger, meepo_config) = {
-            let settings = self.settings.lock().unwrap();
+            let settings = lock_or_recover(&self.settings);
             let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
             refresh_meepo_observed_state(event, &settings, in_danger);
             (
@@ -283,13 +302,13 @@ impl HeroScript for MeepoScript {
     }
 
     fn handle_standalone_trigger(&self) {
-        let latest_event = { self.latest_event.lock().unwrap().clone() };
+        let latest_event = { lock_or_recover(&self.latest_event).clone() };
         let Some(event) = latest_event else {
             warn!("No GSI event received yet - Meepo combo needs item data");
             return;
         };
 
-        let settings = { self.settings.lock().unwrap().clone() };
+        let settings = { lock_or_recover(&self.settings).clone() };
         suspend_for_manual_combo(settings.heroes.meepo.farm_assist.suspend_after_manual_combo_ms);
         self.execute_combo(&event, &settings);
     }
@@ -298,6 +317,11 @@ impl HeroScript for MeepoScript {
         Hero::Meepo.to_game_name()
     }
 
+    fn reset(&self) {
+        *lock_or_recover(&self.latest_event) = None;
+        *lock_or_recover(&self.last_defensive_trigger) = None;
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -316,12 +340,12 @@ mod tests {
     use crate::actions::heroes::meepo_state::{clear_meepo_observed_state, refresh_meepo_observed_state};
     use crate::actions::executor::ActionExecutor;
     use crate::config::Settings;
+    use crate::gsi::load_fixture_event;
     use crate::models::GsiWebhookEvent;
     use std::sync::{Arc, Mutex};
 
     fn meepo_fixture() -> GsiWebhookEvent {
-        serde_json::from_str(include_str!("../../../tests/fixtures/meepo_event.json"))
-            .expect("Meepo fixture should deserialize")
+        load_fixture_event("tests/fixtures/meepo_event.json")
     }
 
     #[test]
@@ -1,5 +1,6 @@
 use crate::actions::heroes::meepo_state::MeepoObservedState;
 use crate::config::settings::MeepoFarmAssistConfig;
+use crate::state::poison::lock_or_reset;
 use lazy_static::lazy_static;
 use std::sync::Mutex;
 #[cfg(test)]
@@ -69,16 +70,15 @@ fn pulse_is_safe(observed: &MeepoObservedState, config: &MeepoFarmAssistConfig)
 }
 
 pub fn latest_meepo_macro_status() -> MeepoMacroStatusSnapshot {
-    let state = MEEPO_MACRO_STATE.lock().unwrap();
+    let state = lock_or_reset(&MEEPO_MACRO_STATE);
     MeepoMacroStatusSnapshot {
         mode: state.mode,
         pulses_executed: state.pulses_executed,
     }
 }
 
-#[cfg_attr(not(test), allow(dead_code))]
 pub fn clear_meepo_macro_state() {
-    *MEEPO_MACRO_STATE.lock().unwrap() = MeepoMacroRuntimeState::default();
+    *lock_or_reset(&MEEPO_MACRO_STATE) = MeepoMacroRuntimeState::default();
 }
 
 #[cfg(test)]
@@ -88,7 +88,7 @@ pub(crate) fn meepo_macro_test_lock() -> &'static Mutex<()> {
 }
 
 pub fn suspend_meepo_macro(reason: MeepoMacroSuspendReason) -> MeepoMacroMode {
-    let mut state = MEEPO_MACRO_STATE.lock().unwrap();
+    let mut state = lock_or_reset(&MEEPO_MACRO_STATE);
     state.mode = MeepoMacroMode::Suspended(reason);
     if reason != MeepoMacroSuspendReason::ManualCombo {
         state.resume_after_manual_combo_at = None;
@@ -97,7 +97,7 @@ pub fn suspend_meepo_macro(reason: MeepoMacroSuspendReason) -> MeepoMacroMode {
 }
 
 pub fn toggle_meepo_macro(enabled: bool, snapshot_available: bool) -> MeepoMacroMode {
-    let mut state = MEEPO_MACRO_STATE.lock().unwrap();
+    let mut state = lock_or_reset(&MEEPO_MACRO_STATE);
     state.resume_after_manual_combo_at = None;
 
     if matches!(state.mode, MeepoMacroMode::Inactive) {
@@ -116,7 +116,7 @@ pub fn toggle_meepo_macro(enabled: bool, snapshot_available: bool) -> MeepoMacro
 }
 
 pub fn suspend_for_manual_combo(cooldown_ms: u64) -> MeepoMacroMode {
-    let mut state = MEEPO_MACRO_STATE.lock().unwrap();
+    let mut state = lock_or_reset(&MEEPO_MACRO_STATE);
     if matches!(state.mode, MeepoMacroMode::Inactive) {
         return state.mode;
     }
@@ -131,7 +131,7 @@ pub fn evaluate_farm_pulse(
     config: &MeepoFarmAssistConfig,
     now: Instant,
 ) -> MeepoFarmPulseDecision {
-    let mut state = MEEPO_MACRO_STATE.lock().unwrap();
+    let mut state = lock_or_reset(&MEEPO_MACRO_STATE);
 
     if !config.enabled {
         state.mode = MeepoMacroMode::Suspended(MeepoMacroSuspendReason::Disabled);
@@ -1,6 +1,7 @@
 use crate::actions::common::find_item_slot;
 use crate::config::Settings;
 use crate::models::{GsiWebhookEvent, Hero, Item};
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use std::sync::Mutex;
 
@@ -37,11 +38,10 @@ pub struct MeepoObservedState {
 }
 
 fn ability_is_ready(event: &GsiWebhookEvent, ability_name: &str) -> bool {
-    (0..=5).any(|index| {
-        event.abilities.get_by_index(index).is_some_and(|ability| {
-            ability.name == ability_name && ability.level > 0 && ability.can_cast
-        })
-    })
+    event
+        .abilities
+        .find_by_name(ability_name)
+        .is_some_and(|ability| ability.level > 0 && ability.can_cast)
 }
 
 fn find_combo_item_key(
@@ -94,16 +94,16 @@ pub fn derive_meepo_observed_state(
 }
 
 pub fn refresh_meepo_observed_state(event: &GsiWebhookEvent, settings: &Settings, in_danger: bool) {
-    let mut state = MEEPO_OBSERVED_STATE.lock().unwrap();
+    let mut state = lock_or_recover(&MEEPO_OBSERVED_STATE);
     *state = derive_meepo_observed_state(event, settings, in_danger);
 }
 
 pub fn clear_meepo_observed_state() {
-    *MEEPO_OBSERVED_STATE.lock().unwrap() = None;
+    *lock_or_recover(&MEEPO_OBSERVED_STATE) = None;
 }
 
 pub fn latest_meepo_observed_state() -> Option<MeepoObservedState> {
-    MEEPO_OBSERVED_STATE.lock().unwrap().clone()
+    lock_or_recover(&MEEPO_OBSERVED_STATE).clone()
 }
 
 #[cfg(test)]
@@ -114,11 +114,11 @@ mod tests {
     };
     use crate::actions::heroes::meepo_macro::meepo_macro_test_lock;
     use crate::config::Settings;
+    use crate::gsi::load_fixture_event;
     use crate::models::GsiWebhookEvent;
 
     fn meepo_fixture() -> GsiWebhookEvent {
-        serde_json::from_str(include_str!("../../../tests/fixtures/meepo_event.json"))
-            .expect("Meepo fixture should deserialize")
+        load_fixture_event("tests/fixtures/meepo_event.json")
     }
 
     #[test]
@@ -1,4 +1,6 @@
+pub mod bristleback;
 pub mod broodmother;
+pub mod generic;
 pub mod huskar;
 pub mod largo;
 pub mod legion_commander;
@@ -9,8 +11,11 @@ pub mod outworld_destroyer;
 pub mod shadow_fiend;
 pub mod tiny;
 pub mod traits;
+pub mod zeus;
 
+pub use bristleback::BristlebackScript;
 pub use broodmother::BroodmotherScript;
+pub use generic::GenericHeroScript;
 pub use huskar::HuskarScript;
 pub use largo::LargoScript;
 pub use legion_commander::LegionCommanderScript;
@@ -19,3 +24,4 @@ pub use outworld_destroyer::OutworldDestroyerScript;
 pub use shadow_fiend::ShadowFiendScript;
 pub use tiny::TinyScript;
 pub use traits::HeroScript;
+pub use zeus::ZeusScript;
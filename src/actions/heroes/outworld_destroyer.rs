@@ -1,9 +1,11 @@
-use crate::actions::common::SurvivabilityActions;
+use crate::actions::auto_items::slot_key_event;
+use crate::actions::common::{auto_bkb, SurvivabilityActions};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::HeroScript;
 use crate::config::{OutworldDestroyerConfig, Settings};
 use crate::input::simulation::press_key;
 use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use std::sync::{mpsc, Arc, LazyLock, Mutex};
 use std::thread;
@@ -61,13 +63,10 @@ static OD_REQUEST_QUEUE: LazyLock<mpsc::Sender<OutworldDestroyerRequest>> = Lazy
 });
 
 fn ability_is_ready(event: &GsiWebhookEvent, ability_name: &str) -> bool {
-    (0..=5).any(|index| {
-        event.abilities
-            .get_by_index(index)
-            .is_some_and(|ability| {
-                ability.name == ability_name && ability.level > 0 && ability.can_cast
-            })
-    })
+    event
+        .abilities
+        .find_by_name(ability_name)
+        .is_some_and(|ability| ability.level > 0 && ability.can_cast)
 }
 
 fn find_castable_slot_key_by_name(
@@ -146,17 +145,7 @@ fn execute_combo_items(event: &GsiWebhookEvent, config: &OutworldDestroyerComboC
 }
 
 fn maybe_cast_bkb(event: &GsiWebhookEvent, config: &OutworldDestroyerComboConfig) {
-    if !config.auto_bkb_on_ultimate {
-        return;
-    }
-
-    if let Some(key) =
-        find_castable_slot_key_by_name(event, &config.slot_keys, "black_king_bar")
-    {
-        info!("🌌 OD using BKB ({})", key);
-        press_key(key);
-        thread::sleep(Duration::from_millis(30));
-        press_key(key);
+    if config.auto_bkb_on_ultimate && auto_bkb(event, &config.slot_keys) {
         thread::sleep(Duration::from_millis(config.post_bkb_delay_ms));
     }
 }
@@ -211,7 +200,7 @@ fn run_ultimate_request(request: OutworldDestroyerRequest) {
         return;
     };
 
-    let event = OD_LAST_EVENT.lock().unwrap().clone();
+    let event = lock_or_recover(&OD_LAST_EVENT).clone();
     let Some(event) = event else {
         info!("🌌 OD ultimate intercept skipped: no GSI event available");
         return;
@@ -235,7 +224,7 @@ fn run_standalone_request(request: OutworldDestroyerRequest) {
         return;
     };
 
-    let event = OD_LAST_EVENT.lock().unwrap().clone();
+    let event = lock_or_recover(&OD_LAST_EVENT).clone();
     let Some(event) = event else {
         info!("🌌 OD standalone combo skipped: no GSI event available");
         return;
@@ -253,9 +242,12 @@ fn run_standalone_request(request: OutworldDestroyerRequest) {
     } else {
         info!("🌌 OD standalone combo continuing without Blink");
     }
-
-    maybe_cast_bkb(&event, &config);
-    execute_combo_items(&event, &config);
+    // Slot keys below are read after the Blink delay; pick up any reshuffle,
+    // and skip the item steps when no fresh event shows where items are.
+    if let Some(fresh) = slot_key_event(&event) {
+        maybe_cast_bkb(&fresh, &config);
+        execute_combo_items(&fresh, &config);
+    }
     maybe_cast_objurgation(&event, &config);
 
     info!("🌌 OD standalone combo casting Sanity's Eclipse (R)");
@@ -271,7 +263,7 @@ fn run_self_astral_request(request: OutworldDestroyerRequest) {
         return;
     };
 
-    let event = OD_LAST_EVENT.lock().unwrap().clone();
+    let event = lock_or_recover(&OD_LAST_EVENT).clone();
     let Some(event) = event else {
         info!("🌌 OD self-Astral skipped: no GSI event available");
         return;
@@ -294,14 +286,7 @@ fn run_self_astral_request(request: OutworldDestroyerRequest) {
 fn build_combo_config(settings: &Settings) -> OutworldDestroyerComboConfig {
     let od = &settings.heroes.outworld_destroyer;
     OutworldDestroyerComboConfig {
-        slot_keys: [
-            settings.keybindings.slot0,
-            settings.keybindings.slot1,
-            settings.keybindings.slot2,
-            settings.keybindings.slot3,
-            settings.keybindings.slot4,
-            settings.keybindings.slot5,
-        ],
+        slot_keys: settings.inventory_slot_keys(),
         objurgation_key: od.objurgation_key,
         arcane_orb_key: od.arcane_orb_key,
         astral_imprisonment_key: od.astral_imprisonment_key,
@@ -321,17 +306,19 @@ pub struct OutworldDestroyerState;
 
 impl OutworldDestroyerState {
     pub fn can_intercept_ultimate() -> bool {
-        OD_LAST_EVENT
-            .lock()
-            .unwrap()
+        lock_or_recover(&OD_LAST_EVENT)
             .as_ref()
-            .is_some_and(|event| event.hero.alive && ability_is_ready(event, SANITYS_ECLIPSE_ABILITY_NAME))
+            .is_some_and(|event| {
+                event.hero.alive && ability_is_ready(event, SANITYS_ECLIPSE_ABILITY_NAME)
+            })
     }
 
     pub fn can_self_cast_astral() -> bool {
-        OD_LAST_EVENT.lock().unwrap().as_ref().is_some_and(|event| {
-            event.hero.alive && ability_is_ready(event, ASTRAL_IMPRISONMENT_ABILITY_NAME)
-        })
+        lock_or_recover(&OD_LAST_EVENT)
+            .as_ref()
+            .is_some_and(|event| {
+                event.hero.alive && ability_is_ready(event, ASTRAL_IMPRISONMENT_ABILITY_NAME)
+            })
     }
 
     pub fn execute_ultimate_combo(config: OutworldDestroyerComboConfig) {
@@ -349,6 +336,12 @@ impl OutworldDestroyerState {
     }
 }
 
+/// Clear the Objurgation cooldown and the cached event.
+pub fn reset() {
+    *lock_or_recover(&LAST_OBJURGATION_TRIGGER) = None;
+    *lock_or_recover(&OD_LAST_EVENT) = None;
+}
+
 pub struct OutworldDestroyerScript {
     settings: Arc<Mutex<Settings>>,
     executor: Arc<ActionExecutor>,
@@ -366,7 +359,7 @@ impl OutworldDestroyerScript {
         in_danger: bool,
     ) {
         let now = Instant::now();
-        let mut last_trigger = LAST_OBJURGATION_TRIGGER.lock().unwrap();
+        let mut last_trigger = lock_or_recover(&LAST_OBJURGATION_TRIGGER);
 
         if !should_trigger_objurgation(event, config, in_danger, now, *last_trigger) {
             return;
@@ -384,12 +377,12 @@ impl OutworldDestroyerScript {
 impl HeroScript for OutworldDestroyerScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
         {
-            let mut last_event = OD_LAST_EVENT.lock().unwrap();
+            let mut last_event = lock_or_recover(&OD_LAST_EVENT);
             *last_event = Some(event.clone());
         }
 
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         self.maybe_trigger_objurgation(event, &settings.heroes.outworld_destroyer, in_danger);
         drop(settings);
@@ -401,7 +394,7 @@ impl HeroScript for OutworldDestroyerScript {
 
     fn handle_standalone_trigger(&self) {
         info!("🌌 Outworld Destroyer standalone combo triggered");
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         OutworldDestroyerState::execute_standalone_combo(build_combo_config(&settings));
     }
 
@@ -425,12 +418,12 @@ mod tests {
         OBJURGATION_ABILITY_NAME, SANITYS_ECLIPSE_ABILITY_NAME,
     };
     use crate::config::Settings;
+    use crate::gsi::load_fixture_event;
     use crate::models::GsiWebhookEvent;
     use std::time::{Duration, Instant};
 
     fn od_fixture() -> GsiWebhookEvent {
-        serde_json::from_str(include_str!("../../../tests/fixtures/outworld_destroyer_event.json"))
-            .expect("OD fixture should deserialize")
+        load_fixture_event("tests/fixtures/outworld_destroyer_event.json")
     }
 
     #[test]
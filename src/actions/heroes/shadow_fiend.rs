@@ -1,15 +1,19 @@
-use crate::actions::common::SurvivabilityActions;
+use crate::actions::common::{auto_bkb, SurvivabilityActions};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::heroes::HeroScript;
 use crate::config::Settings;
-use crate::input::simulation::press_key;
+use crate::input::simulation::{alt_down, alt_up, press_key, right_click};
 use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::{lock_or_recover, lock_or_reset};
 use lazy_static::lazy_static;
 use std::sync::{mpsc, Arc, LazyLock, Mutex};
 use std::thread;
 use std::time::Duration;
 use tracing::{info, warn};
 
+/// Gap around the facing click in [`face_direction_and_cast`].
+const FACE_DIRECTION_SETTLE_MS: u64 = 50;
+
 lazy_static! {
     /// Shared state for Shadow Fiend to allow keyboard.rs to access last GSI event
     pub static ref SF_LAST_EVENT: Arc<Mutex<Option<GsiWebhookEvent>>> = Arc::new(Mutex::new(None));
@@ -19,22 +23,31 @@ lazy_static! {
 
 #[derive(Debug, PartialEq, Eq)]
 enum ShadowFiendRequest {
-    Raze { raze_key: char, raze_delay_ms: u64 },
-    Ultimate { auto_d_on_ultimate: bool },
-    Standalone { auto_bkb_on_ultimate: bool, auto_d_on_ultimate: bool },
+    Raze { raze_key: char, raze_delay_ms: u64, hold_alt: bool },
+    Face { hold_alt: bool },
+    Ultimate { auto_d_on_ultimate: bool, slot_keys: [char; 6] },
+    Standalone {
+        auto_bkb_on_ultimate: bool,
+        auto_d_on_ultimate: bool,
+        slot_keys: [char; 6],
+    },
 }
 
 /// Build a Raze request payload for the worker
-fn build_raze_request(raze_key: char, raze_delay_ms: u64) -> ShadowFiendRequest {
+fn build_raze_request(raze_key: char, raze_delay_ms: u64, hold_alt: bool) -> ShadowFiendRequest {
     ShadowFiendRequest::Raze {
         raze_key,
         raze_delay_ms,
+        hold_alt,
     }
 }
 
 /// Build an Ultimate request payload for the worker
-fn build_ultimate_request(auto_d_on_ultimate: bool) -> ShadowFiendRequest {
-    ShadowFiendRequest::Ultimate { auto_d_on_ultimate }
+fn build_ultimate_request(auto_d_on_ultimate: bool, slot_keys: [char; 6]) -> ShadowFiendRequest {
+    ShadowFiendRequest::Ultimate {
+        auto_d_on_ultimate,
+        slot_keys,
+    }
 }
 
 /// Build a Standalone request payload by copying relevant runtime flags from Settings
@@ -43,22 +56,40 @@ fn build_standalone_request(settings: &Settings) -> ShadowFiendRequest {
     ShadowFiendRequest::Standalone {
         auto_bkb_on_ultimate: sf.auto_bkb_on_ultimate,
         auto_d_on_ultimate: sf.auto_d_on_ultimate,
+        slot_keys: settings.inventory_slot_keys(),
     }
 }
 
-/// Map an inventory slot string (e.g. "slot0") to the common keybinding character
-fn slot_to_common_key(slot: &str) -> Option<char> {
-    match slot {
-        "slot0" => Some('z'),
-        "slot1" => Some('x'),
-        "slot2" => Some('c'),
-        "slot3" => Some('v'),
-        "slot4" => Some('b'),
-        "slot5" => Some('n'),
-        _ => None,
+/// Raze keys currently held down with `cast_on_key_release`.
+#[derive(Debug, Default)]
+struct HeldRazeKeys(Vec<char>);
+
+impl HeldRazeKeys {
+    /// Mark `key` held; false for key-repeat presses of an already held key.
+    fn press(&mut self, key: char) -> bool {
+        if self.0.contains(&key) {
+            return false;
+        }
+        self.0.push(key);
+        true
+    }
+
+    /// Clear `key`; false when it was not held.
+    fn release(&mut self, key: char) -> bool {
+        let held = self.0.len();
+        self.0.retain(|k| *k != key);
+        self.0.len() != held
     }
 }
 
+static HELD_RAZE_KEYS: LazyLock<Mutex<HeldRazeKeys>> =
+    LazyLock::new(|| Mutex::new(HeldRazeKeys::default()));
+
+/// Forget raze keys still marked held, e.g. after a game ended mid-raze.
+pub fn reset() {
+    *lock_or_reset(&HELD_RAZE_KEYS) = HeldRazeKeys::default();
+}
+
 static SHADOW_FIEND_REQUEST_QUEUE: LazyLock<mpsc::Sender<ShadowFiendRequest>> =
     LazyLock::new(|| {
         let (tx, rx) = mpsc::channel::<ShadowFiendRequest>();
@@ -79,6 +110,7 @@ static SHADOW_FIEND_REQUEST_QUEUE: LazyLock<mpsc::Sender<ShadowFiendRequest>> =
 fn run_shadow_fiend_request(request: ShadowFiendRequest) {
     match request {
         request @ ShadowFiendRequest::Raze { .. } => run_raze_request(request),
+        ShadowFiendRequest::Face { hold_alt } => face_direction(hold_alt),
         request @ ShadowFiendRequest::Ultimate { .. } => run_ultimate_request(request),
         request @ ShadowFiendRequest::Standalone { .. } => run_standalone_request(request),
     }
@@ -101,57 +133,58 @@ fn run_raze_request(request: ShadowFiendRequest) {
     let ShadowFiendRequest::Raze {
         raze_key,
         raze_delay_ms,
+        hold_alt,
     } = request
     else {
         return;
     };
 
-    thread::sleep(Duration::from_millis(50));
+    face_direction_and_cast(raze_key, raze_delay_ms, hold_alt);
+}
 
-    crate::input::simulation::alt_down();
-    crate::input::simulation::mouse_click();
+/// Turn the hero toward the cursor, then press `ability_key` after `delay_ms`.
+///
+/// For direction-targeted casts (razes, hooks, skillshots) that fire along
+/// the hero's facing. With `hold_alt` the turn is ALT+right-click, which
+/// only turns in place when Dota's `cl_dota_alt_unit_movetodirection` is
+/// enabled; otherwise a plain right-click starts a move toward the cursor
+/// that the cast then replaces.
+fn face_direction_and_cast(ability_key: char, delay_ms: u64, hold_alt: bool) {
+    face_direction(hold_alt);
+    thread::sleep(Duration::from_millis(delay_ms));
+    press_key(ability_key);
+}
 
-    thread::sleep(Duration::from_millis(50));
-    crate::input::simulation::alt_up();
+/// Turn the hero toward the cursor without casting; see [`face_direction_and_cast`].
+fn face_direction(hold_alt: bool) {
+    thread::sleep(Duration::from_millis(FACE_DIRECTION_SETTLE_MS));
 
-    thread::sleep(Duration::from_millis(raze_delay_ms));
-    crate::input::simulation::press_key(raze_key);
+    if hold_alt {
+        alt_down();
+        right_click();
+        thread::sleep(Duration::from_millis(FACE_DIRECTION_SETTLE_MS));
+        alt_up();
+    } else {
+        right_click();
+        thread::sleep(Duration::from_millis(FACE_DIRECTION_SETTLE_MS));
+    }
 }
 
 fn run_ultimate_request(request: ShadowFiendRequest) {
-    let ShadowFiendRequest::Ultimate { auto_d_on_ultimate } = request else {
+    let ShadowFiendRequest::Ultimate {
+        auto_d_on_ultimate,
+        slot_keys,
+    } = request
+    else {
         return;
     };
 
-    let event_guard = SF_LAST_EVENT.lock().unwrap();
-
-    if let Some(event) = event_guard.as_ref() {
-        let bkb_slot = event
-            .items
-            .all_slots()
-            .iter()
-            .find(|(_, item)| item.name.contains("black_king_bar") && item.can_cast == Some(true))
-            .map(|(slot, _)| *slot);
-
-        if let Some(slot) = bkb_slot {
-            let key = slot_to_common_key(slot);
-
-            if let Some(bkb_key) = key {
-                info!("👻 SF Ultimate: Using BKB ({}) before Requiem", bkb_key);
-                press_key(bkb_key);
-                thread::sleep(Duration::from_millis(30));
-                press_key(bkb_key);
-                thread::sleep(Duration::from_millis(50));
-            }
-        } else {
-            info!("👻 SF Ultimate: BKB not found or on cooldown");
-        }
-    } else {
-        info!("👻 SF Ultimate: No GSI event available, skipping BKB");
+    let event = lock_or_recover(&SF_LAST_EVENT).clone();
+    match event {
+        Some(event) => cast_bkb(&event, &slot_keys),
+        None => info!("👻 SF Ultimate: No GSI event available, skipping BKB"),
     }
 
-    drop(event_guard);
-
     if auto_d_on_ultimate {
         info!("👻 SF Ultimate: Using D ability");
         press_key('d');
@@ -166,60 +199,40 @@ fn run_standalone_request(request: ShadowFiendRequest) {
     let ShadowFiendRequest::Standalone {
         auto_bkb_on_ultimate,
         auto_d_on_ultimate,
+        slot_keys,
     } = request
     else {
         return;
     };
 
-    let event_guard = SF_LAST_EVENT.lock().unwrap();
+    let event = lock_or_recover(&SF_LAST_EVENT).clone();
 
-    if let Some(event) = event_guard.as_ref() {
+    if let Some(event) = event {
         let ult_ready = event.abilities.ability5.can_cast;
         if !ult_ready {
             info!("👻 SF Standalone: Ultimate on cooldown, skipping combo");
             return;
         }
 
-        let blink_slot = event
+        let blink_key = event
             .items
             .all_slots()
             .iter()
-            .find(|(_, item)| item.name.contains("blink") && item.can_cast == Some(true))
-            .map(|(slot, _)| *slot);
+            .zip(slot_keys)
+            .find(|((_, item), _)| item.name.contains("blink") && item.can_cast == Some(true))
+            .map(|(_, key)| key);
 
-        if blink_slot.is_none() {
+        let Some(key) = blink_key else {
             info!("👻 SF Standalone: Blink not found or on cooldown, skipping combo");
             return;
-        }
-
-        let blink_key = blink_slot.and_then(slot_to_common_key);
-        let bkb_key = if auto_bkb_on_ultimate {
-            event
-                .items
-                .all_slots()
-                .iter()
-                .find(|(_, item)| {
-                    item.name.contains("black_king_bar") && item.can_cast == Some(true)
-                })
-                .and_then(|(slot, _)| slot_to_common_key(*slot))
-        } else {
-            None
         };
 
-        drop(event_guard);
+        info!("👻 SF Standalone: Using Blink ({})", key);
+        press_key(key);
+        thread::sleep(Duration::from_millis(50));
 
-        if let Some(key) = blink_key {
-            info!("👻 SF Standalone: Using Blink ({})", key);
-            press_key(key);
-            thread::sleep(Duration::from_millis(50));
-        }
-
-        if let Some(key) = bkb_key {
-            info!("👻 SF Standalone: Using BKB ({})", key);
-            press_key(key);
-            thread::sleep(Duration::from_millis(30));
-            press_key(key);
-            thread::sleep(Duration::from_millis(50));
+        if auto_bkb_on_ultimate {
+            cast_bkb(&event, &slot_keys);
         }
 
         if auto_d_on_ultimate {
@@ -235,18 +248,42 @@ fn run_standalone_request(request: ShadowFiendRequest) {
     }
 }
 
+/// Shared auto-BKB, then the pause Requiem needs before D or R.
+fn cast_bkb(event: &GsiWebhookEvent, slot_keys: &[char; 6]) {
+    if auto_bkb(event, slot_keys) {
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
 pub struct ShadowFiendState;
 
 impl ShadowFiendState {
-    /// Execute a raze with ALT hold for direction facing.
-    pub fn execute_raze(raze_key: char, raze_delay_ms: u64) {
-        enqueue_shadow_fiend_request(build_raze_request(raze_key, raze_delay_ms));
+    /// Execute a raze after facing the cursor (ALT+right-click when `hold_alt`).
+    pub fn execute_raze(raze_key: char, raze_delay_ms: u64, hold_alt: bool) {
+        enqueue_shadow_fiend_request(build_raze_request(raze_key, raze_delay_ms, hold_alt));
+    }
+
+    /// Cast-on-release press: face the cursor once when `raze_key` goes down.
+    /// Returns false for key-repeat presses while the key is already held.
+    pub fn begin_held_raze(raze_key: char, hold_alt: bool) -> bool {
+        if !lock_or_reset(&HELD_RAZE_KEYS).press(raze_key) {
+            return false;
+        }
+        enqueue_shadow_fiend_request(ShadowFiendRequest::Face { hold_alt });
+        true
+    }
+
+    /// Cast-on-release release: clear the held state of `raze_key`. Returns
+    /// whether it was held; the caller then casts with [`Self::execute_raze`],
+    /// which faces the cursor again so the raze goes where the player aimed.
+    pub fn release_held_raze(raze_key: char) -> bool {
+        lock_or_reset(&HELD_RAZE_KEYS).release(raze_key)
     }
 
     /// Execute ultimate with optional D after the caller has decided to run the auto-BKB path.
     /// Sequence: BKB (if available) → D (if enabled) → R
-    pub fn execute_ultimate_combo(auto_d_on_ultimate: bool) {
-        enqueue_shadow_fiend_request(build_ultimate_request(auto_d_on_ultimate));
+    pub fn execute_ultimate_combo(auto_d_on_ultimate: bool, slot_keys: [char; 6]) {
+        enqueue_shadow_fiend_request(build_ultimate_request(auto_d_on_ultimate, slot_keys));
     }
 
     /// Execute standalone combo: Blink + Ultimate (with BKB/D if configured)
@@ -259,8 +296,9 @@ impl ShadowFiendState {
 /// Shadow Fiend script
 ///
 /// Raze interception flow:
-/// 1. keyboard.rs intercepts Q/W/E when SF is enabled (via app_state.sf_enabled)
-/// 2. Calls ShadowFiendState::execute_raze()
+/// 1. keyboard.rs intercepts the raze keys when SF is enabled (via app_state.sf_enabled)
+/// 2. Calls ShadowFiendState::execute_raze(); with `cast_on_key_release` the
+///    press only faces the cursor (begin_held_raze) and the release casts
 /// 3. execute_raze enqueues a request for the dedicated worker, which:
 ///    - Holds ALT (for cl_dota_alt_unit_movetodirection)
 ///    - Right-clicks to face direction
@@ -288,11 +326,11 @@ impl ShadowFiendScript {
 
 impl HeroScript for ShadowFiendScript {
     fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
 
         // Store last event for ultimate combo (BKB lookup)
         {
-            let mut last_event = SF_LAST_EVENT.lock().unwrap();
+            let mut last_event = lock_or_recover(&SF_LAST_EVENT);
             *last_event = Some(event.clone());
         }
 
@@ -307,7 +345,7 @@ impl HeroScript for ShadowFiendScript {
 
     fn handle_standalone_trigger(&self) {
         info!("👻 Shadow Fiend standalone combo triggered");
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         ShadowFiendState::execute_standalone_combo(&settings);
     }
 
@@ -327,16 +365,29 @@ mod tests {
 
     #[test]
     fn build_raze_request_preserves_key_and_delay() {
-        let request = build_raze_request('q', 120);
+        let request = build_raze_request('q', 120, true);
         assert_eq!(
             request,
             ShadowFiendRequest::Raze {
                 raze_key: 'q',
                 raze_delay_ms: 120,
+                hold_alt: true,
             }
         );
     }
 
+    #[test]
+    fn held_raze_keys_ignore_repeats_until_released() {
+        let mut held = HeldRazeKeys::default();
+        assert!(held.press('q'));
+        assert!(!held.press('q'));
+        assert!(held.press('w'));
+        assert!(held.release('q'));
+        assert!(!held.release('q'));
+        assert!(held.press('q'));
+        assert!(!held.release('e'));
+    }
+
     #[test]
     fn build_standalone_request_copies_runtime_flags_from_settings() {
         let mut settings = Settings::default();
@@ -349,28 +400,34 @@ mod tests {
             ShadowFiendRequest::Standalone {
                 auto_bkb_on_ultimate: true,
                 auto_d_on_ultimate: true,
+                slot_keys: ['z', 'x', 'c', 'v', 'b', 'n'],
             }
         );
     }
 
     #[test]
-    fn slot_to_common_key_maps_inventory_slots_consistently() {
-        assert_eq!(slot_to_common_key("slot0"), Some('z'));
-        assert_eq!(slot_to_common_key("slot5"), Some('n'));
-        assert_eq!(slot_to_common_key("neutral0"), None);
-    }
-
-    #[test]
-    fn slot_to_common_key_returns_none_for_unknown_slots() {
-        assert_eq!(slot_to_common_key("stash0"), None);
+    fn build_standalone_request_uses_configured_slot_keys() {
+        let mut settings = Settings::default();
+        settings.keybindings.slot0 = '1';
+        settings.keybindings.slot5 = '6';
+        let ShadowFiendRequest::Standalone { slot_keys, .. } = build_standalone_request(&settings)
+        else {
+            panic!("expected a standalone request");
+        };
+        assert_eq!(slot_keys[0], '1');
+        assert_eq!(slot_keys[5], '6');
     }
 
     #[test]
     fn build_ultimate_request_sets_auto_d_flag() {
-        let request = build_ultimate_request(true);
+        let slot_keys = ['1', '2', '3', '4', '5', '6'];
+        let request = build_ultimate_request(true, slot_keys);
         assert_eq!(
             request,
-            ShadowFiendRequest::Ultimate { auto_d_on_ultimate: true },
+            ShadowFiendRequest::Ultimate {
+                auto_d_on_ultimate: true,
+                slot_keys,
+            },
         );
     }
 
@@ -383,4 +440,40 @@ mod tests {
 
         assert!(matches!(request, ShadowFiendRequest::Standalone { .. }));
     }
+
+    #[test]
+    fn face_direction_and_cast_turns_before_pressing_the_ability() {
+        use crate::input::simulation::{
+            install_thread_input_sink, ModifierKey, RecordingInputSink, SyntheticAction,
+        };
+
+        let recorder = RecordingInputSink::default();
+        let _sink = install_thread_input_sink(recorder.clone());
+        let actions = |recorder: &RecordingInputSink| -> Vec<SyntheticAction> {
+            recorder
+                .actions()
+                .into_iter()
+                .map(|(action, _)| action)
+                .collect()
+        };
+
+        face_direction_and_cast('q', 0, true);
+        assert_eq!(
+            actions(&recorder),
+            vec![
+                SyntheticAction::ModifierDown(ModifierKey::Alt),
+                SyntheticAction::RightClick,
+                SyntheticAction::ModifierUp(ModifierKey::Alt),
+                SyntheticAction::KeyClick('q'),
+            ]
+        );
+
+        let recorder = RecordingInputSink::default();
+        let _sink = install_thread_input_sink(recorder.clone());
+        face_direction_and_cast('w', 0, false);
+        assert_eq!(
+            actions(&recorder),
+            vec![SyntheticAction::RightClick, SyntheticAction::KeyClick('w')]
+        );
+    }
 }
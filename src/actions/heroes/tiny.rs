@@ -1,10 +1,14 @@
 use crate::actions::heroes::traits::HeroScript;
-use crate::actions::common::{find_item_slot, SurvivabilityActions};
+use crate::actions::combo_control::combo_aborted;
+use crate::actions::common::{
+    combo_has_mana, find_item_slot, has_talent, SurvivabilityActions,
+};
 use crate::actions::executor::ActionExecutor;
 use crate::actions::soul_ring::press_ability_with_soul_ring;
 use crate::config::Settings;
 use crate::input::simulation::press_key;
 use crate::models::{GsiWebhookEvent, Hero, Item};
+use crate::state::poison::lock_or_recover;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -27,13 +31,28 @@ impl TinyScript {
     pub fn execute_combo(&self, event: &GsiWebhookEvent) {
         info!("Executing Tiny combo sequence...");
 
-        let settings = self.settings.lock().unwrap();
-        
+        let settings = lock_or_recover(&self.settings);
+        if !combo_has_mana(
+            event,
+            &settings,
+            "Tiny",
+            &["tiny_avalanche", "tiny_toss"],
+            &settings.heroes.tiny.combo_mana_costs,
+        ) {
+            return;
+        }
+        let timing = settings.heroes.tiny.combo_timing();
+        let toss_casts = if has_talent(event, settings.heroes.tiny.extra_toss_talent) {
+            2
+        } else {
+            1
+        };
+
         // 1. Blink Dagger
         if let Some(key) = find_item_slot(event, &settings, Item::Blink) {
             info!("Using Blink ({})", key);
             press_key(key);
-            thread::sleep(Duration::from_millis(100));
+            thread::sleep(Duration::from_millis(timing.post_blink_delay_ms));
         } else {
             warn!("Blink dagger not found in inventory");
         }
@@ -41,7 +60,7 @@ impl TinyScript {
         // 2. Avalanche (W) - with Soul Ring on first press, then spam
         info!("Using Avalanche (W)");
         press_ability_with_soul_ring('w', &settings);
-        for _ in 0..3 {
+        for _ in 0..timing.avalanche_spam_count {
             thread::sleep(Duration::from_millis(30));
             press_key('w');
         }
@@ -49,13 +68,25 @@ impl TinyScript {
         
         drop(settings); // Release settings lock after using it
 
-        // 3. Toss (Q) - spam to ensure cast
-        info!("Using Toss (Q)");
-        for _ in 0..4 {
-            press_key('q');
-            thread::sleep(Duration::from_millis(30));
+        if Self::aborted() {
+            return;
+        }
+
+        // 3. Toss (Q) - spam to ensure cast, twice with the extra Toss charge talent
+        for cast in 1..=toss_casts {
+            info!("Using Toss (Q) {}/{}", cast, toss_casts);
+            for _ in 0..timing.toss_spam_count {
+                press_key('q');
+                thread::sleep(Duration::from_millis(30));
+            }
+            // Toss flies for ~1.3s; landing inside Avalanche's damage ticks is the point
+            // of the combo, so the window must outlast the flight before the next step.
+            thread::sleep(Duration::from_millis(timing.toss_catch_window_ms));
+
+            if Self::aborted() {
+                return;
+            }
         }
-        thread::sleep(Duration::from_millis(1400));
 
         // 4. Tree Grab (D) - Aghanim's ability
         info!("Using Tree Grab (D)");
@@ -66,6 +97,14 @@ impl TinyScript {
 
         info!("Tiny combo sequence complete.");
     }
+
+    fn aborted() -> bool {
+        let aborted = combo_aborted();
+        if aborted {
+            info!("Tiny combo aborted");
+        }
+        aborted
+    }
 }
 
 impl HeroScript for TinyScript {
@@ -77,7 +116,7 @@ impl HeroScript for TinyScript {
         
         // Use common survivability actions (danger detection, healing, defensive items)
         let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
-        let settings = self.settings.lock().unwrap();
+        let settings = lock_or_recover(&self.settings);
         let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
         drop(settings);
         survivability.check_and_use_healing_items_with_danger(event, in_danger);
@@ -103,3 +142,39 @@ impl HeroScript for TinyScript {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+
+    fn fast_settings(extra_toss_talent: u32) -> Settings {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        settings.heroes.tiny.toss_catch_window_ms = 0;
+        settings.heroes.tiny.extra_toss_talent = extra_toss_talent;
+        settings
+    }
+
+    fn combo_event(talent_8: bool) -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+        event.hero.talent_8 = talent_8;
+        event
+    }
+
+    fn toss_presses(settings: Settings, event: &GsiWebhookEvent) -> usize {
+        let script = TinyScript::new(Arc::new(Mutex::new(settings)), ActionExecutor::new());
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        script.execute_combo(event);
+        recorder.key_clicks().iter().filter(|&&key| key == 'q').count()
+    }
+
+    #[test]
+    fn extra_toss_talent_adds_second_toss_only_when_picked() {
+        assert_eq!(toss_presses(fast_settings(8), &combo_event(true)), 8);
+        assert_eq!(toss_presses(fast_settings(8), &combo_event(false)), 4);
+        assert_eq!(toss_presses(fast_settings(0), &combo_event(true)), 4);
+    }
+}
@@ -11,6 +11,10 @@ pub trait HeroScript: Send + Sync {
 
     /// Get hero name for dispatcher routing
     fn hero_name(&self) -> &'static str;
+
+    /// Clear per-script cooldowns and cached events left from a previous
+    /// game. Called by [`crate::actions::ActionDispatcher::reset`].
+    fn reset(&self) {}
     
     /// Allow downcasting to concrete types
     fn as_any(&self) -> &dyn Any;
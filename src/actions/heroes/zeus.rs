@@ -0,0 +1,259 @@
+//! Zeus: Arc Lightning spam on cooldown and a manual Thundergod's Wrath.
+//!
+//! On GSI events, while the player is attacking, the first ready spell of
+//! [`spam_engine`] is cast: Arc Lightning, plus Lightning Bolt with
+//! `spam_lightning_bolt`. GSI does not report attack orders, so "attacking"
+//! means a right-click or `common.attack_key` press within
+//! `attack_window_ms` ([`attack_order_age`]). GSI does not report enemy HP
+//! either, so Thundergod's Wrath is the standalone trigger: the player
+//! presses it when enemies are low, and it is followed by one run of the
+//! spam. Arc Lightning and Bolt are quick-cast at the cursor, so both need
+//! quick-cast enabled in Dota.
+
+use crate::actions::auto_items::latest_gsi_event;
+use crate::actions::common::{smoke_quiet, SurvivabilityActions};
+use crate::actions::executor::ActionExecutor;
+use crate::actions::heroes::traits::HeroScript;
+use crate::actions::item_automation::try_acquire_global_lockout;
+use crate::actions::priority_combo::{ComboEntry, ComboStep, PriorityComboEngine, RecentCasts};
+use crate::actions::soul_ring::press_ability_with_soul_ring;
+use crate::config::{Settings, ZeusConfig};
+use crate::input::keyboard::attack_order_age;
+use crate::models::{GsiWebhookEvent, Hero};
+use crate::state::poison::lock_or_recover;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+const ARC_LIGHTNING_ABILITY_NAME: &str = "zuus_arc_lightning";
+const LIGHTNING_BOLT_ABILITY_NAME: &str = "zuus_lightning_bolt";
+const THUNDERGODS_WRATH_ABILITY_NAME: &str = "zuus_thundergods_wrath";
+
+/// GSI shows the new cooldown a frame late; do not press again before it does.
+const AUTO_SPAM_LOCKOUT_MS: u64 = 500;
+
+/// Spam engine for `config`: Lightning Bolt before Arc Lightning, since Bolt
+/// has the longer cooldown. Bolt and Arc are unordered, so
+/// `randomization.shuffle_unordered` may swap them.
+pub fn spam_engine(config: &ZeusConfig) -> PriorityComboEngine {
+    let mut entries = Vec::new();
+    if config.spam_lightning_bolt {
+        entries.push(
+            ComboEntry::ability(LIGHTNING_BOLT_ABILITY_NAME, config.lightning_bolt_key).unordered(),
+        );
+    }
+    entries.push(
+        ComboEntry::ability(ARC_LIGHTNING_ABILITY_NAME, config.arc_lightning_key).unordered(),
+    );
+
+    PriorityComboEngine::new(entries)
+        .with_mana_reserve(config.mana_reserve)
+        .with_randomization(&config.randomization)
+}
+
+/// Key to press for the on-cooldown spam on this event, if any.
+/// `attack_age` is how long ago the player last issued an attack order.
+fn auto_spam_key(
+    event: &GsiWebhookEvent,
+    settings: &Settings,
+    attack_age: Option<Duration>,
+    now: Instant,
+) -> Option<char> {
+    let config = &settings.heroes.zeus;
+    let attacking =
+        attack_age.is_some_and(|age| age <= Duration::from_millis(config.attack_window_ms));
+    if !config.auto_spam_while_attacking || !attacking {
+        return None;
+    }
+    let hero = &event.hero;
+    if !hero.is_alive() || hero.is_stunned() || hero.silenced {
+        return None;
+    }
+    match spam_engine(config).next_step(event, settings, &RecentCasts::new(), now) {
+        Some(ComboStep::Cast { key, .. }) => Some(key),
+        _ => None,
+    }
+}
+
+/// Thundergod's Wrath key if GSI reports it ready.
+fn wrath_key(event: &GsiWebhookEvent, config: &ZeusConfig) -> Option<char> {
+    event
+        .abilities
+        .find_by_name(THUNDERGODS_WRATH_ABILITY_NAME)
+        .filter(|ability| ability.level > 0 && ability.can_cast && ability.cooldown == 0)
+        .map(|_| config.thundergods_wrath_key)
+}
+
+pub struct ZeusScript {
+    settings: Arc<Mutex<Settings>>,
+    executor: Arc<ActionExecutor>,
+}
+
+impl ZeusScript {
+    pub fn new(settings: Arc<Mutex<Settings>>, executor: Arc<ActionExecutor>) -> Self {
+        Self { settings, executor }
+    }
+
+    fn maybe_spam(&self, event: &GsiWebhookEvent, settings: &Settings) {
+        if smoke_quiet(event, settings) {
+            return;
+        }
+        let Some(key) = auto_spam_key(event, settings, attack_order_age(), Instant::now()) else {
+            return;
+        };
+
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        if !try_acquire_global_lockout("zeus:auto_spam", now_ms, AUTO_SPAM_LOCKOUT_MS) {
+            return;
+        }
+
+        let settings = settings.clone();
+        self.executor.enqueue("zeus-auto-spam", move || {
+            info!("⚡ Zeus spam ({})", key);
+            press_ability_with_soul_ring(key, &settings);
+        });
+    }
+}
+
+impl HeroScript for ZeusScript {
+    fn handle_gsi_event(&self, event: &GsiWebhookEvent) {
+        let survivability = SurvivabilityActions::new(self.settings.clone(), self.executor.clone());
+        let settings = lock_or_recover(&self.settings);
+        let in_danger = crate::actions::danger_detector::update(event, &settings.danger_detection);
+        self.maybe_spam(event, &settings);
+        drop(settings);
+        survivability.check_and_use_healing_items_with_danger(event, in_danger);
+        survivability.use_defensive_items_if_danger_with_snapshot(event, in_danger);
+        survivability.use_neutral_item_if_danger_with_snapshot(event, in_danger);
+    }
+
+    fn handle_standalone_trigger(&self) {
+        let settings = lock_or_recover(&self.settings).clone();
+        let config = &settings.heroes.zeus;
+        match latest_gsi_event().and_then(|(event, _)| wrath_key(&event, config)) {
+            Some(key) => {
+                info!("⚡ Thundergod's Wrath ({})", key);
+                press_ability_with_soul_ring(key, &settings);
+            }
+            None => info!("⚡ Thundergod's Wrath not ready"),
+        }
+        let steps = spam_engine(config).run(&settings, latest_gsi_event);
+        info!("⚡ Zeus combo finished after {} spam casts", steps);
+    }
+
+    fn hero_name(&self) -> &'static str {
+        Hero::Zuus.to_game_name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+    use crate::models::gsi_event::Ability;
+
+    fn zeus_event(mana: u32) -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+
+        let ability = |name: &str| Ability {
+            ability_active: true,
+            can_cast: true,
+            cooldown: 0,
+            level: 1,
+            name: name.to_string(),
+            passive: false,
+            ultimate: false,
+        };
+        event.abilities.ability0 = ability(ARC_LIGHTNING_ABILITY_NAME);
+        event.abilities.ability1 = ability(LIGHTNING_BOLT_ABILITY_NAME);
+        event.abilities.ability5 = ability(THUNDERGODS_WRATH_ABILITY_NAME);
+        event.hero.mana = mana;
+        event
+    }
+
+    fn casts(config: &ZeusConfig, event: &GsiWebhookEvent) -> Vec<char> {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        spam_engine(config)
+            .with_step_delay(0)
+            .with_recast_guard(60_000)
+            .with_max_steps(3)
+            .run(&settings, || Some((event.clone(), Duration::ZERO)));
+        recorder.key_clicks()
+    }
+
+    fn spam_key(settings: &Settings, event: &GsiWebhookEvent, age_ms: Option<u64>) -> Option<char> {
+        auto_spam_key(
+            event,
+            settings,
+            age_ms.map(Duration::from_millis),
+            Instant::now(),
+        )
+    }
+
+    #[test]
+    fn spam_casts_arc_and_only_adds_bolt_when_enabled() {
+        let mut config = ZeusConfig::default();
+        assert_eq!(casts(&config, &zeus_event(1000)), vec!['q']);
+
+        config.spam_lightning_bolt = true;
+        assert_eq!(casts(&config, &zeus_event(1000)), vec!['w', 'q']);
+    }
+
+    #[test]
+    fn mana_reserve_stops_the_spam() {
+        let config = ZeusConfig {
+            mana_reserve: 400,
+            ..ZeusConfig::default()
+        };
+        assert!(casts(&config, &zeus_event(400)).is_empty());
+    }
+
+    #[test]
+    fn auto_spam_only_fires_within_the_attack_window() {
+        let settings = Settings::default();
+        let event = zeus_event(1000);
+
+        assert_eq!(spam_key(&settings, &event, Some(200)), Some('q'));
+        assert_eq!(spam_key(&settings, &event, Some(5_000)), None);
+        assert_eq!(spam_key(&settings, &event, None), None);
+    }
+
+    #[test]
+    fn auto_spam_respects_toggle_silence_and_cooldown() {
+        let mut settings = Settings::default();
+        let mut event = zeus_event(1000);
+
+        event.hero.silenced = true;
+        assert_eq!(spam_key(&settings, &event, Some(200)), None);
+
+        event.hero.silenced = false;
+        event.abilities.ability0.cooldown = 3;
+        assert_eq!(spam_key(&settings, &event, Some(200)), None);
+
+        event.abilities.ability0.cooldown = 0;
+        settings.heroes.zeus.auto_spam_while_attacking = false;
+        assert_eq!(spam_key(&settings, &event, Some(200)), None);
+    }
+
+    #[test]
+    fn wrath_key_requires_a_ready_ultimate() {
+        let config = ZeusConfig::default();
+        let mut event = zeus_event(1000);
+        assert_eq!(wrath_key(&event, &config), Some('r'));
+
+        event.abilities.ability5.cooldown = 90;
+        assert_eq!(wrath_key(&event, &config), None);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::models::Hero;
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -145,8 +147,13 @@ pub fn lookup_item_automation(item_name: &str) -> Option<&'static ItemAutomation
         .find(|spec| spec.item_name == item_name)
 }
 
+/// `excluded_heroes` entries may use any hero alias (`npc_dota_hero_necrolyte`,
+/// `necrophos`, `Necrophos`).
 pub fn hero_is_excluded(hero_name: &str, excluded_heroes: &[String]) -> bool {
-    excluded_heroes.iter().any(|excluded| excluded == hero_name)
+    let hero = Hero::from_alias(hero_name);
+    excluded_heroes.iter().any(|excluded| {
+        excluded == hero_name || hero.is_some_and(|hero| Hero::from_alias(excluded) == Some(hero))
+    })
 }
 
 #[derive(Debug, Default)]
@@ -173,15 +180,12 @@ lazy_static! {
 }
 
 pub fn try_acquire_global_lockout(key: &str, now_ms: u64, lockout_ms: u64) -> bool {
-    GLOBAL_TRIGGER_LOCKOUTS
-        .lock()
-        .unwrap()
-        .try_acquire(key, now_ms, lockout_ms)
+    lock_or_recover(&GLOBAL_TRIGGER_LOCKOUTS).try_acquire(key, now_ms, lockout_ms)
 }
 
-#[cfg(test)]
-pub fn reset_global_lockouts_for_tests() {
-    *GLOBAL_TRIGGER_LOCKOUTS.lock().unwrap() = TriggerLockoutState::default();
+/// Release every trigger lockout.
+pub fn reset() {
+    *lock_or_recover(&GLOBAL_TRIGGER_LOCKOUTS) = TriggerLockoutState::default();
 }
 
 #[cfg(test)]
@@ -228,6 +232,11 @@ mod tests {
         let excluded = vec!["npc_dota_hero_huskar".to_string()];
         assert!(hero_is_excluded("npc_dota_hero_huskar", &excluded));
         assert!(!hero_is_excluded("npc_dota_hero_zuus", &excluded));
+
+        let excluded = vec!["Necrophos".to_string(), "windranger".to_string()];
+        assert!(hero_is_excluded("npc_dota_hero_necrolyte", &excluded));
+        assert!(hero_is_excluded("npc_dota_hero_windrunner", &excluded));
+        assert!(!hero_is_excluded("npc_dota_hero_nevermore", &excluded));
     }
 
     #[test]
@@ -238,4 +247,14 @@ mod tests {
         assert!(!state.try_acquire("danger:item_jidi_pollen_bag", 1_050, 120));
         assert!(state.try_acquire("danger:item_jidi_pollen_bag", 1_200, 120));
     }
+
+    #[test]
+    fn reset_releases_global_lockouts() {
+        assert!(try_acquire_global_lockout("test:reset", 1_000, 60_000));
+        assert!(!try_acquire_global_lockout("test:reset", 1_050, 60_000));
+
+        reset();
+
+        assert!(try_acquire_global_lockout("test:reset", 1_100, 60_000));
+    }
 }
@@ -1,13 +1,18 @@
 pub mod activity;
 pub mod armlet;
 pub mod auto_items;
+pub mod combo_control;
 pub mod common;
+pub mod consumables;
 pub mod danger_detector;
 pub mod dispel;
 pub mod dispatcher;
 pub mod executor;
 pub mod heroes;
 pub mod item_automation;
+pub mod morph_tracker;
+pub mod position_tracker;
+pub mod priority_combo;
 pub mod soul_ring;
 
 pub use dispatcher::ActionDispatcher;
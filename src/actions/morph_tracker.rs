@@ -0,0 +1,168 @@
+//! Morphling Attribute Shift detection, from `hero.max_health` /
+//! `hero.max_mana`.
+//!
+//! Attribute Shift moves Strength into Agility (or back) a point at a time,
+//! so max HP, and current HP with it, falls or climbs every frame while it is
+//! toggled on. GSI has no flag for the toggle, so [`update`] counts frames
+//! where max HP moved while max mana and level stayed put; a level-up or an
+//! item changes several of those at once and only once. Several such frames
+//! close together mean a shift is running, and [`attribute_shift`] reports
+//! its direction so survivability can tell a shrinking HP pool from damage.
+
+use crate::models::GsiWebhookEvent;
+use crate::state::poison::lock_or_reset;
+use lazy_static::lazy_static;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const MORPHLING: &str = "npc_dota_hero_morphling";
+
+/// Shift steps are counted over this long.
+const SHIFT_WINDOW: Duration = Duration::from_millis(1500);
+/// Steps inside [`SHIFT_WINDOW`] before a shift is assumed to be running.
+const SHIFT_MIN_STEPS: usize = 3;
+
+/// Which way Attribute Shift is moving points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftDirection {
+    /// Agility into Strength: max HP rising.
+    ToStrength,
+    /// Strength into Agility: max HP falling.
+    ToAgility,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pools {
+    max_health: u32,
+    max_mana: u32,
+    level: u32,
+}
+
+#[derive(Debug, Default)]
+struct MorphTracker {
+    last: Option<Pools>,
+    /// `(when, direction)` of each shift-like frame, oldest first.
+    steps: VecDeque<(Instant, ShiftDirection)>,
+}
+
+impl MorphTracker {
+    fn record(&mut self, pools: Pools, now: Instant) {
+        if let Some(last) = self.last {
+            let shift_like = pools.max_health != last.max_health
+                && pools.max_mana == last.max_mana
+                && pools.level == last.level;
+            if shift_like {
+                let direction = if pools.max_health > last.max_health {
+                    ShiftDirection::ToStrength
+                } else {
+                    ShiftDirection::ToAgility
+                };
+                self.steps.push_back((now, direction));
+            }
+        }
+        self.last = Some(pools);
+        while self
+            .steps
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > SHIFT_WINDOW)
+        {
+            self.steps.pop_front();
+        }
+    }
+
+    fn shift(&self, now: Instant) -> Option<ShiftDirection> {
+        let recent: Vec<ShiftDirection> = self
+            .steps
+            .iter()
+            .filter(|(at, _)| now.saturating_duration_since(*at) <= SHIFT_WINDOW)
+            .map(|(_, direction)| *direction)
+            .collect();
+        let latest = *recent.last()?;
+        (recent.len() >= SHIFT_MIN_STEPS).then_some(latest)
+    }
+}
+
+lazy_static! {
+    static ref MORPH_TRACKER: Mutex<MorphTracker> = Mutex::new(MorphTracker::default());
+}
+
+/// Record this event's HP and mana pools. Runs once per GSI event from the
+/// dispatcher; other heroes clear the tracker.
+pub fn update(event: &GsiWebhookEvent) {
+    let mut tracker = lock_or_reset(&MORPH_TRACKER);
+    if event.hero.name != MORPHLING || !event.hero.is_alive() {
+        *tracker = MorphTracker::default();
+        return;
+    }
+    tracker.record(
+        Pools {
+            max_health: event.hero.max_health,
+            max_mana: event.hero.max_mana,
+            level: event.hero.level,
+        },
+        Instant::now(),
+    );
+}
+
+/// Direction of the Attribute Shift running right now, if any.
+pub fn attribute_shift() -> Option<ShiftDirection> {
+    lock_or_reset(&MORPH_TRACKER).shift(Instant::now())
+}
+
+/// Forget the pool history; a shift is not detected again until it has run
+/// for a few frames.
+pub fn reset() {
+    *lock_or_reset(&MORPH_TRACKER) = MorphTracker::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pools(max_health: u32, max_mana: u32, level: u32) -> Pools {
+        Pools {
+            max_health,
+            max_mana,
+            level,
+        }
+    }
+
+    #[test]
+    fn sustained_max_hp_changes_read_as_a_shift() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut tracker = MorphTracker::default();
+
+        tracker.record(pools(1800, 700, 15), at(0));
+        tracker.record(pools(1760, 700, 15), at(100));
+        tracker.record(pools(1720, 700, 15), at(200));
+        assert_eq!(tracker.shift(at(200)), None, "two steps are not enough");
+
+        tracker.record(pools(1680, 700, 15), at(300));
+        assert_eq!(tracker.shift(at(300)), Some(ShiftDirection::ToAgility));
+
+        tracker.record(pools(1720, 700, 15), at(400));
+        assert_eq!(tracker.shift(at(400)), Some(ShiftDirection::ToStrength));
+
+        // Shift toggled off: the steps age out.
+        tracker.record(pools(1720, 700, 15), at(2000));
+        assert_eq!(tracker.shift(at(2000)), None);
+    }
+
+    #[test]
+    fn level_ups_and_items_are_not_shift_steps() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut tracker = MorphTracker::default();
+
+        tracker.record(pools(1800, 700, 15), at(0));
+        tracker.record(pools(1850, 720, 16), at(100));
+        tracker.record(pools(2100, 720, 16), at(200));
+        tracker.record(pools(2100, 970, 16), at(300));
+        tracker.record(pools(2400, 1220, 16), at(400));
+
+        assert_eq!(tracker.steps.len(), 1, "only the lone max HP change counts");
+        assert_eq!(tracker.shift(at(400)), None);
+    }
+}
@@ -0,0 +1,175 @@
+//! Hero movement between GSI frames, from `hero.xpos` / `hero.ypos`.
+//!
+//! [`update`] runs once per GSI event from the dispatcher and keeps the last
+//! position; [`current_movement`] and [`is_retreating`] expose the result to
+//! automation that should hold off while the hero runs home.
+//!
+//! GSI reports `(0, 0)` while the hero is dead or not yet spawned, and frames
+//! can arrive seconds apart, so those reset the baseline instead of producing
+//! a bogus speed. Jumps faster than any run speed (Blink, teleports) are
+//! dropped the same way.
+
+use crate::models::GsiWebhookEvent;
+use crate::state::poison::lock_or_reset;
+use lazy_static::lazy_static;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Approximate fountain positions in GSI world coordinates.
+const RADIANT_FOUNTAIN: (f32, f32) = (-7450.0, -6950.0);
+const DIRE_FOUNTAIN: (f32, f32) = (7400.0, 6850.0);
+
+/// Frames further apart than this are too stale to derive movement from.
+const MAX_FRAME_GAP: Duration = Duration::from_secs(2);
+/// Faster than any run speed; anything above is Blink, a teleport or respawn.
+const MAX_RUN_SPEED: f32 = 1200.0;
+/// Slower than this is standing around, not retreating.
+const RETREAT_MIN_SPEED: f32 = 150.0;
+/// Cosine between heading and the way home above which the hero counts as
+/// heading home (about 45°).
+const RETREAT_MIN_ALIGNMENT: f32 = 0.7;
+
+/// Hero movement over the last GSI frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Movement {
+    /// Units per second.
+    pub speed: f32,
+    /// Unit heading vector.
+    pub direction: (f32, f32),
+    /// Cosine between the heading and the way to the own fountain, when the
+    /// team is known (1.0 = straight home, -1.0 = straight away).
+    pub homeward: Option<f32>,
+}
+
+impl Movement {
+    pub fn is_retreating(&self) -> bool {
+        self.speed >= RETREAT_MIN_SPEED
+            && self
+                .homeward
+                .is_some_and(|alignment| alignment >= RETREAT_MIN_ALIGNMENT)
+    }
+}
+
+#[derive(Debug, Default)]
+struct PositionTracker {
+    last: Option<((i32, i32), Instant)>,
+    movement: Option<Movement>,
+}
+
+lazy_static! {
+    static ref POSITION_TRACKER: Mutex<PositionTracker> = Mutex::new(PositionTracker::default());
+}
+
+fn fountain_for_team(team_name: &str) -> Option<(f32, f32)> {
+    match team_name.to_ascii_lowercase().as_str() {
+        "radiant" => Some(RADIANT_FOUNTAIN),
+        "dire" => Some(DIRE_FOUNTAIN),
+        _ => None,
+    }
+}
+
+/// Movement from `previous` to `current` over `elapsed`, or `None` when the
+/// frames can't be trusted (no elapsed time, stale, or a jump).
+pub fn movement_between(
+    previous: (i32, i32),
+    current: (i32, i32),
+    elapsed: Duration,
+    fountain: Option<(f32, f32)>,
+) -> Option<Movement> {
+    if elapsed.is_zero() || elapsed > MAX_FRAME_GAP {
+        return None;
+    }
+    let dx = (current.0 - previous.0) as f32;
+    let dy = (current.1 - previous.1) as f32;
+    let distance = dx.hypot(dy);
+    let speed = distance / elapsed.as_secs_f32();
+    if speed > MAX_RUN_SPEED {
+        return None;
+    }
+    if distance == 0.0 {
+        return Some(Movement {
+            speed: 0.0,
+            direction: (0.0, 0.0),
+            homeward: None,
+        });
+    }
+
+    let direction = (dx / distance, dy / distance);
+    let homeward = fountain.and_then(|(fx, fy)| {
+        let hx = fx - current.0 as f32;
+        let hy = fy - current.1 as f32;
+        let home_distance = hx.hypot(hy);
+        (home_distance > 0.0)
+            .then(|| (direction.0 * hx + direction.1 * hy) / home_distance)
+    });
+
+    Some(Movement {
+        speed,
+        direction,
+        homeward,
+    })
+}
+
+/// Record this event's hero position and refresh the movement estimate.
+pub fn update(event: &GsiWebhookEvent) {
+    let mut tracker = lock_or_reset(&POSITION_TRACKER);
+
+    let position = (event.hero.xpos, event.hero.ypos);
+    if !event.hero.is_alive() || position == (0, 0) {
+        *tracker = PositionTracker::default();
+        return;
+    }
+
+    let now = Instant::now();
+    let fountain = event
+        .player
+        .as_ref()
+        .and_then(|player| player.team_name.as_deref())
+        .and_then(fountain_for_team);
+    tracker.movement = tracker.last.and_then(|(previous, at)| {
+        movement_between(previous, position, now.duration_since(at), fountain)
+    });
+    tracker.last = Some((position, now));
+}
+
+/// Forget the last position; movement is unknown until two more frames.
+pub fn reset() {
+    *lock_or_reset(&POSITION_TRACKER) = PositionTracker::default();
+}
+
+/// Movement over the last GSI frame, if it could be measured.
+pub fn current_movement() -> Option<Movement> {
+    lock_or_reset(&POSITION_TRACKER).movement
+}
+
+/// Whether the hero is clearly running toward its own fountain.
+pub fn is_retreating() -> bool {
+    current_movement().is_some_and(|movement| movement.is_retreating())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_home_counts_as_retreating_but_blinks_and_stale_frames_do_not() {
+        let frame = Duration::from_millis(200);
+        let home = Some(RADIANT_FOUNTAIN);
+
+        let retreat = movement_between((0, 0), (-50, -50), frame, home).unwrap();
+        assert!((retreat.speed - 353.55).abs() < 0.1);
+        assert!(retreat.is_retreating());
+
+        let chase = movement_between((0, 0), (50, 50), frame, home).unwrap();
+        assert!(!chase.is_retreating());
+
+        let unknown_team = movement_between((0, 0), (-50, -50), frame, None).unwrap();
+        assert!(!unknown_team.is_retreating());
+
+        let standing = movement_between((0, 0), (-5, -5), frame, home).unwrap();
+        assert!(!standing.is_retreating());
+
+        assert_eq!(movement_between((0, 0), (-1000, -1000), frame, home), None);
+        assert_eq!(movement_between((0, 0), (-50, -50), Duration::from_secs(3), home), None);
+    }
+}
@@ -0,0 +1,492 @@
+//! Priority combo loop for spell-spam heroes.
+//!
+//! A [`PriorityComboEngine`] holds an ordered list of abilities and items.
+//! Each step casts the highest-priority entry GSI reports ready; when nothing
+//! is ready it casts the optional fallback (a refresher-style ability) and
+//! starts over, otherwise the loop ends.
+//!
+//! With a mana reserve the entries are skipped once the hero's mana is at or
+//! below it, so the fallback (usually the ultimate) can still be afforded.
+//!
+//! GSI only shows a new cooldown on its next POST, so an entry that was just
+//! cast is skipped for `recast_guard` instead of being pressed again. The
+//! loop stops rather than act on an event older than
+//! `common.combo_max_event_age_ms`, and waits `common.combo_fallback_settle_ms`
+//! after the fallback for GSI to catch up.
+//!
+//! [`PriorityComboEngine::with_randomization`] varies each run: the step
+//! delay is jittered, and entries marked [`ComboEntry::unordered`] that sit
+//! next to each other are picked at random among the ready ones instead of
+//! strictly by priority.
+
+use crate::actions::combo_control::combo_aborted;
+use crate::actions::soul_ring::press_ability_with_soul_ring;
+use crate::config::settings::ComboRandomizationConfig;
+use crate::config::Settings;
+use crate::input::simulation::press_key;
+use crate::models::GsiWebhookEvent;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+const DEFAULT_STEP_DELAY_MS: u64 = 50;
+const DEFAULT_RECAST_GUARD_MS: u64 = 500;
+const DEFAULT_MAX_STEPS: usize = 32;
+
+/// One castable thing in the priority list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComboEntry {
+    /// Ability by internal name (e.g. `zuus_arc_lightning`), cast with `key`.
+    Ability {
+        name: String,
+        key: char,
+        unordered: bool,
+    },
+    /// Item by internal name (partial match), cast with its slot key.
+    Item { name: String, unordered: bool },
+}
+
+impl ComboEntry {
+    pub fn ability(name: &str, key: char) -> Self {
+        Self::Ability {
+            name: name.to_string(),
+            key,
+            unordered: false,
+        }
+    }
+
+    pub fn item(name: &str) -> Self {
+        Self::Item {
+            name: name.to_string(),
+            unordered: false,
+        }
+    }
+
+    /// Let this entry trade places with adjacent unordered entries when the
+    /// engine shuffles them.
+    pub fn unordered(mut self) -> Self {
+        match &mut self {
+            Self::Ability { unordered, .. } | Self::Item { unordered, .. } => *unordered = true,
+        }
+        self
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Ability { name, .. } | Self::Item { name, .. } => name,
+        }
+    }
+
+    fn is_unordered(&self) -> bool {
+        match self {
+            Self::Ability { unordered, .. } | Self::Item { unordered, .. } => *unordered,
+        }
+    }
+
+    /// Key to press if GSI reports this entry ready.
+    fn ready_key(&self, event: &GsiWebhookEvent, settings: &Settings) -> Option<char> {
+        match self {
+            Self::Ability { name, key, .. } => event
+                .abilities
+                .find_by_name(name)
+                .filter(|ability| ability.level > 0 && ability.can_cast && ability.cooldown == 0)
+                .map(|_| *key),
+            Self::Item { name, .. } => event
+                .items
+                .all_slots()
+                .into_iter()
+                .find(|(_, item)| item.name.contains(name.as_str()) && item.is_ready())
+                .and_then(|(slot, _)| settings.get_key_for_slot(slot)),
+        }
+    }
+}
+
+/// What the engine wants to do next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComboStep {
+    /// Cast `entries[index]`.
+    Cast { index: usize, key: char },
+    /// Nothing in the list is ready; cast the fallback.
+    Fallback { key: char },
+}
+
+/// Entries cast during this run, by index (`entries.len()` is the fallback).
+pub type RecentCasts = HashMap<usize, Instant>;
+
+#[derive(Debug, Clone)]
+pub struct PriorityComboEngine {
+    entries: Vec<ComboEntry>,
+    fallback: Option<ComboEntry>,
+    step_delay: Duration,
+    recast_guard: Duration,
+    max_steps: usize,
+    mana_reserve: u32,
+    step_jitter: Duration,
+    shuffle_unordered: bool,
+}
+
+impl PriorityComboEngine {
+    /// Engine over `entries`, highest priority first.
+    pub fn new(entries: Vec<ComboEntry>) -> Self {
+        Self {
+            entries,
+            fallback: None,
+            step_delay: Duration::from_millis(DEFAULT_STEP_DELAY_MS),
+            recast_guard: Duration::from_millis(DEFAULT_RECAST_GUARD_MS),
+            max_steps: DEFAULT_MAX_STEPS,
+            mana_reserve: 0,
+            step_jitter: Duration::ZERO,
+            shuffle_unordered: false,
+        }
+    }
+
+    /// Cast `fallback` when every entry is on cooldown.
+    pub fn with_fallback(mut self, fallback: ComboEntry) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    pub fn with_step_delay(mut self, delay_ms: u64) -> Self {
+        self.step_delay = Duration::from_millis(delay_ms);
+        self
+    }
+
+    pub fn with_recast_guard(mut self, guard_ms: u64) -> Self {
+        self.recast_guard = Duration::from_millis(guard_ms);
+        self
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Skip every entry but the fallback while hero mana is at or below
+    /// `mana`. 0 disables the reserve.
+    pub fn with_mana_reserve(mut self, mana: u32) -> Self {
+        self.mana_reserve = mana;
+        self
+    }
+
+    /// Move each step delay by up to `jitter_ms` either way, and with
+    /// `shuffle_unordered` pick among ready adjacent unordered entries at
+    /// random.
+    pub fn with_randomization(mut self, config: &ComboRandomizationConfig) -> Self {
+        self.step_jitter = Duration::from_millis(config.jitter_ms);
+        self.shuffle_unordered = config.shuffle_unordered;
+        self
+    }
+
+    fn guarded(&self, recent: &RecentCasts, index: usize, now: Instant) -> bool {
+        recent
+            .get(&index)
+            .is_some_and(|cast_at| now.saturating_duration_since(*cast_at) < self.recast_guard)
+    }
+
+    /// Pick the next step for `event`, skipping entries cast within the
+    /// recast guard.
+    pub fn next_step(
+        &self,
+        event: &GsiWebhookEvent,
+        settings: &Settings,
+        recent: &RecentCasts,
+        now: Instant,
+    ) -> Option<ComboStep> {
+        self.pick_step(event, settings, recent, now, &mut rand::rng())
+    }
+
+    fn pick_step(
+        &self,
+        event: &GsiWebhookEvent,
+        settings: &Settings,
+        recent: &RecentCasts,
+        now: Instant,
+        rng: &mut impl Rng,
+    ) -> Option<ComboStep> {
+        let reserved = self.mana_reserve > 0 && event.hero.mana <= self.mana_reserve;
+        let castable = |index: usize| {
+            if reserved || self.guarded(recent, index, now) {
+                return None;
+            }
+            self.entries[index]
+                .ready_key(event, settings)
+                .map(|key| ComboStep::Cast { index, key })
+        };
+        let cast = (0..self.entries.len()).find_map(castable);
+        if let Some(ComboStep::Cast { index, .. }) = cast {
+            if self.shuffle_unordered && self.entries[index].is_unordered() {
+                // Higher-priority entries of the run were not castable, so
+                // the choice is between this one and the run after it.
+                let run: Vec<ComboStep> = (index..self.entries.len())
+                    .take_while(|index| self.entries[*index].is_unordered())
+                    .filter_map(castable)
+                    .collect();
+                return run.choose(rng).cloned();
+            }
+        }
+        if cast.is_some() {
+            return cast;
+        }
+
+        let fallback = self.fallback.as_ref()?;
+        if self.guarded(recent, self.entries.len(), now) {
+            return None;
+        }
+        fallback
+            .ready_key(event, settings)
+            .map(|key| ComboStep::Fallback { key })
+    }
+
+    /// Run the loop against the freshest event and its age from
+    /// `latest_event` (usually `auto_items::latest_gsi_event`) until nothing
+    /// is ready, the event is stale, the combo is aborted, or `max_steps` is
+    /// reached.
+    pub fn run(
+        &self,
+        settings: &Settings,
+        latest_event: impl Fn() -> Option<(GsiWebhookEvent, Duration)>,
+    ) -> usize {
+        let max_age = Duration::from_millis(settings.common.combo_max_event_age_ms);
+        let fallback_settle = Duration::from_millis(settings.common.combo_fallback_settle_ms);
+        let mut recent = RecentCasts::new();
+        let mut steps = 0;
+
+        while steps < self.max_steps {
+            if combo_aborted() {
+                info!("⚡ Priority combo aborted after {} steps", steps);
+                return steps;
+            }
+            let Some((event, age)) = latest_event() else {
+                debug!("⚡ No GSI state for priority combo");
+                return steps;
+            };
+            if !max_age.is_zero() && age > max_age {
+                info!(
+                    "⚡ Priority combo skipped: GSI state is {}ms old (max {}ms)",
+                    age.as_millis(),
+                    max_age.as_millis()
+                );
+                return steps;
+            }
+
+            let now = Instant::now();
+            match self.next_step(&event, settings, &recent, now) {
+                Some(ComboStep::Cast { index, key }) => {
+                    let entry = &self.entries[index];
+                    info!("⚡ Priority combo: {} ({})", entry.name(), key);
+                    match entry {
+                        ComboEntry::Ability { .. } => press_ability_with_soul_ring(key, settings),
+                        ComboEntry::Item { .. } => press_key(key),
+                    }
+                    recent.insert(index, now);
+                    thread::sleep(self.jittered_step_delay(&mut rand::rng()));
+                }
+                Some(ComboStep::Fallback { key }) => {
+                    info!("⚡ Priority combo: everything on cooldown, fallback ({})", key);
+                    press_ability_with_soul_ring(key, settings);
+                    recent.clear();
+                    recent.insert(self.entries.len(), now);
+                    thread::sleep(fallback_settle);
+                }
+                None => {
+                    debug!("⚡ Priority combo: nothing ready after {} steps", steps);
+                    return steps;
+                }
+            }
+            steps += 1;
+        }
+
+        steps
+    }
+
+    fn jittered_step_delay(&self, rng: &mut impl Rng) -> Duration {
+        if self.step_jitter.is_zero() {
+            return self.step_delay;
+        }
+        let jitter = self.step_jitter.as_millis() as i64;
+        let delay = self.step_delay.as_millis() as i64 + rng.random_range(-jitter..=jitter);
+        Duration::from_millis(delay.max(0) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+    use crate::input::simulation::{install_thread_input_sink, RecordingInputSink};
+    use crate::models::gsi_event::{Ability, Item as GsiItem};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn spam_event() -> GsiWebhookEvent {
+        let mut event = load_fixture_event("tests/fixtures/huskar_event.json");
+
+        let ability = |name: &str, cooldown: u32| Ability {
+            ability_active: true,
+            can_cast: cooldown == 0,
+            cooldown,
+            level: 1,
+            name: name.to_string(),
+            passive: false,
+            ultimate: false,
+        };
+        event.abilities.ability0 = ability("zuus_arc_lightning", 0);
+        event.abilities.ability1 = ability("zuus_lightning_bolt", 4);
+        event.abilities.ability5 = ability("zuus_thundergods_wrath", 0);
+        event.items.slot0 = GsiItem {
+            name: "item_dagon_3".to_string(),
+            can_cast: Some(true),
+            cooldown: Some(0),
+            ..GsiItem::default()
+        };
+        event
+    }
+
+    fn engine() -> PriorityComboEngine {
+        PriorityComboEngine::new(vec![
+            ComboEntry::ability("zuus_lightning_bolt", 'w'),
+            ComboEntry::item("item_dagon"),
+            ComboEntry::ability("zuus_arc_lightning", 'q'),
+        ])
+        .with_fallback(ComboEntry::ability("zuus_thundergods_wrath", 'r'))
+        .with_step_delay(0)
+        .with_recast_guard(60_000)
+    }
+
+    #[test]
+    fn next_step_takes_the_highest_ready_entry_then_the_fallback() {
+        let settings = Settings::default();
+        let event = spam_event();
+        let engine = engine();
+        let now = Instant::now();
+        let mut recent = RecentCasts::new();
+
+        assert_eq!(
+            engine.next_step(&event, &settings, &recent, now),
+            Some(ComboStep::Cast {
+                index: 1,
+                key: settings.keybindings.slot0
+            })
+        );
+        recent.insert(1, now);
+        assert_eq!(
+            engine.next_step(&event, &settings, &recent, now),
+            Some(ComboStep::Cast { index: 2, key: 'q' })
+        );
+        recent.insert(2, now);
+        assert_eq!(
+            engine.next_step(&event, &settings, &recent, now),
+            Some(ComboStep::Fallback { key: 'r' })
+        );
+
+        let without_fallback = PriorityComboEngine::new(engine.entries.clone());
+        assert_eq!(
+            without_fallback.next_step(&event, &settings, &recent, now),
+            None
+        );
+    }
+
+    #[test]
+    fn mana_reserve_skips_entries_but_not_the_fallback() {
+        let settings = Settings::default();
+        let mut event = spam_event();
+        event.hero.mana = 300;
+        let recent = RecentCasts::new();
+        let now = Instant::now();
+
+        assert_eq!(
+            engine()
+                .with_mana_reserve(300)
+                .next_step(&event, &settings, &recent, now),
+            Some(ComboStep::Fallback { key: 'r' })
+        );
+        assert!(matches!(
+            engine()
+                .with_mana_reserve(299)
+                .next_step(&event, &settings, &recent, now),
+            Some(ComboStep::Cast { .. })
+        ));
+    }
+
+    #[test]
+    fn randomization_shuffles_adjacent_unordered_entries_and_jitters_delay() {
+        let settings = Settings::default();
+        let event = spam_event();
+        let recent = RecentCasts::new();
+        let now = Instant::now();
+        let entries = vec![
+            ComboEntry::item("item_dagon").unordered(),
+            ComboEntry::ability("zuus_arc_lightning", 'q').unordered(),
+            ComboEntry::ability("zuus_thundergods_wrath", 'r'),
+        ];
+        let randomization = ComboRandomizationConfig {
+            jitter_ms: 20,
+            shuffle_unordered: true,
+        };
+        let ordered = PriorityComboEngine::new(entries.clone()).with_step_delay(50);
+        let shuffled = ordered.clone().with_randomization(&randomization);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let mut picked = HashSet::new();
+        for _ in 0..50 {
+            let step = shuffled.pick_step(&event, &settings, &recent, now, &mut rng);
+            let Some(ComboStep::Cast { index, .. }) = step else {
+                panic!("expected a cast, got {step:?}");
+            };
+            picked.insert(index);
+
+            let delay = shuffled.jittered_step_delay(&mut rng).as_millis();
+            assert!((30..=70).contains(&delay));
+        }
+        assert_eq!(picked, HashSet::from([0, 1]));
+
+        for _ in 0..10 {
+            assert!(matches!(
+                ordered.pick_step(&event, &settings, &recent, now, &mut rng),
+                Some(ComboStep::Cast { index: 0, .. })
+            ));
+            assert_eq!(
+                ordered.jittered_step_delay(&mut rng),
+                Duration::from_millis(50)
+            );
+        }
+    }
+
+    #[test]
+    fn run_casts_each_ready_entry_once_then_falls_back() {
+        let mut settings = Settings::default();
+        settings.soul_ring.enabled = false;
+        settings.common.combo_fallback_settle_ms = 0;
+        let event = spam_event();
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        let steps = engine()
+            .with_max_steps(4)
+            .run(&settings, || Some((event.clone(), Duration::ZERO)));
+
+        assert_eq!(steps, 4);
+        assert_eq!(
+            recorder.key_clicks(),
+            vec![settings.keybindings.slot0, 'q', 'r', settings.keybindings.slot0]
+        );
+    }
+
+    #[test]
+    fn run_skips_stale_gsi_state() {
+        let mut settings = Settings::default();
+        settings.common.combo_max_event_age_ms = 2000;
+        let event = spam_event();
+
+        let recorder = RecordingInputSink::default();
+        let _guard = install_thread_input_sink(recorder.clone());
+        let steps = engine().run(&settings, || Some((event.clone(), Duration::from_secs(3))));
+
+        assert_eq!(steps, 0);
+        assert!(recorder.key_clicks().is_empty());
+    }
+}
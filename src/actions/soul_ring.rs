@@ -7,8 +7,9 @@
 //! - Cooldown lockout has elapsed (prevents double-fire)
 //! - Item being used costs mana (skip list items like Blink, Phase Boots are excluded)
 
-use crate::actions::activity::{push_activity, ActivityCategory};
+use crate::actions::activity::{push_action, ActionEvent};
 use crate::config::Settings;
+use crate::state::poison::lock_or_reset;
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -182,6 +183,12 @@ impl SoulRingState {
 pub static SOUL_RING_STATE: std::sync::LazyLock<Arc<Mutex<SoulRingState>>> =
     std::sync::LazyLock::new(|| Arc::new(Mutex::new(SoulRingState::new())));
 
+/// Forget the tracked Soul Ring slot and trigger cooldown; the next GSI event
+/// repopulates the rest.
+pub fn reset() {
+    *lock_or_reset(&SOUL_RING_STATE) = SoulRingState::new();
+}
+
 /// Static keyboard configuration snapshot for Soul Ring, derived from `Settings`.
 ///
 /// Separates config-time constants (ability keys, item slot keys, thresholds)
@@ -310,15 +317,25 @@ impl SoulRingState {
 /// Press an ability key with automatic Soul Ring triggering (for use in combos)
 /// This is the programmatic equivalent of the keyboard interception
 pub fn press_ability_with_soul_ring(key: char, settings: &Settings) {
-    let mut state = SOUL_RING_STATE.lock().unwrap();
-    
-    if state.should_trigger(settings) && state.is_ability_key(key, settings) {
+    if let Some(reason) = crate::actions::combo_control::ability_blocked_reason() {
+        debug!("Skipping ability '{}' while {}", key, reason);
+        return;
+    }
+
+    let mut state = lock_or_reset(&SOUL_RING_STATE);
+    let items_blocked = crate::actions::combo_control::item_blocked_reason().is_some();
+
+    if !items_blocked && state.should_trigger(settings) && state.is_ability_key(key, settings) {
         if let Some(sr_key) = state.slot_key {
             state.mark_triggered();
             drop(state); // Release lock before sleeping
             
             info!("💍 Soul Ring before ability '{}'", key);
-            push_activity(ActivityCategory::Action, "Soul Ring combo triggered");
+            push_action(
+                ActionEvent::SoulRing,
+                format!("before ability '{}'", key),
+                crate::input::simulation::practice_mode_enabled(),
+            );
             crate::input::simulation::press_key(sr_key);
             std::thread::sleep(std::time::Duration::from_millis(
                 settings.soul_ring.delay_before_ability_ms,
@@ -340,7 +357,7 @@ pub fn update_from_gsi(
     hero: &crate::models::gsi_event::Hero,
     settings: &Settings,
 ) {
-    let mut state = SOUL_RING_STATE.lock().unwrap();
+    let mut state = lock_or_reset(&SOUL_RING_STATE);
 
     // Update hero stats
     state.hero_mana_percent = hero.mana_percent;
@@ -0,0 +1,148 @@
+//! Errors from finding, reading, merging and writing the config.
+//!
+//! [`ErrorCategory`] is shared with `update::UpdateError` so the UI can tell
+//! a dropped connection from a permissions problem, and only offer a retry
+//! when one can help.
+
+use serde::Serialize;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Coarse kind of a failure, for choosing the message the UI shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Offline, timed out, or the server answered with an error.
+    Network,
+    /// Access denied, e.g. a read-only install folder.
+    Permission,
+    /// A file, profile, release or asset that is not there.
+    NotFound,
+    /// Content that does not parse or validate.
+    Invalid,
+    /// This install or environment cannot do it (ZIP layout, no
+    /// `%LOCALAPPDATA%`).
+    Unsupported,
+    /// Something else already holds it, e.g. a port another program
+    /// listens on.
+    Conflict,
+    Internal,
+}
+
+impl ErrorCategory {
+    /// Whether trying the same thing again later can succeed.
+    pub fn retryable(self) -> bool {
+        self == Self::Network
+    }
+
+    /// Category of a failed filesystem or process call.
+    pub fn from_io(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::PermissionDenied => Self::Permission,
+            io::ErrorKind::NotFound => Self::NotFound,
+            _ => Self::Internal,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("LOCALAPPDATA is not set")]
+    NoLocalAppData,
+    #[error("Failed to resolve current exe: {0}")]
+    CurrentExe(#[source] io::Error),
+    #[error("Current exe has no parent directory")]
+    NoExeDir,
+    #[error("Failed to {action} {}: {source}", .path.display())]
+    Io {
+        /// Verb for the message, e.g. `"write"`.
+        action: &'static str,
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    /// A TOML document that does not parse; `what` names it (`"Live"`,
+    /// `"Profile"`, ...).
+    #[error("{what} TOML error: {source}")]
+    Parse {
+        what: &'static str,
+        #[source]
+        source: Box<toml::de::Error>,
+    },
+    #[error("{0} TOML is not a table")]
+    NotATable(&'static str),
+    #[error("TOML serialization error: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    /// A shared config that does not deserialize into `Settings`.
+    #[error("Invalid config: {0}")]
+    Invalid(#[source] Box<toml::de::Error>),
+    #[error("Invalid profile name '{0}' (use letters, digits, '-' or '_')")]
+    InvalidProfileName(String),
+    #[error("Profile '{0}' does not exist")]
+    ProfileNotFound(String),
+}
+
+impl ConfigError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::NoLocalAppData | Self::CurrentExe(_) | Self::NoExeDir => {
+                ErrorCategory::Unsupported
+            }
+            Self::Io { source, .. } => ErrorCategory::from_io(source),
+            Self::Parse { .. }
+            | Self::NotATable(_)
+            | Self::Invalid(_)
+            | Self::InvalidProfileName(_) => ErrorCategory::Invalid,
+            Self::ProfileNotFound(_) => ErrorCategory::NotFound,
+            Self::Serialize(_) => ErrorCategory::Internal,
+        }
+    }
+
+    pub fn retryable(&self) -> bool {
+        self.category().retryable()
+    }
+
+    /// `Io` error for `action` on `path`, for use with `map_err`.
+    pub fn io(action: &'static str, path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io {
+            action,
+            path,
+            source,
+        }
+    }
+
+    /// `Parse` error for the TOML document `what`, for use with `map_err`.
+    pub fn parse(what: &'static str) -> impl FnOnce(toml::de::Error) -> Self {
+        move |source| Self::Parse {
+            what,
+            source: Box::new(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_errors_are_categorized_by_kind_and_only_network_retries() {
+        let denied = ConfigError::io("write", "config.toml")(io::Error::from(
+            io::ErrorKind::PermissionDenied,
+        ));
+        assert_eq!(denied.category(), ErrorCategory::Permission);
+        assert!(denied
+            .to_string()
+            .starts_with("Failed to write config.toml:"));
+        assert!(!denied.retryable());
+
+        let missing = ConfigError::ProfileNotFound("carry".to_string());
+        assert_eq!(missing.category(), ErrorCategory::NotFound);
+        assert!(ErrorCategory::Network.retryable());
+        assert_eq!(
+            serde_json::to_value(ErrorCategory::NotFound).unwrap(),
+            "not_found"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 pub mod constants;
+pub mod error;
+pub mod profiles;
 pub mod settings;
 pub mod storage;
 
 pub use settings::{
-    AutoAbilityConfig, DangerDetectionConfig, MinimapAnalysisConfig, MinimapCaptureConfig,
-    OutworldDestroyerConfig, RuneAlertConfig, Settings,
+    AutoAbilityConfig, BristlebackConfig, DangerDetectionConfig, GsiLoggingConfig,
+    MinimapAnalysisConfig, MinimapCaptureConfig, OutworldDestroyerConfig, OverlayConfig,
+    RuneAlertConfig, Settings, ZeusConfig,
 };
@@ -0,0 +1,146 @@
+//! Named config profiles.
+//!
+//! A profile is `%LOCALAPPDATA%\dota2-scripts\config\profiles\<name>.toml`
+//! holding only the [`PROFILE_SECTIONS`]: hero settings and thresholds that
+//! differ between play styles. Everything else (server, keybindings, logging,
+//! updates, ...) stays global in the live `config.toml`, which also records
+//! the active profile under `[profiles] active`.
+//!
+//! On load the active profile is overlaid onto the live config; on save the
+//! profile sections go to the profile file and the live config keeps its own
+//! copy untouched, so switching back to no profile restores it.
+
+use crate::config::error::ConfigError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Top-level `Settings` sections a profile owns.
+pub const PROFILE_SECTIONS: &[&str] = &[
+    "common",
+    "armlet",
+    "heroes",
+    "danger_detection",
+    "neutral_items",
+    "mana_automation",
+    "soul_ring",
+];
+
+/// Profile names become file names, so keep them to `[A-Za-z0-9_-]`.
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+pub fn profile_path(profiles_dir: &Path, name: &str) -> PathBuf {
+    profiles_dir.join(format!("{name}.toml"))
+}
+
+/// Names of the saved profiles, sorted.
+pub fn list_profiles(profiles_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(profiles_dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+        .filter(|name| is_valid_profile_name(name))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Split serialized settings into (global, profile) TOML documents.
+pub fn split_profile_sections(
+    settings_contents: &str,
+) -> Result<(String, String), ConfigError> {
+    let value: toml::Value =
+        toml::from_str(settings_contents).map_err(ConfigError::parse("Settings"))?;
+    let toml::Value::Table(mut global) = value else {
+        return Err(ConfigError::NotATable("Settings"));
+    };
+
+    let mut profile = toml::map::Map::new();
+    for section in PROFILE_SECTIONS {
+        if let Some(value) = global.remove(*section) {
+            profile.insert((*section).to_string(), value);
+        }
+    }
+
+    Ok((
+        toml::to_string_pretty(&global)?,
+        toml::to_string_pretty(&profile)?,
+    ))
+}
+
+/// Replace the profile sections of `live_contents` with those in
+/// `profile_contents`. Global sections in the profile file are ignored.
+pub fn apply_profile(live_contents: &str, profile_contents: &str) -> Result<String, ConfigError> {
+    let live: toml::Value =
+        toml::from_str(live_contents).map_err(ConfigError::parse("Live"))?;
+    let profile: toml::Value =
+        toml::from_str(profile_contents).map_err(ConfigError::parse("Profile"))?;
+    let (toml::Value::Table(mut live), toml::Value::Table(profile)) = (live, profile) else {
+        return Err(ConfigError::NotATable("Config"));
+    };
+
+    for section in PROFILE_SECTIONS {
+        if let Some(value) = profile.get(*section) {
+            live.insert((*section).to_string(), value.clone());
+        }
+    }
+
+    Ok(toml::to_string_pretty(&live)?)
+}
+
+/// Write the profile sections of `settings_contents` to `<name>.toml`.
+pub fn write_profile(
+    profiles_dir: &Path,
+    name: &str,
+    settings_contents: &str,
+) -> Result<PathBuf, ConfigError> {
+    if !is_valid_profile_name(name) {
+        return Err(ConfigError::InvalidProfileName(name.to_string()));
+    }
+    fs::create_dir_all(profiles_dir).map_err(ConfigError::io("create", profiles_dir))?;
+
+    let (_, profile) = split_profile_sections(settings_contents)?;
+    let path = profile_path(profiles_dir, name);
+    fs::write(&path, profile).map_err(ConfigError::io("write", &path))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Settings;
+    use tempfile::tempdir;
+
+    #[test]
+    fn profiles_carry_hero_sections_but_not_server_or_keybindings() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path().join("profiles");
+
+        let mut carry = Settings::default();
+        carry.danger_detection.hp_threshold_percent = 55;
+        carry.server.port = 4000;
+        carry.keybindings.slot0 = 'q';
+        let contents = toml::to_string_pretty(&carry).unwrap();
+        write_profile(&dir, "carry", &contents).unwrap();
+        assert!(write_profile(&dir, "../escape", &contents).is_err());
+        assert_eq!(list_profiles(&dir), vec!["carry".to_string()]);
+
+        let saved = fs::read_to_string(profile_path(&dir, "carry")).unwrap();
+        assert!(!saved.contains("[server]") && !saved.contains("[keybindings]"));
+
+        let live = toml::to_string_pretty(&Settings::default()).unwrap();
+        let merged: Settings = toml::from_str(&apply_profile(&live, &saved).unwrap()).unwrap();
+        assert_eq!(merged.danger_detection.hp_threshold_percent, 55);
+        assert_eq!(merged.server.port, Settings::default().server.port);
+        assert_eq!(merged.keybindings.slot0, Settings::default().keybindings.slot0);
+    }
+}
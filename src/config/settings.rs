@@ -1,9 +1,13 @@
+use crate::config::error::ConfigError;
+use crate::config::profiles;
 use crate::config::storage::{
     bootstrap_live_config, persist_live_config, ConfigPaths, EMBEDDED_CONFIG_TEMPLATE,
 };
+use crate::models::Hero;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use tracing::{info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +16,9 @@ pub struct UpdateConfig {
     pub check_on_startup: bool,
     #[serde(default = "default_include_prereleases")]
     pub include_prereleases: bool,
+    /// Minutes between background update checks while running; 0 disables them.
+    #[serde(default = "default_check_interval_minutes")]
+    pub check_interval_minutes: u32,
 }
 
 impl Default for UpdateConfig {
@@ -19,6 +26,7 @@ impl Default for UpdateConfig {
         Self {
             check_on_startup: default_check_on_startup(),
             include_prereleases: default_include_prereleases(),
+            check_interval_minutes: default_check_interval_minutes(),
         }
     }
 }
@@ -31,10 +39,45 @@ fn default_include_prereleases() -> bool {
     false
 }
 
+fn default_check_interval_minutes() -> u32 {
+    0
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     #[serde(default = "default_port")]
     pub port: u16,
+    /// IP the GSI listener binds. Anything other than loopback lets every
+    /// host that can reach the port post fake game state.
+    #[serde(default = "default_bind_address")]
+    pub bind_address: String,
+    /// Skip hero dispatch for a GSI event whose hero, ability, item and clock
+    /// state matches the previous one. The UI still gets every event.
+    #[serde(default = "default_dedup_identical_events")]
+    pub dedup_identical_events: bool,
+    /// Path Dota posts GSI events to; must match the `uri` in the GSI cfg.
+    #[serde(default = "default_webhook_path")]
+    pub webhook_path: String,
+}
+
+impl ServerConfig {
+    /// Parsed `bind_address`, or loopback when it is not a valid IP.
+    pub fn bind_ip(&self) -> IpAddr {
+        self.bind_address
+            .trim()
+            .parse()
+            .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+    }
+
+    pub fn socket_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.bind_ip(), self.port)
+    }
+
+    /// `webhook_path` as an axum route: trimmed, with a leading `/`.
+    pub fn webhook_route(&self) -> String {
+        let path = self.webhook_path.trim().trim_start_matches('/');
+        format!("/{}", path)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,14 +96,26 @@ pub struct KeybindingsConfig {
     pub slot5: char,
     #[serde(default = "default_neutral")]
     pub neutral0: char,
+    /// Key bound to the dedicated TP scroll slot (`items.teleport0`).
+    #[serde(default = "default_teleport")]
+    pub teleport0: char,
     #[serde(default = "default_hotkey")]
     pub combo_trigger: String,
+    /// Pressing a standalone trigger again while its combo runs aborts the
+    /// remaining steps instead of being ignored.
+    #[serde(default)]
+    pub combo_toggle_abort: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
     #[serde(default = "default_log_level")]
     pub level: String,
+    /// Also write logs to a daily-rotated file in `dir`.
+    #[serde(default = "default_log_file_enabled")]
+    pub file_enabled: bool,
+    #[serde(default = "default_log_dir")]
+    pub dir: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +126,42 @@ pub struct CommonConfig {
     pub lane_phase_duration_seconds: u64,
     #[serde(default = "default_lane_phase_healing_threshold")]
     pub lane_phase_healing_threshold: u32,
+    /// Magic Wand and Magic Stick only heal with at least this many charges.
+    #[serde(default = "default_wand_min_charges")]
+    pub wand_min_charges: u32,
+    /// While smoked and not in danger, hold automation that could break the
+    /// smoke (armlet toggles, low-mana items, Largo beats).
+    #[serde(default = "default_respect_smoke")]
+    pub respect_smoke: bool,
+    /// Hold proactive offense (Largo beats, Bristleback Quill Spray) while the
+    /// hero is running toward its own fountain, as tracked by
+    /// `position_tracker`.
+    #[serde(default = "default_hold_offense_while_retreating")]
+    pub hold_offense_while_retreating: bool,
+    /// Run healing, defensive and danger-neutral items for heroes without a
+    /// script (`GenericHeroScript`). Armlet follows `[armlet]` either way.
+    #[serde(default = "default_generic_hero_survivability")]
+    pub generic_hero_survivability: bool,
+    /// Stop/Hold key pressed once a standalone combo finishes, so the hero
+    /// stays put instead of resuming its last order. Off when unset.
+    #[serde(default)]
+    pub combo_hold_after: Option<char>,
+    /// Dota's Attack key, pressed before a left-click for attack-move orders.
+    #[serde(default = "default_attack_key")]
+    pub attack_key: char,
+    /// Face the cursor with ALT+right-click before direction-targeted casts.
+    /// Needs Dota's `cl_dota_alt_unit_movetodirection`; when off, a plain
+    /// right-click turns the hero instead.
+    #[serde(default = "default_face_direction_with_alt")]
+    pub face_direction_with_alt: bool,
+    /// Priority combos stop instead of acting on cached GSI state older than
+    /// this (0 = no limit).
+    #[serde(default = "default_combo_max_event_age_ms")]
+    pub combo_max_event_age_ms: u64,
+    /// Wait after a priority combo's fallback cast for GSI to report the
+    /// refreshed cooldowns.
+    #[serde(default = "default_combo_fallback_settle_ms")]
+    pub combo_fallback_settle_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -91,6 +182,18 @@ pub struct ArmletRoshanConfig {
     pub stale_reset_ms: u64,
 }
 
+/// Detection of an armlet stuck on after a toggle: HP keeps falling below
+/// `toggle_threshold` for several GSI frames despite the recent attempt.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArmletStuckDetectionConfig {
+    /// Consecutive falling frames before a forced re-toggle (0 disables).
+    #[serde(default = "default_armlet_stuck_falling_frames")]
+    pub falling_frames: u32,
+    /// How long after a toggle attempt falling frames still count.
+    #[serde(default = "default_armlet_stuck_window_ms")]
+    pub window_ms: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArmletAutomationConfig {
     #[serde(default = "default_armlet_enabled")]
@@ -103,8 +206,18 @@ pub struct ArmletAutomationConfig {
     pub predictive_offset: u32,
     #[serde(default = "default_armlet_cooldown")]
     pub toggle_cooldown_ms: u64,
+    /// Hotkey that toggles armlet immediately, ignoring the toggle cooldown.
+    /// Empty disables it.
+    #[serde(default)]
+    pub emergency_toggle_key: String,
+    #[serde(default)]
+    pub stuck_detection: ArmletStuckDetectionConfig,
     #[serde(default)]
     pub roshan: ArmletRoshanConfig,
+    /// Overrides for heroes without their own `[heroes.<name>]` section,
+    /// keyed by hero name without the `npc_dota_hero_` prefix.
+    #[serde(default)]
+    pub heroes: BTreeMap<String, HeroArmletOverrideConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -135,6 +248,7 @@ pub struct EffectiveArmletConfig {
     pub toggle_threshold: u32,
     pub predictive_offset: u32,
     pub toggle_cooldown_ms: u64,
+    pub stuck_detection: ArmletStuckDetectionConfig,
     pub roshan: ArmletRoshanConfig,
 }
 
@@ -150,6 +264,39 @@ pub struct HuskarRoshanSpearsConfig {
     pub reenable_buffer_hp: u32,
 }
 
+/// Standalone combo options every hero section shares, flattened into
+/// `[heroes.<hero>]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandaloneComboConfig {
+    /// Standalone triggers within this long of the last accepted one are
+    /// ignored (0 = off).
+    #[serde(default)]
+    pub combo_retrigger_cooldown_ms: u64,
+}
+
+/// Orders added around a standalone combo that runs on the action executor
+/// (`combo_control::ComboOrders`), flattened into those heroes' sections.
+/// Heroes whose trigger runs inline have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComboOrdersConfig {
+    #[serde(default)]
+    pub attack_move: AttackMove,
+}
+
+/// When a standalone combo attack-moves at the cursor (`attack_move` in the
+/// hero section). Any other value fails the config parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttackMove {
+    /// `"off"`: no attack-move; the default.
+    #[default]
+    Off,
+    /// `"before"`: attack-move, then the combo.
+    Before,
+    /// `"after"`: the combo, then attack-move instead of the hold key.
+    After,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HuskarConfig {
     #[serde(default = "default_armlet_threshold")]
@@ -162,8 +309,24 @@ pub struct HuskarConfig {
     pub berserker_blood_key: char,
     #[serde(default = "default_berserker_blood_delay")]
     pub berserker_blood_delay_ms: u64,
+    /// After a cleanse, start a new delay window if Huskar is still (or
+    /// again) debuffed instead of waiting for the debuff flag to clear.
+    #[serde(default = "default_berserker_blood_rearm")]
+    pub berserker_blood_rearm: bool,
+    /// Only cleanse when the debuff window included a disable (stun,
+    /// silence, hex, mute or disarm).
+    #[serde(default)]
+    pub berserker_blood_require_disable: bool,
+    /// HP added to the armlet toggle threshold while Broken, when Berserker's
+    /// Blood gives no regen or resistance. 0 disables.
+    #[serde(default = "default_huskar_break_armlet_threshold_bonus")]
+    pub break_armlet_threshold_bonus: u32,
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
     #[serde(default)]
@@ -174,16 +337,57 @@ pub struct HuskarConfig {
 pub struct LegionCommanderConfig {
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
+    /// Pre-blink self-buff items per facet, keyed by the GSI `hero.facet`
+    /// number. Facets without an entry use Blade Mail → Mjollnir → BKB.
+    #[serde(default)]
+    pub facet_self_buff_items: BTreeMap<String, Vec<String>>,
+    /// Press BKB right before Duel instead of among the self-buff items.
+    #[serde(default)]
+    pub auto_bkb_before_duel: bool,
+    /// Mana cost per ability name, summed by the pre-combo mana check.
+    #[serde(default)]
+    pub combo_mana_costs: BTreeMap<String, u32>,
+    /// Wait after Blink before Orchid/Duel so they are cast from the
+    /// landing spot.
+    #[serde(default = "default_legion_post_blink_delay_ms")]
+    pub post_blink_delay_ms: u64,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
 
+impl LegionCommanderConfig {
+    pub fn self_buff_items_for_facet(&self, facet: u32) -> Option<&[String]> {
+        self.facet_self_buff_items
+            .get(&facet.to_string())
+            .map(Vec::as_slice)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShadowFiendConfig {
     #[serde(default = "default_sf_raze_enabled")]
     pub raze_intercept_enabled: bool,
     #[serde(default = "default_raze_delay")]
     pub raze_delay_ms: u64,
+    /// Dota key for Shadowraze (close); intercepted and re-pressed after facing
+    #[serde(default = "default_sf_close_raze_key")]
+    pub close_raze_key: char,
+    /// Dota key for Shadowraze (medium)
+    #[serde(default = "default_sf_medium_raze_key")]
+    pub medium_raze_key: char,
+    /// Dota key for Shadowraze (far)
+    #[serde(default = "default_sf_far_raze_key")]
+    pub far_raze_key: char,
+    /// Face the cursor on raze key press and cast on release, so the player
+    /// can aim by holding the key and moving the cursor
+    #[serde(default)]
+    pub cast_on_key_release: bool,
     /// Automatically use BKB before ultimate (Requiem of Souls)
     #[serde(default = "default_sf_auto_bkb_on_ultimate")]
     pub auto_bkb_on_ultimate: bool,
@@ -193,6 +397,10 @@ pub struct ShadowFiendConfig {
     /// Standalone combo trigger key (Blink + Ultimate combo)
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
@@ -201,14 +409,108 @@ pub struct ShadowFiendConfig {
 pub struct TinyConfig {
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
+    /// `"low_ping"`, `"medium_ping"`, `"high_ping"`, or `"custom"` to use
+    /// the explicit timing fields below.
+    #[serde(default = "default_tiny_timing_preset")]
+    pub timing_preset: String,
+    /// Wait after Toss before Tree Grab; must cover Toss's flight so the
+    /// target lands inside Avalanche before the follow-up.
+    #[serde(default = "default_tiny_toss_catch_window_ms")]
+    pub toss_catch_window_ms: u64,
+    /// Extra Avalanche presses after the first (Soul Ring) press.
+    #[serde(default = "default_tiny_avalanche_spam_count")]
+    pub avalanche_spam_count: u32,
+    #[serde(default = "default_tiny_toss_spam_count")]
+    pub toss_spam_count: u32,
+    /// Wait after Blink before Avalanche so the cast starts from the landing
+    /// spot.
+    #[serde(default = "default_tiny_post_blink_delay_ms")]
+    pub post_blink_delay_ms: u64,
+    /// GSI talent slot (1-8) of the extra Toss charge talent; when picked
+    /// the combo tosses twice. 0 disables.
+    #[serde(default)]
+    pub extra_toss_talent: u32,
+    /// Mana cost per ability name, summed by the pre-combo mana check.
+    #[serde(default)]
+    pub combo_mana_costs: BTreeMap<String, u32>,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
 
+/// Tiny combo timing after resolving `TinyConfig::timing_preset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TinyComboTiming {
+    pub toss_catch_window_ms: u64,
+    pub avalanche_spam_count: u32,
+    pub toss_spam_count: u32,
+    pub post_blink_delay_ms: u64,
+}
+
+/// How long Toss keeps its target airborne; every preset's catch window
+/// has to outlast it.
+pub const TINY_TOSS_FLIGHT_MS: u64 = 1300;
+
+/// Named `TinyConfig::timing_preset` values. The UI reads them through the
+/// `get_tiny_timing_presets` command rather than keeping its own copy.
+pub const TINY_TIMING_PRESETS: [(&str, TinyComboTiming); 3] = [
+    (
+        "low_ping",
+        TinyComboTiming {
+            toss_catch_window_ms: 1350,
+            avalanche_spam_count: 3,
+            toss_spam_count: 4,
+            post_blink_delay_ms: 80,
+        },
+    ),
+    (
+        "medium_ping",
+        TinyComboTiming {
+            toss_catch_window_ms: 1400,
+            avalanche_spam_count: 3,
+            toss_spam_count: 4,
+            post_blink_delay_ms: 100,
+        },
+    ),
+    (
+        "high_ping",
+        TinyComboTiming {
+            toss_catch_window_ms: 1550,
+            avalanche_spam_count: 4,
+            toss_spam_count: 6,
+            post_blink_delay_ms: 150,
+        },
+    ),
+];
+
+impl TinyConfig {
+    pub fn combo_timing(&self) -> TinyComboTiming {
+        TINY_TIMING_PRESETS
+            .iter()
+            .find(|(name, _)| *name == self.timing_preset)
+            .map(|(_, timing)| *timing)
+            .unwrap_or(TinyComboTiming {
+                toss_catch_window_ms: self.toss_catch_window_ms,
+                avalanche_spam_count: self.avalanche_spam_count,
+                toss_spam_count: self.toss_spam_count,
+                post_blink_delay_ms: self.post_blink_delay_ms,
+            })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutworldDestroyerConfig {
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
     #[serde(default = "default_od_objurgation_key")]
     pub objurgation_key: char,
     #[serde(default = "default_od_arcane_orb_key")]
@@ -248,6 +550,8 @@ pub struct OutworldDestroyerConfig {
     #[serde(default = "default_od_arcane_orb_press_interval_ms")]
     pub arcane_orb_press_interval_ms: u64,
     #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
+    #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
 
@@ -272,8 +576,6 @@ pub struct BroodmotherConfig {
     pub spider_control_group_key: String,
     #[serde(default = "default_broodmother_reselect_hero_key")]
     pub reselect_hero_key: String,
-    #[serde(default = "default_broodmother_attack_key")]
-    pub attack_key: char,
     #[serde(default = "default_auto_items_enabled")]
     pub auto_items_enabled: bool,
     #[serde(default = "default_auto_items_modifier")]
@@ -284,6 +586,14 @@ pub struct BroodmotherConfig {
     pub auto_abilities: Vec<AutoAbilityConfig>,
     #[serde(default = "default_auto_abilities_first")]
     pub auto_abilities_first: bool,
+    #[serde(default = "default_standalone_key")]
+    pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
@@ -314,6 +624,10 @@ pub struct LargoConfig {
     pub r_ability_key: char,
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
     #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
@@ -346,6 +660,10 @@ pub struct MeepoFarmAssistConfig {
 pub struct MeepoConfig {
     #[serde(default = "default_standalone_key")]
     pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
     #[serde(default = "default_meepo_earthbind_key")]
     pub earthbind_key: char,
     #[serde(default = "default_meepo_poof_key")]
@@ -370,6 +688,9 @@ pub struct MeepoConfig {
     pub poof_press_count: u32,
     #[serde(default = "default_meepo_poof_press_interval_ms")]
     pub poof_press_interval_ms: u64,
+    /// Press BKB right before the combo's Poof channel.
+    #[serde(default)]
+    pub auto_bkb_before_poof: bool,
     #[serde(default = "default_meepo_auto_dig_on_danger")]
     pub auto_dig_on_danger: bool,
     #[serde(default = "default_meepo_dig_hp_threshold_percent")]
@@ -383,6 +704,76 @@ pub struct MeepoConfig {
     #[serde(default)]
     pub farm_assist: MeepoFarmAssistConfig,
     #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
+    #[serde(default)]
+    pub armlet: HeroArmletOverrideConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BristlebackConfig {
+    #[serde(default = "default_standalone_key")]
+    pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
+    #[serde(default = "default_bristleback_viscous_nasal_goo_key")]
+    pub viscous_nasal_goo_key: char,
+    /// Cast Quill Spray whenever it is ready, keeping Warpath stacked.
+    #[serde(default = "default_bristleback_auto_quill_spray")]
+    pub auto_quill_spray: bool,
+    #[serde(default = "default_bristleback_quill_spray_key")]
+    pub quill_spray_key: char,
+    /// Auto Quill Spray stops below this mana percent.
+    #[serde(default = "default_bristleback_quill_min_mana_percent")]
+    pub quill_min_mana_percent: u32,
+    /// Auto Quill Spray only fires within this long of taking damage or
+    /// issuing an attack order. 0 casts it whenever ready.
+    #[serde(default = "default_bristleback_quill_combat_window_ms")]
+    pub quill_combat_window_ms: u64,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
+    #[serde(default)]
+    pub armlet: HeroArmletOverrideConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZeusConfig {
+    #[serde(default = "default_standalone_key")]
+    pub standalone_key: String,
+    #[serde(flatten)]
+    pub standalone_combo: StandaloneComboConfig,
+    #[serde(flatten)]
+    pub combo_orders: ComboOrdersConfig,
+    #[serde(default = "default_zeus_arc_lightning_key")]
+    pub arc_lightning_key: char,
+    #[serde(default = "default_zeus_lightning_bolt_key")]
+    pub lightning_bolt_key: char,
+    #[serde(default = "default_zeus_thundergods_wrath_key")]
+    pub thundergods_wrath_key: char,
+    /// Add Lightning Bolt to the spam. Off by default: it is quick-cast at
+    /// the cursor, which may not be where the player wants it.
+    #[serde(default)]
+    pub spam_lightning_bolt: bool,
+    /// Cast Arc Lightning (and Bolt) on GSI events whenever ready while the
+    /// player is attacking.
+    #[serde(default = "default_zeus_auto_spam_while_attacking")]
+    pub auto_spam_while_attacking: bool,
+    /// A right-click or `common.attack_key` press counts as attacking for
+    /// this long.
+    #[serde(default = "default_zeus_attack_window_ms")]
+    pub attack_window_ms: u64,
+    /// Mana kept for Thundergod's Wrath: the spam stops at or below it.
+    /// 0 disables.
+    #[serde(default)]
+    pub mana_reserve: u32,
+    /// Per-run variation of the spam. Lightning Bolt and Arc Lightning are
+    /// the unordered entries `shuffle_unordered` may swap.
+    #[serde(default)]
+    pub randomization: ComboRandomizationConfig,
+    #[serde(default)]
+    pub survivability_hp_threshold_override: Option<u32>,
+    #[serde(default)]
     pub armlet: HeroArmletOverrideConfig,
 }
 
@@ -404,6 +795,10 @@ pub struct HeroesConfig {
     pub broodmother: BroodmotherConfig,
     #[serde(default)]
     pub meepo: MeepoConfig,
+    #[serde(default)]
+    pub zeus: ZeusConfig,
+    #[serde(default)]
+    pub bristleback: BristlebackConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -422,6 +817,13 @@ pub struct DangerDetectionConfig {
     pub healing_threshold_in_danger: u32,
     #[serde(default = "default_max_healing_items")]
     pub max_healing_items_per_danger: u32,
+    /// HP the hero must have actually lost within `clear_delay_seconds` for
+    /// danger to switch healing to the danger threshold (0 = off).
+    #[serde(default)]
+    pub healing_min_recent_loss_hp: u32,
+    /// Defensive items fired per danger episode (0 = no limit).
+    #[serde(default)]
+    pub max_defensive_items_per_danger: u32,
     #[serde(default = "default_auto_bkb")]
     pub auto_bkb: bool,
     #[serde(default = "default_auto_satanic")]
@@ -436,12 +838,53 @@ pub struct DangerDetectionConfig {
     pub auto_ghost_scepter: bool,
     #[serde(default = "default_auto_shivas_guard")]
     pub auto_shivas_guard: bool,
+    /// Order defensive items are fired in while in danger. Items left out
+    /// keep their default place after the listed ones.
+    #[serde(default = "default_defensive_item_priority")]
+    pub defensive_item_priority: Vec<String>,
+    /// Per-item self-cast method. Items not listed are pressed once.
+    #[serde(default = "default_self_cast_methods")]
+    pub self_cast_methods: BTreeMap<String, SelfCastMethod>,
+    /// Dota's self-cast modifier, held by `self_cast_modifier` items.
+    #[serde(default)]
+    pub self_cast_modifier: CastModifier,
     #[serde(default = "default_auto_manta_on_silence")]
     pub auto_manta_on_silence: bool,
     #[serde(default = "default_auto_lotus_on_silence")]
     pub auto_lotus_on_silence: bool,
 }
 
+/// How a defensive item is cast on the hero itself
+/// (`[danger_detection.self_cast_methods]`). Any other value fails the
+/// config parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfCastMethod {
+    /// `"double_tap"`: press the item key twice; only self-casts when the
+    /// second press lands on the hero, which depends on the item's default
+    /// target.
+    DoubleTap,
+    /// `"self_cast_modifier"`: hold `self_cast_modifier` and press the item
+    /// key once.
+    SelfCastModifier,
+}
+
+/// Dota's self-cast key (`[danger_detection] self_cast_modifier`). Any
+/// other value fails the config parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CastModifier {
+    /// `"Alt"`; the default.
+    #[default]
+    #[serde(alias = "alt")]
+    Alt,
+    /// `"Ctrl"`, also read from `"Control"`.
+    #[serde(alias = "ctrl", alias = "Control", alias = "control")]
+    Ctrl,
+    /// `"Shift"`.
+    #[serde(alias = "shift")]
+    Shift,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeutralItemConfig {
     #[serde(default = "default_neutral_items_enabled")]
@@ -456,6 +899,10 @@ pub struct NeutralItemConfig {
     pub hp_threshold: u32,
     #[serde(default = "default_allowed_items")]
     pub allowed_items: Vec<String>,
+    /// Also fire the equipped neutral during Space+right-click auto-items
+    /// when it is in `allowed_items` and ready.
+    #[serde(default)]
+    pub use_in_auto_items: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -508,6 +955,231 @@ pub struct GsiLoggingConfig {
     pub enabled: bool,
     #[serde(default = "default_gsi_logging_dir")]
     pub output_dir: String,
+    /// Roll to a new numbered file once the current one exceeds this size (0 = no cap)
+    #[serde(default = "default_gsi_logging_max_file_bytes")]
+    pub max_file_bytes: u64,
+    /// Keep at most this many files per session, deleting the oldest (0 = keep all)
+    #[serde(default = "default_gsi_logging_max_files")]
+    pub max_files: u32,
+    /// Skip events where the hero is dead
+    #[serde(default = "default_gsi_logging_alive_only")]
+    pub alive_only: bool,
+}
+
+/// Active named profile; see `config::profiles`. Always global.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfilesConfig {
+    /// Profile overlaid on this config at load; empty for none.
+    #[serde(default)]
+    pub active: String,
+}
+
+/// Where synthetic input may land. Always global.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputConfig {
+    /// Drop key presses and clicks while another window is in the foreground
+    /// (Windows only; elsewhere Dota always counts as focused).
+    #[serde(default = "default_only_input_when_dota_focused")]
+    pub only_input_when_dota_focused: bool,
+    /// Minimum gap the input worker keeps between two synthetic presses or
+    /// clicks, whichever feature queued them (0 = off). Capped at
+    /// [`MAX_MIN_ACTION_INTERVAL_MS`] when loaded.
+    #[serde(default)]
+    pub min_action_interval_ms: u64,
+}
+
+/// Upper bound for `[input] min_action_interval_ms`; every limited press can
+/// wait this long, so larger values would make combos unusable.
+pub const MAX_MIN_ACTION_INTERVAL_MS: u64 = 500;
+
+/// How synthetic input reaches the OS. Always global.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    #[serde(default)]
+    pub backend: InputBackend,
+}
+
+/// Which backend the input worker sends through (`[simulation] backend`).
+/// Any other value fails the config parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputBackend {
+    /// `"enigo"`: Enigo `Key::Unicode` presses; the default.
+    #[default]
+    Enigo,
+    /// `"scancode"`: `SendInput` hardware scancodes (Windows only), for input
+    /// layers that ignore Unicode keys.
+    Scancode,
+}
+
+/// Whether GSI automation and the standalone script are on when the app
+/// launches (Tauri UI only; the headless binary has no switch and always
+/// starts armed). Always global.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartupConfig {
+    /// Start with both switches on. Off by default so launching mid-game does
+    /// not fire anything before the user turns automation on.
+    #[serde(default)]
+    pub arm_on_launch: bool,
+    /// Start with the switches as they were when the app last ran, instead
+    /// of `arm_on_launch`.
+    #[serde(default)]
+    pub restore_last_arm_state: bool,
+    /// Written when a switch changes while `restore_last_arm_state` is on.
+    #[serde(default)]
+    pub last_gsi_enabled: bool,
+    #[serde(default)]
+    pub last_standalone_enabled: bool,
+}
+
+impl StartupConfig {
+    /// `(gsi_enabled, standalone_enabled)` to launch with.
+    pub fn launch_arm_state(&self) -> (bool, bool) {
+        if self.restore_last_arm_state {
+            (self.last_gsi_enabled, self.last_standalone_enabled)
+        } else {
+            (self.arm_on_launch, self.arm_on_launch)
+        }
+    }
+}
+
+/// Informational activity-feed notices. Always global.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    /// Announce when an item some automation uses first shows up in the
+    /// inventory (e.g. "Black King Bar acquired: auto-BKB in danger now active").
+    #[serde(default = "default_notify_item_acquired")]
+    pub item_acquired: bool,
+}
+
+/// Use permanent upgrade items on the hero once they are in the inventory.
+/// Always global.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumablesConfig {
+    #[serde(default = "default_auto_consume")]
+    pub auto_consume: bool,
+    /// Items to consume. Cheese is skipped even if listed.
+    #[serde(default = "default_consumable_items")]
+    pub allowed_items: Vec<String>,
+}
+
+/// Combos recorded by demonstration and replayed by the standalone trigger.
+/// Always global.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComboRecorderConfig {
+    /// Watch `toggle_key` and replay recordings; built-in combos run otherwise.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Starts recording for the current hero; the next press stops and saves.
+    #[serde(default = "default_combo_recorder_toggle_key")]
+    pub toggle_key: String,
+    /// Longer pauses between two recorded inputs are shortened to this.
+    #[serde(default = "default_combo_recorder_max_step_delay_ms")]
+    pub max_step_delay_ms: u64,
+    /// Recorded combos keyed by hero config name (`"tiny"`). A hero with a
+    /// recording replays it instead of its built-in standalone combo.
+    #[serde(default)]
+    pub recordings: BTreeMap<String, Vec<RecordedComboStep>>,
+    /// Per-recording timing/order variation, keyed like `recordings`.
+    #[serde(default)]
+    pub randomization: BTreeMap<String, ComboRandomizationConfig>,
+}
+
+/// One input of a recorded combo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedComboStep {
+    pub input: ComboInput,
+    /// Wait after the previous step.
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// May trade places with the neighbouring steps that are also marked
+    /// unordered. Set by hand; the recorder leaves it off.
+    #[serde(default)]
+    pub unordered: bool,
+}
+
+/// What a recorded combo step presses. Written as the key character
+/// (`"q"`) or `"left_click"` / `"right_click"`; anything else fails the
+/// config parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ComboInput {
+    Key(char),
+    LeftClick,
+    RightClick,
+}
+
+impl TryFrom<String> for ComboInput {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "left_click" => return Ok(Self::LeftClick),
+            "right_click" => return Ok(Self::RightClick),
+            _ => {}
+        }
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(key), None) => Ok(Self::Key(key)),
+            _ => Err(format!(
+                "unknown combo input {:?}: expected a single key, \"left_click\" or \"right_click\"",
+                value
+            )),
+        }
+    }
+}
+
+impl From<ComboInput> for String {
+    fn from(input: ComboInput) -> Self {
+        match input {
+            ComboInput::Key(key) => key.to_string(),
+            ComboInput::LeftClick => "left_click".to_string(),
+            ComboInput::RightClick => "right_click".to_string(),
+        }
+    }
+}
+
+/// Variation applied to a recorded combo or a priority-engine spam each time
+/// it runs, so it is not pressed with identical timing every time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComboRandomizationConfig {
+    /// Each step's delay moves by up to this much either way (never below 0).
+    #[serde(default)]
+    pub jitter_ms: u64,
+    /// Shuffle each run of adjacent `unordered` steps.
+    #[serde(default)]
+    pub shuffle_unordered: bool,
+}
+
+/// Always-on-top cooldown overlay window (Tauri UI only). Always global.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlayConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Screen position of the overlay's top-left corner, in logical pixels.
+    #[serde(default = "default_overlay_x")]
+    pub x: i32,
+    #[serde(default = "default_overlay_y")]
+    pub y: i32,
+    /// Also list hero abilities, not just items.
+    #[serde(default = "default_overlay_show_abilities")]
+    pub show_abilities: bool,
+}
+
+/// Master switch that turns all synthetic input into log lines. GSI, danger
+/// detection and the UI keep running. Always global.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SafeModeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Like safe mode, but each action the automation would take is written to the
+/// activity feed with the reason it fired. Always global.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PracticeModeConfig {
+    #[serde(default)]
+    pub enabled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -573,6 +1245,9 @@ impl Default for GsiLoggingConfig {
         Self {
             enabled: default_gsi_logging_enabled(),
             output_dir: default_gsi_logging_dir(),
+            max_file_bytes: default_gsi_logging_max_file_bytes(),
+            max_files: default_gsi_logging_max_files(),
+            alive_only: default_gsi_logging_alive_only(),
         }
     }
 }
@@ -717,6 +1392,26 @@ pub struct Settings {
     pub minimap_capture: MinimapCaptureConfig,
     #[serde(default)]
     pub minimap_analysis: MinimapAnalysisConfig,
+    #[serde(default)]
+    pub profiles: ProfilesConfig,
+    #[serde(default)]
+    pub safe_mode: SafeModeConfig,
+    #[serde(default)]
+    pub practice_mode: PracticeModeConfig,
+    #[serde(default)]
+    pub input: InputConfig,
+    #[serde(default)]
+    pub simulation: SimulationConfig,
+    #[serde(default)]
+    pub overlay: OverlayConfig,
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    #[serde(default)]
+    pub consumables: ConsumablesConfig,
+    #[serde(default)]
+    pub combo_recorder: ComboRecorderConfig,
+    #[serde(default)]
+    pub startup: StartupConfig,
 }
 
 // Default functions
@@ -724,6 +1419,18 @@ fn default_port() -> u16 {
     3000
 }
 
+fn default_bind_address() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_dedup_identical_events() -> bool {
+    true
+}
+
+fn default_webhook_path() -> String {
+    "/".to_string()
+}
+
 fn default_slot0() -> char {
     'z'
 }
@@ -745,21 +1452,102 @@ fn default_slot5() -> char {
 fn default_neutral() -> char {
     '0'
 }
+fn default_teleport() -> char {
+    't'
+}
 fn default_hotkey() -> String {
     "Home".to_string()
 }
 fn default_log_level() -> String {
     "info".to_string()
 }
+fn default_log_file_enabled() -> bool {
+    true
+}
+/// `%LOCALAPPDATA%\dota2-scripts\logs`, next to the live config, so logs
+/// do not depend on the working directory. `logs` when that cannot be found.
+fn default_log_dir() -> String {
+    ConfigPaths::detect()
+        .map(|paths| paths.app_data_dir().join("logs").display().to_string())
+        .unwrap_or_else(|_| "logs".to_string())
+}
 fn default_survivability_threshold() -> u32 {
     30
 }
 fn default_lane_phase_duration_seconds() -> u64 {
     480
 }
+fn default_respect_smoke() -> bool {
+    true
+}
+fn default_generic_hero_survivability() -> bool {
+    true
+}
+fn default_hold_offense_while_retreating() -> bool {
+    true
+}
+
+fn default_face_direction_with_alt() -> bool {
+    true
+}
+
+fn default_attack_key() -> char {
+    'a'
+}
+
+fn default_combo_max_event_age_ms() -> u64 {
+    2000
+}
+
+fn default_combo_fallback_settle_ms() -> u64 {
+    500
+}
+
+fn default_only_input_when_dota_focused() -> bool {
+    true
+}
+
+fn default_notify_item_acquired() -> bool {
+    true
+}
+
+fn default_auto_consume() -> bool {
+    false
+}
+
+fn default_consumable_items() -> Vec<String> {
+    vec![
+        "item_aghanims_shard".to_string(),
+        "item_moon_shard".to_string(),
+    ]
+}
+
+fn default_combo_recorder_toggle_key() -> String {
+    "F8".to_string()
+}
+
+fn default_combo_recorder_max_step_delay_ms() -> u64 {
+    1000
+}
+
+fn default_overlay_x() -> i32 {
+    40
+}
+
+fn default_overlay_y() -> i32 {
+    200
+}
+
+fn default_overlay_show_abilities() -> bool {
+    true
+}
+
 fn default_lane_phase_healing_threshold() -> u32 {
     12
 }
+fn default_wand_min_charges() -> u32 {
+    1
+}
 fn default_armlet_enabled() -> bool {
     true
 }
@@ -775,6 +1563,12 @@ fn default_armlet_offset() -> u32 {
 fn default_armlet_cooldown() -> u64 {
     250
 }
+fn default_armlet_stuck_falling_frames() -> u32 {
+    2
+}
+fn default_armlet_stuck_window_ms() -> u64 {
+    1500
+}
 fn default_armlet_roshan_enabled() -> bool {
     false
 }
@@ -802,6 +1596,12 @@ fn default_berserker_blood_key() -> char {
 fn default_berserker_blood_delay() -> u64 {
     300
 }
+fn default_berserker_blood_rearm() -> bool {
+    true
+}
+fn default_huskar_break_armlet_threshold_bonus() -> u32 {
+    150
+}
 fn default_huskar_roshan_spears_enabled() -> bool {
     false
 }
@@ -817,12 +1617,39 @@ fn default_huskar_roshan_spears_reenable_buffer_hp() -> u32 {
 fn default_standalone_key() -> String {
     "Home".to_string()
 }
+fn default_legion_post_blink_delay_ms() -> u64 {
+    100
+}
+fn default_tiny_timing_preset() -> String {
+    "custom".to_string()
+}
+fn default_tiny_toss_catch_window_ms() -> u64 {
+    1400
+}
+fn default_tiny_avalanche_spam_count() -> u32 {
+    3
+}
+fn default_tiny_toss_spam_count() -> u32 {
+    4
+}
+fn default_tiny_post_blink_delay_ms() -> u64 {
+    100
+}
 fn default_sf_raze_enabled() -> bool {
     true
 }
 fn default_raze_delay() -> u64 {
     100
 }
+fn default_sf_close_raze_key() -> char {
+    'q'
+}
+fn default_sf_medium_raze_key() -> char {
+    'w'
+}
+fn default_sf_far_raze_key() -> char {
+    'e'
+}
 fn default_sf_auto_bkb_on_ultimate() -> bool {
     false
 }
@@ -896,9 +1723,6 @@ fn default_broodmother_spider_control_group() -> String {
 fn default_broodmother_reselect_hero_key() -> String {
     "F1".to_string()
 }
-fn default_broodmother_attack_key() -> char {
-    'a'
-}
 
 fn default_auto_items_enabled() -> bool {
     false
@@ -1031,6 +1855,36 @@ fn default_meepo_farm_assist_poof_press_count() -> u32 {
 fn default_meepo_farm_assist_poof_press_interval_ms() -> u64 {
     35
 }
+fn default_bristleback_auto_quill_spray() -> bool {
+    true
+}
+fn default_bristleback_viscous_nasal_goo_key() -> char {
+    'q'
+}
+fn default_bristleback_quill_spray_key() -> char {
+    'w'
+}
+fn default_bristleback_quill_min_mana_percent() -> u32 {
+    30
+}
+fn default_bristleback_quill_combat_window_ms() -> u64 {
+    3000
+}
+fn default_zeus_arc_lightning_key() -> char {
+    'q'
+}
+fn default_zeus_lightning_bolt_key() -> char {
+    'w'
+}
+fn default_zeus_thundergods_wrath_key() -> char {
+    'r'
+}
+fn default_zeus_auto_spam_while_attacking() -> bool {
+    true
+}
+fn default_zeus_attack_window_ms() -> u64 {
+    1000
+}
 
 fn default_danger_enabled() -> bool {
     true
@@ -1074,6 +1928,19 @@ fn default_auto_ghost_scepter() -> bool {
 fn default_auto_shivas_guard() -> bool {
     true
 }
+fn default_defensive_item_priority() -> Vec<String> {
+    vec![
+        "item_black_king_bar".to_string(),
+        "item_satanic".to_string(),
+        "item_blade_mail".to_string(),
+        "item_glimmer_cape".to_string(),
+        "item_ghost".to_string(),
+        "item_shivas_guard".to_string(),
+    ]
+}
+fn default_self_cast_methods() -> BTreeMap<String, SelfCastMethod> {
+    BTreeMap::from([("item_glimmer_cape".to_string(), SelfCastMethod::DoubleTap)])
+}
 fn default_auto_manta_on_silence() -> bool {
     true
 }
@@ -1120,6 +1987,15 @@ fn default_gsi_logging_enabled() -> bool {
 fn default_gsi_logging_dir() -> String {
     "logs/gsi_events".to_string()
 }
+fn default_gsi_logging_max_file_bytes() -> u64 {
+    50 * 1024 * 1024
+}
+fn default_gsi_logging_max_files() -> u32 {
+    5
+}
+fn default_gsi_logging_alive_only() -> bool {
+    false
+}
 
 fn default_rune_alerts_enabled() -> bool {
     true
@@ -1193,6 +2069,9 @@ impl Default for ServerConfig {
     fn default() -> Self {
         Self {
             port: default_port(),
+            bind_address: default_bind_address(),
+            dedup_identical_events: default_dedup_identical_events(),
+            webhook_path: default_webhook_path(),
         }
     }
 }
@@ -1207,7 +2086,9 @@ impl Default for KeybindingsConfig {
             slot4: default_slot4(),
             slot5: default_slot5(),
             neutral0: default_neutral(),
+            teleport0: default_teleport(),
             combo_trigger: default_hotkey(),
+            combo_toggle_abort: false,
         }
     }
 }
@@ -1216,6 +2097,58 @@ impl Default for LoggingConfig {
     fn default() -> Self {
         Self {
             level: default_log_level(),
+            file_enabled: default_log_file_enabled(),
+            dir: default_log_dir(),
+        }
+    }
+}
+
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            item_acquired: default_notify_item_acquired(),
+        }
+    }
+}
+
+impl Default for ConsumablesConfig {
+    fn default() -> Self {
+        Self {
+            auto_consume: default_auto_consume(),
+            allowed_items: default_consumable_items(),
+        }
+    }
+}
+
+impl Default for ComboRecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: default_combo_recorder_toggle_key(),
+            max_step_delay_ms: default_combo_recorder_max_step_delay_ms(),
+            recordings: BTreeMap::new(),
+            randomization: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for OverlayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            x: default_overlay_x(),
+            y: default_overlay_y(),
+            show_abilities: default_overlay_show_abilities(),
+        }
+    }
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            only_input_when_dota_focused: default_only_input_when_dota_focused(),
+            min_action_interval_ms: 0,
         }
     }
 }
@@ -1226,6 +2159,15 @@ impl Default for CommonConfig {
             survivability_hp_threshold: default_survivability_threshold(),
             lane_phase_duration_seconds: default_lane_phase_duration_seconds(),
             lane_phase_healing_threshold: default_lane_phase_healing_threshold(),
+            wand_min_charges: default_wand_min_charges(),
+            respect_smoke: default_respect_smoke(),
+            hold_offense_while_retreating: default_hold_offense_while_retreating(),
+            generic_hero_survivability: default_generic_hero_survivability(),
+            combo_hold_after: None,
+            attack_key: default_attack_key(),
+            face_direction_with_alt: default_face_direction_with_alt(),
+            combo_max_event_age_ms: default_combo_max_event_age_ms(),
+            combo_fallback_settle_ms: default_combo_fallback_settle_ms(),
         }
     }
 }
@@ -1238,7 +2180,19 @@ impl Default for ArmletAutomationConfig {
             toggle_threshold: default_armlet_threshold(),
             predictive_offset: default_armlet_offset(),
             toggle_cooldown_ms: default_armlet_cooldown(),
+            emergency_toggle_key: String::new(),
+            stuck_detection: ArmletStuckDetectionConfig::default(),
             roshan: ArmletRoshanConfig::default(),
+            heroes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for ArmletStuckDetectionConfig {
+    fn default() -> Self {
+        Self {
+            falling_frames: default_armlet_stuck_falling_frames(),
+            window_ms: default_armlet_stuck_window_ms(),
         }
     }
 }
@@ -1265,7 +2219,12 @@ impl Default for HuskarConfig {
             armlet_toggle_cooldown_ms: default_armlet_cooldown(),
             berserker_blood_key: default_berserker_blood_key(),
             berserker_blood_delay_ms: default_berserker_blood_delay(),
+            berserker_blood_rearm: default_berserker_blood_rearm(),
+            berserker_blood_require_disable: false,
+            break_armlet_threshold_bonus: default_huskar_break_armlet_threshold_bonus(),
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
             roshan_spears: HuskarRoshanSpearsConfig::default(),
         }
@@ -1287,19 +2246,44 @@ impl Default for LegionCommanderConfig {
     fn default() -> Self {
         Self {
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
+            facet_self_buff_items: BTreeMap::new(),
+            auto_bkb_before_duel: false,
+            combo_mana_costs: BTreeMap::new(),
+            post_blink_delay_ms: default_legion_post_blink_delay_ms(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
 }
 
+impl ShadowFiendConfig {
+    /// Close, medium and far raze keys, lowercased, with their field names.
+    pub fn raze_keys(&self) -> [(&'static str, char); 3] {
+        [
+            ("close_raze_key", self.close_raze_key),
+            ("medium_raze_key", self.medium_raze_key),
+            ("far_raze_key", self.far_raze_key),
+        ]
+        .map(|(field, key)| (field, key.to_ascii_lowercase()))
+    }
+}
+
 impl Default for ShadowFiendConfig {
     fn default() -> Self {
         Self {
             raze_intercept_enabled: default_sf_raze_enabled(),
             raze_delay_ms: default_raze_delay(),
+            close_raze_key: default_sf_close_raze_key(),
+            medium_raze_key: default_sf_medium_raze_key(),
+            far_raze_key: default_sf_far_raze_key(),
+            cast_on_key_release: false,
             auto_bkb_on_ultimate: default_sf_auto_bkb_on_ultimate(),
             auto_d_on_ultimate: default_sf_auto_d_on_ultimate(),
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1309,6 +2293,8 @@ impl Default for OutworldDestroyerConfig {
     fn default() -> Self {
         Self {
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
             objurgation_key: default_od_objurgation_key(),
             arcane_orb_key: default_od_arcane_orb_key(),
             astral_imprisonment_key: default_od_astral_imprisonment_key(),
@@ -1328,6 +2314,7 @@ impl Default for OutworldDestroyerConfig {
             combo_item_delay_ms: default_od_combo_item_delay_ms(),
             post_ultimate_arcane_orb_presses: default_od_post_ultimate_arcane_orb_presses(),
             arcane_orb_press_interval_ms: default_od_arcane_orb_press_interval_ms(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1339,12 +2326,15 @@ impl Default for BroodmotherConfig {
             spider_micro_enabled: default_broodmother_enabled(),
             spider_control_group_key: default_broodmother_spider_control_group(),
             reselect_hero_key: default_broodmother_reselect_hero_key(),
-            attack_key: default_broodmother_attack_key(),
             auto_items_enabled: default_auto_items_enabled(),
             auto_items_modifier: default_auto_items_modifier(),
             auto_items: default_auto_items(),
             auto_abilities: default_auto_abilities(),
             auto_abilities_first: default_auto_abilities_first(),
+            standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1354,6 +2344,16 @@ impl Default for TinyConfig {
     fn default() -> Self {
         Self {
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
+            timing_preset: default_tiny_timing_preset(),
+            toss_catch_window_ms: default_tiny_toss_catch_window_ms(),
+            avalanche_spam_count: default_tiny_avalanche_spam_count(),
+            toss_spam_count: default_tiny_toss_spam_count(),
+            post_blink_delay_ms: default_tiny_post_blink_delay_ms(),
+            extra_toss_talent: 0,
+            combo_mana_costs: BTreeMap::new(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1374,6 +2374,8 @@ impl Default for LargoConfig {
             e_ability_key: default_largo_e_key(),
             r_ability_key: default_largo_r_key(),
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1400,6 +2402,8 @@ impl Default for MeepoConfig {
     fn default() -> Self {
         Self {
             standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
             earthbind_key: default_meepo_earthbind_key(),
             poof_key: default_meepo_poof_key(),
             dig_key: default_meepo_dig_key(),
@@ -1412,12 +2416,51 @@ impl Default for MeepoConfig {
             earthbind_press_interval_ms: default_meepo_earthbind_press_interval_ms(),
             poof_press_count: default_meepo_poof_press_count(),
             poof_press_interval_ms: default_meepo_poof_press_interval_ms(),
+            auto_bkb_before_poof: false,
             auto_dig_on_danger: default_meepo_auto_dig_on_danger(),
             dig_hp_threshold_percent: default_meepo_dig_hp_threshold_percent(),
             auto_megameepo_on_danger: default_meepo_auto_megameepo_on_danger(),
             megameepo_hp_threshold_percent: default_meepo_megameepo_hp_threshold_percent(),
             defensive_trigger_cooldown_ms: default_meepo_defensive_trigger_cooldown_ms(),
             farm_assist: MeepoFarmAssistConfig::default(),
+            survivability_hp_threshold_override: None,
+            armlet: HeroArmletOverrideConfig::default(),
+        }
+    }
+}
+
+impl Default for BristlebackConfig {
+    fn default() -> Self {
+        Self {
+            standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
+            viscous_nasal_goo_key: default_bristleback_viscous_nasal_goo_key(),
+            auto_quill_spray: default_bristleback_auto_quill_spray(),
+            quill_spray_key: default_bristleback_quill_spray_key(),
+            quill_min_mana_percent: default_bristleback_quill_min_mana_percent(),
+            quill_combat_window_ms: default_bristleback_quill_combat_window_ms(),
+            survivability_hp_threshold_override: None,
+            armlet: HeroArmletOverrideConfig::default(),
+        }
+    }
+}
+
+impl Default for ZeusConfig {
+    fn default() -> Self {
+        Self {
+            standalone_key: default_standalone_key(),
+            standalone_combo: StandaloneComboConfig::default(),
+            combo_orders: ComboOrdersConfig::default(),
+            arc_lightning_key: default_zeus_arc_lightning_key(),
+            lightning_bolt_key: default_zeus_lightning_bolt_key(),
+            thundergods_wrath_key: default_zeus_thundergods_wrath_key(),
+            spam_lightning_bolt: false,
+            auto_spam_while_attacking: default_zeus_auto_spam_while_attacking(),
+            attack_window_ms: default_zeus_attack_window_ms(),
+            mana_reserve: 0,
+            randomization: ComboRandomizationConfig::default(),
+            survivability_hp_threshold_override: None,
             armlet: HeroArmletOverrideConfig::default(),
         }
     }
@@ -1434,6 +2477,8 @@ impl Default for HeroesConfig {
             largo: LargoConfig::default(),
             broodmother: BroodmotherConfig::default(),
             meepo: MeepoConfig::default(),
+            zeus: ZeusConfig::default(),
+            bristleback: BristlebackConfig::default(),
         }
     }
 }
@@ -1448,6 +2493,8 @@ impl Default for DangerDetectionConfig {
             clear_delay_seconds: default_clear_delay_seconds(),
             healing_threshold_in_danger: default_healing_threshold_in_danger(),
             max_healing_items_per_danger: default_max_healing_items(),
+            healing_min_recent_loss_hp: 0,
+            max_defensive_items_per_danger: 0,
             auto_bkb: default_auto_bkb(),
             auto_satanic: default_auto_satanic(),
             satanic_hp_threshold: default_satanic_hp_threshold(),
@@ -1455,6 +2502,9 @@ impl Default for DangerDetectionConfig {
             auto_glimmer_cape: default_auto_glimmer_cape(),
             auto_ghost_scepter: default_auto_ghost_scepter(),
             auto_shivas_guard: default_auto_shivas_guard(),
+            defensive_item_priority: default_defensive_item_priority(),
+            self_cast_methods: default_self_cast_methods(),
+            self_cast_modifier: CastModifier::default(),
             auto_manta_on_silence: default_auto_manta_on_silence(),
             auto_lotus_on_silence: default_auto_lotus_on_silence(),
         }
@@ -1470,6 +2520,7 @@ impl Default for NeutralItemConfig {
             use_in_danger: default_use_in_danger(),
             hp_threshold: default_neutral_hp_threshold(),
             allowed_items: default_allowed_items(),
+            use_in_auto_items: false,
         }
     }
 }
@@ -1503,11 +2554,70 @@ impl Default for Settings {
             rune_alerts: RuneAlertConfig::default(),
             minimap_capture: MinimapCaptureConfig::default(),
             minimap_analysis: MinimapAnalysisConfig::default(),
+            profiles: ProfilesConfig::default(),
+            safe_mode: SafeModeConfig::default(),
+            practice_mode: PracticeModeConfig::default(),
+            input: InputConfig::default(),
+            simulation: SimulationConfig::default(),
+            overlay: OverlayConfig::default(),
+            notifications: NotificationsConfig::default(),
+            consumables: ConsumablesConfig::default(),
+            combo_recorder: ComboRecorderConfig::default(),
+            startup: StartupConfig::default(),
+        }
+    }
+}
+
+/// Overlay the active profile named in `live_contents`, if any. A missing or
+/// invalid profile is logged and the live config is used as-is.
+fn with_active_profile(paths: &ConfigPaths, live_contents: String) -> String {
+    let active = toml::from_str::<toml::Value>(&live_contents)
+        .ok()
+        .and_then(|value| value.get("profiles")?.get("active")?.as_str().map(str::to_string))
+        .unwrap_or_default();
+    if active.is_empty() {
+        return live_contents;
+    }
+    if !profiles::is_valid_profile_name(&active) {
+        warn!("Ignoring invalid active profile name '{}'", active);
+        return live_contents;
+    }
+
+    let path = profiles::profile_path(&paths.profiles_dir(), &active);
+    let applied = fs::read_to_string(&path)
+        .map_err(ConfigError::io("read", &path))
+        .and_then(|profile| profiles::apply_profile(&live_contents, &profile));
+    match applied {
+        Ok(contents) => {
+            info!("Applied profile '{}'", active);
+            contents
+        }
+        Err(e) => {
+            warn!("{}. Using the base config.", e);
+            live_contents
         }
     }
 }
 
 impl Settings {
+    /// Just `[logging]` from the live config, read without logging anything
+    /// so the file logger can be installed before [`Settings::load`] reports
+    /// its warnings. Defaults when the config is missing or does not parse.
+    pub fn load_logging() -> LoggingConfig {
+        #[derive(Deserialize)]
+        struct LoggingOnly {
+            #[serde(default)]
+            logging: LoggingConfig,
+        }
+
+        ConfigPaths::detect()
+            .ok()
+            .and_then(|paths| fs::read_to_string(paths.live_config_path()).ok())
+            .and_then(|contents| toml::from_str::<LoggingOnly>(&contents).ok())
+            .map(|config| config.logging)
+            .unwrap_or_default()
+    }
+
     pub fn load() -> Self {
         let paths = match ConfigPaths::detect() {
             Ok(paths) => paths,
@@ -1526,11 +2636,14 @@ impl Settings {
         };
 
         match fs::read_to_string(&config_path) {
-            Ok(contents) => match toml::from_str(&contents) {
+            Ok(contents) => match toml::from_str(&with_active_profile(&paths, contents)) {
                 Ok(settings) => {
                     info!("Loaded configuration from {}", config_path.display());
-                    let settings: Settings = settings;
+                    let mut settings: Settings = settings;
                     settings.validate_keybindings();
+                    settings.validate_bind_address();
+                    settings.validate_webhook_path();
+                    settings.clamp_min_action_interval();
                     settings
                 }
                 Err(e) => {
@@ -1554,47 +2667,162 @@ impl Settings {
     }
 
     fn validate_keybindings(&self) {
-        let mut key_map: HashMap<char, Vec<&str>> = HashMap::new();
+        for conflict in self.keybinding_conflicts() {
+            warn!("Keybinding conflict: {}", conflict);
+        }
+    }
+
+    /// Keys bound to more than one item slot, ability key (Q/W/E/R/D/F), Shadow
+    /// Fiend raze key, or hotkey.
+    ///
+    /// Hero standalone keys and `combo_trigger` may share a key with each other
+    /// since only the selected hero's trigger is live; any other overlap is reported.
+    /// A raze key is Shadow Fiend's Q/W/E, so it takes the place of the ability
+    /// it matches instead of clashing with it.
+    pub fn keybinding_conflicts(&self) -> Vec<String> {
+        let kb = &self.keybindings;
+        let mut bindings: Vec<(String, String, bool)> = [
+            ("slot0", kb.slot0),
+            ("slot1", kb.slot1),
+            ("slot2", kb.slot2),
+            ("slot3", kb.slot3),
+            ("slot4", kb.slot4),
+            ("slot5", kb.slot5),
+            ("neutral0", kb.neutral0),
+            ("teleport0", kb.teleport0),
+        ]
+        .into_iter()
+        .map(|(slot, key)| (key.to_string(), slot.to_string(), false))
+        .collect();
+
+        let raze_keys = self.heroes.shadow_fiend.raze_keys();
+        for ability in ['q', 'w', 'e', 'r', 'd', 'f'] {
+            if raze_keys.iter().any(|(_, key)| *key == ability) {
+                continue;
+            }
+            let label = format!("ability {}", ability.to_ascii_uppercase());
+            bindings.push((ability.to_string(), label, false));
+        }
+        for (field, key) in raze_keys {
+            let label = format!("heroes.shadow_fiend.{}", field);
+            bindings.push((key.to_string(), label, false));
+        }
+
+        let mut triggers = vec![(
+            "keybindings.combo_trigger".to_string(),
+            kb.combo_trigger.clone(),
+        )];
+        for hero in [
+            "bristleback",
+            "broodmother",
+            "huskar",
+            "largo",
+            "legion_commander",
+            "shadow_fiend",
+            "tiny",
+            "outworld_destroyer",
+            "meepo",
+            "zeus",
+        ] {
+            triggers.push((
+                format!("heroes.{}.standalone_key", hero),
+                self.get_standalone_key(hero),
+            ));
+        }
+        for (label, key) in triggers {
+            bindings.push((key, label, true));
+        }
+
+        let bm = &self.heroes.broodmother;
+        for (label, key) in [
+            ("armlet.emergency_toggle_key", &self.armlet.emergency_toggle_key),
+            ("armlet.roshan.toggle_key", &self.armlet.roshan.toggle_key),
+            ("combo_recorder.toggle_key", &self.combo_recorder.toggle_key),
+            (
+                "heroes.meepo.farm_assist.toggle_key",
+                &self.heroes.meepo.farm_assist.toggle_key,
+            ),
+            (
+                "heroes.outworld_destroyer.astral_self_cast_key",
+                &self.heroes.outworld_destroyer.astral_self_cast_key,
+            ),
+            ("heroes.broodmother.spider_control_group_key", &bm.spider_control_group_key),
+            ("heroes.broodmother.reselect_hero_key", &bm.reselect_hero_key),
+        ] {
+            bindings.push((key.clone(), label.to_string(), false));
+        }
+
+        let mut key_map: BTreeMap<String, Vec<(String, bool)>> = BTreeMap::new();
+        for (key, label, is_trigger) in bindings {
+            let key = key.trim().to_lowercase();
+            if key.is_empty() {
+                continue;
+            }
+            key_map.entry(key).or_default().push((label, is_trigger));
+        }
 
         key_map
-            .entry(self.keybindings.slot0)
-            .or_insert_with(Vec::new)
-            .push("slot0");
-        key_map
-            .entry(self.keybindings.slot1)
-            .or_insert_with(Vec::new)
-            .push("slot1");
-        key_map
-            .entry(self.keybindings.slot2)
-            .or_insert_with(Vec::new)
-            .push("slot2");
-        key_map
-            .entry(self.keybindings.slot3)
-            .or_insert_with(Vec::new)
-            .push("slot3");
-        key_map
-            .entry(self.keybindings.slot4)
-            .or_insert_with(Vec::new)
-            .push("slot4");
-        key_map
-            .entry(self.keybindings.slot5)
-            .or_insert_with(Vec::new)
-            .push("slot5");
-        key_map
-            .entry(self.keybindings.neutral0)
-            .or_insert_with(Vec::new)
-            .push("neutral0");
+            .into_iter()
+            .filter(|(_, labels)| labels.len() > 1 && !labels.iter().all(|(_, t)| *t))
+            .map(|(key, labels)| {
+                let labels: Vec<String> = labels.into_iter().map(|(label, _)| label).collect();
+                format!("key '{}' is bound to {}", key, labels.join(", "))
+            })
+            .collect()
+    }
 
-        for (key, slots) in key_map.iter() {
-            if slots.len() > 1 {
+    fn validate_bind_address(&mut self) {
+        let server = &mut self.server;
+        match server.bind_address.trim().parse::<IpAddr>() {
+            Ok(ip) if ip.is_loopback() => {}
+            Ok(ip) => warn!(
+                "GSI server will listen on {}:{}. Any host that can reach this port can post fake game state; only use this on a trusted network and firewall the port.",
+                ip, server.port
+            ),
+            Err(_) => {
                 warn!(
-                    "Keybinding conflict: Key '{}' is assigned to multiple slots: {:?}",
-                    key, slots
+                    "Invalid server.bind_address '{}'; falling back to {}",
+                    server.bind_address,
+                    default_bind_address()
                 );
+                server.bind_address = default_bind_address();
             }
         }
     }
 
+    /// axum reads `:` and `*` as route parameters, so a path with either
+    /// would match requests Dota never sends.
+    fn validate_webhook_path(&mut self) {
+        let server = &mut self.server;
+        if server.webhook_path.contains([':', '*']) {
+            warn!(
+                "Invalid server.webhook_path '{}' (':' and '*' are not allowed); falling back to '{}'",
+                server.webhook_path,
+                default_webhook_path()
+            );
+            server.webhook_path = default_webhook_path();
+        }
+    }
+
+    fn clamp_min_action_interval(&mut self) {
+        let input = &mut self.input;
+        if input.min_action_interval_ms > MAX_MIN_ACTION_INTERVAL_MS {
+            warn!(
+                "input.min_action_interval_ms {} is above the {}ms cap; capping it",
+                input.min_action_interval_ms, MAX_MIN_ACTION_INTERVAL_MS
+            );
+            input.min_action_interval_ms = MAX_MIN_ACTION_INTERVAL_MS;
+        }
+    }
+
+    /// Keys bound to inventory slots 0-5, in slot order.
+    pub fn inventory_slot_keys(&self) -> [char; 6] {
+        let keys = &self.keybindings;
+        [
+            keys.slot0, keys.slot1, keys.slot2, keys.slot3, keys.slot4, keys.slot5,
+        ]
+    }
+
     pub fn get_key_for_slot(&self, slot: &str) -> Option<char> {
         match slot {
             "slot0" => Some(self.keybindings.slot0),
@@ -1604,6 +2832,7 @@ impl Settings {
             "slot4" => Some(self.keybindings.slot4),
             "slot5" => Some(self.keybindings.slot5),
             "neutral0" => Some(self.keybindings.neutral0),
+            "teleport0" => Some(self.keybindings.teleport0),
             _ => None,
         }
     }
@@ -1633,10 +2862,65 @@ impl Settings {
             "npc_dota_hero_largo" => Some(self.heroes.largo.armlet.clone()),
             "npc_dota_hero_broodmother" => Some(self.heroes.broodmother.armlet.clone()),
             "npc_dota_hero_meepo" => Some(self.heroes.meepo.armlet.clone()),
+            "npc_dota_hero_zuus" => Some(self.heroes.zeus.armlet.clone()),
+            "npc_dota_hero_bristleback" => Some(self.heroes.bristleback.armlet.clone()),
+            _ => {
+                let overrides = &self.armlet.heroes;
+                overrides
+                    .get(hero_name.trim_start_matches("npc_dota_hero_"))
+                    .or_else(|| {
+                        // Keys may use the display name (`[armlet.heroes.windranger]`)
+                        let hero = Hero::from_alias(hero_name)?;
+                        overrides
+                            .iter()
+                            .find(|(name, _)| Hero::from_alias(name) == Some(hero))
+                            .map(|(_, hero_override)| hero_override)
+                    })
+                    .cloned()
+            }
+        }
+    }
+
+    fn hero_survivability_hp_threshold(&self, hero_name: &str) -> Option<u32> {
+        let heroes = &self.heroes;
+        match hero_name {
+            "npc_dota_hero_huskar" => heroes.huskar.survivability_hp_threshold_override,
+            "npc_dota_hero_legion_commander" => {
+                heroes.legion_commander.survivability_hp_threshold_override
+            }
+            "npc_dota_hero_nevermore" => heroes.shadow_fiend.survivability_hp_threshold_override,
+            "npc_dota_hero_tiny" => heroes.tiny.survivability_hp_threshold_override,
+            "npc_dota_hero_obsidian_destroyer" => {
+                heroes
+                    .outworld_destroyer
+                    .survivability_hp_threshold_override
+            }
+            "npc_dota_hero_largo" => heroes.largo.survivability_hp_threshold_override,
+            "npc_dota_hero_broodmother" => heroes.broodmother.survivability_hp_threshold_override,
+            "npc_dota_hero_meepo" => heroes.meepo.survivability_hp_threshold_override,
+            "npc_dota_hero_zuus" => heroes.zeus.survivability_hp_threshold_override,
+            "npc_dota_hero_bristleback" => heroes.bristleback.survivability_hp_threshold_override,
             _ => None,
         }
     }
 
+    /// Out-of-danger healing threshold for `hero_name`: its
+    /// `survivability_hp_threshold_override` when set, otherwise
+    /// `[common] survivability_hp_threshold`.
+    pub fn survivability_hp_threshold(&self, hero_name: &str) -> u32 {
+        self.hero_survivability_hp_threshold(hero_name)
+            .unwrap_or(self.common.survivability_hp_threshold)
+    }
+
+    /// HP added to `hero_name`'s armlet toggle threshold while it is Broken.
+    /// Only Huskar, whose Berserker's Blood passive Break turns off, has one.
+    pub fn break_armlet_threshold_bonus(&self, hero_name: &str) -> u32 {
+        match hero_name {
+            "npc_dota_hero_huskar" => self.heroes.huskar.break_armlet_threshold_bonus,
+            _ => 0,
+        }
+    }
+
     pub fn resolve_armlet_config(&self, hero_name: &str) -> EffectiveArmletConfig {
         let mut resolved = EffectiveArmletConfig {
             enabled: self.armlet.enabled,
@@ -1644,6 +2928,7 @@ impl Settings {
             toggle_threshold: self.armlet.toggle_threshold,
             predictive_offset: self.armlet.predictive_offset,
             toggle_cooldown_ms: self.armlet.toggle_cooldown_ms,
+            stuck_detection: self.armlet.stuck_detection.clone(),
             roshan: self.armlet.roshan.clone(),
         };
 
@@ -1665,27 +2950,130 @@ impl Settings {
         resolved
     }
 
+    /// Standalone combo key for a hero, keyed by its `[heroes.<name>]` section.
     pub fn get_standalone_key(&self, hero: &str) -> String {
         match hero {
+            "bristleback" => self.heroes.bristleback.standalone_key.clone(),
+            "broodmother" => self.heroes.broodmother.standalone_key.clone(),
             "huskar" => self.heroes.huskar.standalone_key.clone(),
+            "largo" => self.heroes.largo.standalone_key.clone(),
             "legion_commander" => self.heroes.legion_commander.standalone_key.clone(),
-            "shadow_fiend" => "q".to_string(), // SF uses Q/W/E interception
+            "shadow_fiend" => self.heroes.shadow_fiend.standalone_key.clone(),
             "tiny" => self.heroes.tiny.standalone_key.clone(),
             "outworld_destroyer" => self.heroes.outworld_destroyer.standalone_key.clone(),
             "meepo" => self.heroes.meepo.standalone_key.clone(),
+            "zeus" => self.heroes.zeus.standalone_key.clone(),
             _ => default_standalone_key(),
         }
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let paths = ConfigPaths::detect().map_err(std::io::Error::other)?;
-        let desired_contents = toml::to_string_pretty(self)?;
+    /// Standalone combo options for a hero, keyed by its `[heroes.<name>]`
+    /// section.
+    pub fn get_standalone_combo(&self, hero: &str) -> Option<&StandaloneComboConfig> {
+        match hero {
+            "bristleback" => Some(&self.heroes.bristleback.standalone_combo),
+            "broodmother" => Some(&self.heroes.broodmother.standalone_combo),
+            "huskar" => Some(&self.heroes.huskar.standalone_combo),
+            "largo" => Some(&self.heroes.largo.standalone_combo),
+            "legion_commander" => Some(&self.heroes.legion_commander.standalone_combo),
+            "shadow_fiend" => Some(&self.heroes.shadow_fiend.standalone_combo),
+            "tiny" => Some(&self.heroes.tiny.standalone_combo),
+            "outworld_destroyer" => Some(&self.heroes.outworld_destroyer.standalone_combo),
+            "meepo" => Some(&self.heroes.meepo.standalone_combo),
+            "zeus" => Some(&self.heroes.zeus.standalone_combo),
+            _ => None,
+        }
+    }
+
+    /// Orders around a hero's standalone combo; only heroes whose combo runs
+    /// on the action executor have them.
+    pub fn get_combo_orders(&self, hero: &str) -> Option<&ComboOrdersConfig> {
+        match hero {
+            "bristleback" => Some(&self.heroes.bristleback.combo_orders),
+            "broodmother" => Some(&self.heroes.broodmother.combo_orders),
+            "legion_commander" => Some(&self.heroes.legion_commander.combo_orders),
+            "tiny" => Some(&self.heroes.tiny.combo_orders),
+            "outworld_destroyer" => Some(&self.heroes.outworld_destroyer.combo_orders),
+            "meepo" => Some(&self.heroes.meepo.combo_orders),
+            "zeus" => Some(&self.heroes.zeus.combo_orders),
+            _ => None,
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let paths = ConfigPaths::detect()?;
+        let mut desired_contents = toml::to_string_pretty(self)?;
+
+        // With a profile active, its sections belong to the profile file and
+        // the live config keeps the base copy.
+        if !self.profiles.active.is_empty() {
+            let profile_path = profiles::write_profile(
+                &paths.profiles_dir(),
+                &self.profiles.active,
+                &desired_contents,
+            )?;
+            info!("Profile saved to {}", profile_path.display());
+            desired_contents = profiles::split_profile_sections(&desired_contents)?.0;
+        }
+
         let config_path =
-            persist_live_config(&paths, &desired_contents, EMBEDDED_CONFIG_TEMPLATE)
-                .map_err(std::io::Error::other)?;
+            persist_live_config(&paths, &desired_contents, EMBEDDED_CONFIG_TEMPLATE)?;
         info!("Settings saved to {}", config_path.display());
         Ok(())
     }
+
+    /// Serialize these settings for sharing (clipboard export).
+    pub fn to_shared_toml(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Parse a shared config, keeping this machine's `[server]`, `[profiles]`,
+    /// `[safe_mode]`, `[practice_mode]` and `[startup]` so an import cannot
+    /// move the GSI listener, point at a profile that does not exist here, or
+    /// switch input back on. Nothing is saved.
+    pub fn from_shared_toml(contents: &str, current: &Settings) -> Result<Settings, ConfigError> {
+        let mut imported: Settings =
+            toml::from_str(contents).map_err(|e| ConfigError::Invalid(Box::new(e)))?;
+        imported.server = current.server.clone();
+        imported.profiles = current.profiles.clone();
+        imported.safe_mode = current.safe_mode.clone();
+        imported.practice_mode = current.practice_mode.clone();
+        imported.startup = current.startup.clone();
+        imported.validate_keybindings();
+        imported.clamp_min_action_interval();
+        Ok(imported)
+    }
+
+    /// Names of the saved profiles.
+    pub fn list_profiles() -> Vec<String> {
+        ConfigPaths::detect()
+            .map(|paths| profiles::list_profiles(&paths.profiles_dir()))
+            .unwrap_or_default()
+    }
+
+    /// Save the current profile sections as profile `name`.
+    pub fn save_as_profile(&self, name: &str) -> Result<(), ConfigError> {
+        let paths = ConfigPaths::detect()?;
+        let contents = toml::to_string_pretty(self)?;
+        let path = profiles::write_profile(&paths.profiles_dir(), name, &contents)?;
+        info!("Profile '{}' saved to {}", name, path.display());
+        Ok(())
+    }
+
+    /// Make `name` the active profile (empty for none) and reload settings.
+    pub fn switch_profile(name: &str) -> Result<Settings, ConfigError> {
+        let paths = ConfigPaths::detect()?;
+        if !name.is_empty()
+            && !profiles::profile_path(&paths.profiles_dir(), name).is_file()
+        {
+            return Err(ConfigError::ProfileNotFound(name.to_string()));
+        }
+
+        let active = format!("[profiles]\nactive = {}\n", toml::Value::from(name));
+        persist_live_config(&paths, &active, EMBEDDED_CONFIG_TEMPLATE)?;
+        info!("Switched to profile '{}'", if name.is_empty() { "(none)" } else { name });
+        Ok(Settings::load())
+    }
 }
 
 #[cfg(test)]
@@ -1702,6 +3090,230 @@ mod tests {
         assert_eq!(settings.heroes.huskar.roshan_spears.reenable_buffer_hp, 100);
     }
 
+    #[test]
+    fn shared_config_round_trips_but_keeps_local_server() {
+        let mut shared = Settings::default();
+        shared.server.port = 4000;
+        shared.common.survivability_hp_threshold = 42;
+        let exported = shared.to_shared_toml().unwrap();
+
+        let mut current = Settings::default();
+        current.safe_mode.enabled = true;
+        current.practice_mode.enabled = true;
+        let imported = Settings::from_shared_toml(&exported, &current).unwrap();
+        assert_eq!(imported.common.survivability_hp_threshold, 42);
+        assert_eq!(imported.server.port, current.server.port);
+        assert!(imported.safe_mode.enabled);
+        assert!(imported.practice_mode.enabled);
+
+        let err = Settings::from_shared_toml("[common]\nsurvivability_hp_threshold = \"high\"", &current)
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::Invalid(_)), "{err}");
+        assert!(err.to_string().starts_with("Invalid config:"), "{err}");
+    }
+
+    #[test]
+    fn teleport_slot_key_is_configurable() {
+        let settings = Settings::default();
+        assert_eq!(settings.get_key_for_slot("teleport0"), Some('t'));
+
+        let settings: Settings = toml::from_str("[keybindings]\nteleport0 = \"q\"\n").unwrap();
+        assert_eq!(settings.get_key_for_slot("teleport0"), Some('q'));
+        assert_eq!(settings.get_key_for_slot("neutral0"), Some('0'));
+    }
+
+    #[test]
+    fn keybinding_conflicts_flag_ability_and_hotkey_overlaps() {
+        let mut settings = Settings::default();
+        assert!(settings.keybinding_conflicts().is_empty());
+
+        settings.heroes.shadow_fiend.standalone_key = "Q".to_string();
+        settings.keybindings.neutral0 = 'x';
+        settings.armlet.emergency_toggle_key = "Home".to_string();
+        assert_eq!(
+            settings.keybinding_conflicts(),
+            vec![
+                "key 'home' is bound to keybindings.combo_trigger, \
+                 heroes.bristleback.standalone_key, heroes.broodmother.standalone_key, heroes.huskar.standalone_key, heroes.largo.standalone_key, \
+                 heroes.legion_commander.standalone_key, heroes.tiny.standalone_key, \
+                 heroes.outworld_destroyer.standalone_key, heroes.meepo.standalone_key, \
+                 heroes.zeus.standalone_key, armlet.emergency_toggle_key",
+                "key 'q' is bound to heroes.shadow_fiend.close_raze_key, heroes.shadow_fiend.standalone_key",
+                "key 'x' is bound to slot1, neutral0",
+            ]
+        );
+    }
+
+    #[test]
+    fn raze_keys_must_not_reuse_item_or_other_raze_keys() {
+        let mut settings = Settings::default();
+        assert!(settings.keybinding_conflicts().is_empty());
+
+        settings.heroes.shadow_fiend.far_raze_key = 'Q';
+        assert_eq!(
+            settings.keybinding_conflicts(),
+            vec![
+                "key 'q' is bound to heroes.shadow_fiend.close_raze_key, \
+                 heroes.shadow_fiend.far_raze_key"
+            ]
+        );
+
+        settings.heroes.shadow_fiend.close_raze_key = 'z';
+        settings.heroes.shadow_fiend.far_raze_key = 'e';
+        assert_eq!(
+            settings.keybinding_conflicts(),
+            vec!["key 'z' is bound to slot0, heroes.shadow_fiend.close_raze_key"]
+        );
+    }
+
+    #[test]
+    fn invalid_bind_address_falls_back_to_loopback() {
+        let mut settings: Settings =
+            toml::from_str("[server]\nport = 3001\nbind_address = \"0.0.0.0\"\n").unwrap();
+        settings.validate_bind_address();
+        assert_eq!(settings.server.socket_addr().to_string(), "0.0.0.0:3001");
+
+        settings.server.bind_address = "gaming-pc".to_string();
+        settings.validate_bind_address();
+        assert_eq!(settings.server.bind_address, "127.0.0.1");
+        assert_eq!(settings.server.socket_addr().to_string(), "127.0.0.1:3001");
+    }
+
+    #[test]
+    fn webhook_path_with_route_parameters_falls_back_to_default() {
+        let mut settings: Settings = toml::from_str("[server]\nwebhook_path = \"/gsi\"\n").unwrap();
+        settings.validate_webhook_path();
+        assert_eq!(settings.server.webhook_route(), "/gsi");
+
+        for path in ["/gsi/:id", "/gsi/*rest"] {
+            settings.server.webhook_path = path.to_string();
+            settings.validate_webhook_path();
+            assert_eq!(settings.server.webhook_route(), "/");
+        }
+    }
+
+    #[test]
+    fn simulation_backend_is_validated_when_parsed() {
+        let parse = |toml: &str| toml::from_str::<Settings>(toml).map(|s| s.simulation.backend);
+        assert_eq!(parse("").unwrap(), InputBackend::Enigo);
+        assert_eq!(
+            parse("[simulation]\nbackend = \"scancode\"\n").unwrap(),
+            InputBackend::Scancode
+        );
+        assert!(parse("[simulation]\nbackend = \"sendinput\"\n").is_err());
+        assert!(parse("[simulation]\nbackend = \"ScanCode\"\n").is_err());
+    }
+
+    #[test]
+    fn self_cast_settings_are_validated_when_parsed() {
+        let parse = |toml: &str| toml::from_str::<Settings>(toml).map(|s| s.danger_detection);
+        let danger = parse(
+            "[danger_detection]\nself_cast_modifier = \"Control\"\n\
+             [danger_detection.self_cast_methods]\nitem_ghost = \"self_cast_modifier\"\n",
+        )
+        .unwrap();
+        assert_eq!(danger.self_cast_modifier, CastModifier::Ctrl);
+        assert_eq!(
+            danger.self_cast_methods["item_ghost"],
+            SelfCastMethod::SelfCastModifier
+        );
+        assert!(parse("[danger_detection]\nself_cast_modifier = \"Win\"\n").is_err());
+        assert!(parse("[danger_detection.self_cast_methods]\nitem_ghost = \"triple\"\n").is_err());
+    }
+
+    #[test]
+    fn attack_move_is_validated_when_parsed() {
+        let parse = |value: &str| {
+            toml::from_str::<Settings>(&format!("[heroes.tiny]\nattack_move = \"{}\"\n", value))
+                .map(|s| s.heroes.tiny.combo_orders.attack_move)
+        };
+        assert_eq!(parse("after").unwrap(), AttackMove::After);
+        assert_eq!(parse("off").unwrap(), AttackMove::Off);
+        assert!(parse("After").is_err());
+        assert!(parse("sideways").is_err());
+        let settings: Settings = toml::from_str("").unwrap();
+        assert_eq!(
+            settings.heroes.tiny.combo_orders.attack_move,
+            AttackMove::Off
+        );
+    }
+
+    #[test]
+    fn recorded_combo_inputs_are_validated_when_parsed() {
+        let parse = |input: &str| {
+            toml::from_str::<Settings>(&format!(
+                "[combo_recorder.recordings]\ntiny = [{{ input = \"{}\" }}]\n",
+                input
+            ))
+            .map(|s| s.combo_recorder.recordings["tiny"][0].input)
+        };
+        assert_eq!(parse("q").unwrap(), ComboInput::Key('q'));
+        assert_eq!(parse("right_click").unwrap(), ComboInput::RightClick);
+        assert!(parse("qw").is_err());
+        assert!(parse("").is_err());
+        assert_eq!(String::from(ComboInput::LeftClick), "left_click");
+    }
+
+    #[test]
+    fn min_action_interval_is_capped_at_load() {
+        let mut settings: Settings =
+            toml::from_str("[input]\nmin_action_interval_ms = 2000\n").unwrap();
+        settings.clamp_min_action_interval();
+        assert_eq!(
+            settings.input.min_action_interval_ms,
+            MAX_MIN_ACTION_INTERVAL_MS
+        );
+
+        let imported = Settings::from_shared_toml(
+            "[input]\nmin_action_interval_ms = 40\n",
+            &Settings::default(),
+        )
+        .unwrap();
+        assert_eq!(imported.input.min_action_interval_ms, 40);
+    }
+
+    #[test]
+    fn webhook_route_always_has_a_leading_slash() {
+        let mut server = ServerConfig::default();
+        assert_eq!(server.webhook_route(), "/");
+
+        server.webhook_path = " dota/gsi ".to_string();
+        assert_eq!(server.webhook_route(), "/dota/gsi");
+
+        server.webhook_path = "//gsi".to_string();
+        assert_eq!(server.webhook_route(), "/gsi");
+    }
+
+    #[test]
+    fn tiny_timing_presets_override_custom_fields() {
+        let mut tiny = TinyConfig::default();
+        assert_eq!(
+            tiny.combo_timing(),
+            TinyComboTiming {
+                toss_catch_window_ms: 1400,
+                avalanche_spam_count: 3,
+                toss_spam_count: 4,
+                post_blink_delay_ms: 100,
+            }
+        );
+
+        tiny.toss_catch_window_ms = 900;
+        assert_eq!(tiny.combo_timing().toss_catch_window_ms, 900);
+
+        tiny.timing_preset = "high_ping".to_string();
+        assert_eq!(tiny.combo_timing().toss_catch_window_ms, 1550);
+        assert_eq!(tiny.combo_timing().toss_spam_count, 6);
+        assert_eq!(tiny.combo_timing().post_blink_delay_ms, 150);
+
+        for (name, timing) in TINY_TIMING_PRESETS {
+            assert!(
+                timing.toss_catch_window_ms > TINY_TOSS_FLIGHT_MS,
+                "{} catch window ends before Toss lands",
+                name
+            );
+        }
+    }
+
     #[test]
     fn meepo_defaults_are_exposed_through_settings() {
         let settings = Settings::default();
@@ -1746,6 +3358,36 @@ mod tests {
         assert_eq!(settings.get_standalone_key("meepo"), "Home");
     }
 
+    #[test]
+    fn get_standalone_key_reads_each_hero_section() {
+        let mut settings = Settings::default();
+        settings.heroes.shadow_fiend.standalone_key = "F6".to_string();
+        settings.heroes.broodmother.standalone_key = "F7".to_string();
+        settings.heroes.largo.standalone_key = "Insert".to_string();
+
+        assert_eq!(settings.get_standalone_key("shadow_fiend"), "F6");
+        assert_eq!(settings.get_standalone_key("broodmother"), "F7");
+        assert_eq!(settings.get_standalone_key("largo"), "Insert");
+        assert_eq!(settings.get_standalone_key("unknown"), "Home");
+    }
+
+    #[test]
+    fn standalone_combo_options_are_read_flat_from_the_hero_section() {
+        let settings: Settings = toml::from_str(
+            "[heroes.tiny]\ncombo_retrigger_cooldown_ms = 300\nattack_move = \"after\"\n\
+             [heroes.huskar]\ncombo_retrigger_cooldown_ms = 150\n",
+        )
+        .unwrap();
+
+        let combo = |hero| settings.get_standalone_combo(hero).unwrap();
+        let orders = |hero| settings.get_combo_orders(hero);
+        assert_eq!(combo("tiny").combo_retrigger_cooldown_ms, 300);
+        assert_eq!(orders("tiny").unwrap().attack_move, "after");
+        assert_eq!(combo("huskar").combo_retrigger_cooldown_ms, 150);
+        assert!(orders("huskar").is_none());
+        assert_eq!(orders("zeus").unwrap().attack_move, "off");
+    }
+
     #[test]
     fn rune_alert_defaults_are_exposed_through_settings() {
         let settings = Settings::default();
@@ -1,3 +1,4 @@
+use crate::config::error::ConfigError;
 use std::fs;
 use std::path::PathBuf;
 
@@ -11,14 +12,14 @@ pub struct ConfigPaths {
 }
 
 impl ConfigPaths {
-    pub fn detect() -> Result<Self, String> {
+    pub fn detect() -> Result<Self, ConfigError> {
         let local_app_data_dir = std::env::var_os("LOCALAPPDATA")
             .map(PathBuf::from)
-            .ok_or_else(|| "LOCALAPPDATA is not set".to_string())?;
+            .ok_or(ConfigError::NoLocalAppData)?;
         let exe_dir = std::env::current_exe()
-            .map_err(|e| format!("Failed to resolve current exe: {e}"))?
+            .map_err(ConfigError::CurrentExe)?
             .parent()
-            .ok_or_else(|| "Current exe has no parent directory".to_string())?
+            .ok_or(ConfigError::NoExeDir)?
             .to_path_buf();
 
         Ok(Self::from_parts(local_app_data_dir, exe_dir))
@@ -31,11 +32,18 @@ impl ConfigPaths {
         }
     }
 
+    /// `%LOCALAPPDATA%\dota2-scripts`, home of the live config, logs and update backups.
+    pub fn app_data_dir(&self) -> PathBuf {
+        self.local_app_data_dir.join("dota2-scripts")
+    }
+
     pub fn live_config_path(&self) -> PathBuf {
-        self.local_app_data_dir
-            .join("dota2-scripts")
-            .join("config")
-            .join("config.toml")
+        self.app_data_dir().join("config").join("config.toml")
+    }
+
+    /// Named profile files, see `config::profiles`.
+    pub fn profiles_dir(&self) -> PathBuf {
+        self.app_data_dir().join("config").join("profiles")
     }
 
     pub fn legacy_install_config_path(&self) -> PathBuf {
@@ -46,11 +54,11 @@ impl ConfigPaths {
 pub fn bootstrap_live_config(
     paths: &ConfigPaths,
     embedded_template: &str,
-) -> Result<PathBuf, String> {
+) -> Result<PathBuf, ConfigError> {
     let live_path = paths.live_config_path();
 
     if let Some(parent) = live_path.parent() {
-        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {e}"))?;
+        fs::create_dir_all(parent).map_err(ConfigError::io("create", parent))?;
     }
 
     if live_path.exists() {
@@ -58,13 +66,13 @@ pub fn bootstrap_live_config(
     }
 
     let seed = if paths.legacy_install_config_path().exists() {
-        fs::read_to_string(paths.legacy_install_config_path())
-            .map_err(|e| format!("Failed to import legacy config: {e}"))?
+        let legacy_path = paths.legacy_install_config_path();
+        fs::read_to_string(&legacy_path).map_err(ConfigError::io("import", legacy_path))?
     } else {
         embedded_template.to_string()
     };
 
-    fs::write(&live_path, seed).map_err(|e| format!("Failed to write live config: {e}"))?;
+    fs::write(&live_path, seed).map_err(ConfigError::io("write", &live_path))?;
 
     Ok(live_path)
 }
@@ -72,46 +80,45 @@ pub fn bootstrap_live_config(
 pub fn merge_template_with_local(
     template_contents: &str,
     local_contents: &str,
-) -> Result<String, String> {
+) -> Result<String, ConfigError> {
     let mut template_value: toml::Value =
-        toml::from_str(template_contents).map_err(|e| format!("Template TOML error: {e}"))?;
+        toml::from_str(template_contents).map_err(ConfigError::parse("Template"))?;
     let local_value: toml::Value =
-        toml::from_str(local_contents).map_err(|e| format!("Local TOML error: {e}"))?;
+        toml::from_str(local_contents).map_err(ConfigError::parse("Local"))?;
 
     merge_values(&mut template_value, &local_value);
 
-    toml::to_string_pretty(&template_value).map_err(|e| format!("TOML serialization error: {e}"))
+    Ok(toml::to_string_pretty(&template_value)?)
 }
 
 pub fn merge_saved_settings_with_existing(
     existing_contents: &str,
     desired_contents: &str,
-) -> Result<String, String> {
+) -> Result<String, ConfigError> {
     if existing_contents.trim().is_empty() {
         return Ok(desired_contents.to_string());
     }
 
     let mut existing_value: toml::Value =
-        toml::from_str(existing_contents).map_err(|e| format!("Existing TOML error: {e}"))?;
+        toml::from_str(existing_contents).map_err(ConfigError::parse("Existing"))?;
     let desired_value: toml::Value =
-        toml::from_str(desired_contents).map_err(|e| format!("Desired TOML error: {e}"))?;
+        toml::from_str(desired_contents).map_err(ConfigError::parse("Desired"))?;
 
     merge_values(&mut existing_value, &desired_value);
 
-    toml::to_string_pretty(&existing_value).map_err(|e| format!("TOML serialization error: {e}"))
+    Ok(toml::to_string_pretty(&existing_value)?)
 }
 
 pub fn persist_live_config(
     paths: &ConfigPaths,
     desired_contents: &str,
     embedded_template: &str,
-) -> Result<PathBuf, String> {
+) -> Result<PathBuf, ConfigError> {
     let live_path = bootstrap_live_config(paths, embedded_template)?;
     let existing_contents = fs::read_to_string(&live_path).unwrap_or_default();
     let merged_contents = merge_saved_settings_with_existing(&existing_contents, desired_contents)?;
 
-    fs::write(&live_path, merged_contents)
-        .map_err(|e| format!("Failed to write live config: {e}"))?;
+    fs::write(&live_path, merged_contents).map_err(ConfigError::io("write", &live_path))?;
 
     Ok(live_path)
 }
@@ -0,0 +1,383 @@
+//! `--doctor` self-test: checks GSI connectivity prerequisites and input
+//! simulation, then prints a pass/fail checklist.
+
+use crate::config::Settings;
+use crate::input::simulation::{modifier_down, modifier_up, ModifierKey};
+use rdev::{grab, EventType, Key};
+use std::fmt;
+use std::fs;
+use std::net::{SocketAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Command-line flag that runs the doctor instead of the app.
+pub const DOCTOR_FLAG: &str = "--doctor";
+
+const DOTA_GSI_CONFIG_SUBDIR: &str =
+    "steamapps/common/dota 2 beta/game/dota/cfg/gamestate_integration";
+const HOOK_INSTALL_DELAY: Duration = Duration::from_millis(250);
+const INPUT_ECHO_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl fmt::Display for DoctorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoctorStatus::Pass => write!(f, "PASS"),
+            DoctorStatus::Warn => write!(f, "WARN"),
+            DoctorStatus::Fail => write!(f, "FAIL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DoctorCheck {
+    pub name: &'static str,
+    pub status: DoctorStatus,
+    pub detail: String,
+}
+
+impl DoctorCheck {
+    fn new(name: &'static str, status: DoctorStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    pub fn has_failures(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == DoctorStatus::Fail)
+    }
+
+    /// Render the checklist as plain text for the terminal.
+    pub fn render(&self) -> String {
+        let mut out = String::from("Dota 2 Scripts doctor\n\n");
+        for check in &self.checks {
+            out.push_str(&format!("[{}] {}: {}\n", check.status, check.name, check.detail));
+        }
+        let passed = self
+            .checks
+            .iter()
+            .filter(|check| check.status == DoctorStatus::Pass)
+            .count();
+        out.push_str(&format!("\n{}/{} checks passed\n", passed, self.checks.len()));
+        out
+    }
+}
+
+/// Entry point for `--doctor`: runs every check, prints the checklist, and
+/// returns the process exit code (non-zero when any check failed).
+pub fn run_cli() -> i32 {
+    attach_parent_console();
+
+    let settings = Settings::load();
+    let report = run_doctor(&settings);
+    println!("{}", report.render());
+
+    if report.has_failures() {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn run_doctor(settings: &Settings) -> DoctorReport {
+    let port = settings.server.port;
+    DoctorReport {
+        checks: vec![
+            check_gsi_port(settings.server.socket_addr()),
+            check_gsi_config(port, &default_gsi_config_dirs()),
+            check_input_simulation(),
+            check_elevation(),
+        ],
+    }
+}
+
+pub fn check_gsi_port(addr: SocketAddr) -> DoctorCheck {
+    const NAME: &str = "GSI port";
+    match TcpListener::bind(addr) {
+        Ok(_) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Pass,
+            format!("{} is free and bindable", addr),
+        ),
+        Err(e) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Fail,
+            format!(
+                "cannot bind {} ({}); is another instance already running?",
+                addr, e
+            ),
+        ),
+    }
+}
+
+/// Look for a `gamestate_integration_*.cfg` whose URI targets our port.
+pub fn check_gsi_config(port: u16, config_dirs: &[PathBuf]) -> DoctorCheck {
+    const NAME: &str = "GSI config";
+    let existing_dirs: Vec<&PathBuf> = config_dirs.iter().filter(|dir| dir.is_dir()).collect();
+    if existing_dirs.is_empty() {
+        return DoctorCheck::new(
+            NAME,
+            DoctorStatus::Fail,
+            "no Dota 2 gamestate_integration folder found in the Steam libraries",
+        );
+    }
+
+    let config_files: Vec<PathBuf> = existing_dirs
+        .iter()
+        .flat_map(|dir| find_gsi_config_files(dir))
+        .collect();
+    if config_files.is_empty() {
+        return DoctorCheck::new(
+            NAME,
+            DoctorStatus::Fail,
+            format!(
+                "no gamestate_integration_*.cfg in {}",
+                existing_dirs[0].display()
+            ),
+        );
+    }
+
+    let expected_uris = [
+        format!("127.0.0.1:{}", port),
+        format!("localhost:{}", port),
+    ];
+    let matching = config_files.iter().find(|path| {
+        fs::read_to_string(path)
+            .map(|contents| expected_uris.iter().any(|uri| contents.contains(uri.as_str())))
+            .unwrap_or(false)
+    });
+
+    match matching {
+        Some(path) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Pass,
+            format!("{} points at port {}", path.display(), port),
+        ),
+        None => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Warn,
+            format!(
+                "found {} config file(s) but none has a uri for 127.0.0.1:{}",
+                config_files.len(),
+                port
+            ),
+        ),
+    }
+}
+
+fn find_gsi_config_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with("gamestate_integration") && name.ends_with(".cfg")
+                })
+        })
+        .collect()
+}
+
+/// Candidate `gamestate_integration` folders across the default Steam
+/// install locations and any extra libraries listed in `libraryfolders.vdf`.
+pub fn default_gsi_config_dirs() -> Vec<PathBuf> {
+    let mut steam_roots = Vec::new();
+    for var in ["ProgramFiles(x86)", "ProgramFiles"] {
+        if let Ok(program_files) = std::env::var(var) {
+            steam_roots.push(PathBuf::from(program_files).join("Steam"));
+        }
+    }
+
+    let mut libraries = steam_roots.clone();
+    for root in &steam_roots {
+        let vdf_path = root.join("steamapps").join("libraryfolders.vdf");
+        if let Ok(contents) = fs::read_to_string(vdf_path) {
+            libraries.extend(parse_library_folders(&contents));
+        }
+    }
+
+    let mut dirs: Vec<PathBuf> = libraries
+        .into_iter()
+        .map(|library| library.join(DOTA_GSI_CONFIG_SUBDIR))
+        .collect();
+    dirs.dedup();
+    dirs
+}
+
+/// Extract `"path"` entries from Steam's `libraryfolders.vdf`.
+fn parse_library_folders(contents: &str) -> Vec<PathBuf> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut quoted = line.split('"').skip(1).step_by(2);
+            match (quoted.next(), quoted.next()) {
+                (Some("path"), Some(value)) => Some(PathBuf::from(value.replace("\\\\", "\\"))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Tap Shift through the synthetic input worker and confirm a low-level
+/// keyboard hook observes it.
+fn check_input_simulation() -> DoctorCheck {
+    const NAME: &str = "Input simulation";
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = grab(move |event| {
+            if matches!(
+                event.event_type,
+                EventType::KeyPress(Key::ShiftLeft | Key::ShiftRight)
+            ) {
+                let _ = tx.send(());
+            }
+            Some(event)
+        });
+    });
+
+    thread::sleep(HOOK_INSTALL_DELAY);
+    modifier_down(ModifierKey::Shift);
+    modifier_up(ModifierKey::Shift);
+
+    match rx.recv_timeout(INPUT_ECHO_TIMEOUT) {
+        Ok(()) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Pass,
+            "simulated Shift press was observed by the keyboard hook",
+        ),
+        Err(RecvTimeoutError::Timeout) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Fail,
+            "simulated Shift press was not observed; input may be blocked by another program or missing permissions",
+        ),
+        Err(RecvTimeoutError::Disconnected) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Fail,
+            "could not install the global keyboard hook",
+        ),
+    }
+}
+
+fn check_elevation() -> DoctorCheck {
+    const NAME: &str = "Elevation";
+    let os = format!("{} {}", std::env::consts::OS, std::env::consts::ARCH);
+    match super::elevation::is_elevated() {
+        Some(true) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Pass,
+            format!("{}, running as administrator", os),
+        ),
+        Some(false) => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Warn,
+            format!(
+                "{}, not elevated; input into Dota 2 may be ignored if Dota runs as administrator",
+                os
+            ),
+        ),
+        None => DoctorCheck::new(
+            NAME,
+            DoctorStatus::Warn,
+            format!("{}, elevation status unavailable", os),
+        ),
+    }
+}
+
+/// Release builds use the Windows GUI subsystem, so reattach to the
+/// launching terminal for the checklist to be visible.
+fn attach_parent_console() {
+    #[cfg(windows)]
+    {
+        use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+        let _ = unsafe { AttachConsole(ATTACH_PARENT_PROCESS) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gsi_port_check_fails_when_port_is_taken() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        assert_eq!(check_gsi_port(addr).status, DoctorStatus::Fail);
+        drop(listener);
+        assert_eq!(check_gsi_port(addr).status, DoctorStatus::Pass);
+    }
+
+    #[test]
+    fn gsi_config_check_matches_uri_port() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("gamestate_integration_dota2scripts.cfg"),
+            "\"uri\" \"http://127.0.0.1:3000/\"",
+        )
+        .unwrap();
+        let dirs = vec![dir.path().to_path_buf()];
+
+        assert_eq!(check_gsi_config(3000, &dirs).status, DoctorStatus::Pass);
+        assert_eq!(check_gsi_config(4000, &dirs).status, DoctorStatus::Warn);
+    }
+
+    #[test]
+    fn gsi_config_check_fails_without_config_or_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let dirs = vec![dir.path().to_path_buf()];
+        assert_eq!(check_gsi_config(3000, &dirs).status, DoctorStatus::Fail);
+
+        let missing = vec![dir.path().join("missing")];
+        assert_eq!(check_gsi_config(3000, &missing).status, DoctorStatus::Fail);
+    }
+
+    #[test]
+    fn library_folders_paths_are_unescaped() {
+        let vdf = "\"libraryfolders\"\n{\n\t\"0\"\n\t{\n\t\t\"path\"\t\t\"D:\\\\SteamLibrary\"\n\t}\n}";
+
+        assert_eq!(
+            parse_library_folders(vdf),
+            vec![PathBuf::from("D:\\SteamLibrary")]
+        );
+    }
+
+    #[test]
+    fn report_render_counts_passes_and_flags_failures() {
+        let report = DoctorReport {
+            checks: vec![
+                DoctorCheck::new("A", DoctorStatus::Pass, "ok"),
+                DoctorCheck::new("B", DoctorStatus::Fail, "broken"),
+            ],
+        };
+
+        let rendered = report.render();
+        assert!(rendered.contains("[PASS] A: ok"));
+        assert!(rendered.contains("[FAIL] B: broken"));
+        assert!(rendered.contains("1/2 checks passed"));
+        assert!(report.has_failures());
+    }
+}
@@ -0,0 +1,109 @@
+use tracing::{info, warn};
+
+/// Whether the current process runs with an elevated (administrator) token.
+///
+/// Returns `None` when elevation cannot be determined, including on
+/// non-Windows platforms where the concept does not apply.
+pub fn is_elevated() -> Option<bool> {
+    #[cfg(windows)]
+    {
+        is_elevated_win32()
+    }
+    #[cfg(not(windows))]
+    {
+        None
+    }
+}
+
+#[cfg(windows)]
+fn is_elevated_win32() -> Option<bool> {
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::Security::{
+        GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+    };
+    use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    let mut token = HANDLE::default();
+    unsafe { OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) }.ok()?;
+
+    let mut elevation = TOKEN_ELEVATION::default();
+    let mut returned_len = 0u32;
+    let result = unsafe {
+        GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut TOKEN_ELEVATION as *mut std::ffi::c_void),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut returned_len,
+        )
+    };
+    let _ = unsafe { CloseHandle(token) };
+
+    result.ok().map(|()| elevation.TokenIsElevated != 0)
+}
+
+/// Log the elevation status once at startup, warning when input simulation
+/// into an elevated Dota 2 client is likely to be dropped.
+pub fn log_elevation_status() {
+    match is_elevated() {
+        Some(true) => info!("Running with administrator privileges"),
+        Some(false) => warn!(
+            "Not running as administrator: simulated input may be ignored if Dota 2 runs elevated"
+        ),
+        None => {}
+    }
+}
+
+/// Start a new elevated copy of this executable via the UAC "runas" verb.
+///
+/// On success the caller should exit so the elevated copy can take over the
+/// GSI port.
+pub fn relaunch_elevated() -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        relaunch_elevated_win32()
+    }
+    #[cfg(not(windows))]
+    {
+        Err("Relaunching elevated is only supported on Windows".to_string())
+    }
+}
+
+#[cfg(windows)]
+fn relaunch_elevated_win32() -> Result<(), String> {
+    use windows::core::{w, HSTRING, PCWSTR};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve current executable: {}", e))?;
+    let args = std::env::args()
+        .skip(1)
+        .map(|arg| format!("\"{}\"", arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let exe = HSTRING::from(exe.as_os_str());
+    let args = HSTRING::from(args);
+    let result = unsafe {
+        ShellExecuteW(
+            HWND::default(),
+            w!("runas"),
+            &exe,
+            &args,
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    // ShellExecuteW reports success with a value greater than 32.
+    if result.0 as isize > 32 {
+        Ok(())
+    } else {
+        Err(format!(
+            "Elevated relaunch was cancelled or failed (code {})",
+            result.0 as isize
+        ))
+    }
+}
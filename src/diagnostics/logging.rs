@@ -0,0 +1,64 @@
+//! Tracing subscriber setup shared by the CLI and Tauri entry points.
+//!
+//! Logs always go to the console. With `[logging] file_enabled` they are also
+//! written to a daily-rotated file in `[logging] dir`, since the windowed
+//! release build has no console and a crash loses whatever it printed.
+
+use crate::config::settings::LoggingConfig;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Log files are named `dota2-scripts.<date>.log`.
+pub const LOG_FILE_PREFIX: &str = "dota2-scripts";
+
+/// Daily files kept before the oldest is deleted.
+const MAX_LOG_FILES: usize = 7;
+
+/// Install the global subscriber. Call once, before the first log line.
+///
+/// `RUST_LOG` overrides `[logging] level`. The returned guard flushes the
+/// file writer when dropped, so keep it alive until the app exits. If the log
+/// directory cannot be created, logging falls back to the console and a
+/// warning is logged.
+pub fn init(config: &LoggingConfig) -> Option<WorkerGuard> {
+    let level = std::env::var("RUST_LOG").unwrap_or_else(|_| config.level.clone());
+
+    let appender = config.file_enabled.then(|| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix("log")
+            .max_log_files(MAX_LOG_FILES)
+            .build(&config.dir)
+    });
+    let (file_layer, guard, file_error) = match appender {
+        Some(Ok(appender)) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = fmt::layer().with_ansi(false).with_writer(writer);
+            (Some(layer), Some(guard), None)
+        }
+        Some(Err(error)) => (None, None, Some(error)),
+        None => (None, None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::new(level))
+        .with(fmt::layer())
+        .with(file_layer)
+        .init();
+
+    match file_error {
+        Some(error) => tracing::warn!(
+            "File logging disabled: cannot open log directory {:?}: {}",
+            config.dir,
+            error
+        ),
+        None if guard.is_some() => tracing::info!("Logging to {}/", config.dir),
+        None => {}
+    }
+
+    guard
+}
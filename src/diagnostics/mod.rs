@@ -0,0 +1,6 @@
+pub mod doctor;
+pub mod elevation;
+pub mod logging;
+pub mod panic_report;
+
+pub use doctor::{run_cli, DOCTOR_FLAG};
@@ -0,0 +1,196 @@
+//! Panic hook that turns a dead thread into a visible, diagnosable failure.
+//!
+//! Without it a panicking background thread (a poisoned `lock().unwrap()`,
+//! say) just stops, and whatever it drove goes quiet with no sign in the UI.
+//! The hook logs the panic with a backtrace, writes a
+//! `panic-<timestamp>.txt` report to `[logging] dir`, and records the crash so
+//! the UI can show which subsystem stopped. Panics caught by
+//! [`catch_panics`] (the action executor, the Broodmother callback worker)
+//! are logged but not reported as a dead subsystem.
+
+use crate::actions::activity::{try_push_activity, ActivityCategory};
+use crate::config::settings::LoggingConfig;
+use crate::state::poison::lock_or_recover;
+use chrono::Local;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::panic::{self, PanicHookInfo, UnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{error, warn};
+
+/// A panic that ended the thread or task it happened on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashReport {
+    /// Thread name, or the panicking module when the thread has none.
+    pub subsystem: String,
+    pub message: String,
+    /// `file:line` of the panic.
+    pub location: String,
+}
+
+static CRASHES: Mutex<Vec<CrashReport>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// How many [`catch_panics`] calls are running on this thread; nested
+    /// ones (the Broodmother worker inside an executor job) must not end the
+    /// outer catch when they return.
+    static CATCHING: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Panic payload as text; `panic!` carries either a `&str` or a `String`.
+pub fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown panic payload"
+    }
+}
+
+/// Name for the crashed subsystem: the thread name when it is a meaningful
+/// one, else the module path of the panicking file (`actions::armlet`).
+fn subsystem_name(thread_name: Option<&str>, file: &str) -> String {
+    if let Some(name) = thread_name {
+        if name != "main" && !name.starts_with("tokio-runtime") {
+            return name.to_string();
+        }
+    }
+    let module = Path::new(file)
+        .with_extension("")
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy().into_owned())
+        .skip_while(|part| part != "src")
+        .skip(1)
+        .collect::<Vec<_>>()
+        .join("::");
+    if module.is_empty() {
+        file.to_string()
+    } else {
+        module
+    }
+}
+
+/// Run `job`, catching a panic so the calling thread keeps running. The hook
+/// logs such panics without reporting the thread as dead.
+pub fn catch_panics<R>(job: impl FnOnce() -> R + UnwindSafe) -> std::thread::Result<R> {
+    CATCHING.with(|depth| depth.set(depth.get() + 1));
+    let result = panic::catch_unwind(job);
+    CATCHING.with(|depth| depth.set(depth.get() - 1));
+    result
+}
+
+/// Subsystems that have died since startup, oldest first.
+pub fn crashes() -> Vec<CrashReport> {
+    lock_or_recover(&CRASHES).clone()
+}
+
+fn write_report(dir: &Path, report: &CrashReport, backtrace: &Backtrace) -> Option<PathBuf> {
+    std::fs::create_dir_all(dir).ok()?;
+    let path = dir.join(format!(
+        "panic-{}.txt",
+        Local::now().format("%Y-%m-%d_%H-%M-%S")
+    ));
+    let contents = format!(
+        "dota2-scripts {} panic\nsubsystem: {}\nlocation: {}\nmessage: {}\n\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        report.subsystem,
+        report.location,
+        report.message,
+        backtrace
+    );
+    std::fs::write(&path, contents).ok()?;
+    Some(path)
+}
+
+fn handle_panic(info: &PanicHookInfo<'_>, report_dir: &Path) {
+    let (file, line) = info
+        .location()
+        .map(|location| (location.file(), location.line()))
+        .unwrap_or(("unknown", 0));
+    let report = CrashReport {
+        subsystem: subsystem_name(std::thread::current().name(), file),
+        message: payload_message(info.payload()).to_string(),
+        location: format!("{}:{}", file, line),
+    };
+    let backtrace = Backtrace::force_capture();
+
+    if CATCHING.with(Cell::get) > 0 {
+        warn!(
+            "Caught panic in {} at {}: {}",
+            report.subsystem, report.location, report.message
+        );
+        return;
+    }
+
+    error!(
+        "💥 Panic in {} at {}: {}\n{}",
+        report.subsystem, report.location, report.message, backtrace
+    );
+    match write_report(report_dir, &report, &backtrace) {
+        Some(path) => error!("Panic report written to {}", path.display()),
+        None => error!("Could not write a panic report to {}", report_dir.display()),
+    }
+    // The panic may have fired while this thread held the activity buffer.
+    if !try_push_activity(
+        ActivityCategory::Error,
+        format!("{} crashed: {}", report.subsystem, report.message),
+    ) {
+        warn!(
+            "Activity feed busy; {} crash is only in the log",
+            report.subsystem
+        );
+    }
+    lock_or_recover(&CRASHES).push(report);
+}
+
+/// Install the panic hook. Call right after logging is initialized so the
+/// report reaches the log file.
+pub fn install(config: &LoggingConfig) {
+    let report_dir = PathBuf::from(&config.dir);
+    panic::set_hook(Box::new(move |info| handle_panic(info, &report_dir)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsystem_prefers_named_threads_over_the_panicking_module() {
+        assert_eq!(
+            subsystem_name(Some("largo-beat-worker"), "src/actions/heroes/largo.rs"),
+            "largo-beat-worker"
+        );
+        assert_eq!(
+            subsystem_name(Some("tokio-runtime-worker"), "src/gsi/handler.rs"),
+            "gsi::handler"
+        );
+        assert_eq!(
+            subsystem_name(None, "src/actions/armlet.rs"),
+            "actions::armlet"
+        );
+    }
+
+    #[test]
+    fn catch_panics_hands_back_the_panic_message() {
+        let payload = catch_panics(|| panic!("boom")).expect_err("panic should be caught");
+        assert_eq!(payload_message(payload.as_ref()), "boom");
+
+        let payload = catch_panics(|| panic!("{} failed", "lock")).unwrap_err();
+        assert_eq!(payload_message(payload.as_ref()), "lock failed");
+        assert_eq!(catch_panics(|| 7).ok(), Some(7));
+    }
+
+    #[test]
+    fn nested_catch_leaves_the_outer_job_caught() {
+        let outer = catch_panics(|| {
+            let inner = catch_panics(|| panic!("inner"));
+            assert!(inner.is_err());
+            CATCHING.with(Cell::get)
+        });
+        assert_eq!(outer.ok(), Some(1));
+        assert_eq!(CATCHING.with(Cell::get), 0);
+    }
+}
@@ -0,0 +1,303 @@
+//! Suggest `[danger_detection]` thresholds from a recorded GSI session.
+//!
+//! Input is the JSONL written by [`super::event_log::GsiEventLog`]. The
+//! danger detector compares each event with the previous one, so the
+//! analysis works on per-frame HP loss: the hits taken in the seconds before
+//! each death versus the hits taken the rest of the session.
+//!
+//! Only the `gsi_calibrate` example calls into this module, so the app
+//! binary sees it as dead code.
+#![allow(dead_code)]
+
+use crate::models::GsiWebhookEvent;
+
+/// Frames further apart than this (pause, disconnect, recording gap) do not
+/// count towards alive time or the frame interval.
+const MAX_FRAME_GAP_SECS: i32 = 5;
+
+/// Percentiles reported for the per-frame HP loss distribution.
+const LOSS_PERCENTILES: [u32; 5] = [50, 75, 90, 99, 100];
+
+/// Tuning knobs for [`calibrate`].
+#[derive(Debug, Clone)]
+pub struct CalibrateOptions {
+    /// Game-clock seconds before a death whose hits count as "would have
+    /// needed to react".
+    pub lead_secs: i32,
+    /// Rapid-loss triggers outside a death lead-up tolerated per alive minute.
+    pub max_false_alarms_per_min: f64,
+}
+
+impl Default for CalibrateOptions {
+    fn default() -> Self {
+        Self {
+            lead_secs: 3,
+            max_false_alarms_per_min: 1.0,
+        }
+    }
+}
+
+/// What the recording looked like and the thresholds it suggests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationReport {
+    /// Consecutive alive frame pairs analysed.
+    pub frame_pairs: usize,
+    pub alive_minutes: f64,
+    pub deaths: usize,
+    /// Average gap between frames, from the game clock.
+    pub frame_interval_ms: Option<u64>,
+    /// `(percentile, hp)` of per-frame HP loss over all alive frames.
+    pub loss_percentiles: Vec<(u32, u32)>,
+    /// Biggest single-frame hit in each death's lead-up, in death order.
+    pub death_peak_hits: Vec<u32>,
+    /// `health_percent` `lead_secs` before each death, in death order.
+    pub death_lead_hp_percents: Vec<u32>,
+    /// Suggested `rapid_loss_hp`; `None` without any alive frames.
+    pub rapid_loss_hp: Option<u32>,
+    /// Deaths whose lead-up contains a hit above `rapid_loss_hp`.
+    pub deaths_flagged: usize,
+    /// Rapid-loss triggers outside death lead-ups at `rapid_loss_hp`.
+    pub false_alarms_per_min: f64,
+    /// Suggested `time_window_ms`.
+    pub time_window_ms: Option<u64>,
+    /// Suggested `healing_threshold_in_danger`; `None` without deaths.
+    pub healing_threshold_in_danger: Option<u32>,
+}
+
+impl CalibrationReport {
+    /// Suggested values as a `[danger_detection]` snippet for `config.toml`.
+    pub fn suggested_toml(&self) -> String {
+        let mut out = String::from("[danger_detection]\n");
+        if let Some(value) = self.rapid_loss_hp {
+            out.push_str(&format!("rapid_loss_hp = {}\n", value));
+        }
+        if let Some(value) = self.time_window_ms {
+            out.push_str(&format!("time_window_ms = {}\n", value));
+        }
+        if let Some(value) = self.healing_threshold_in_danger {
+            out.push_str(&format!("healing_threshold_in_danger = {}\n", value));
+        }
+        out
+    }
+}
+
+/// Analyse a recording and suggest danger-detection thresholds.
+///
+/// - **`rapid_loss_hp`**: the detector fires when one frame loses more than
+///   this much HP. The suggestion is the highest value that still flags every
+///   death (one below the smallest lead-up peak hit), raised as far as needed
+///   to keep triggers outside death lead-ups under
+///   `max_false_alarms_per_min`. When both cannot hold, the false-alarm budget
+///   wins and `deaths_flagged` shows how many deaths are still caught.
+/// - **`time_window_ms`**: hits only count when frames arrive within the
+///   window, so the suggestion is twice the observed frame interval, rounded
+///   up to 100 ms.
+/// - **`healing_threshold_in_danger`**: the median `health_percent` seen
+///   `lead_secs` before each death, so healing would have started with that
+///   much time to spare in at least half of them.
+pub fn calibrate(events: &[GsiWebhookEvent], options: &CalibrateOptions) -> CalibrationReport {
+    let mut losses: Vec<(usize, u32)> = Vec::new();
+    let mut alive_secs: i64 = 0;
+    let mut deaths: Vec<usize> = Vec::new();
+
+    for index in 1..events.len() {
+        let previous = &events[index - 1];
+        let current = &events[index];
+        if previous.hero.alive && !current.hero.alive {
+            deaths.push(index);
+        }
+        if !(previous.hero.alive && current.hero.alive) {
+            continue;
+        }
+        let gap = current.map.clock_time - previous.map.clock_time;
+        if !(0..=MAX_FRAME_GAP_SECS).contains(&gap) {
+            continue;
+        }
+        alive_secs += i64::from(gap);
+        losses.push((
+            index,
+            previous.hero.health.saturating_sub(current.hero.health),
+        ));
+    }
+
+    let alive_minutes = alive_secs as f64 / 60.0;
+    let frame_interval_ms = (!losses.is_empty() && alive_secs > 0)
+        .then(|| (alive_secs as u64 * 1000) / losses.len() as u64);
+
+    let lead_up = |death: usize| {
+        let death_clock = events[death].map.clock_time;
+        let mut start = death;
+        while start > 0
+            && events[start - 1].hero.alive
+            && events[start - 1].map.clock_time >= death_clock - options.lead_secs
+        {
+            start -= 1;
+        }
+        start..death
+    };
+
+    let mut death_peak_hits = Vec::new();
+    let mut death_lead_hp_percents = Vec::new();
+    let mut in_lead_up = vec![false; events.len()];
+    for &death in &deaths {
+        let range = lead_up(death);
+        if range.is_empty() {
+            continue;
+        }
+        death_lead_hp_percents.push(events[range.start].hero.health_percent);
+        let peak = losses
+            .iter()
+            .filter(|(index, _)| range.contains(index))
+            .map(|(_, loss)| *loss)
+            .max()
+            .unwrap_or(0);
+        death_peak_hits.push(peak);
+        in_lead_up[range].fill(true);
+    }
+
+    let mut background: Vec<u32> = losses
+        .iter()
+        .filter(|(index, _)| !in_lead_up[*index])
+        .map(|(_, loss)| *loss)
+        .collect();
+    background.sort_unstable();
+    background.reverse();
+
+    let rapid_loss_hp = (!losses.is_empty()).then(|| {
+        let catch_all = death_peak_hits
+            .iter()
+            .min()
+            .map(|peak| peak.saturating_sub(1))
+            .unwrap_or(0);
+        let allowed = (options.max_false_alarms_per_min * alive_minutes).floor() as usize;
+        let budget_floor = background.get(allowed).copied().unwrap_or(0);
+        catch_all.max(budget_floor).max(1)
+    });
+
+    let (deaths_flagged, false_alarms_per_min) = match rapid_loss_hp {
+        Some(threshold) => {
+            let flagged = death_peak_hits
+                .iter()
+                .filter(|&&peak| peak > threshold)
+                .count();
+            let alarms = background.iter().filter(|&&loss| loss > threshold).count();
+            let per_min = if alive_minutes > 0.0 {
+                alarms as f64 / alive_minutes
+            } else {
+                0.0
+            };
+            (flagged, per_min)
+        }
+        None => (0, 0.0),
+    };
+
+    let mut all_losses: Vec<u32> = losses.iter().map(|(_, loss)| *loss).collect();
+    all_losses.sort_unstable();
+
+    CalibrationReport {
+        frame_pairs: losses.len(),
+        alive_minutes,
+        deaths: deaths.len(),
+        frame_interval_ms,
+        loss_percentiles: LOSS_PERCENTILES
+            .iter()
+            .filter_map(|&pct| percentile(&all_losses, pct).map(|hp| (pct, hp)))
+            .collect(),
+        rapid_loss_hp,
+        deaths_flagged,
+        false_alarms_per_min,
+        time_window_ms: frame_interval_ms.map(|ms| (ms * 2).div_ceil(100).max(1) * 100),
+        healing_threshold_in_danger: median(&death_lead_hp_percents).map(|pct| pct.clamp(1, 100)),
+        death_peak_hits,
+        death_lead_hp_percents,
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice.
+fn percentile(sorted: &[u32], pct: u32) -> Option<u32> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct as usize * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank.min(sorted.len()) - 1])
+}
+
+fn median(values: &[u32]) -> Option<u32> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted.get(sorted.len().saturating_sub(1) / 2).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+
+    fn base_event() -> GsiWebhookEvent {
+        load_fixture_event("tests/fixtures/huskar_event.json")
+    }
+
+    /// Two frames per clock second, `health` out of 1000.
+    fn timeline(healths: &[u32]) -> Vec<GsiWebhookEvent> {
+        healths
+            .iter()
+            .enumerate()
+            .map(|(i, &hp)| {
+                let mut event = base_event();
+                event.map.clock_time = 100 + (i / 2) as i32;
+                event.hero.health = hp;
+                event.hero.max_health = 1000;
+                event.hero.health_percent = hp / 10;
+                event.hero.alive = hp > 0;
+                event
+            })
+            .collect()
+    }
+
+    #[test]
+    fn suggests_threshold_that_flags_the_death_without_background_noise() {
+        let mut healths = vec![1000; 120];
+        // Steady 50 HP chip damage every few frames in lane
+        for i in (10..100).step_by(5) {
+            healths[i] = 950;
+        }
+        // Gank: 300-HP hits, then death
+        healths.extend([900, 600, 300, 0, 0]);
+
+        let report = calibrate(&timeline(&healths), &CalibrateOptions::default());
+
+        assert_eq!(report.deaths, 1);
+        assert_eq!(report.death_peak_hits, vec![300]);
+        assert_eq!(report.rapid_loss_hp, Some(299));
+        assert_eq!(report.deaths_flagged, 1);
+        assert_eq!(report.false_alarms_per_min, 0.0);
+        assert_eq!(report.frame_interval_ms, Some(500));
+        assert_eq!(report.time_window_ms, Some(1000));
+        assert!(report.suggested_toml().contains("rapid_loss_hp = 299\n"));
+    }
+
+    #[test]
+    fn false_alarm_budget_raises_threshold_above_frequent_hits() {
+        let mut healths = Vec::new();
+        // Two minutes of trading 200-HP hits every other frame
+        for _ in 0..120 {
+            healths.extend([1000, 800]);
+        }
+        healths.extend([900, 750, 0, 0]);
+
+        let report = calibrate(&timeline(&healths), &CalibrateOptions::default());
+
+        assert_eq!(report.rapid_loss_hp, Some(200));
+        assert_eq!(report.deaths_flagged, 0);
+        assert!(report.false_alarms_per_min <= 1.0);
+    }
+
+    #[test]
+    fn empty_recording_has_no_suggestions() {
+        let report = calibrate(&[], &CalibrateOptions::default());
+
+        assert_eq!(report.rapid_loss_hp, None);
+        assert_eq!(report.healing_threshold_in_danger, None);
+        assert_eq!(report.suggested_toml(), "[danger_detection]\n");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::GsiLoggingConfig;
+use crate::models::GsiWebhookEvent;
+use chrono::Local;
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Per-session JSONL writer for raw GSI events with size-based rotation.
+///
+/// The first file is `gsi_events_<session>.jsonl`; each roll opens
+/// `gsi_events_<session>.<n>.jsonl`. Only this session's files count towards
+/// `max_files`, so logs from earlier sessions are never deleted.
+pub struct GsiEventLog {
+    output_dir: PathBuf,
+    session_stem: String,
+    max_file_bytes: u64,
+    max_files: u32,
+    alive_only: bool,
+    files: VecDeque<PathBuf>,
+    next_index: u32,
+    current_bytes: u64,
+}
+
+impl GsiEventLog {
+    /// Create the output directory and first session file name, or `None`
+    /// when logging is disabled or the directory cannot be created.
+    pub fn from_config(config: &GsiLoggingConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+
+        let session_stem = format!("gsi_events_{}", Local::now().format("%Y-%m-%d_%H-%M-%S"));
+        let log = Self::new(Path::new(&config.output_dir), session_stem, config);
+        if let Err(e) = &log {
+            warn!("Failed to create GSI log directory: {}", e);
+        }
+        log.ok()
+    }
+
+    fn new(
+        output_dir: &Path,
+        session_stem: String,
+        config: &GsiLoggingConfig,
+    ) -> std::io::Result<Self> {
+        fs::create_dir_all(output_dir)?;
+
+        let first_file = output_dir.join(format!("{}.jsonl", session_stem));
+        info!("GSI event logging enabled, writing to: {:?}", first_file);
+
+        Ok(Self {
+            output_dir: output_dir.to_path_buf(),
+            session_stem,
+            max_file_bytes: config.max_file_bytes,
+            max_files: config.max_files,
+            alive_only: config.alive_only,
+            files: VecDeque::from([first_file]),
+            next_index: 1,
+            current_bytes: 0,
+        })
+    }
+
+    pub fn current_file(&self) -> &Path {
+        self.files.back().expect("session always has a current file")
+    }
+
+    /// Append one event as a JSON line, rotating first if the current file
+    /// has reached `max_file_bytes`.
+    pub fn record(&mut self, event: &GsiWebhookEvent) {
+        if self.alive_only && !event.hero.is_alive() {
+            return;
+        }
+
+        let Ok(json) = serde_json::to_string(event) else {
+            return;
+        };
+
+        if self.max_file_bytes > 0 && self.current_bytes >= self.max_file_bytes {
+            self.rotate();
+        }
+
+        if let Ok(mut file) = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.current_file())
+        {
+            if writeln!(file, "{}", json).is_ok() {
+                self.current_bytes += json.len() as u64 + 1;
+            }
+        }
+    }
+
+    fn rotate(&mut self) {
+        let next_file = self
+            .output_dir
+            .join(format!("{}.{}.jsonl", self.session_stem, self.next_index));
+        self.next_index += 1;
+        self.files.push_back(next_file);
+        self.current_bytes = 0;
+
+        while self.max_files > 0 && self.files.len() > self.max_files as usize {
+            if let Some(oldest) = self.files.pop_front() {
+                if let Err(e) = fs::remove_file(&oldest) {
+                    warn!("Failed to remove rotated GSI log {:?}: {}", oldest, e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+
+    fn huskar_event() -> GsiWebhookEvent {
+        load_fixture_event("tests/fixtures/huskar_event.json")
+    }
+
+    fn session_files(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn rotates_past_size_cap_and_keeps_newest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GsiLoggingConfig {
+            enabled: true,
+            output_dir: dir.path().to_string_lossy().into_owned(),
+            max_file_bytes: 1,
+            max_files: 2,
+            alive_only: false,
+        };
+        let mut log = GsiEventLog::new(dir.path(), "gsi_events_test".to_string(), &config)
+            .unwrap();
+        let event = huskar_event();
+
+        for _ in 0..4 {
+            log.record(&event);
+        }
+
+        assert_eq!(
+            session_files(dir.path()),
+            vec!["gsi_events_test.2.jsonl", "gsi_events_test.3.jsonl"]
+        );
+        assert!(log.current_file().ends_with("gsi_events_test.3.jsonl"));
+    }
+
+    #[test]
+    fn alive_only_skips_dead_hero_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GsiLoggingConfig {
+            enabled: true,
+            output_dir: dir.path().to_string_lossy().into_owned(),
+            max_file_bytes: 0,
+            max_files: 0,
+            alive_only: true,
+        };
+        let mut log = GsiEventLog::new(dir.path(), "gsi_events_test".to_string(), &config)
+            .unwrap();
+        let mut dead_event = huskar_event();
+        dead_event.hero.alive = false;
+        dead_event.hero.health = 0;
+
+        log.record(&dead_event);
+        assert!(session_files(dir.path()).is_empty());
+
+        log.record(&huskar_event());
+        let contents = fs::read_to_string(log.current_file()).unwrap();
+        assert_eq!(contents.lines().count(), 1);
+    }
+
+    #[test]
+    fn disabled_config_creates_no_log() {
+        assert!(GsiEventLog::from_config(&GsiLoggingConfig::default()).is_none());
+    }
+}
@@ -0,0 +1,304 @@
+//! Extract regression-test fixtures from recorded GSI sessions.
+//!
+//! Input is the JSONL written by [`super::event_log::GsiEventLog`]. Each
+//! detected moment becomes a short `.jsonl` clip of consecutive events that a
+//! test can replay through the same code paths as live traffic.
+//!
+//! Only the `gsi_fixturegen` example calls into this module, so the app
+//! binary sees it as dead code.
+#![allow(dead_code)]
+
+use crate::models::GsiWebhookEvent;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Tuning knobs for [`extract_moments`].
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    /// Events kept before and after the trigger frame in each clip.
+    pub context_frames: usize,
+    /// Minimum `health_percent` drop that counts as rapid HP loss.
+    pub hp_loss_percent: u32,
+    /// Number of frames the HP drop must happen within.
+    pub hp_loss_window: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            context_frames: 10,
+            hp_loss_percent: 30,
+            hp_loss_window: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MomentKind {
+    Death,
+    RapidHpLoss { from_percent: u32, to_percent: u32 },
+    ItemCast { item: String },
+}
+
+impl MomentKind {
+    fn label(&self) -> String {
+        match self {
+            MomentKind::Death => "death".to_string(),
+            MomentKind::RapidHpLoss { .. } => "hp_loss".to_string(),
+            MomentKind::ItemCast { item } => {
+                format!("cast_{}", item.trim_start_matches("item_"))
+            }
+        }
+    }
+}
+
+/// One interesting moment and the range of recording frames around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureMoment {
+    /// File-safe fixture name, e.g. `death_t754`.
+    pub name: String,
+    pub kind: MomentKind,
+    /// Index of the event where the moment was detected.
+    pub trigger_index: usize,
+    /// First event of the clip (inclusive).
+    pub start: usize,
+    /// Last event of the clip (exclusive).
+    pub end: usize,
+}
+
+/// Read a recorded session, skipping lines that fail to deserialize.
+///
+/// Returns the parsed events and the number of skipped lines.
+pub fn read_recording(path: &Path) -> io::Result<(Vec<GsiWebhookEvent>, usize)> {
+    let contents = fs::read_to_string(path)?;
+    let mut events = Vec::new();
+    let mut skipped = 0;
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str(line) {
+            Ok(event) => events.push(event),
+            Err(_) => skipped += 1,
+        }
+    }
+    Ok((events, skipped))
+}
+
+/// Scan a recording for moments worth turning into fixtures.
+///
+/// Heuristics, applied to each pair of consecutive events:
+///
+/// - **Death**: `hero.alive` flips from `true` to `false`. Respawn is not a
+///   moment; clips around a death already show the dead frames.
+/// - **Rapid HP loss**: while alive, `health_percent` drops by at least
+///   `hp_loss_percent` compared with the highest value in the previous
+///   `hp_loss_window` frames. After a hit, detection pauses for one window so
+///   a single burst yields one fixture instead of one per frame. Deaths are
+///   reported separately, so frames where the hero is dead are ignored.
+/// - **Item cast**: an inventory slot holds the same item in both frames and
+///   its `cooldown` goes from 0 (or missing) to above 0. Buying, swapping or
+///   selling an item changes the name and is not treated as a cast.
+///
+/// Every moment is named `<kind>_t<clock>` from `map.clock_time` (negative
+/// pre-horn clocks become `tm<secs>`), with a numeric suffix when two
+/// moments of the same kind share a clock second.
+pub fn extract_moments(events: &[GsiWebhookEvent], options: &ExtractOptions) -> Vec<FixtureMoment> {
+    let mut found: Vec<(usize, MomentKind)> = Vec::new();
+    let mut hp_loss_cooldown_until = 0;
+
+    for index in 1..events.len() {
+        let previous = &events[index - 1];
+        let current = &events[index];
+
+        if previous.hero.alive && !current.hero.alive {
+            found.push((index, MomentKind::Death));
+        }
+
+        if current.hero.alive && index >= hp_loss_cooldown_until {
+            let window_start = index.saturating_sub(options.hp_loss_window.max(1));
+            let peak = events[window_start..index]
+                .iter()
+                .filter(|event| event.hero.alive)
+                .map(|event| event.hero.health_percent)
+                .max();
+            if let Some(peak) = peak {
+                let now = current.hero.health_percent;
+                if peak.saturating_sub(now) >= options.hp_loss_percent {
+                    found.push((
+                        index,
+                        MomentKind::RapidHpLoss {
+                            from_percent: peak,
+                            to_percent: now,
+                        },
+                    ));
+                    hp_loss_cooldown_until = index + options.hp_loss_window.max(1);
+                }
+            }
+        }
+
+        for ((_, before), (_, after)) in previous
+            .items
+            .all_slots()
+            .into_iter()
+            .zip(current.items.all_slots())
+        {
+            if before.name == "empty" || before.name != after.name {
+                continue;
+            }
+            let was_ready = before.cooldown.unwrap_or(0) == 0;
+            let now_on_cooldown = after.cooldown.unwrap_or(0) > 0;
+            if was_ready && now_on_cooldown {
+                found.push((
+                    index,
+                    MomentKind::ItemCast {
+                        item: after.name.clone(),
+                    },
+                ));
+            }
+        }
+    }
+
+    let mut used_names: Vec<String> = Vec::new();
+    found
+        .into_iter()
+        .map(|(trigger_index, kind)| {
+            let base = format!(
+                "{}_{}",
+                kind.label(),
+                clock_label(events[trigger_index].map.clock_time)
+            );
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while used_names.contains(&name) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            used_names.push(name.clone());
+
+            FixtureMoment {
+                name,
+                kind,
+                trigger_index,
+                start: trigger_index.saturating_sub(options.context_frames),
+                end: (trigger_index + options.context_frames + 1).min(events.len()),
+            }
+        })
+        .collect()
+}
+
+fn clock_label(clock_time: i32) -> String {
+    if clock_time < 0 {
+        format!("tm{}", clock_time.unsigned_abs())
+    } else {
+        format!("t{}", clock_time)
+    }
+}
+
+/// Write each moment as `<out_dir>/<prefix>_<name>.jsonl`, one event per
+/// line in the same format as the recorder.
+pub fn write_fixtures(
+    events: &[GsiWebhookEvent],
+    moments: &[FixtureMoment],
+    out_dir: &Path,
+    prefix: &str,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir)?;
+    let mut written = Vec::with_capacity(moments.len());
+    for moment in moments {
+        let path = out_dir.join(format!("{}_{}.jsonl", prefix, moment.name));
+        let mut contents = String::new();
+        for event in &events[moment.start..moment.end] {
+            contents.push_str(&serde_json::to_string(event).map_err(io::Error::other)?);
+            contents.push('\n');
+        }
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsi::load_fixture_event;
+
+    fn base_event() -> GsiWebhookEvent {
+        load_fixture_event("tests/fixtures/huskar_event.json")
+    }
+
+    fn timeline(health_percents: &[u32]) -> Vec<GsiWebhookEvent> {
+        health_percents
+            .iter()
+            .enumerate()
+            .map(|(i, &hp)| {
+                let mut event = base_event();
+                event.map.clock_time = 100 + i as i32;
+                event.hero.health_percent = hp;
+                event.hero.alive = hp > 0;
+                event.items.slot0.cooldown = Some(0);
+                event
+            })
+            .collect()
+    }
+
+    fn options() -> ExtractOptions {
+        ExtractOptions {
+            context_frames: 2,
+            hp_loss_percent: 30,
+            hp_loss_window: 3,
+        }
+    }
+
+    #[test]
+    fn detects_death_and_single_hp_loss_per_burst() {
+        let events = timeline(&[100, 100, 80, 60, 40, 30, 0, 0]);
+
+        let moments = extract_moments(&events, &options());
+        let names: Vec<&str> = moments.iter().map(|m| m.name.as_str()).collect();
+
+        assert_eq!(names, vec!["hp_loss_t103", "death_t106"]);
+        assert_eq!(
+            moments[0].kind,
+            MomentKind::RapidHpLoss {
+                from_percent: 100,
+                to_percent: 60
+            }
+        );
+        assert_eq!((moments[1].start, moments[1].end), (4, 8));
+    }
+
+    #[test]
+    fn detects_item_cast_but_not_item_swap() {
+        let mut events = timeline(&[100, 100, 100, 100]);
+        let item = events[0].items.slot0.name.clone();
+        events[2].items.slot0.cooldown = Some(12);
+        events[3].items.slot0.cooldown = Some(11);
+
+        let moments = extract_moments(&events, &options());
+        assert_eq!(moments.len(), 1);
+        assert_eq!(moments[0].kind, MomentKind::ItemCast { item });
+        assert_eq!(moments[0].trigger_index, 2);
+
+        let mut swapped = timeline(&[100, 100]);
+        swapped[1].items.slot0.name = "item_other".to_string();
+        swapped[1].items.slot0.cooldown = Some(5);
+        assert!(extract_moments(&swapped, &options()).is_empty());
+    }
+
+    #[test]
+    fn written_fixtures_round_trip_through_reader() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut events = timeline(&[100, 100, 0]);
+        for event in &mut events {
+            event.map.clock_time -= 200;
+        }
+
+        let moments = extract_moments(&events, &options());
+        let paths = write_fixtures(&events, &moments, dir.path(), "huskar").unwrap();
+
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("huskar_death_tm98.jsonl"));
+        let (clip, skipped) = read_recording(&paths[0]).unwrap();
+        assert_eq!(clip.len(), 3);
+        assert_eq!(skipped, 0);
+    }
+}
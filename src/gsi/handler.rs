@@ -1,14 +1,22 @@
 use crate::actions::activity::{push_activity, ActivityCategory};
 use crate::config::Settings;
+use crate::gsi::event_log::GsiEventLog;
+use crate::models::gsi_event::Hero as GsiHero;
 use crate::models::{GsiWebhookEvent, Hero};
-use crate::state::AppState;
-use axum::{extract::State, http::StatusCode, Json};
-use chrono::Local;
+use crate::state::app_state::QueueMetrics;
+use crate::state::poison::lock_or_recover;
+use crate::state::{AppState, AutomationMetrics};
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    http::StatusCode,
+    Json,
+};
 use lazy_static::lazy_static;
-use std::fs::{self, OpenOptions};
-use std::io::Write;
-use std::path::PathBuf;
-use std::sync::atomic::Ordering;
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
@@ -16,8 +24,13 @@ use tracing::{debug, info, warn};
 lazy_static! {
     /// Track if hero was alive in the previous GSI event (to detect death transitions)
     static ref WAS_ALIVE: Mutex<bool> = Mutex::new(true);
+    /// Unknown hero fields already reported by `warn_on_schema_drift`
+    static ref REPORTED_HERO_FIELDS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
 }
 
+/// Set once the first undecodable payload has been logged at warn level.
+static PAYLOAD_REJECTION_WARNED: AtomicBool = AtomicBool::new(false);
+
 pub type GsiEventSender = mpsc::Sender<GsiWebhookEvent>;
 
 #[derive(Clone)]
@@ -26,6 +39,11 @@ pub struct GsiServerState {
     pub app_state: Arc<Mutex<AppState>>,
 }
 
+/// Assume the hero is alive again, as at startup.
+pub fn reset() {
+    *lock_or_recover(&WAS_ALIVE) = true;
+}
+
 fn refresh_keyboard_runtime_state(event: &GsiWebhookEvent, settings: &Settings) {
     // Canonical owner of shared keyboard/runtime cache refresh.
     // Called once per event in process_gsi_events() before the gsi_enabled gate.
@@ -38,9 +56,7 @@ fn refresh_keyboard_runtime_state(event: &GsiWebhookEvent, settings: &Settings)
     );
 
     if event.hero.name == Hero::Nevermore.to_game_name() {
-        let mut last_event = crate::actions::heroes::shadow_fiend::SF_LAST_EVENT
-            .lock()
-            .unwrap();
+        let mut last_event = lock_or_recover(&crate::actions::heroes::shadow_fiend::SF_LAST_EVENT);
         *last_event = Some(event.clone());
     }
 
@@ -67,23 +83,123 @@ fn refresh_observability_state(
         event.map.clock_time,
         &settings.rune_alerts,
     );
-    if let Ok(mut state) = app_state.lock() {
-        state.rune_alerts = Some(snapshot);
+    lock_or_recover(app_state).rune_alerts = Some(snapshot);
+}
+
+/// Fields in `extra` that have not been reported yet, sorted. Marks them reported.
+fn unreported_fields(reported: &mut HashSet<String>, hero: &GsiHero) -> Vec<String> {
+    let mut fresh: Vec<String> = hero
+        .extra
+        .keys()
+        .filter(|key| reported.insert((*key).clone()))
+        .cloned()
+        .collect();
+    fresh.sort();
+    fresh
+}
+
+/// Warn once per unknown hero field, so a Dota patch that changes the GSI
+/// schema shows up in the log instead of going unnoticed.
+fn warn_on_schema_drift(hero: &GsiHero) {
+    if hero.extra.is_empty() {
+        return;
+    }
+    let fresh = unreported_fields(&mut lock_or_recover(&REPORTED_HERO_FIELDS), hero);
+    if !fresh.is_empty() {
+        warn!(
+            "GSI hero payload has fields this build does not know: {} (a Dota patch may have changed the schema)",
+            fresh.join(", ")
+        );
+    }
+}
+
+/// Match and hero an event belongs to. When it changes between two events,
+/// state left from the previous game is cleared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GameIdentity {
+    matchid: Option<String>,
+    hero: String,
+}
+
+impl GameIdentity {
+    fn of(event: &GsiWebhookEvent) -> Self {
+        Self {
+            matchid: event.map.matchid.clone(),
+            hero: event.hero.name.clone(),
+        }
+    }
+}
+
+/// Hash of the event state hero logic acts on: hero vitals, status and
+/// position, ability and item cooldowns, and the game clock.
+///
+/// Dota posts on a timer even when nothing changed; two events with the same
+/// fingerprint would make the dispatcher redo identical work. The clock keeps
+/// at least one event per game second distinct for time-based logic.
+fn frame_fingerprint(event: &GsiWebhookEvent) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let hero = &event.hero;
+    (&hero.name, hero.alive, hero.health, hero.max_health, hero.mana, hero.max_mana)
+        .hash(&mut hasher);
+    (hero.level, hero.xpos, hero.ypos, hero.respawn_seconds).hash(&mut hasher);
+    (
+        hero.stunned,
+        hero.silenced,
+        hero.hexed,
+        hero.disarmed,
+        hero.muted,
+        hero.is_break,
+        hero.magicimmune,
+        hero.smoked,
+        hero.has_debuff,
+    )
+        .hash(&mut hasher);
+    (hero.aghanims_scepter, hero.aghanims_shard).hash(&mut hasher);
+
+    for ability in (0..6).filter_map(|index| event.abilities.get_by_index(index)) {
+        (&ability.name, ability.level, ability.cooldown, ability.can_cast).hash(&mut hasher);
+        ability.ability_active.hash(&mut hasher);
+    }
+    for (slot, item) in event.items.all_slots_including_backpack() {
+        (
+            slot,
+            &item.name,
+            item.cooldown,
+            item.can_cast,
+            item.charge_count(),
+        )
+            .hash(&mut hasher);
     }
+    event.items.teleport0.cooldown.hash(&mut hasher);
+    event.map.clock_time.hash(&mut hasher);
+    hasher.finish()
 }
 
 pub async fn gsi_webhook_handler(
     State(server_state): State<GsiServerState>,
-    Json(event): Json<GsiWebhookEvent>,
+    payload: Result<Json<GsiWebhookEvent>, JsonRejection>,
 ) -> StatusCode {
+    let event = match payload {
+        Ok(Json(event)) => event,
+        Err(rejection) => {
+            if !PAYLOAD_REJECTION_WARNED.swap(true, Ordering::Relaxed) {
+                warn!(
+                    "Rejecting GSI payload that does not match the expected schema (further rejections logged at debug): {}",
+                    rejection.body_text()
+                );
+            } else {
+                debug!("Rejected GSI payload: {}", rejection.body_text());
+            }
+            return rejection.status();
+        }
+    };
     debug!("Received GSI event for hero: {}", event.hero.name);
+    warn_on_schema_drift(&event.hero);
 
     match server_state.tx.try_send(event) {
         Ok(_) => StatusCode::OK,
         Err(mpsc::error::TrySendError::Full(_)) => {
-            if let Ok(mut state) = server_state.app_state.lock() {
-                state.metrics.events_dropped += 1;
-            }
+            lock_or_recover(&server_state.app_state).metrics.events_dropped += 1;
             warn!("GSI event queue full, dropping event");
             StatusCode::SERVICE_UNAVAILABLE
         }
@@ -94,48 +210,67 @@ pub async fn gsi_webhook_handler(
     }
 }
 
+/// Body of `GET /metrics`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricsResponse {
+    pub queue: QueueMetrics,
+    pub automation: AutomationMetrics,
+}
+
+/// Liveness probe for local proxies and other GSI tools.
+pub async fn gsi_healthz_handler() -> &'static str {
+    "ok"
+}
+
+pub async fn gsi_metrics_handler(
+    State(server_state): State<GsiServerState>,
+) -> Json<MetricsResponse> {
+    let state = lock_or_recover(&server_state.app_state);
+    let automation = lock_or_recover(&state.automation_metrics).clone();
+
+    Json(MetricsResponse {
+        queue: state.metrics.clone(),
+        automation,
+    })
+}
+
 pub async fn process_gsi_events(
     mut rx: mpsc::Receiver<GsiWebhookEvent>,
     app_state: Arc<Mutex<AppState>>,
     dispatcher: Arc<crate::actions::ActionDispatcher>,
     settings: Arc<Mutex<Settings>>,
 ) {
-    // Generate session filename once at startup
-    let session_file: Option<PathBuf> = {
-        let settings = settings.lock().unwrap();
-        if settings.gsi_logging.enabled {
-            let output_dir = PathBuf::from(&settings.gsi_logging.output_dir);
-            if let Err(e) = fs::create_dir_all(&output_dir) {
-                warn!("Failed to create GSI log directory: {}", e);
-                None
-            } else {
-                let filename = output_dir.join(format!(
-                    "gsi_events_{}.jsonl",
-                    Local::now().format("%Y-%m-%d_%H-%M-%S")
-                ));
-                info!("GSI event logging enabled, writing to: {:?}", filename);
-                Some(filename)
-            }
-        } else {
-            None
-        }
-    };
+    // Open the session log once at startup
+    let mut event_log = GsiEventLog::from_config(&lock_or_recover(&settings).gsi_logging);
+    let mut last_fingerprint: Option<u64> = None;
+    let mut last_game: Option<GameIdentity> = None;
 
     while let Some(event) = rx.recv().await {
         // Log event to file if enabled
-        if let Some(ref filename) = session_file {
-            if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(filename) {
-                if let Ok(json) = serde_json::to_string(&event) {
-                    let _ = writeln!(file, "{}", json);
-                }
-            }
+        if let Some(event_log) = event_log.as_mut() {
+            event_log.record(&event);
+        }
+
+        // Cooldowns and trackers from the previous match or hero do not apply
+        let game = GameIdentity::of(&event);
+        if last_game
+            .replace(game.clone())
+            .is_some_and(|previous| previous != game)
+        {
+            info!(
+                "New match or hero ({}); resetting automation state",
+                game.hero
+            );
+            crate::state::reset::reset_all_state();
+            dispatcher.reset();
         }
 
         // Update app state
         {
-            let mut state = app_state.lock().unwrap();
+            let settings = lock_or_recover(&settings);
+            let mut state = lock_or_recover(&app_state);
             let first_event = state.last_event.is_none();
-            state.update_from_gsi(event.clone());
+            state.update_from_gsi(event.clone(), &settings);
             state.metrics.current_queue_depth = rx.len();
             if first_event {
                 push_activity(ActivityCategory::System, "GSI connected");
@@ -145,7 +280,7 @@ pub async fn process_gsi_events(
         // Keep keyboard-supporting runtime state fresh even when the main
         // GSI automation toggle is disabled.
         {
-            let settings = settings.lock().unwrap();
+            let settings = lock_or_recover(&settings);
             refresh_keyboard_runtime_state(&event, &settings);
             refresh_observability_state(&event, &app_state, &settings);
         }
@@ -163,9 +298,18 @@ pub async fn process_gsi_events(
             }
         }
 
+        // Skip dispatch for a repeat of the previous frame
+        let fingerprint = frame_fingerprint(&event);
+        let repeated = last_fingerprint.replace(fingerprint) == Some(fingerprint);
+        if repeated && lock_or_recover(&settings).server.dedup_identical_events {
+            lock_or_recover(&app_state).metrics.events_deduplicated += 1;
+            debug!("Skipping dispatch for unchanged GSI event");
+            continue;
+        }
+
         // Check if GSI automation is enabled
         let gsi_enabled = {
-            let state = app_state.lock().unwrap();
+            let state = lock_or_recover(&app_state);
             state.gsi_enabled
         };
 
@@ -177,7 +321,10 @@ pub async fn process_gsi_events(
 
 #[cfg(test)]
 mod tests {
-    use super::{gsi_webhook_handler, process_gsi_events, GsiServerState};
+    use super::{
+        frame_fingerprint, gsi_webhook_handler, process_gsi_events, unreported_fields,
+        GameIdentity, GsiServerState,
+    };
     use crate::actions::auto_items::LATEST_GSI_EVENT;
     use crate::actions::executor::ActionExecutor;
     use crate::actions::heroes::broodmother::BROODMOTHER_ACTIVE;
@@ -189,6 +336,7 @@ mod tests {
     use crate::actions::soul_ring::{SoulRingState, SOUL_RING_STATE};
     use crate::actions::ActionDispatcher;
     use crate::config::Settings;
+    use crate::gsi::load_fixture_event;
     use crate::models::GsiWebhookEvent;
     use crate::observability::rune_alerts::{
         latest_rune_alert_snapshot, reset_rune_alert_state_for_tests,
@@ -196,6 +344,7 @@ mod tests {
     use crate::state::AppState;
     use axum::{extract::State, http::StatusCode, Json};
     use std::fs;
+    use std::collections::HashSet;
     use std::sync::{Mutex, OnceLock};
     use tokio::sync::mpsc;
 
@@ -204,11 +353,6 @@ mod tests {
         TEST_LOCK.get_or_init(|| Mutex::new(()))
     }
 
-    fn load_fixture_event(path: &str) -> GsiWebhookEvent {
-        let json_data = fs::read_to_string(path).expect("Failed to read GSI fixture");
-        serde_json::from_str(&json_data).expect("Failed to deserialize GSI fixture")
-    }
-
     fn reset_keyboard_runtime_state() {
         *LATEST_GSI_EVENT.lock().unwrap() = None;
         *SF_LAST_EVENT.lock().unwrap() = None;
@@ -219,6 +363,91 @@ mod tests {
         reset_rune_alert_state_for_tests();
     }
 
+    #[test]
+    fn hero_tolerates_dropped_and_unknown_fields_and_reports_new_ones_once() {
+        let json_data = fs::read_to_string("tests/fixtures/huskar_event.json").unwrap();
+        let mut payload: serde_json::Value = serde_json::from_str(&json_data).unwrap();
+        let hero = payload["hero"].as_object_mut().unwrap();
+        hero.remove("smoked");
+        hero.remove("talent_8");
+        hero.insert("new_patch_field".to_string(), serde_json::json!(3));
+
+        let event: GsiWebhookEvent = serde_json::from_value(payload).unwrap();
+        assert!(!event.hero.smoked);
+        assert!(event.hero.extra.contains_key("new_patch_field"));
+
+        let mut reported = HashSet::new();
+        assert_eq!(
+            unreported_fields(&mut reported, &event.hero),
+            vec!["new_patch_field".to_string()]
+        );
+        assert!(unreported_fields(&mut reported, &event.hero).is_empty());
+    }
+
+    #[test]
+    fn game_identity_changes_with_the_match_or_the_hero() {
+        let event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let mut same_match = event.clone();
+        same_match.map.clock_time += 30;
+        let mut next_match = event.clone();
+        next_match.map.matchid = Some("8000000001".to_string());
+        let mut other_hero = event.clone();
+        other_hero.hero.name = "npc_dota_hero_tiny".to_string();
+
+        let game = GameIdentity::of(&event);
+        assert_eq!(GameIdentity::of(&same_match), game);
+        assert_ne!(GameIdentity::of(&next_match), game);
+        assert_ne!(GameIdentity::of(&other_hero), game);
+    }
+
+    #[tokio::test]
+    async fn process_gsi_events_skips_dispatch_for_repeated_frames_only_when_enabled() {
+        let _guard = shared_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        reset_keyboard_runtime_state();
+
+        let event = load_fixture_event("tests/fixtures/huskar_event.json");
+        let mut hurt = event.clone();
+        hurt.hero.health -= 50;
+        assert_eq!(frame_fingerprint(&event), frame_fingerprint(&event.clone()));
+        assert_ne!(frame_fingerprint(&event), frame_fingerprint(&hurt));
+        // Builds that only send `item_charges` still change the fingerprint
+        let mut wand = event.clone();
+        wand.items.slot0.charges = None;
+        wand.items.slot0.item_charges = Some(3);
+        let mut used_wand = wand.clone();
+        used_wand.items.slot0.item_charges = Some(0);
+        assert_ne!(frame_fingerprint(&wand), frame_fingerprint(&used_wand));
+
+        for (dedup, expected_skipped) in [(true, 1), (false, 0)] {
+            let app_state = AppState::new();
+            app_state.lock().unwrap().gsi_enabled = false;
+            let mut settings_value = Settings::default();
+            settings_value.server.dedup_identical_events = dedup;
+            let settings = std::sync::Arc::new(std::sync::Mutex::new(settings_value));
+            let dispatcher = std::sync::Arc::new(ActionDispatcher::new(
+                settings.clone(),
+                ActionExecutor::new(),
+            ));
+            let (tx, rx) = mpsc::channel(3);
+            for frame in [&event, &event, &hurt] {
+                tx.send(frame.clone()).await.expect("test event should send");
+            }
+            drop(tx);
+
+            process_gsi_events(rx, app_state.clone(), dispatcher, settings).await;
+
+            let state = app_state.lock().unwrap();
+            assert_eq!(state.metrics.events_processed, 3);
+            assert_eq!(state.metrics.events_deduplicated, expected_skipped);
+            assert_eq!(
+                state.last_event.as_ref().unwrap().hero.health,
+                hurt.hero.health
+            );
+        }
+    }
+
     #[tokio::test]
     async fn webhook_handler_tracks_dropped_events_when_queue_is_full() {
         let event = load_fixture_event("tests/fixtures/huskar_event.json");
@@ -233,7 +462,7 @@ mod tests {
                 tx,
                 app_state: app_state.clone(),
             }),
-            Json(event),
+            Ok(Json(event)),
         )
         .await;
 
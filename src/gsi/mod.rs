@@ -1,4 +1,14 @@
+pub mod calibrate;
+pub mod event_log;
+pub mod fixture_extract;
 pub mod handler;
 pub mod server;
 
 pub use server::start_gsi_server;
+
+/// Deserialize a GSI fixture such as `tests/fixtures/huskar_event.json`.
+#[cfg(test)]
+pub(crate) fn load_fixture_event(path: &str) -> crate::models::GsiWebhookEvent {
+    let json_data = std::fs::read_to_string(path).expect("Failed to read GSI fixture");
+    serde_json::from_str(&json_data).expect("Failed to deserialize GSI fixture")
+}
@@ -1,47 +1,307 @@
+use crate::config::error::ErrorCategory;
 use crate::config::Settings;
-use crate::gsi::handler::{gsi_webhook_handler, process_gsi_events, GsiServerState};
+use crate::gsi::handler::{
+    gsi_healthz_handler, gsi_metrics_handler, gsi_webhook_handler, process_gsi_events,
+    GsiServerState,
+};
 use crate::models::GsiWebhookEvent;
+use crate::state::poison::lock_or_recover;
+use crate::state::shutdown::{is_shutting_down, wait_for_shutdown};
 use crate::state::AppState;
-use axum::{routing::post, Router};
-use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc;
-use tracing::info;
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, oneshot};
+use tracing::{error, info};
 
 const EVENT_QUEUE_CAPACITY: usize = 10;
 
+/// Where the app's GSI server is listening, or why it is not.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GsiListenerStatus {
+    pub addr: Option<SocketAddr>,
+    /// Why the last bind failed while the server is not listening.
+    pub error: Option<String>,
+    /// Free port near the one that failed, offered in the UI.
+    pub suggested_port: Option<u16>,
+}
+
+/// Ports after a taken one that are probed for [`GsiListenerStatus::suggested_port`].
+const FREE_PORT_PROBES: u16 = 20;
+
+static LISTENER_STATUS: Mutex<GsiListenerStatus> = Mutex::new(GsiListenerStatus {
+    addr: None,
+    error: None,
+    suggested_port: None,
+});
+
+/// Listeners bound by [`rebind_gsi_server`], handed to [`start_gsi_server`],
+/// which serves the latest one and drops any it skipped.
+static REBINDS: LazyLock<RebindChannel> = LazyLock::new(|| {
+    let (tx, rx) = mpsc::unbounded_channel();
+    RebindChannel {
+        tx,
+        rx: Mutex::new(Some(rx)),
+    }
+});
+
+struct RebindChannel {
+    tx: mpsc::UnboundedSender<TcpListener>,
+    /// Taken by the one running [`start_gsi_server`].
+    rx: Mutex<Option<mpsc::UnboundedReceiver<TcpListener>>>,
+}
+
+/// Address the server listens on or is moving to, so a repeated request for
+/// it does not try to bind it a second time.
+static TARGET_ADDR: Mutex<Option<SocketAddr>> = Mutex::new(None);
+
+pub fn listener_status() -> GsiListenerStatus {
+    lock_or_recover(&LISTENER_STATUS).clone()
+}
+
+fn set_listener_status(status: GsiListenerStatus) {
+    *lock_or_recover(&LISTENER_STATUS) = status;
+}
+
+/// First port after `taken` on `ip` that can be bound right now.
+pub fn next_free_port(ip: IpAddr, taken: u16) -> Option<u16> {
+    (1..=FREE_PORT_PROBES)
+        .filter_map(|offset| taken.checked_add(offset))
+        .find(|&port| std::net::TcpListener::bind((ip, port)).is_ok())
+}
+
+/// Why [`rebind_gsi_server`] could not listen on `addr`; displays as
+/// [`bind_error_message`].
+#[derive(Debug)]
+pub struct BindError {
+    pub addr: SocketAddr,
+    pub source: io::Error,
+}
+
+impl BindError {
+    /// A taken port is a [`ErrorCategory::Conflict`] the user can fix by
+    /// closing the other program or picking another port.
+    pub fn category(&self) -> ErrorCategory {
+        match self.source.kind() {
+            io::ErrorKind::AddrInUse => ErrorCategory::Conflict,
+            io::ErrorKind::AddrNotAvailable => ErrorCategory::Invalid,
+            _ => ErrorCategory::from_io(&self.source),
+        }
+    }
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&bind_error_message(self.addr, &self.source))
+    }
+}
+
+impl std::error::Error for BindError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Bind error worded for the UI.
+fn bind_error_message(addr: SocketAddr, error: &io::Error) -> String {
+    match error.kind() {
+        io::ErrorKind::AddrInUse => format!(
+            "Port {} is already in use. Is another instance of the app running?",
+            addr.port()
+        ),
+        io::ErrorKind::AddrNotAvailable => {
+            format!("{} is not an address of this PC", addr.ip())
+        }
+        io::ErrorKind::PermissionDenied => {
+            format!("Port {} is reserved or blocked by the system", addr.port())
+        }
+        _ => format!("Cannot listen on {}: {}", addr, error),
+    }
+}
+
+/// Run the app's GSI server on `addr` until shutdown.
+///
+/// [`rebind_gsi_server`] moves it to another address without a restart. If
+/// `addr` cannot be bound, the error is logged and kept in
+/// [`listener_status`], and the server waits for a rebind instead.
 pub async fn start_gsi_server(
-    port: u16,
+    addr: SocketAddr,
     app_state: Arc<Mutex<AppState>>,
     dispatcher: Arc<crate::actions::ActionDispatcher>,
     settings: Arc<Mutex<Settings>>,
 ) {
-    let (tx, rx) = mpsc::channel::<GsiWebhookEvent>(EVENT_QUEUE_CAPACITY);
-
-    // Spawn event processor
-    let app_state_clone = app_state.clone();
-    let dispatcher_clone = dispatcher.clone();
-    let settings_clone = settings.clone();
-    tokio::spawn(async move {
-        process_gsi_events(rx, app_state_clone, dispatcher_clone, settings_clone).await;
-    });
+    info!("Starting GSI server on http://{}", addr);
+    let Some(mut rebinds) = lock_or_recover(&REBINDS.rx).take() else {
+        error!("GSI server is already running");
+        return;
+    };
 
-    // Build router
-    let server_state = GsiServerState {
-        tx,
-        app_state: app_state.clone(),
+    let mut next = match TcpListener::bind(addr).await {
+        Ok(listener) => {
+            *lock_or_recover(&TARGET_ADDR) = Some(addr);
+            Some(listener)
+        }
+        Err(bind_error) => {
+            let message = bind_error_message(addr, &bind_error);
+            let suggested_port = matches!(
+                bind_error.kind(),
+                io::ErrorKind::AddrInUse | io::ErrorKind::PermissionDenied
+            )
+            .then(|| next_free_port(addr.ip(), addr.port()))
+            .flatten();
+            error!(
+                "GSI server not started: {} Change [server] port{}",
+                message,
+                suggested_port
+                    .map(|port| format!(", e.g. to {} which is free", port))
+                    .unwrap_or_default()
+            );
+            set_listener_status(GsiListenerStatus {
+                addr: None,
+                error: Some(message),
+                suggested_port,
+            });
+            None
+        }
     };
-    let app = Router::new()
-        .route("/", post(gsi_webhook_handler))
-        .with_state(server_state);
 
-    let addr = format!("127.0.0.1:{}", port);
-    info!("Starting GSI server on http://{}", addr);
+    loop {
+        let Some(listener) = next.take() else {
+            // Not listening: keep a bind error for the UI, but no address
+            lock_or_recover(&LISTENER_STATUS).addr = None;
+            tokio::select! {
+                _ = wait_for_shutdown() => break,
+                received = rebinds.recv() => next = latest_listener(received, &mut rebinds),
+            }
+            continue;
+        };
+
+        set_listener_status(GsiListenerStatus {
+            addr: listener.local_addr().ok(),
+            ..GsiListenerStatus::default()
+        });
+        let (stop_tx, stop_rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve_until(
+            listener,
+            app_state.clone(),
+            dispatcher.clone(),
+            settings.clone(),
+            async move {
+                let _ = stop_rx.await;
+            },
+        ));
+        tokio::select! {
+            _ = wait_for_shutdown() => {}
+            received = rebinds.recv() => next = latest_listener(received, &mut rebinds),
+        }
+        let _ = stop_tx.send(());
+        let _ = server.await;
+        if is_shutting_down() {
+            break;
+        }
+    }
+
+    set_listener_status(GsiListenerStatus::default());
+    info!("GSI server stopped");
+}
 
-    let listener = tokio::net::TcpListener::bind(&addr)
+/// `received` or, if more rebinds queued up behind it, the newest of them.
+fn latest_listener(
+    received: Option<TcpListener>,
+    rebinds: &mut mpsc::UnboundedReceiver<TcpListener>,
+) -> Option<TcpListener> {
+    let mut latest = received;
+    while let Ok(listener) = rebinds.try_recv() {
+        latest = Some(listener);
+    }
+    latest
+}
+
+/// Move the app's GSI server to `addr`, or start it there if it could not
+/// bind at startup.
+///
+/// The new address is bound before the old one is released, so when it is
+/// taken the error is returned and the server keeps listening where it was.
+/// Asking again for the address the server is on or already moving to does
+/// nothing.
+pub async fn rebind_gsi_server(addr: SocketAddr) -> Result<(), BindError> {
+    if *lock_or_recover(&TARGET_ADDR) == Some(addr) {
+        return Ok(());
+    }
+    let listener = TcpListener::bind(addr)
         .await
-        .expect("Failed to bind GSI server");
+        .map_err(|source| BindError { addr, source })?;
+
+    info!("Moving GSI server to http://{}", addr);
+    *lock_or_recover(&TARGET_ADDR) = Some(addr);
+    let _ = REBINDS.tx.send(listener);
+    Ok(())
+}
+
+/// Serve the GSI webhook on an already-bound listener until shutdown.
+///
+/// Split out from [`start_gsi_server`] so tests can bind an ephemeral port.
+pub async fn serve_gsi(
+    listener: TcpListener,
+    app_state: Arc<Mutex<AppState>>,
+    dispatcher: Arc<crate::actions::ActionDispatcher>,
+    settings: Arc<Mutex<Settings>>,
+) {
+    serve_until(
+        listener,
+        app_state,
+        dispatcher,
+        settings,
+        wait_for_shutdown(),
+    )
+    .await;
+    info!("GSI server stopped");
+}
+
+/// Serve until `stop` resolves, then finish in-flight requests.
+async fn serve_until(
+    listener: TcpListener,
+    app_state: Arc<Mutex<AppState>>,
+    dispatcher: Arc<crate::actions::ActionDispatcher>,
+    settings: Arc<Mutex<Settings>>,
+    stop: impl Future<Output = ()> + Send + 'static,
+) {
+    let app = build_gsi_router(app_state, dispatcher, settings);
 
     axum::serve(listener, app)
+        .with_graceful_shutdown(stop)
         .await
         .expect("Failed to start GSI server");
 }
+
+/// Build the webhook router and spawn the event processor that drains it.
+pub fn build_gsi_router(
+    app_state: Arc<Mutex<AppState>>,
+    dispatcher: Arc<crate::actions::ActionDispatcher>,
+    settings: Arc<Mutex<Settings>>,
+) -> Router {
+    let (tx, rx) = mpsc::channel::<GsiWebhookEvent>(EVENT_QUEUE_CAPACITY);
+    let webhook_route = lock_or_recover(&settings).server.webhook_route();
+    info!("GSI webhook listening for POST {}", webhook_route);
+
+    // Spawn event processor
+    let app_state_clone = app_state.clone();
+    tokio::spawn(async move {
+        process_gsi_events(rx, app_state_clone, dispatcher, settings).await;
+    });
+
+    // Build router
+    let server_state = GsiServerState { tx, app_state };
+    Router::new()
+        .route(&webhook_route, post(gsi_webhook_handler))
+        .route("/metrics", get(gsi_metrics_handler))
+        .route("/healthz", get(gsi_healthz_handler))
+        .with_state(server_state)
+}
@@ -0,0 +1,364 @@
+//! Record a standalone combo by demonstration.
+//!
+//! `[combo_recorder] toggle_key` starts recording for the current hero. While
+//! recording, the keyboard listener passes every key press and left/right
+//! click the player makes to [`record`]; the tool's own synthetic input never
+//! reaches the listener callback, and the toggle key itself is filtered out
+//! by [`recordable_input`]. The next toggle press saves the steps under
+//! `[combo_recorder] recordings`, and the hero's standalone trigger then
+//! [`replay`]s them with the recorded gaps, varied per replay by
+//! `[combo_recorder.randomization]`.
+
+use crate::actions::activity::{push_activity, ActivityCategory};
+use crate::actions::combo_control::combo_aborted;
+use crate::config::settings::{ComboInput, ComboRandomizationConfig, RecordedComboStep};
+use crate::config::Settings;
+use crate::input::keyboard::key_to_char;
+use crate::input::simulation::{left_click, press_key, right_click};
+use crate::state::poison::{lock_or_recover, lock_or_reset};
+use crate::state::HeroType;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rdev::{Button, EventType, Key};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+#[derive(Debug)]
+struct Recording {
+    hero: HeroType,
+    max_step_delay: Duration,
+    last_input_at: Option<Instant>,
+    steps: Vec<RecordedComboStep>,
+}
+
+impl Recording {
+    fn new(hero: HeroType, max_step_delay_ms: u64) -> Self {
+        Self {
+            hero,
+            max_step_delay: Duration::from_millis(max_step_delay_ms),
+            last_input_at: None,
+            steps: Vec::new(),
+        }
+    }
+
+    /// The wait before the first input is not part of the combo; later gaps
+    /// are capped at `max_step_delay`.
+    fn push(&mut self, input: ComboInput, at: Instant) {
+        let delay = self.last_input_at.map_or(Duration::ZERO, |last| {
+            at.saturating_duration_since(last).min(self.max_step_delay)
+        });
+        self.last_input_at = Some(at);
+        self.steps.push(RecordedComboStep {
+            input,
+            delay_ms: delay.as_millis() as u64,
+            unordered: false,
+        });
+    }
+}
+
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+
+/// Bumped on every saved recording, so the UI knows to reload the config.
+static SAVED_RECORDINGS: AtomicU64 = AtomicU64::new(0);
+
+pub fn is_recording() -> bool {
+    lock_or_reset(&RECORDING).is_some()
+}
+
+/// Input to record for a listener event: key presses that map to a
+/// character, and left/right clicks. `toggle_key` is never recorded, so
+/// neither the press that starts recording nor the one that stops it ends up
+/// in the combo.
+pub fn recordable_input(event_type: &EventType, toggle_key: Option<Key>) -> Option<ComboInput> {
+    match event_type {
+        EventType::KeyPress(key) if Some(*key) != toggle_key => {
+            key_to_char(*key).map(ComboInput::Key)
+        }
+        EventType::ButtonPress(Button::Left) => Some(ComboInput::LeftClick),
+        EventType::ButtonPress(Button::Right) => Some(ComboInput::RightClick),
+        _ => None,
+    }
+}
+
+/// Append `input` to the recording in progress, if any.
+pub fn record(input: ComboInput, at: Instant) {
+    if let Some(recording) = lock_or_reset(&RECORDING).as_mut() {
+        recording.push(input, at);
+    }
+}
+
+/// Start recording for `hero`, or stop and save the recording in progress to
+/// `[combo_recorder] recordings`.
+pub fn toggle(hero: Option<HeroType>, settings: &Arc<Mutex<Settings>>) {
+    let finished = lock_or_reset(&RECORDING).take();
+    if let Some(recording) = finished {
+        save(recording, settings);
+        return;
+    }
+
+    let Some(hero) = hero else {
+        info!("🎬 No hero selected; combo recording not started");
+        return;
+    };
+    let (max_step_delay_ms, toggle_key) = {
+        let settings = lock_or_recover(&settings);
+        (
+            settings.combo_recorder.max_step_delay_ms,
+            settings.combo_recorder.toggle_key.clone(),
+        )
+    };
+    *lock_or_reset(&RECORDING) = Some(Recording::new(hero, max_step_delay_ms));
+    info!("🎬 Recording {} combo", hero.to_display_name());
+    push_activity(
+        ActivityCategory::System,
+        format!(
+            "Recording {} combo; press {} again to save it",
+            hero.to_display_name(),
+            toggle_key
+        ),
+    );
+}
+
+fn save(recording: Recording, settings: &Arc<Mutex<Settings>>) {
+    let hero = recording.hero.to_display_name();
+    if recording.steps.is_empty() {
+        info!(
+            "🎬 Nothing recorded for {}; keeping the previous combo",
+            hero
+        );
+        push_activity(
+            ActivityCategory::System,
+            format!("Combo recording for {} was empty and not saved", hero),
+        );
+        return;
+    }
+
+    let step_count = recording.steps.len();
+    let mut settings = lock_or_recover(&settings);
+    settings
+        .combo_recorder
+        .recordings
+        .insert(recording.hero.config_key().to_string(), recording.steps);
+    if let Err(e) = settings.save() {
+        warn!("Failed to save recorded combo: {}", e);
+    }
+    SAVED_RECORDINGS.fetch_add(1, Ordering::Release);
+    info!("🎬 Saved {}-step {} combo", step_count, hero);
+    push_activity(
+        ActivityCategory::System,
+        format!("Saved {}-step recorded combo for {}", step_count, hero),
+    );
+}
+
+/// Number of recordings saved so far. The Tauri event loop emits
+/// `config_changed` when it moves.
+pub fn saved_recordings() -> u64 {
+    SAVED_RECORDINGS.load(Ordering::Acquire)
+}
+
+/// Discard a recording in progress.
+pub fn reset() {
+    *lock_or_reset(&RECORDING) = None;
+}
+
+/// Recorded combo that replaces `hero_name`'s built-in standalone combo,
+/// with its randomization already applied for this replay.
+pub fn recording_for(settings: &Settings, hero_name: &str) -> Option<Vec<RecordedComboStep>> {
+    if !settings.combo_recorder.enabled {
+        return None;
+    }
+    let hero = HeroType::from_hero_name(hero_name)?;
+    let config = &settings.combo_recorder;
+    let steps = config
+        .recordings
+        .get(hero.config_key())
+        .filter(|steps| !steps.is_empty())?;
+    Some(match config.randomization.get(hero.config_key()) {
+        Some(randomization) => randomize(steps, randomization, &mut rand::rng()),
+        None => steps.clone(),
+    })
+}
+
+/// Shuffle runs of adjacent unordered steps and jitter every delay. Delays
+/// stay with their position in the combo, so a shuffled step takes over the
+/// gap of the one it swapped with.
+fn randomize(
+    steps: &[RecordedComboStep],
+    config: &ComboRandomizationConfig,
+    rng: &mut impl Rng,
+) -> Vec<RecordedComboStep> {
+    let mut steps = steps.to_vec();
+
+    if config.shuffle_unordered {
+        let mut start = 0;
+        while start < steps.len() {
+            let run = steps[start..]
+                .iter()
+                .take_while(|step| step.unordered)
+                .count();
+            if run > 1 {
+                let mut inputs: Vec<ComboInput> = steps[start..start + run]
+                    .iter()
+                    .map(|step| step.input)
+                    .collect();
+                inputs.shuffle(rng);
+                for (step, input) in steps[start..start + run].iter_mut().zip(inputs) {
+                    step.input = input;
+                }
+            }
+            start += run.max(1);
+        }
+    }
+
+    if config.jitter_ms > 0 {
+        let jitter = config.jitter_ms as i64;
+        for step in &mut steps {
+            let offset = rng.random_range(-jitter..=jitter);
+            step.delay_ms = (step.delay_ms as i64 + offset).max(0) as u64;
+        }
+    }
+
+    steps
+}
+
+/// Press a recorded combo back with its gaps. Returns the number of steps
+/// pressed; stops early when the combo is aborted.
+pub fn replay(steps: &[RecordedComboStep]) -> usize {
+    for (index, step) in steps.iter().enumerate() {
+        thread::sleep(Duration::from_millis(step.delay_ms));
+        if combo_aborted() {
+            info!("🎬 Recorded combo aborted after {} steps", index);
+            return index;
+        }
+        match step.input {
+            ComboInput::Key(key) => press_key(key),
+            ComboInput::LeftClick => left_click(),
+            ComboInput::RightClick => right_click(),
+        }
+    }
+    steps.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn step(key: char, delay_ms: u64, unordered: bool) -> RecordedComboStep {
+        RecordedComboStep {
+            input: ComboInput::Key(key),
+            delay_ms,
+            unordered,
+        }
+    }
+
+    #[test]
+    fn toggle_key_and_unmapped_keys_are_not_recorded() {
+        let toggle = Some(Key::F8);
+
+        assert_eq!(
+            recordable_input(&EventType::KeyPress(Key::F8), toggle),
+            None
+        );
+        assert_eq!(
+            recordable_input(&EventType::KeyPress(Key::KeyQ), toggle),
+            Some(ComboInput::Key('q'))
+        );
+        assert_eq!(
+            recordable_input(&EventType::ButtonPress(Button::Right), toggle),
+            Some(ComboInput::RightClick)
+        );
+        assert_eq!(
+            recordable_input(&EventType::KeyRelease(Key::KeyQ), toggle),
+            None
+        );
+        assert_eq!(
+            recordable_input(&EventType::KeyPress(Key::ShiftLeft), toggle),
+            None
+        );
+
+        // A character toggle key is filtered the same way
+        assert_eq!(
+            recordable_input(&EventType::KeyPress(Key::KeyQ), Some(Key::KeyQ)),
+            None
+        );
+    }
+
+    #[test]
+    fn recording_keeps_gaps_but_drops_the_lead_in_and_caps_long_pauses() {
+        let start = Instant::now();
+        let mut recording = Recording::new(HeroType::Tiny, 1000);
+
+        recording.push(ComboInput::Key('q'), start + Duration::from_millis(3000));
+        recording.push(ComboInput::Key('w'), start + Duration::from_millis(3250));
+        recording.push(ComboInput::Key('d'), start + Duration::from_millis(9000));
+
+        let delays: Vec<u64> = recording.steps.iter().map(|step| step.delay_ms).collect();
+        assert_eq!(delays, vec![0, 250, 1000]);
+    }
+
+    #[test]
+    fn recordings_replace_the_combo_only_when_enabled() {
+        let mut settings = Settings::default();
+        settings
+            .combo_recorder
+            .recordings
+            .insert("tiny".to_string(), vec![step('q', 0, false)]);
+
+        assert_eq!(recording_for(&settings, "npc_dota_hero_tiny"), None);
+
+        settings.combo_recorder.enabled = true;
+        assert_eq!(
+            recording_for(&settings, "npc_dota_hero_tiny").map(|steps| steps.len()),
+            Some(1)
+        );
+        assert_eq!(recording_for(&settings, "npc_dota_hero_huskar"), None);
+    }
+
+    #[test]
+    fn randomization_jitters_delays_and_shuffles_only_unordered_runs() {
+        let steps = vec![
+            step('q', 0, false),
+            step('w', 100, true),
+            step('e', 100, true),
+            step('f', 100, true),
+            step('r', 300, false),
+            step('d', 50, true),
+        ];
+        let config = ComboRandomizationConfig {
+            jitter_ms: 20,
+            shuffle_unordered: true,
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..50 {
+            let replay = randomize(&steps, &config, &mut rng);
+            let keys: Vec<char> = replay
+                .iter()
+                .map(|step| match step.input {
+                    ComboInput::Key(key) => key,
+                    _ => unreachable!(),
+                })
+                .collect();
+
+            assert_eq!((keys[0], keys[4], keys[5]), ('q', 'r', 'd'));
+            let mut middle = keys[1..4].to_vec();
+            middle.sort();
+            assert_eq!(middle, vec!['e', 'f', 'w']);
+            assert!(replay[0].delay_ms <= 20);
+            assert!(replay
+                .iter()
+                .zip(&steps)
+                .all(|(replayed, recorded)| replayed.delay_ms.abs_diff(recorded.delay_ms) <= 20));
+        }
+
+        assert_eq!(
+            randomize(&steps, &ComboRandomizationConfig::default(), &mut rng),
+            steps
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Foreground-window check so synthetic input only lands in Dota 2.
+//!
+//! `simulation` consults [`dota_is_focused`] before each action while
+//! `[input] only_input_when_dota_focused` is on. Off Windows there is no
+//! foreground window to ask about, so Dota always counts as focused.
+
+/// Window title of the Dota 2 client.
+const DOTA_WINDOW_TITLE: &str = "Dota 2";
+/// Window class of the Source 2 (SDL) Dota 2 client.
+const DOTA_WINDOW_CLASS: &str = "SDL_app";
+
+/// Whether a window with this title and class is the Dota 2 client.
+///
+/// The title must match exactly so a browser tab titled "Dota 2 patch notes"
+/// does not count; the class alone is shared by other SDL games.
+pub fn is_dota_window(title: &str, class: &str) -> bool {
+    title.trim() == DOTA_WINDOW_TITLE && (class.is_empty() || class == DOTA_WINDOW_CLASS)
+}
+
+/// Whether the foreground window is Dota 2. Always `true` off Windows.
+pub fn dota_is_focused() -> bool {
+    #[cfg(windows)]
+    {
+        dota_is_focused_win32()
+    }
+    #[cfg(not(windows))]
+    {
+        true
+    }
+}
+
+#[cfg(windows)]
+fn dota_is_focused_win32() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetClassNameW, GetForegroundWindow, GetWindowTextW,
+    };
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_invalid() {
+        return false;
+    }
+
+    let mut title = [0u16; 256];
+    let title_len = unsafe { GetWindowTextW(hwnd, &mut title) }.max(0) as usize;
+    let mut class = [0u16; 256];
+    let class_len = unsafe { GetClassNameW(hwnd, &mut class) }.max(0) as usize;
+
+    is_dota_window(
+        &String::from_utf16_lossy(&title[..title_len]),
+        &String::from_utf16_lossy(&class[..class_len]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_dota_client_window_counts() {
+        assert!(is_dota_window("Dota 2", "SDL_app"));
+        assert!(is_dota_window("Dota 2", ""));
+        assert!(!is_dota_window("Dota 2 patch notes - Google Chrome", "Chrome_WidgetWin_1"));
+        assert!(!is_dota_window("Dota 2", "Chrome_WidgetWin_1"));
+        assert!(!is_dota_window("Counter-Strike 2", "SDL_app"));
+    }
+}
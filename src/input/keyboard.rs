@@ -1,12 +1,13 @@
+use crate::state::poison::{lock_or_recover, lock_or_reset, read_or_recover};
 use rdev::{grab, simulate, Button, Event, EventType, Key};
 use std::sync::atomic::Ordering;
 use std::sync::mpsc::{self, Receiver, Sender};
-use std::sync::{Arc, LazyLock, RwLock};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
-use crate::actions::auto_items::MODIFIER_KEY_HELD;
+use crate::actions::auto_items::{AutoNeutral, MODIFIER_KEY_HELD};
 use crate::actions::heroes::broodmother::BROODMOTHER_ACTIVE;
 use crate::actions::heroes::outworld_destroyer::{
     build_keyboard_combo_config, OutworldDestroyerComboConfig, OutworldDestroyerState,
@@ -15,17 +16,39 @@ use crate::actions::heroes::shadow_fiend::ShadowFiendState;
 use crate::actions::SOUL_RING_STATE;
 use crate::actions::soul_ring::{SoulRingKeyboardConfig, SoulRingState};
 use crate::config::{AutoAbilityConfig, Settings};
+use crate::diagnostics::panic_report;
+use crate::input::combo_recorder;
+use crate::input::focus::dota_is_focused;
 use crate::input::simulation::SIMULATING_KEYS;
-use crate::state::app_state::AppState;
+use crate::state::app_state::{resolve_standalone_hero, AppState, HeroType};
 
 pub enum HotkeyEvent {
     ComboTrigger,
+    /// A hero's own `standalone_key` was pressed; carries the hero it belongs to.
+    HeroComboTrigger(HeroType),
     MeepoFarmToggle,
     ArmletRoshanToggle,
+    /// Manual armlet toggle that bypasses the automation cooldown.
+    ArmletEmergencyToggle,
     LargoQ,
     LargoW,
     LargoE,
     LargoR,
+    /// Start recording a combo for the current hero, or save the one in progress.
+    ComboRecorderToggle,
+}
+
+/// When the player last right-clicked or pressed `common.attack_key`.
+static LAST_ATTACK_ORDER: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// How long ago the player last right-clicked or pressed the Attack key.
+/// GSI does not report attack orders, so scripts use this as "attacking".
+pub fn attack_order_age() -> Option<Duration> {
+    lock_or_recover(&LAST_ATTACK_ORDER).map(|at| at.elapsed())
+}
+
+fn note_attack_order() {
+    *lock_or_recover(&LAST_ATTACK_ORDER) = Some(Instant::now());
 }
 
 pub struct KeyboardListenerConfig {
@@ -111,7 +134,7 @@ pub fn char_to_key(ch: char) -> Option<Key> {
 }
 
 /// Convert rdev::Key to char (for keys we care about)
-fn key_to_char(key: Key) -> Option<char> {
+pub(crate) fn key_to_char(key: Key) -> Option<char> {
     match key {
         Key::KeyA => Some('a'),
         Key::KeyB => Some('b'),
@@ -205,7 +228,7 @@ static SOUL_RING_REPLAY_QUEUE: LazyLock<Sender<SoulRingReplayRequest>> = LazyLoc
         
         while let Ok(request) = rx.recv() {
             // Lock state and compute the replay plan
-            let soul_ring_state = SOUL_RING_STATE.lock().unwrap();
+            let soul_ring_state = lock_or_reset(&SOUL_RING_STATE);
             let plan = plan_soul_ring_replay(&soul_ring_state, request.original_key, &request.config);
             // Execute using shared helper to ensure identical behavior between worker and fallback
             execute_soul_ring_plan_with_context(soul_ring_state, plan, "");
@@ -277,7 +300,7 @@ fn spawn_soul_ring_then_key(original_key: Key, sr_config: SoulRingKeyboardConfig
         warn!("Soul Ring replay queue closed unexpectedly, falling back to thread spawn: {:?}", e);
         
         thread::spawn(move || {
-            let soul_ring_state = SOUL_RING_STATE.lock().unwrap();
+            let soul_ring_state = lock_or_reset(&SOUL_RING_STATE);
             let plan = plan_soul_ring_replay(&soul_ring_state, original_key, &sr_config);
 
             // Use the same execution path as the worker but include a (fallback) suffix in logs
@@ -301,6 +324,15 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                 return Some(event);
             }
 
+            // Player input is recorded even when a handler below consumes it
+            if combo_recorder::is_recording() {
+                let toggle_key = read_or_recover(&config.snapshot).combo_recorder_toggle_key;
+                if let Some(input) = combo_recorder::recordable_input(&event.event_type, toggle_key)
+                {
+                    combo_recorder::record(input, Instant::now());
+                }
+            }
+
             // Track Space key (modifier for auto-items)
             match event.event_type {
                 EventType::KeyPress(Key::Space) => {
@@ -315,11 +347,12 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
             // Handle Broodmother callback actions without touching snapshot unless needed.
             match event.event_type {
                 EventType::ButtonPress(Button::Right) => {
+                    note_attack_order();
                     let modifier_held = MODIFIER_KEY_HELD.load(Ordering::SeqCst);
                     let broodmother_active = BROODMOTHER_ACTIVE.load(Ordering::SeqCst);
 
                     if modifier_held && broodmother_active {
-                        let snapshot = config.snapshot.read().unwrap().clone();
+                        let snapshot = read_or_recover(&config.snapshot).clone();
                         if let Some(action) = plan_broodmother_callback_action(
                             &event.event_type,
                             modifier_held,
@@ -336,7 +369,7 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                     let broodmother_active = BROODMOTHER_ACTIVE.load(Ordering::SeqCst);
 
                     if broodmother_active {
-                        let snapshot = config.snapshot.read().unwrap().clone();
+                        let snapshot = read_or_recover(&config.snapshot).clone();
                         if let Some(action) = plan_broodmother_callback_action(
                             &event.event_type,
                             false,
@@ -353,14 +386,24 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
             }
 
             if let EventType::KeyPress(key) = event.event_type {
-                let snapshot = config.snapshot.read().unwrap().clone();
+                let snapshot = read_or_recover(&config.snapshot).clone();
                 // Read snapshot once per keyboard event — static config comes from here.
+
+                // Typing in another app must not trigger razes, songs or Soul Ring.
+                if snapshot.only_when_dota_focused && !dota_is_focused() {
+                    return Some(event);
+                }
+
+                if snapshot.attack_key == Some(key) {
+                    note_attack_order();
+                }
+
                 // Convert key to char to check if we should intercept
                 let key_char = key_to_char(key);
                 
                 // Single live SOUL_RING_STATE read for all Soul Ring interception decisions
                 let should_intercept_for_soul_ring = if let Some(ch) = key_char {
-                    let soul_ring_state = SOUL_RING_STATE.lock().unwrap();
+                    let soul_ring_state = lock_or_reset(&SOUL_RING_STATE);
                     let should_intercept = soul_ring_state.should_intercept_key_with_config(ch, &snapshot.soul_ring);
                     let should_trigger = soul_ring_state.should_trigger_with_config(&snapshot.soul_ring);
                     debug!(
@@ -374,20 +417,32 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                     false
                 };
 
-                // Handle Shadow Fiend Q/W/E keys (when SF is selected AND raze interception is enabled)
+                // Handle Shadow Fiend raze keys (when SF is selected AND raze interception is enabled)
                 let sf_raze_active = snapshot.sf_enabled && snapshot.shadow_fiend.raze_intercept_enabled;
                 if sf_raze_active {
-                    match key {
-                        Key::KeyQ | Key::KeyW | Key::KeyE => {
-                            let raze_key = key_to_char(key).unwrap();
+                    if let Some(raze_key) =
+                        key_char.filter(|ch| snapshot.shadow_fiend.raze_keys.contains(ch))
+                    {
+                        if !snapshot.shadow_fiend.cast_on_key_release {
                             info!("{} key pressed - SF raze", raze_key.to_ascii_uppercase());
-                            
-                            ShadowFiendState::execute_raze(raze_key, snapshot.shadow_fiend.raze_delay_ms);
-                            
-                            // Block original key
-                            return None;
+
+                            ShadowFiendState::execute_raze(
+                                raze_key,
+                                snapshot.shadow_fiend.raze_delay_ms,
+                                snapshot.shadow_fiend.face_direction_with_alt,
+                            );
+                        } else if ShadowFiendState::begin_held_raze(
+                            raze_key,
+                            snapshot.shadow_fiend.face_direction_with_alt,
+                        ) {
+                            info!(
+                                "{} key held - SF raze aiming, casts on release",
+                                raze_key.to_ascii_uppercase()
+                            );
                         }
-                        _ => {}
+
+                        // Block original key (and its key-repeats while held)
+                        return None;
                     }
                 }
 
@@ -395,7 +450,10 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                 if snapshot.sf_enabled && snapshot.shadow_fiend.auto_bkb_on_ultimate && key == Key::KeyR {
                     info!("R key pressed - SF auto-BKB ultimate combo");
                     
-                    ShadowFiendState::execute_ultimate_combo(snapshot.shadow_fiend.auto_d_on_ultimate);
+                    ShadowFiendState::execute_ultimate_combo(
+                        snapshot.shadow_fiend.auto_d_on_ultimate,
+                        snapshot.shadow_fiend.slot_keys,
+                    );
                     
                     // Block original key (will be pressed by execute_ultimate_combo)
                     return None;
@@ -442,12 +500,8 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                 match key {
                     Key::KeyQ | Key::KeyW | Key::KeyE | Key::KeyR | Key::KeyD | Key::KeyF => {
                         // Send Largo events for beat timing
-                        match key {
-                            Key::KeyQ => { let _ = event_tx.send(HotkeyEvent::LargoQ); }
-                            Key::KeyW => { let _ = event_tx.send(HotkeyEvent::LargoW); }
-                            Key::KeyE => { let _ = event_tx.send(HotkeyEvent::LargoE); }
-                            Key::KeyR => { let _ = event_tx.send(HotkeyEvent::LargoR); }
-                            _ => {}
+                        if let Some(song_event) = plan_largo_song_event(key, &snapshot) {
+                            let _ = event_tx.send(song_event);
                         }
                         
                         // If Soul Ring should trigger, spawn handler and block original
@@ -478,8 +532,31 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                             let _ = event_tx.send(hotkey_event);
                             return None;
                         }
+                        HotkeyEvent::ArmletEmergencyToggle => {
+                            info!(
+                                "{:?} key pressed - emergency armlet toggle",
+                                snapshot.armlet_emergency_key
+                            );
+                            let _ = event_tx.send(hotkey_event);
+                            return None;
+                        }
+                        HotkeyEvent::ComboRecorderToggle => {
+                            info!("{:?} key pressed - toggling combo recording", key);
+                            let _ = event_tx.send(hotkey_event);
+                            return None;
+                        }
                         HotkeyEvent::ComboTrigger => {
-                            info!("{:?} key pressed - triggering combo", snapshot.trigger_key);
+                            info!(
+                                "{:?} key pressed - triggering combo",
+                                snapshot.trigger_key()
+                            );
+                        }
+                        HotkeyEvent::HeroComboTrigger(hero) => {
+                            info!(
+                                "{:?} key pressed - triggering {} combo",
+                                key,
+                                hero.to_display_name()
+                            );
                         }
                         HotkeyEvent::MeepoFarmToggle => {
                             info!(
@@ -494,6 +571,31 @@ pub fn start_keyboard_listener(config: KeyboardListenerConfig) -> Receiver<Hotke
                 }
             }
             
+            // Cast-on-release razes fire when their held key comes back up
+            if let EventType::KeyRelease(key) = event.event_type {
+                if let Some(raze_key) = key_to_char(key) {
+                    let snapshot = read_or_recover(&config.snapshot).clone();
+                    if snapshot.shadow_fiend.raze_keys.contains(&raze_key)
+                        && ShadowFiendState::release_held_raze(raze_key)
+                    {
+                        // Still honour a config change or focus loss while held
+                        let still_active = snapshot.sf_enabled
+                            && snapshot.shadow_fiend.raze_intercept_enabled
+                            && !(snapshot.only_when_dota_focused && !dota_is_focused());
+                        if still_active {
+                            info!("{} key released - SF raze", raze_key.to_ascii_uppercase());
+                            ShadowFiendState::execute_raze(
+                                raze_key,
+                                snapshot.shadow_fiend.raze_delay_ms,
+                                snapshot.shadow_fiend.face_direction_with_alt,
+                            );
+                        }
+                        // The press was blocked, so the game never saw this key down
+                        return None;
+                    }
+                }
+            }
+
             // Pass through all other events (key releases, mouse events, etc.)
             Some(event)
         };
@@ -514,7 +616,14 @@ pub struct ShadowFiendKeyboardSnapshot {
     pub raze_intercept_enabled: bool,
     pub auto_bkb_on_ultimate: bool,
     pub raze_delay_ms: u64,
+    /// Close, medium and far raze keys, lowercased
+    pub raze_keys: [char; 3],
+    pub cast_on_key_release: bool,
     pub auto_d_on_ultimate: bool,
+    /// `common.face_direction_with_alt`
+    pub face_direction_with_alt: bool,
+    /// Inventory slot keys, for the auto-BKB press
+    pub slot_keys: [char; 6],
 }
 
 #[derive(Debug, Clone)]
@@ -541,22 +650,44 @@ pub struct BroodmotherKeyboardSnapshot {
     pub abilities_first: bool,
     /// Slot keybindings [slot0..slot5] for item-key lookup.
     pub slot_keys: [char; 6],
+    /// Neutral slot to include, when `neutral_items.use_in_auto_items` is on.
+    pub auto_neutral: Option<AutoNeutral>,
 }
 
 /// Immutable snapshot of all keyboard-listener configuration, derived from
 /// `Settings` and `AppState` at a point in time.
 ///
 /// The callback reads this snapshot once per event instead of locking
-/// `Settings`, `sf_enabled`, and `trigger_key` separately.
+/// `Settings` and `sf_enabled` separately.
 #[derive(Debug, Clone)]
 pub struct KeyboardSnapshot {
-    /// The parsed combo-trigger key, or `None` if the configured string is
-    /// not a recognised key name.
-    pub trigger_key: Option<Key>,
+    /// Shared with `AppState::trigger_key`; parsed at keypress time so a GSI
+    /// hero switch rebinds the combo trigger without a refresh.
+    pub trigger_key: Arc<Mutex<String>>,
+    /// Parsed per-hero `standalone_key` bindings, watched simultaneously.
+    pub hero_trigger_keys: Vec<(HeroType, Key)>,
+    /// Hero currently selected (manually or via GSI).
+    pub selected_hero: Option<HeroType>,
+    /// Shared with `AppState::live_hero`; takes precedence over
+    /// `selected_hero` when routing per-hero standalone keys.
+    pub live_hero: Arc<Mutex<Option<HeroType>>>,
+    /// Standalone automation toggle; Largo song keys are only reported while on.
+    pub standalone_enabled: bool,
+    /// Shared with `AppState::largo_enabled`; read at keypress time.
+    pub largo_enabled: Arc<Mutex<bool>>,
+    /// Leave key presses untouched while another window is focused
+    /// (`[input] only_input_when_dota_focused`).
+    pub only_when_dota_focused: bool,
     /// Parsed Meepo farm-assist toggle key for the current hero, if active.
     pub meepo_farm_toggle_key: Option<Key>,
     /// Parsed Armlet Roshan mode toggle key, if enabled.
     pub armlet_roshan_toggle_key: Option<Key>,
+    /// Parsed emergency armlet toggle key, if configured.
+    pub armlet_emergency_key: Option<Key>,
+    /// Parsed combo recorder start/stop key, if the recorder is enabled.
+    pub combo_recorder_toggle_key: Option<Key>,
+    /// Parsed `common.attack_key`; presses count toward [`attack_order_age`].
+    pub attack_key: Option<Key>,
     /// Whether Shadow Fiend raze interception is active.
     pub sf_enabled: bool,
     pub od_enabled: bool,
@@ -574,6 +705,7 @@ enum BroodmotherCallbackAction {
         auto_items: Vec<String>,
         auto_abilities: Vec<AutoAbilityConfig>,
         abilities_first: bool,
+        auto_neutral: Option<AutoNeutral>,
     },
     SpiderMicro {
         spider_key: Option<Key>,
@@ -613,14 +745,8 @@ fn execute_broodmother_callback_action_with_guard<F>(context: &str, action: F)
 where
     F: FnOnce(),
 {
-    if let Err(panic_payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(action)) {
-        let panic_message = if let Some(message) = panic_payload.downcast_ref::<&str>() {
-            *message
-        } else if let Some(message) = panic_payload.downcast_ref::<String>() {
-            message.as_str()
-        } else {
-            "unknown panic payload"
-        };
+    if let Err(panic_payload) = panic_report::catch_panics(std::panic::AssertUnwindSafe(action)) {
+        let panic_message = panic_report::payload_message(panic_payload.as_ref());
 
         warn!(
             "Broodmother callback action panicked{}; worker will continue running: {}",
@@ -633,13 +759,20 @@ where
 /// Used by both the worker and fallback thread to ensure identical behavior.
 fn execute_broodmother_callback_action(action: BroodmotherCallbackAction, context: &str) {
     match action {
-        BroodmotherCallbackAction::AutoItems { slot_keys, auto_items, auto_abilities, abilities_first } => {
+        BroodmotherCallbackAction::AutoItems {
+            slot_keys,
+            auto_items,
+            auto_abilities,
+            abilities_first,
+            auto_neutral,
+        } => {
             debug!("🕷️ Executing Broodmother auto-items{}", context);
             crate::actions::auto_items::execute_auto_items(
                 &slot_keys,
                 &auto_items,
                 &auto_abilities,
                 abilities_first,
+                auto_neutral.as_ref(),
             );
         }
         BroodmotherCallbackAction::SpiderMicro { spider_key, hero_key } => {
@@ -671,18 +804,30 @@ fn enqueue_broodmother_callback_action(action: BroodmotherCallbackAction) {
 impl KeyboardSnapshot {
     /// Build a snapshot from the current runtime settings and app state.
     pub fn from_runtime(settings: &Settings, state: &AppState) -> Self {
-        let trigger_key_str = state.trigger_key.lock().unwrap().clone();
-        let trigger_key = parse_key_string(&trigger_key_str);
-        let sf_enabled = *state.sf_enabled.lock().unwrap();
-        let od_enabled = *state.od_enabled.lock().unwrap();
+        let sf_enabled = *lock_or_recover(&state.sf_enabled);
+        let od_enabled = *lock_or_recover(&state.od_enabled);
 
         let sf = &settings.heroes.shadow_fiend;
         let od = &settings.heroes.outworld_destroyer;
         let bm = &settings.heroes.broodmother;
 
+        let hero_trigger_keys = HeroType::ALL
+            .into_iter()
+            .filter_map(|hero| {
+                parse_key_string(&settings.get_standalone_key(hero.config_key()))
+                    .map(|key| (hero, key))
+            })
+            .collect();
+
         Self {
-            trigger_key,
-            meepo_farm_toggle_key: if state.selected_hero == Some(crate::state::HeroType::Meepo)
+            trigger_key: Arc::clone(&state.trigger_key),
+            hero_trigger_keys,
+            selected_hero: state.selected_hero,
+            live_hero: Arc::clone(&state.live_hero),
+            standalone_enabled: state.standalone_enabled,
+            largo_enabled: Arc::clone(&state.largo_enabled),
+            only_when_dota_focused: settings.input.only_input_when_dota_focused,
+            meepo_farm_toggle_key: if state.standalone_hero() == Some(HeroType::Meepo)
                 && state.standalone_enabled
                 && settings.heroes.meepo.farm_assist.enabled
             {
@@ -695,13 +840,24 @@ impl KeyboardSnapshot {
             } else {
                 None
             },
+            armlet_emergency_key: parse_key_string(&settings.armlet.emergency_toggle_key),
+            combo_recorder_toggle_key: if settings.combo_recorder.enabled {
+                parse_key_string(&settings.combo_recorder.toggle_key)
+            } else {
+                None
+            },
+            attack_key: char_to_key(settings.common.attack_key),
             sf_enabled,
             od_enabled,
             shadow_fiend: ShadowFiendKeyboardSnapshot {
                 raze_intercept_enabled: sf.raze_intercept_enabled,
                 auto_bkb_on_ultimate: sf.auto_bkb_on_ultimate,
                 raze_delay_ms: sf.raze_delay_ms,
+                raze_keys: sf.raze_keys().map(|(_, key)| key),
+                cast_on_key_release: sf.cast_on_key_release,
                 auto_d_on_ultimate: sf.auto_d_on_ultimate,
+                face_direction_with_alt: settings.common.face_direction_with_alt,
+                slot_keys: settings.inventory_slot_keys(),
             },
             outworld_destroyer: OutworldDestroyerKeyboardSnapshot {
                 ultimate_intercept_enabled: od.ultimate_intercept_enabled,
@@ -717,18 +873,23 @@ impl KeyboardSnapshot {
                 auto_items: bm.auto_items.clone(),
                 auto_abilities: bm.auto_abilities.clone(),
                 abilities_first: bm.auto_abilities_first,
-                slot_keys: [
-                    settings.keybindings.slot0,
-                    settings.keybindings.slot1,
-                    settings.keybindings.slot2,
-                    settings.keybindings.slot3,
-                    settings.keybindings.slot4,
-                    settings.keybindings.slot5,
-                ],
+                slot_keys: settings.inventory_slot_keys(),
+                auto_neutral: (settings.neutral_items.enabled
+                    && settings.neutral_items.use_in_auto_items)
+                    .then(|| AutoNeutral {
+                        key: settings.keybindings.neutral0,
+                        allowed_items: settings.neutral_items.allowed_items.clone(),
+                    }),
             },
             soul_ring: SoulRingKeyboardConfig::from_settings(settings),
         }
     }
+
+    /// The parsed combo-trigger key, or `None` if the configured string is
+    /// not a recognised key name.
+    pub fn trigger_key(&self) -> Option<Key> {
+        parse_key_string(&lock_or_recover(&self.trigger_key))
+    }
 }
 
 fn plan_broodmother_callback_action(
@@ -746,6 +907,7 @@ fn plan_broodmother_callback_action(
                 auto_items: snapshot.broodmother.auto_items.clone(),
                 auto_abilities: snapshot.broodmother.auto_abilities.clone(),
                 abilities_first: snapshot.broodmother.abilities_first,
+                auto_neutral: snapshot.broodmother.auto_neutral.clone(),
             })
         }
         EventType::ButtonPress(Button::Middle)
@@ -761,6 +923,13 @@ fn plan_broodmother_callback_action(
 }
 
 fn plan_global_hotkey_event(key: Key, snapshot: &KeyboardSnapshot) -> Option<HotkeyEvent> {
+    if snapshot
+        .combo_recorder_toggle_key
+        .is_some_and(|toggle_key| key == toggle_key)
+    {
+        return Some(HotkeyEvent::ComboRecorderToggle);
+    }
+
     if snapshot
         .meepo_farm_toggle_key
         .is_some_and(|toggle_key| key == toggle_key)
@@ -775,34 +944,102 @@ fn plan_global_hotkey_event(key: Key, snapshot: &KeyboardSnapshot) -> Option<Hot
         return Some(HotkeyEvent::ArmletRoshanToggle);
     }
 
-    if snapshot.trigger_key.is_some_and(|trigger_key| key == trigger_key) {
+    if snapshot
+        .armlet_emergency_key
+        .is_some_and(|emergency_key| key == emergency_key)
+    {
+        return Some(HotkeyEvent::ArmletEmergencyToggle);
+    }
+
+    if let Some(hero) = plan_hero_combo_trigger(key, snapshot) {
+        return Some(HotkeyEvent::HeroComboTrigger(hero));
+    }
+
+    if snapshot
+        .trigger_key()
+        .is_some_and(|trigger_key| key == trigger_key)
+    {
         return Some(HotkeyEvent::ComboTrigger);
     }
 
     None
 }
 
+/// Largo song event for an ability key, only while Largo is the selected hero.
+fn plan_largo_song_event(key: Key, snapshot: &KeyboardSnapshot) -> Option<HotkeyEvent> {
+    let largo_enabled = *lock_or_recover(&snapshot.largo_enabled);
+    if !snapshot.standalone_enabled || !largo_enabled {
+        return None;
+    }
+
+    match key {
+        Key::KeyQ => Some(HotkeyEvent::LargoQ),
+        Key::KeyW => Some(HotkeyEvent::LargoW),
+        Key::KeyE => Some(HotkeyEvent::LargoE),
+        Key::KeyR => Some(HotkeyEvent::LargoR),
+        _ => None,
+    }
+}
+
+/// Resolve which hero a per-hero standalone key belongs to.
+///
+/// The live GSI hero wins over the UI selection, so picking a different
+/// hero in game than the one pre-selected still routes to the right combo.
+/// With a hero known only that hero's key fires, so shared keys (the
+/// default `Home` for everyone) never trigger another hero's combo. With no
+/// hero known yet, a key fires only when it is unique to one hero.
+fn plan_hero_combo_trigger(key: Key, snapshot: &KeyboardSnapshot) -> Option<HeroType> {
+    let mut owners = snapshot
+        .hero_trigger_keys
+        .iter()
+        .filter(|(_, hero_key)| *hero_key == key)
+        .map(|(hero, _)| *hero);
+
+    match resolve_standalone_hero(&snapshot.live_hero, snapshot.selected_hero) {
+        Some(selected) => owners.find(|hero| *hero == selected),
+        None => {
+            let first = owners.next()?;
+            owners.next().is_none().then_some(first)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::sync::Mutex;
     use std::sync::atomic::{AtomicBool, Ordering};
-    use crate::state::app_state::{HeroType, QueueMetrics, UpdateCheckState};
+    use crate::state::app_state::{QueueMetrics, UpdateCheckState};
+    use crate::state::AutomationMetrics;
     use crate::actions::soul_ring::{SoulRingState, SoulRingKeyboardConfig};
     use std::collections::HashSet;
+    use crate::gsi::load_fixture_event;
 
     fn broodmother_test_snapshot() -> KeyboardSnapshot {
         KeyboardSnapshot {
-            trigger_key: None,
+            trigger_key: Arc::new(Mutex::new(String::new())),
+            hero_trigger_keys: Vec::new(),
+            selected_hero: None,
+            live_hero: Arc::new(Mutex::new(None)),
+            standalone_enabled: true,
+            largo_enabled: Arc::new(Mutex::new(false)),
+            only_when_dota_focused: false,
             meepo_farm_toggle_key: None,
             armlet_roshan_toggle_key: None,
+            armlet_emergency_key: None,
+            combo_recorder_toggle_key: None,
+            attack_key: Some(Key::KeyA),
             sf_enabled: false,
             od_enabled: false,
             shadow_fiend: ShadowFiendKeyboardSnapshot {
                 raze_intercept_enabled: false,
                 auto_bkb_on_ultimate: false,
                 raze_delay_ms: 0,
+                raze_keys: ['q', 'w', 'e'],
+                cast_on_key_release: false,
                 auto_d_on_ultimate: false,
+                face_direction_with_alt: true,
+                slot_keys: ['z', 'x', 'c', 'v', 'b', 'n'],
             },
             outworld_destroyer: OutworldDestroyerKeyboardSnapshot {
                 ultimate_intercept_enabled: false,
@@ -819,6 +1056,7 @@ mod tests {
                 auto_abilities: vec![],
                 abilities_first: true,
                 slot_keys: ['a', 's', 'd', 'f', 'g', 'h'],
+                auto_neutral: None,
             },
             soul_ring: SoulRingKeyboardConfig::from_settings(&Settings::default()),
         }
@@ -826,9 +1064,11 @@ mod tests {
 
     #[test]
     fn keyboard_snapshot_parses_trigger_key_and_sf_flags() {
-        let settings = Settings::default();
+        let mut settings = Settings::default();
+        settings.heroes.shadow_fiend.close_raze_key = 'J';
         let state = AppState {
             selected_hero: Some(HeroType::ShadowFiend),
+            live_hero: Arc::new(Mutex::new(None)),
             gsi_enabled: true,
             standalone_enabled: true,
             last_event: None,
@@ -837,17 +1077,21 @@ mod tests {
             trigger_key: Arc::new(Mutex::new("Home".to_string())),
             sf_enabled: Arc::new(Mutex::new(true)),
             od_enabled: Arc::new(Mutex::new(false)),
+            largo_enabled: Arc::new(Mutex::new(false)),
             update_state: Arc::new(Mutex::new(UpdateCheckState::Idle)),
             rune_alerts: None,
             minimap_capture: None,
+            automation_metrics: Arc::new(Mutex::new(AutomationMetrics::default())),
         };
 
         let snapshot = KeyboardSnapshot::from_runtime(&settings, &state);
 
-        assert_eq!(snapshot.trigger_key, Some(Key::Home));
+        assert_eq!(snapshot.trigger_key(), Some(Key::Home));
         assert_eq!(snapshot.meepo_farm_toggle_key, None);
         assert!(snapshot.sf_enabled);
         assert!(snapshot.shadow_fiend.raze_intercept_enabled);
+        assert_eq!(snapshot.shadow_fiend.raze_keys, ['j', 'w', 'e']);
+        assert!(!snapshot.shadow_fiend.cast_on_key_release);
     }
 
     #[test]
@@ -855,6 +1099,7 @@ mod tests {
         let settings = Settings::default();
         let state = AppState {
             selected_hero: Some(HeroType::OutworldDestroyer),
+            live_hero: Arc::new(Mutex::new(None)),
             gsi_enabled: true,
             standalone_enabled: true,
             last_event: None,
@@ -863,9 +1108,11 @@ mod tests {
             trigger_key: Arc::new(Mutex::new("Home".to_string())),
             sf_enabled: Arc::new(Mutex::new(false)),
             od_enabled: Arc::new(Mutex::new(true)),
+            largo_enabled: Arc::new(Mutex::new(false)),
             update_state: Arc::new(Mutex::new(UpdateCheckState::Idle)),
             rune_alerts: None,
             minimap_capture: None,
+            automation_metrics: Arc::new(Mutex::new(AutomationMetrics::default())),
         };
 
         let snapshot = KeyboardSnapshot::from_runtime(&settings, &state);
@@ -882,7 +1129,7 @@ mod tests {
 
         let snapshot = KeyboardSnapshot::from_runtime(&Settings::default(), &state);
 
-        assert_eq!(snapshot.trigger_key, None);
+        assert_eq!(snapshot.trigger_key(), None);
         assert_eq!(snapshot.meepo_farm_toggle_key, None);
     }
 
@@ -900,7 +1147,30 @@ mod tests {
         let state = AppState::default();
         *state.trigger_key.lock().unwrap() = "F5".to_string();
         let snapshot = KeyboardSnapshot::from_runtime(&Settings::default(), &state);
-        assert_eq!(snapshot.trigger_key, Some(Key::F5));
+        assert_eq!(snapshot.trigger_key(), Some(Key::F5));
+    }
+
+    #[test]
+    fn keyboard_snapshot_trigger_key_follows_gsi_hero_switch() {
+        let mut settings = Settings::default();
+        settings.heroes.huskar.standalone_key = "F6".to_string();
+        settings.heroes.tiny.standalone_key = "F7".to_string();
+        let mut state = AppState::default();
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &state);
+        assert_eq!(snapshot.trigger_key(), Some(Key::Home));
+
+        for (fixture, expected) in [
+            ("tests/fixtures/huskar_event.json", Key::F6),
+            ("tests/fixtures/tiny_event.json", Key::F7),
+        ] {
+            state.update_from_gsi(load_fixture_event(fixture), &settings);
+
+            assert_eq!(snapshot.trigger_key(), Some(expected));
+            assert!(matches!(
+                plan_global_hotkey_event(expected, &snapshot),
+                Some(HotkeyEvent::ComboTrigger | HotkeyEvent::HeroComboTrigger(_))
+            ));
+        }
     }
 
     #[test]
@@ -949,6 +1219,123 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn armlet_emergency_key_plans_hotkey_event_when_configured() {
+        let mut settings = Settings::default();
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &AppState::default());
+        assert_eq!(snapshot.armlet_emergency_key, None);
+
+        settings.armlet.emergency_toggle_key = "PageDown".to_string();
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &AppState::default());
+
+        assert!(matches!(
+            plan_global_hotkey_event(Key::PageDown, &snapshot),
+            Some(HotkeyEvent::ArmletEmergencyToggle)
+        ));
+    }
+
+    #[test]
+    fn combo_recorder_key_plans_hotkey_event_only_when_enabled() {
+        let mut settings = Settings::default();
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &AppState::default());
+        assert_eq!(snapshot.combo_recorder_toggle_key, None);
+
+        settings.combo_recorder.enabled = true;
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &AppState::default());
+
+        assert!(matches!(
+            plan_global_hotkey_event(Key::F8, &snapshot),
+            Some(HotkeyEvent::ComboRecorderToggle)
+        ));
+    }
+
+    #[test]
+    fn keyboard_snapshot_collects_every_hero_standalone_key() {
+        let mut settings = Settings::default();
+        settings.heroes.tiny.standalone_key = "F6".to_string();
+        settings.heroes.huskar.standalone_key = "not-a-key".to_string();
+
+        let snapshot = KeyboardSnapshot::from_runtime(&settings, &AppState::default());
+
+        assert!(snapshot
+            .hero_trigger_keys
+            .contains(&(HeroType::Tiny, Key::F6)));
+        assert!(snapshot
+            .hero_trigger_keys
+            .contains(&(HeroType::Broodmother, Key::Home)));
+        assert!(!snapshot
+            .hero_trigger_keys
+            .iter()
+            .any(|(hero, _)| *hero == HeroType::Huskar));
+    }
+
+    #[test]
+    fn hero_standalone_key_plans_hero_tagged_trigger_for_selected_hero() {
+        let mut snapshot = broodmother_test_snapshot();
+        snapshot.hero_trigger_keys = vec![
+            (HeroType::Tiny, Key::F6),
+            (HeroType::LegionCommander, Key::F7),
+        ];
+        snapshot.selected_hero = Some(HeroType::LegionCommander);
+
+        assert!(matches!(
+            plan_global_hotkey_event(Key::F7, &snapshot),
+            Some(HotkeyEvent::HeroComboTrigger(HeroType::LegionCommander))
+        ));
+        assert!(plan_global_hotkey_event(Key::F6, &snapshot).is_none());
+    }
+
+    #[test]
+    fn live_gsi_hero_overrides_stale_selection_for_shared_keys() {
+        let mut snapshot = broodmother_test_snapshot();
+        snapshot.hero_trigger_keys = vec![
+            (HeroType::Tiny, Key::Home),
+            (HeroType::LegionCommander, Key::Home),
+        ];
+        snapshot.selected_hero = Some(HeroType::Tiny);
+        *snapshot.live_hero.lock().unwrap() = Some(HeroType::LegionCommander);
+
+        assert!(matches!(
+            plan_global_hotkey_event(Key::Home, &snapshot),
+            Some(HotkeyEvent::HeroComboTrigger(HeroType::LegionCommander))
+        ));
+    }
+
+    #[test]
+    fn shared_hero_standalone_key_needs_a_selected_hero() {
+        let mut snapshot = broodmother_test_snapshot();
+        snapshot.hero_trigger_keys = vec![
+            (HeroType::Tiny, Key::Home),
+            (HeroType::Meepo, Key::Home),
+            (HeroType::LegionCommander, Key::F7),
+        ];
+
+        assert!(plan_global_hotkey_event(Key::Home, &snapshot).is_none());
+        assert!(matches!(
+            plan_global_hotkey_event(Key::F7, &snapshot),
+            Some(HotkeyEvent::HeroComboTrigger(HeroType::LegionCommander))
+        ));
+    }
+
+    #[test]
+    fn largo_song_events_need_largo_enabled() {
+        let mut state = AppState::default();
+        let snapshot = KeyboardSnapshot::from_runtime(&Settings::default(), &state);
+        assert!(plan_largo_song_event(Key::KeyQ, &snapshot).is_none());
+
+        // Hero changes flip the shared flag without rebuilding the snapshot.
+        *state.largo_enabled.lock().unwrap() = true;
+        assert!(matches!(
+            plan_largo_song_event(Key::KeyQ, &snapshot),
+            Some(HotkeyEvent::LargoQ)
+        ));
+        assert!(plan_largo_song_event(Key::KeyD, &snapshot).is_none());
+
+        state.standalone_enabled = false;
+        let snapshot = KeyboardSnapshot::from_runtime(&Settings::default(), &state);
+        assert!(plan_largo_song_event(Key::KeyQ, &snapshot).is_none());
+    }
+
     // Soul Ring replay-plan tests
     fn soul_ring_test_config() -> SoulRingKeyboardConfig {
         SoulRingKeyboardConfig {
@@ -1046,7 +1433,13 @@ mod tests {
         );
 
         match action {
-            Some(BroodmotherCallbackAction::AutoItems { slot_keys, auto_items, auto_abilities, abilities_first }) => {
+            Some(BroodmotherCallbackAction::AutoItems {
+                slot_keys,
+                auto_items,
+                auto_abilities,
+                abilities_first,
+                ..
+            }) => {
                 assert_eq!(slot_keys, snapshot.broodmother.slot_keys);
                 assert_eq!(auto_items, snapshot.broodmother.auto_items);
                 assert_eq!(auto_abilities.len(), snapshot.broodmother.auto_abilities.len());
@@ -1,4 +1,7 @@
+pub mod combo_recorder;
+pub mod focus;
 pub mod keyboard;
+pub mod scancode;
 pub mod simulation;
 
 pub use simulation::press_key;
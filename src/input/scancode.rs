@@ -0,0 +1,220 @@
+//! `SendInput` backend that sends hardware scancodes instead of Unicode keys.
+//!
+//! Enigo's `Key::Unicode` presses arrive as virtual-key/Unicode events, which
+//! some game input layers ignore. Selected with `[simulation] backend =
+//! "scancode"`; off Windows `simulation::set_input_backend` keeps Enigo, since
+//! every action here would fail.
+
+use super::simulation::{InputSink, ModifierKey, SyntheticAction};
+
+/// Set-1 scancode of a key on a US layout, for the characters the tool binds
+/// (letters, digits, punctuation, space). Upper case maps to the same key.
+pub fn char_scancode(key: char) -> Option<u16> {
+    let code = match key.to_ascii_lowercase() {
+        '1' => 0x02,
+        '2' => 0x03,
+        '3' => 0x04,
+        '4' => 0x05,
+        '5' => 0x06,
+        '6' => 0x07,
+        '7' => 0x08,
+        '8' => 0x09,
+        '9' => 0x0A,
+        '0' => 0x0B,
+        '-' => 0x0C,
+        '=' => 0x0D,
+        'q' => 0x10,
+        'w' => 0x11,
+        'e' => 0x12,
+        'r' => 0x13,
+        't' => 0x14,
+        'y' => 0x15,
+        'u' => 0x16,
+        'i' => 0x17,
+        'o' => 0x18,
+        'p' => 0x19,
+        '[' => 0x1A,
+        ']' => 0x1B,
+        'a' => 0x1E,
+        's' => 0x1F,
+        'd' => 0x20,
+        'f' => 0x21,
+        'g' => 0x22,
+        'h' => 0x23,
+        'j' => 0x24,
+        'k' => 0x25,
+        'l' => 0x26,
+        ';' => 0x27,
+        '\'' => 0x28,
+        '`' => 0x29,
+        '\\' => 0x2B,
+        'z' => 0x2C,
+        'x' => 0x2D,
+        'c' => 0x2E,
+        'v' => 0x2F,
+        'b' => 0x30,
+        'n' => 0x31,
+        'm' => 0x32,
+        ',' => 0x33,
+        '.' => 0x34,
+        '/' => 0x35,
+        ' ' => 0x39,
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Scancode of the left-hand modifier key.
+pub fn modifier_scancode(modifier: ModifierKey) -> u16 {
+    match modifier {
+        ModifierKey::Shift => 0x2A,
+        ModifierKey::Control => 0x1D,
+        ModifierKey::Alt => 0x38,
+    }
+}
+
+/// Key half of an action: `(scancode, key_down)` per event, in order.
+fn key_events(action: SyntheticAction) -> Result<Vec<(u16, bool)>, String> {
+    let scancode =
+        |key: char| char_scancode(key).ok_or_else(|| format!("No scancode for key '{}'", key));
+    match action {
+        SyntheticAction::KeyClick(key) => {
+            let code = scancode(key)?;
+            Ok(vec![(code, true), (code, false)])
+        }
+        SyntheticAction::KeyDown(key) => Ok(vec![(scancode(key)?, true)]),
+        SyntheticAction::KeyUp(key) => Ok(vec![(scancode(key)?, false)]),
+        SyntheticAction::ModifierDown(modifier) => Ok(vec![(modifier_scancode(modifier), true)]),
+        SyntheticAction::ModifierUp(modifier) => Ok(vec![(modifier_scancode(modifier), false)]),
+        SyntheticAction::RightClick
+        | SyntheticAction::LeftClick
+        | SyntheticAction::ArmletChord { .. } => Ok(Vec::new()),
+    }
+}
+
+/// Scancode backend. Mouse clicks go through `SendInput` as well, so one
+/// backend owns the whole input stream.
+#[derive(Default)]
+pub struct ScancodeInputSink;
+
+impl InputSink for ScancodeInputSink {
+    fn perform(&mut self, action: SyntheticAction) -> Result<(), String> {
+        if matches!(action, SyntheticAction::ArmletChord { .. }) {
+            tracing::warn!("Armlet chord should be expanded before single-action execution");
+            return Ok(());
+        }
+        send(action, key_events(action)?)
+    }
+
+    /// `SendInput` keeps no handle that could be rebuilt, so repeated
+    /// failures are reported rather than papered over.
+    fn reinitialize(&mut self) -> Result<(), String> {
+        Err("the scancode backend has nothing to rebuild; SendInput itself is failing".to_string())
+    }
+}
+
+#[cfg(windows)]
+fn send(action: SyntheticAction, keys: Vec<(u16, bool)>) -> Result<(), String> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT, KEYEVENTF_KEYUP,
+        KEYEVENTF_SCANCODE, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP, MOUSEEVENTF_RIGHTDOWN,
+        MOUSEEVENTF_RIGHTUP, MOUSEINPUT, MOUSE_EVENT_FLAGS, VIRTUAL_KEY,
+    };
+
+    let key_input = |scancode: u16, down: bool| {
+        let flags = if down {
+            KEYEVENTF_SCANCODE
+        } else {
+            KEYEVENTF_SCANCODE | KEYEVENTF_KEYUP
+        };
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: VIRTUAL_KEY(0),
+                    wScan: scancode,
+                    dwFlags: flags,
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        }
+    };
+    let mouse_input = |flags: MOUSE_EVENT_FLAGS| INPUT {
+        r#type: INPUT_MOUSE,
+        Anonymous: INPUT_0 {
+            mi: MOUSEINPUT {
+                dx: 0,
+                dy: 0,
+                mouseData: 0,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+
+    let inputs: Vec<INPUT> = match action {
+        SyntheticAction::RightClick => vec![
+            mouse_input(MOUSEEVENTF_RIGHTDOWN),
+            mouse_input(MOUSEEVENTF_RIGHTUP),
+        ],
+        SyntheticAction::LeftClick => vec![
+            mouse_input(MOUSEEVENTF_LEFTDOWN),
+            mouse_input(MOUSEEVENTF_LEFTUP),
+        ],
+        _ => keys
+            .into_iter()
+            .map(|(scancode, down)| key_input(scancode, down))
+            .collect(),
+    };
+
+    let sent = unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        Err(format!(
+            "SendInput accepted {} of {} events for {:?}: {}",
+            sent,
+            inputs.len(),
+            action,
+            windows::core::Error::from_win32()
+        ))
+    }
+}
+
+#[cfg(not(windows))]
+fn send(action: SyntheticAction, _keys: Vec<(u16, bool)>) -> Result<(), String> {
+    Err(format!(
+        "Scancode input backend is Windows-only; dropped {:?}",
+        action
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bound_keys_map_to_us_layout_scancodes() {
+        assert_eq!(char_scancode('q'), Some(0x10));
+        assert_eq!(char_scancode('Q'), Some(0x10));
+        assert_eq!(char_scancode('z'), Some(0x2C));
+        assert_eq!(char_scancode('0'), Some(0x0B));
+        assert_eq!(char_scancode('é'), None);
+        assert!(('a'..='z').all(|key| char_scancode(key).is_some()));
+    }
+
+    #[test]
+    fn click_is_a_press_then_release_of_the_same_scancode() {
+        assert_eq!(
+            key_events(SyntheticAction::KeyClick('w')).unwrap(),
+            vec![(0x11, true), (0x11, false)]
+        );
+        assert_eq!(
+            key_events(SyntheticAction::ModifierUp(ModifierKey::Alt)).unwrap(),
+            vec![(0x38, false)]
+        );
+        assert!(key_events(SyntheticAction::KeyDown('é')).is_err());
+    }
+}
@@ -1,11 +1,19 @@
+use crate::actions::activity::{push_activity, ActivityCategory};
+use crate::config::settings::CastModifier;
+use crate::state::poison::lock_or_recover;
 use enigo::{Button, Direction, Enigo, Key, Keyboard, Mouse, Settings};
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
-use tracing::{debug, warn};
+use tracing::{debug, error, info, warn};
+
+use super::focus::dota_is_focused;
+use super::scancode::ScancodeInputSink;
+
+pub use crate::config::settings::InputBackend;
 
 const POST_ACTION_GUARD_DELAY_MS: u64 = 10;
 
@@ -15,6 +23,24 @@ static METRICS: OnceLock<Mutex<SyntheticInputMetricsState>> = OnceLock::new();
 /// Global flag to indicate we're simulating keys - prevents keyboard grab re-interception
 pub static SIMULATING_KEYS: AtomicBool = AtomicBool::new(false);
 
+/// Safe mode: every synthetic action is logged and dropped instead of sent.
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Practice mode: input is dropped like safe mode; action sites explain instead.
+static PRACTICE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Consecutive backend failures before the worker rebuilds the input sink.
+const REINIT_AFTER_FAILURES: u32 = 3;
+
+/// Drop synthetic input while another window is in the foreground.
+static ONLY_WHEN_DOTA_FOCUSED: AtomicBool = AtomicBool::new(false);
+
+/// Send through the scancode backend instead of Enigo.
+static SCANCODE_BACKEND: AtomicBool = AtomicBool::new(false);
+
+/// Minimum gap between two rate-limited actions on the worker, 0 = off.
+static MIN_ACTION_INTERVAL_MS: AtomicU64 = AtomicU64::new(0);
+
 #[cfg_attr(not(test), allow(dead_code))]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 struct SyntheticInputMetricsState {
@@ -23,6 +49,9 @@ struct SyntheticInputMetricsState {
     dropped_total: u64,
     current_depth: usize,
     peak_depth: usize,
+    failed_total: u64,
+    consecutive_failures: u32,
+    reinit_total: u64,
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
@@ -33,6 +62,13 @@ pub struct SyntheticInputMetricsSnapshot {
     pub dropped_total: u64,
     pub current_depth: usize,
     pub peak_depth: usize,
+    /// Actions the backend reported as failed.
+    pub failed_total: u64,
+    /// Failures since the last action that went through; non-zero means
+    /// input is currently not reaching the game.
+    pub consecutive_failures: u32,
+    /// Times the backend was rebuilt after repeated failures.
+    pub reinit_total: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,8 +78,19 @@ pub enum ModifierKey {
     Shift,
 }
 
+impl From<CastModifier> for ModifierKey {
+    fn from(modifier: CastModifier) -> Self {
+        match modifier {
+            CastModifier::Alt => Self::Alt,
+            CastModifier::Ctrl => Self::Control,
+            CastModifier::Shift => Self::Shift,
+        }
+    }
+}
+
+/// One low-level input step handed to an [`InputSink`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SyntheticAction {
+pub enum SyntheticAction {
     KeyClick(char),
     KeyDown(char),
     KeyUp(char),
@@ -100,6 +147,267 @@ struct GuardExecutionPlan {
     final_simulating_value: Option<bool>,
 }
 
+/// Backend that delivers synthetic actions to the OS.
+///
+/// Chords are expanded before `perform` is called, so implementations never
+/// see `SyntheticAction::ArmletChord`.
+pub trait InputSink: Send {
+    /// Send `action`, or describe why the backend failed to.
+    fn perform(&mut self, action: SyntheticAction) -> Result<(), String>;
+
+    /// Rebuild the backend after repeated failures.
+    fn reinitialize(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Default backend: Enigo key and mouse events.
+pub struct EnigoInputSink {
+    enigo: Enigo,
+}
+
+impl EnigoInputSink {
+    pub fn new() -> Result<Self, String> {
+        Enigo::new(&Settings::default())
+            .map(|enigo| Self { enigo })
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl InputSink for EnigoInputSink {
+    fn perform(&mut self, action: SyntheticAction) -> Result<(), String> {
+        perform_single_action(&mut self.enigo, action)
+    }
+
+    /// A fresh Enigo handle, e.g. after a display change left the old one
+    /// unable to send events.
+    fn reinitialize(&mut self) -> Result<(), String> {
+        self.enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Sends through Enigo or [`ScancodeInputSink`], following
+/// [`set_input_backend`] on every action so a config save applies at once.
+pub struct SelectedInputSink {
+    enigo: EnigoInputSink,
+    scancode: ScancodeInputSink,
+}
+
+impl SelectedInputSink {
+    pub fn new() -> Result<Self, String> {
+        Ok(Self {
+            enigo: EnigoInputSink::new()?,
+            scancode: ScancodeInputSink,
+        })
+    }
+}
+
+impl InputSink for SelectedInputSink {
+    fn perform(&mut self, action: SyntheticAction) -> Result<(), String> {
+        match input_backend() {
+            InputBackend::Enigo => self.enigo.perform(action),
+            InputBackend::Scancode => self.scancode.perform(action),
+        }
+    }
+
+    /// Rebuild the backend in use, not always Enigo.
+    fn reinitialize(&mut self) -> Result<(), String> {
+        match input_backend() {
+            InputBackend::Enigo => self.enigo.reinitialize(),
+            InputBackend::Scancode => self.scancode.reinitialize(),
+        }
+    }
+}
+
+/// Backend that records each action with the time it was performed instead
+/// of sending it, so combo sequences can be asserted without a live game.
+#[cfg_attr(not(test), allow(dead_code))]
+#[derive(Clone, Default)]
+pub struct RecordingInputSink {
+    actions: Arc<Mutex<Vec<(SyntheticAction, Instant)>>>,
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+impl RecordingInputSink {
+    pub fn actions(&self) -> Vec<(SyntheticAction, Instant)> {
+        lock_or_recover(&self.actions).clone()
+    }
+
+    /// Keys from `KeyClick` actions, in press order.
+    pub fn key_clicks(&self) -> Vec<char> {
+        lock_or_recover(&self.actions)
+            .iter()
+            .filter_map(|(action, _)| match action {
+                SyntheticAction::KeyClick(key) => Some(*key),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl InputSink for RecordingInputSink {
+    fn perform(&mut self, action: SyntheticAction) -> Result<(), String> {
+        lock_or_recover(&self.actions).push((action, Instant::now()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    static THREAD_INPUT_SINK: std::cell::RefCell<Option<Box<dyn InputSink>>> =
+        std::cell::RefCell::new(None);
+}
+
+/// Route synthetic input from the current thread into `sink` instead of the
+/// worker until the returned guard is dropped.
+#[cfg(test)]
+pub(crate) fn install_thread_input_sink(sink: impl InputSink + 'static) -> ThreadInputSinkGuard {
+    THREAD_INPUT_SINK.with(|cell| *cell.borrow_mut() = Some(Box::new(sink)));
+    ThreadInputSinkGuard
+}
+
+#[cfg(test)]
+pub(crate) struct ThreadInputSinkGuard;
+
+#[cfg(test)]
+impl Drop for ThreadInputSinkGuard {
+    fn drop(&mut self) {
+        THREAD_INPUT_SINK.with(|cell| *cell.borrow_mut() = None);
+    }
+}
+
+#[cfg(test)]
+fn perform_on_thread_sink(action: SyntheticAction) -> bool {
+    THREAD_INPUT_SINK.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(sink) => {
+            let _ = perform_action(sink.as_mut(), action);
+            true
+        }
+        None => false,
+    })
+}
+
+/// Turn safe mode on or off (`[safe_mode] enabled`).
+pub fn set_safe_mode(enabled: bool) {
+    if SAFE_MODE.swap(enabled, Ordering::SeqCst) != enabled {
+        if enabled {
+            warn!("🛡 Safe mode ON: synthetic input is logged but not sent");
+        } else {
+            info!("Safe mode OFF: synthetic input re-enabled");
+        }
+    }
+}
+
+pub fn safe_mode_enabled() -> bool {
+    SAFE_MODE.load(Ordering::SeqCst)
+}
+
+/// Turn practice mode on or off (`[practice_mode] enabled`).
+pub fn set_practice_mode(enabled: bool) {
+    if PRACTICE_MODE.swap(enabled, Ordering::SeqCst) != enabled {
+        if enabled {
+            warn!("🎯 Practice mode ON: actions are explained in the activity feed, not sent");
+        } else {
+            info!("Practice mode OFF: synthetic input re-enabled");
+        }
+    }
+}
+
+pub fn practice_mode_enabled() -> bool {
+    PRACTICE_MODE.load(Ordering::SeqCst)
+}
+
+/// Pick the input backend (`[simulation] backend`). Scancodes need
+/// `SendInput`, so off Windows the request is logged and Enigo stays.
+pub fn set_input_backend(backend: InputBackend) {
+    let scancode = backend == InputBackend::Scancode && cfg!(windows);
+    if backend == InputBackend::Scancode && !scancode {
+        warn!("Scancode input backend is Windows-only; keeping enigo");
+    }
+    if SCANCODE_BACKEND.swap(scancode, Ordering::SeqCst) != scancode {
+        info!("Input backend: {:?}", input_backend());
+    }
+}
+
+pub fn input_backend() -> InputBackend {
+    if SCANCODE_BACKEND.load(Ordering::SeqCst) {
+        InputBackend::Scancode
+    } else {
+        InputBackend::Enigo
+    }
+}
+
+/// Only send input while Dota 2 is the foreground window
+/// (`[input] only_input_when_dota_focused`).
+pub fn set_only_when_dota_focused(enabled: bool) {
+    ONLY_WHEN_DOTA_FOCUSED.store(enabled, Ordering::SeqCst);
+}
+
+/// Keep at least `interval_ms` between two synthetic presses or clicks
+/// (`[input] min_action_interval_ms`). 0 turns the limit off.
+pub fn set_min_action_interval_ms(interval_ms: u64) {
+    MIN_ACTION_INTERVAL_MS.store(interval_ms, Ordering::SeqCst);
+}
+
+/// Push the input switches from `settings` (`[safe_mode]`, `[practice_mode]`,
+/// `[input]`, `[simulation] backend`) into the input layer. Called at startup
+/// and whenever the config is saved, switched or imported.
+pub fn apply_input_settings(settings: &crate::config::Settings) {
+    set_safe_mode(settings.safe_mode.enabled);
+    set_practice_mode(settings.practice_mode.enabled);
+    set_only_when_dota_focused(settings.input.only_input_when_dota_focused);
+    set_min_action_interval_ms(settings.input.min_action_interval_ms);
+    set_input_backend(settings.simulation.backend);
+}
+
+/// Whether `action` starts a new press and so waits out the rate limit.
+/// Releases and modifier holds belong to a press already under way, so they
+/// run immediately. Armlet chords are urgent and hold their modifier only
+/// while they run, so they are never delayed either.
+fn is_rate_limited(action: SyntheticAction) -> bool {
+    matches!(
+        action,
+        SyntheticAction::KeyClick(_)
+            | SyntheticAction::KeyDown(_)
+            | SyntheticAction::RightClick
+            | SyntheticAction::LeftClick
+    )
+}
+
+/// How long the worker sleeps before `action` so it lands at least
+/// `interval` after the previous rate-limited action. Nothing waits while a
+/// modifier is held down (`modifier_held`), so a click inside an ALT-facing
+/// sequence never stretches how long ALT stays down.
+fn rate_limit_wait(
+    action: SyntheticAction,
+    last_limited: Option<Instant>,
+    interval: Duration,
+    now: Instant,
+    modifier_held: bool,
+) -> Duration {
+    match last_limited {
+        Some(last) if is_rate_limited(action) && !modifier_held => {
+            (last + interval).saturating_duration_since(now)
+        }
+        _ => Duration::ZERO,
+    }
+}
+
+/// Whether the focus gate drops `action`. Releases always go through so an
+/// ALT or key held before an alt-tab is never left stuck down.
+fn focus_gate_blocks(
+    action: SyntheticAction,
+    gate_enabled: bool,
+    dota_focused: impl FnOnce() -> bool,
+) -> bool {
+    let is_release = matches!(
+        action,
+        SyntheticAction::KeyUp(_) | SyntheticAction::ModifierUp(_)
+    );
+    gate_enabled && !is_release && !dota_focused()
+}
+
 /// Press a single key (sets SIMULATING_KEYS flag to prevent re-interception)
 pub fn press_key(key_char: char) {
     enqueue_command_and_wait(press_key_command(key_char), SyntheticInputPriority::Normal);
@@ -117,13 +425,13 @@ pub fn key_up(key_char: char) {
     enqueue_command_and_wait(key_up_command(key_char), SyntheticInputPriority::Normal);
 }
 
-/// Perform a right mouse click
-pub fn mouse_click() {
-    enqueue_command_and_wait(mouse_click_command(), SyntheticInputPriority::Normal);
+/// Right mouse click: Dota's move/attack order at the cursor.
+pub fn right_click() {
+    enqueue_command_and_wait(right_click_command(), SyntheticInputPriority::Normal);
 }
 
-/// Perform a left mouse click
-#[allow(dead_code)]
+/// Left mouse click: selects units or confirms a targeted cast. Not a
+/// move/attack order; use [`right_click`] for that.
 pub fn left_click() {
     enqueue_command_and_wait(left_click_command(), SyntheticInputPriority::Normal);
 }
@@ -176,7 +484,7 @@ fn key_up_command(key_char: char) -> SyntheticInputCommand {
     }
 }
 
-fn mouse_click_command() -> SyntheticInputCommand {
+fn right_click_command() -> SyntheticInputCommand {
     SyntheticInputCommand {
         action: SyntheticAction::RightClick,
         guard_behavior: GuardBehavior::Pulse {
@@ -185,7 +493,6 @@ fn mouse_click_command() -> SyntheticInputCommand {
     }
 }
 
-#[allow(dead_code)]
 fn left_click_command() -> SyntheticInputCommand {
     SyntheticInputCommand {
         action: SyntheticAction::LeftClick,
@@ -230,7 +537,49 @@ fn armlet_chord_command(slot_key: char, modifier: ModifierKey) -> SyntheticInput
     }
 }
 
+/// Activity feed line for an input practice mode drops, so presses from
+/// hero scripts show up next to the explained survivability actions.
+/// Releases and modifier holds only frame a press and are left out.
+fn practice_press_message(action: SyntheticAction) -> Option<String> {
+    match action {
+        SyntheticAction::KeyClick(key) | SyntheticAction::KeyDown(key) => {
+            Some(format!("Would press '{}'", key))
+        }
+        SyntheticAction::RightClick => Some("Would right-click".to_string()),
+        SyntheticAction::LeftClick => Some("Would left-click".to_string()),
+        SyntheticAction::ArmletChord { slot_key, .. } => {
+            Some(format!("Would double-tap armlet '{}'", slot_key))
+        }
+        SyntheticAction::KeyUp(_)
+        | SyntheticAction::ModifierDown(_)
+        | SyntheticAction::ModifierUp(_) => None,
+    }
+}
+
 fn enqueue_command_and_wait(command: SyntheticInputCommand, priority: SyntheticInputPriority) {
+    #[cfg(test)]
+    if perform_on_thread_sink(command.action) {
+        return;
+    }
+
+    if safe_mode_enabled() {
+        info!("🛡 Safe mode: skipped {:?}", command.action);
+        return;
+    }
+    if practice_mode_enabled() {
+        debug!("🎯 Practice mode: skipped {:?}", command.action);
+        if let Some(message) = practice_press_message(command.action) {
+            push_activity(ActivityCategory::Action, message);
+        }
+        return;
+    }
+
+    let gate_enabled = ONLY_WHEN_DOTA_FOCUSED.load(Ordering::SeqCst);
+    if focus_gate_blocks(command.action, gate_enabled, dota_is_focused) {
+        debug!("Dota 2 not focused: skipped {:?}", command.action);
+        return;
+    }
+
     let (completion_tx, completion_rx) = mpsc::channel();
     let action = command.action;
     let job = SyntheticInputJob {
@@ -256,7 +605,7 @@ fn enqueue_with_sender(
     job: SyntheticInputJob,
     action: SyntheticAction,
 ) -> bool {
-    let mut state = metrics_store().lock().unwrap();
+    let mut state = lock_or_recover(metrics_store());
     let checkpoint = EnqueueMetricsCheckpoint {
         queued_total: state.queued_total,
         current_depth: state.current_depth,
@@ -315,6 +664,66 @@ fn metrics_snapshot(state: &SyntheticInputMetricsState) -> SyntheticInputMetrics
         dropped_total: state.dropped_total,
         current_depth: state.current_depth,
         peak_depth: state.peak_depth,
+        failed_total: state.failed_total,
+        consecutive_failures: state.consecutive_failures,
+        reinit_total: state.reinit_total,
+    }
+}
+
+/// Record the outcome of one performed command. Returns whether the sink
+/// should be rebuilt: every [`REINIT_AFTER_FAILURES`] failures in a row.
+fn record_action_result(
+    state: &mut SyntheticInputMetricsState,
+    action: SyntheticAction,
+    result: &Result<(), String>,
+) -> bool {
+    match result {
+        Ok(()) => {
+            if state.consecutive_failures > 0 {
+                info!(
+                    "Synthetic input recovered after {} failed actions",
+                    state.consecutive_failures
+                );
+            }
+            state.consecutive_failures = 0;
+            false
+        }
+        Err(e) => {
+            state.failed_total += 1;
+            state.consecutive_failures += 1;
+            warn!(
+                "Synthetic input {:?} failed ({} in a row): {}",
+                action, state.consecutive_failures, e
+            );
+            state.consecutive_failures % REINIT_AFTER_FAILURES == 0
+        }
+    }
+}
+
+fn reinitialize_sink(sink: &mut dyn InputSink) {
+    let consecutive_failures = lock_or_recover(metrics_store()).consecutive_failures;
+    warn!(
+        "Synthetic input failed {} times in a row; reinitializing the input backend",
+        consecutive_failures
+    );
+    match sink.reinitialize() {
+        Ok(()) => {
+            lock_or_recover(metrics_store()).reinit_total += 1;
+            push_activity(
+                ActivityCategory::Error,
+                format!(
+                    "Input backend failed {} times in a row and was reinitialized",
+                    consecutive_failures
+                ),
+            );
+        }
+        Err(e) => {
+            error!("Failed to reinitialize the input backend: {}", e);
+            push_activity(
+                ActivityCategory::Error,
+                format!("Input backend is failing and could not be reinitialized: {}", e),
+            );
+        }
     }
 }
 
@@ -324,9 +733,7 @@ fn metrics_store() -> &'static Mutex<SyntheticInputMetricsState> {
 
 /// Public snapshot access to synthetic input backlog metrics
 pub fn synthetic_input_metrics() -> SyntheticInputMetricsSnapshot {
-    let store = metrics_store();
-    let state = store.lock().unwrap();
-    metrics_snapshot(&state)
+    metrics_snapshot(&lock_or_recover(metrics_store()))
 }
 
 fn worker_sender() -> &'static Sender<SyntheticInputJob> {
@@ -335,25 +742,57 @@ fn worker_sender() -> &'static Sender<SyntheticInputJob> {
 
 fn spawn_worker() -> Sender<SyntheticInputJob> {
     let (tx, rx) = mpsc::channel();
-    let enigo = Enigo::new(&Settings::default()).expect("Failed to initialize Enigo");
+    let sink = SelectedInputSink::new().expect("Failed to initialize Enigo");
 
     thread::Builder::new()
         .name("synthetic-input-worker".to_string())
-        .spawn(move || run_worker(rx, enigo))
+        .spawn(move || run_worker(rx, Box::new(sink)))
         .expect("Failed to spawn synthetic input worker");
 
     tx
 }
 
-fn run_worker(rx: Receiver<SyntheticInputJob>, mut enigo: Enigo) {
+fn run_worker(rx: Receiver<SyntheticInputJob>, mut sink: Box<dyn InputSink>) {
     let mut guard_state = WorkerGuardState::default();
     let mut armlet_backlog = VecDeque::new();
     let mut normal_backlog = VecDeque::new();
+    let mut last_limited: Option<Instant> = None;
 
     while let Some(job) = next_job(&rx, &mut armlet_backlog, &mut normal_backlog) {
-        execute_command(&mut enigo, job.command, &mut guard_state);
+        // Still complete the job so callers waiting on it do not hang.
+        if !crate::state::shutdown::is_shutting_down() {
+            let interval = Duration::from_millis(MIN_ACTION_INTERVAL_MS.load(Ordering::SeqCst));
+            let wait = rate_limit_wait(
+                job.command.action,
+                last_limited,
+                interval,
+                Instant::now(),
+                guard_state.modifier_guard_held,
+            );
+            if !wait.is_zero() {
+                debug!(
+                    "Rate limit: delaying {:?} by {}ms",
+                    job.command.action,
+                    wait.as_millis()
+                );
+                thread::sleep(wait);
+            }
+            if is_rate_limited(job.command.action) {
+                last_limited = Some(Instant::now());
+            }
+
+            let result = execute_command(sink.as_mut(), job.command, &mut guard_state);
+            let reinit = record_action_result(
+                &mut lock_or_recover(metrics_store()),
+                job.command.action,
+                &result,
+            );
+            if reinit {
+                reinitialize_sink(sink.as_mut());
+            }
+        }
         
-        let mut state = metrics_store().lock().unwrap();
+        let mut state = lock_or_recover(metrics_store());
         record_completion(&mut state);
         drop(state);
         
@@ -416,10 +855,10 @@ fn dequeue_next_job(
 }
 
 fn execute_command(
-    enigo: &mut Enigo,
+    sink: &mut dyn InputSink,
     command: SyntheticInputCommand,
     guard_state: &mut WorkerGuardState,
-) {
+) -> Result<(), String> {
     let action = command.action;
     let guard_plan = plan_guard_execution(guard_state, command.guard_behavior);
     let started = Instant::now();
@@ -428,7 +867,7 @@ fn execute_command(
         SIMULATING_KEYS.store(value, Ordering::SeqCst);
     }
 
-    perform_action(enigo, action);
+    let result = perform_action(sink, action);
 
     if let Some(delay_ms) = guard_plan.post_action_delay_ms {
         thread::sleep(Duration::from_millis(delay_ms));
@@ -444,6 +883,7 @@ fn execute_command(
         command.guard_behavior,
         started.elapsed().as_millis()
     );
+    result
 }
 
 fn plan_guard_execution(
@@ -480,7 +920,10 @@ fn plan_guard_execution(
     }
 }
 
-fn perform_action(enigo: &mut Enigo, action: SyntheticAction) {
+/// Perform `action`, expanding chords. Every chord step is attempted so a
+/// modifier is still released after an earlier step fails; the first error
+/// is returned.
+fn perform_action(sink: &mut dyn InputSink, action: SyntheticAction) -> Result<(), String> {
     match action {
         action @ SyntheticAction::KeyClick(_)
         | action @ SyntheticAction::KeyDown(_)
@@ -488,7 +931,7 @@ fn perform_action(enigo: &mut Enigo, action: SyntheticAction) {
         | action @ SyntheticAction::RightClick
         | action @ SyntheticAction::LeftClick
         | action @ SyntheticAction::ModifierDown(_)
-        | action @ SyntheticAction::ModifierUp(_) => perform_single_action(enigo, action),
+        | action @ SyntheticAction::ModifierUp(_) => sink.perform(action),
         SyntheticAction::ArmletChord { slot_key, modifier } => {
             let started = Instant::now();
             let steps = armlet_chord_steps(slot_key, modifier);
@@ -497,9 +940,13 @@ fn perform_action(enigo: &mut Enigo, action: SyntheticAction) {
                 slot_key, modifier, steps
             );
 
+            let mut result = Ok(());
             for (index, step) in steps.into_iter().enumerate() {
                 let step_started = Instant::now();
-                perform_single_action(enigo, step);
+                let step_result = sink.perform(step);
+                if result.is_ok() {
+                    result = step_result;
+                }
                 debug!(
                     "Synthetic armlet chord step {}/{} {:?} completed at +{}ms (step {}ms)",
                     index + 1,
@@ -509,49 +956,37 @@ fn perform_action(enigo: &mut Enigo, action: SyntheticAction) {
                     step_started.elapsed().as_millis()
                 );
             }
+            result
         }
     }
 }
 
-fn perform_single_action(enigo: &mut Enigo, action: SyntheticAction) {
+fn perform_single_action(enigo: &mut Enigo, action: SyntheticAction) -> Result<(), String> {
     match action {
-        SyntheticAction::KeyClick(key_char) => {
-            if let Err(e) = enigo.key(Key::Unicode(key_char), Direction::Click) {
-                warn!("Failed to press key '{}': {}", key_char, e);
-            }
-        }
-        SyntheticAction::KeyDown(key_char) => {
-            if let Err(e) = enigo.key(Key::Unicode(key_char), Direction::Press) {
-                warn!("Failed to press down key '{}': {}", key_char, e);
-            }
-        }
-        SyntheticAction::KeyUp(key_char) => {
-            if let Err(e) = enigo.key(Key::Unicode(key_char), Direction::Release) {
-                warn!("Failed to release key '{}': {}", key_char, e);
-            }
-        }
-        SyntheticAction::RightClick => {
-            if let Err(e) = enigo.button(Button::Right, Direction::Click) {
-                warn!("Failed to perform right click: {}", e);
-            }
-        }
-        SyntheticAction::LeftClick => {
-            if let Err(e) = enigo.button(Button::Left, Direction::Click) {
-                warn!("Failed to perform left click: {}", e);
-            }
-        }
-        SyntheticAction::ModifierDown(modifier) => {
-            if let Err(e) = enigo.key(enigo_modifier_key(modifier), Direction::Press) {
-                warn!("Failed to press {:?} down: {}", modifier, e);
-            }
-        }
-        SyntheticAction::ModifierUp(modifier) => {
-            if let Err(e) = enigo.key(enigo_modifier_key(modifier), Direction::Release) {
-                warn!("Failed to release {:?}: {}", modifier, e);
-            }
-        }
+        SyntheticAction::KeyClick(key_char) => enigo
+            .key(Key::Unicode(key_char), Direction::Click)
+            .map_err(|e| format!("Failed to press key '{}': {}", key_char, e)),
+        SyntheticAction::KeyDown(key_char) => enigo
+            .key(Key::Unicode(key_char), Direction::Press)
+            .map_err(|e| format!("Failed to press down key '{}': {}", key_char, e)),
+        SyntheticAction::KeyUp(key_char) => enigo
+            .key(Key::Unicode(key_char), Direction::Release)
+            .map_err(|e| format!("Failed to release key '{}': {}", key_char, e)),
+        SyntheticAction::RightClick => enigo
+            .button(Button::Right, Direction::Click)
+            .map_err(|e| format!("Failed to perform right click: {}", e)),
+        SyntheticAction::LeftClick => enigo
+            .button(Button::Left, Direction::Click)
+            .map_err(|e| format!("Failed to perform left click: {}", e)),
+        SyntheticAction::ModifierDown(modifier) => enigo
+            .key(enigo_modifier_key(modifier), Direction::Press)
+            .map_err(|e| format!("Failed to press {:?} down: {}", modifier, e)),
+        SyntheticAction::ModifierUp(modifier) => enigo
+            .key(enigo_modifier_key(modifier), Direction::Release)
+            .map_err(|e| format!("Failed to release {:?}: {}", modifier, e)),
         SyntheticAction::ArmletChord { .. } => {
             warn!("Armlet chord should be expanded before single-action execution");
+            Ok(())
         }
     }
 }
@@ -635,6 +1070,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn practice_mode_reports_presses_but_not_releases() {
+        assert_eq!(
+            practice_press_message(SyntheticAction::KeyClick('q')).as_deref(),
+            Some("Would press 'q'")
+        );
+        assert_eq!(
+            practice_press_message(SyntheticAction::RightClick).as_deref(),
+            Some("Would right-click")
+        );
+        assert_eq!(practice_press_message(SyntheticAction::KeyUp('q')), None);
+        assert_eq!(
+            practice_press_message(SyntheticAction::ModifierDown(ModifierKey::Alt)),
+            None
+        );
+    }
+
+    #[test]
+    fn focus_gate_drops_presses_but_never_releases_while_unfocused() {
+        let unfocused = || false;
+        assert!(focus_gate_blocks(SyntheticAction::KeyClick('q'), true, unfocused));
+        assert!(focus_gate_blocks(
+            SyntheticAction::ModifierDown(ModifierKey::Alt),
+            true,
+            unfocused
+        ));
+        assert!(!focus_gate_blocks(
+            SyntheticAction::ModifierUp(ModifierKey::Alt),
+            true,
+            unfocused
+        ));
+        assert!(!focus_gate_blocks(SyntheticAction::KeyUp('q'), true, unfocused));
+        assert!(!focus_gate_blocks(SyntheticAction::KeyClick('q'), true, || true));
+        assert!(!focus_gate_blocks(SyntheticAction::KeyClick('q'), false, unfocused));
+    }
+
+    #[test]
+    fn rate_limit_spaces_presses_but_not_releases() {
+        let last = Instant::now();
+        let interval = Duration::from_millis(40);
+        let soon = last + Duration::from_millis(15);
+        let wait = |action, last_limited, interval, now| {
+            rate_limit_wait(action, last_limited, interval, now, false)
+        };
+
+        assert_eq!(
+            wait(SyntheticAction::KeyClick('q'), Some(last), interval, soon),
+            Duration::from_millis(25)
+        );
+        assert_eq!(
+            wait(
+                SyntheticAction::RightClick,
+                Some(last),
+                interval,
+                last + interval
+            ),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait(SyntheticAction::KeyUp('q'), Some(last), interval, soon),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait(
+                SyntheticAction::ModifierDown(ModifierKey::Alt),
+                Some(last),
+                interval,
+                soon
+            ),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait(
+                SyntheticAction::ArmletChord {
+                    slot_key: 'x',
+                    modifier: ModifierKey::Alt
+                },
+                Some(last),
+                interval,
+                soon
+            ),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait(SyntheticAction::KeyClick('q'), None, interval, soon),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait(
+                SyntheticAction::KeyClick('q'),
+                Some(last),
+                Duration::ZERO,
+                soon
+            ),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn rate_limit_never_delays_input_while_a_modifier_is_held() {
+        let last = Instant::now();
+        let interval = Duration::from_millis(40);
+        let soon = last + Duration::from_millis(15);
+
+        assert_eq!(
+            rate_limit_wait(
+                SyntheticAction::RightClick,
+                Some(last),
+                interval,
+                soon,
+                true
+            ),
+            Duration::ZERO
+        );
+        assert_eq!(
+            rate_limit_wait(
+                SyntheticAction::RightClick,
+                Some(last),
+                interval,
+                soon,
+                false
+            ),
+            Duration::from_millis(25)
+        );
+    }
+
     #[test]
     fn commands_with_post_action_delay_are_labeled_correctly() {
         assert_eq!(
@@ -644,7 +1205,7 @@ mod tests {
             }
         );
         assert_eq!(
-            mouse_click_command().guard_behavior,
+            right_click_command().guard_behavior,
             GuardBehavior::Pulse {
                 delay_ms: POST_ACTION_GUARD_DELAY_MS,
             }
@@ -686,7 +1247,7 @@ mod tests {
         assert!(guard_state.modifier_guard_held);
 
         let click_plan =
-            plan_guard_execution(&mut guard_state, mouse_click_command().guard_behavior);
+            plan_guard_execution(&mut guard_state, right_click_command().guard_behavior);
         assert_eq!(
             click_plan,
             GuardExecutionPlan {
@@ -727,7 +1288,7 @@ mod tests {
         ));
         assert!(enqueue_with_sender(
             &tx,
-            test_job(mouse_click_command()),
+            test_job(right_click_command()),
             SyntheticAction::RightClick
         ));
 
@@ -737,7 +1298,7 @@ mod tests {
             vec![
                 press_key_command('q'),
                 alt_down_command(),
-                mouse_click_command()
+                right_click_command()
             ]
         );
     }
@@ -817,6 +1378,7 @@ mod tests {
                 dropped_total: 0,
                 current_depth: 1,
                 peak_depth: 1,
+                ..SyntheticInputMetricsSnapshot::default()
             }
         );
 
@@ -830,6 +1392,7 @@ mod tests {
                 dropped_total: 0,
                 current_depth: 2,
                 peak_depth: 2,
+                ..SyntheticInputMetricsSnapshot::default()
             }
         );
     }
@@ -842,6 +1405,7 @@ mod tests {
             dropped_total: 0,
             current_depth: 2,
             peak_depth: 2,
+            ..SyntheticInputMetricsState::default()
         };
 
         record_completion(&mut metrics);
@@ -854,6 +1418,7 @@ mod tests {
                 dropped_total: 0,
                 current_depth: 1,
                 peak_depth: 2,
+                ..SyntheticInputMetricsSnapshot::default()
             }
         );
 
@@ -868,6 +1433,7 @@ mod tests {
                 dropped_total: 0,
                 current_depth: 0,
                 peak_depth: 2,
+                ..SyntheticInputMetricsSnapshot::default()
             }
         );
     }
@@ -880,6 +1446,7 @@ mod tests {
             dropped_total: 1,
             current_depth: 2,
             peak_depth: 5,
+            ..SyntheticInputMetricsState::default()
         };
 
         record_enqueue_failure(&mut metrics);
@@ -892,6 +1459,7 @@ mod tests {
                 dropped_total: 2,
                 current_depth: 2,
                 peak_depth: 5,
+                ..SyntheticInputMetricsSnapshot::default()
             }
         );
     }
@@ -905,7 +1473,7 @@ mod tests {
 
         assert!(!enqueue_with_sender(
             &tx,
-            test_job(mouse_click_command()),
+            test_job(right_click_command()),
             SyntheticAction::RightClick
         ));
 
@@ -918,6 +1486,56 @@ mod tests {
         assert_eq!(after.dropped_total, before.dropped_total + 1);
     }
 
+    /// Fails every action until reinitialized.
+    struct FlakySink {
+        broken: bool,
+        reinits: u32,
+    }
+
+    impl InputSink for FlakySink {
+        fn perform(&mut self, _action: SyntheticAction) -> Result<(), String> {
+            if self.broken {
+                Err("backend gone".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        fn reinitialize(&mut self) -> Result<(), String> {
+            self.broken = false;
+            self.reinits += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn repeated_failures_rebuild_the_sink_and_success_resets_the_streak() {
+        let mut metrics = SyntheticInputMetricsState::default();
+        let mut sink = FlakySink {
+            broken: true,
+            reinits: 0,
+        };
+        let action = SyntheticAction::KeyClick('q');
+
+        let mut reinit_requests = 0;
+        for _ in 0..REINIT_AFTER_FAILURES {
+            let result = perform_action(&mut sink, action);
+            if record_action_result(&mut metrics, action, &result) {
+                reinit_requests += 1;
+                sink.reinitialize().unwrap();
+            }
+        }
+        assert_eq!(reinit_requests, 1);
+        assert_eq!(sink.reinits, 1);
+        assert_eq!(metrics.failed_total, u64::from(REINIT_AFTER_FAILURES));
+        assert_eq!(metrics.consecutive_failures, REINIT_AFTER_FAILURES);
+
+        let result = perform_action(&mut sink, action);
+        assert!(!record_action_result(&mut metrics, action, &result));
+        assert_eq!(metrics.consecutive_failures, 0);
+        assert_eq!(metrics.failed_total, u64::from(REINIT_AFTER_FAILURES));
+    }
+
     #[test]
     fn snapshot_copies_all_metric_fields() {
         let state = SyntheticInputMetricsState {
@@ -926,6 +1544,7 @@ mod tests {
             dropped_total: 1,
             current_depth: 2,
             peak_depth: 5,
+            ..SyntheticInputMetricsState::default()
         };
 
         let snapshot = metrics_snapshot(&state);
@@ -948,7 +1567,7 @@ mod tests {
             &mut normal_backlog,
         );
         queue_job(
-            test_job(mouse_click_command()),
+            test_job(right_click_command()),
             &mut armlet_backlog,
             &mut normal_backlog,
         );
@@ -974,6 +1593,33 @@ mod tests {
 
         let third = dequeue_next_job(&mut armlet_backlog, &mut normal_backlog)
             .expect("second normal job should remain queued");
-        assert_eq!(third.command, mouse_click_command());
+        assert_eq!(third.command, right_click_command());
+    }
+
+    #[test]
+    fn thread_sink_records_expanded_actions_without_the_worker() {
+        let recorder = RecordingInputSink::default();
+        let guard = install_thread_input_sink(recorder.clone());
+
+        press_key('q');
+        armlet_chord('x', ModifierKey::Alt);
+        drop(guard);
+
+        let actions: Vec<SyntheticAction> = recorder
+            .actions()
+            .into_iter()
+            .map(|(action, _)| action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                SyntheticAction::KeyClick('q'),
+                SyntheticAction::KeyClick('x'),
+                SyntheticAction::ModifierDown(ModifierKey::Alt),
+                SyntheticAction::KeyClick('x'),
+                SyntheticAction::ModifierUp(ModifierKey::Alt),
+            ]
+        );
+        assert_eq!(recorder.key_clicks(), vec!['q', 'x', 'x']);
     }
 }
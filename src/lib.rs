@@ -1,5 +1,6 @@
 pub mod actions;
 pub mod config;
+pub mod diagnostics;
 pub mod gsi;
 pub mod input;
 pub mod models;
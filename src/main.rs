@@ -2,6 +2,7 @@
 
 mod actions;
 mod config;
+mod diagnostics;
 mod gsi;
 mod input;
 mod models;
@@ -15,33 +16,42 @@ use crate::actions::ActionDispatcher;
 use crate::config::Settings;
 use crate::gsi::start_gsi_server;
 use crate::input::keyboard::{start_keyboard_listener, KeyboardSnapshot};
+use crate::state::poison::lock_or_recover;
 use crate::state::{AppState, UpdateCheckState};
 
 use crate::update::{check_for_update, UpdateCheckResult};
 use std::sync::{Arc, Mutex, RwLock};
-use tracing::info;
-use tracing_subscriber;
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() {
-    // Load settings first to get log level
-    let settings = Arc::new(Mutex::new(Settings::load()));
+    if std::env::args().any(|arg| arg == diagnostics::DOCTOR_FLAG) {
+        std::process::exit(diagnostics::run_cli());
+    }
 
-    // Initialize logging with config level or environment variable
-    let log_level = std::env::var("RUST_LOG")
-        .unwrap_or_else(|_| settings.lock().unwrap().logging.level.clone());
-    tracing_subscriber::fmt().with_env_filter(log_level).init();
+    // Initialize logging with config level or environment variable before
+    // loading settings, so load warnings reach the log file
+    let logging = Settings::load_logging();
+    let _log_guard = diagnostics::logging::init(&logging);
+    diagnostics::panic_report::install(&logging);
+
+    let settings = Arc::new(Mutex::new(Settings::load()));
 
     info!("Starting Dota 2 Script Automation...");
-    info!("Server port: {}", settings.lock().unwrap().server.port);
+    diagnostics::elevation::log_elevation_status();
+    info!(
+        "Server address: {}",
+        lock_or_recover(&settings).server.socket_addr()
+    );
+    crate::input::simulation::apply_input_settings(&lock_or_recover(&settings));
 
     // Initialize shared state
     let app_state = AppState::new();
 
     // Build the initial keyboard snapshot before starting the listener
     let initial_snapshot = {
-        let settings_guard = settings.lock().unwrap();
-        let state_guard = app_state.lock().unwrap();
+        let settings_guard = lock_or_recover(&settings);
+        let state_guard = lock_or_recover(&app_state);
         Arc::new(RwLock::new(KeyboardSnapshot::from_runtime(
             &settings_guard,
             &state_guard,
@@ -59,42 +69,48 @@ async fn main() {
     let hotkey_rx = start_keyboard_listener(keyboard_config);
 
     // Start GSI server in background
-    let port = settings.lock().unwrap().server.port;
+    let gsi_addr = lock_or_recover(&settings).server.socket_addr();
     let app_state_clone = app_state.clone();
     let dispatcher_clone = dispatcher.clone();
     let settings_clone = settings.clone();
-    tokio::spawn(async move {
-        start_gsi_server(port, app_state_clone, dispatcher_clone, settings_clone).await;
+    let gsi_server = tokio::spawn(async move {
+        start_gsi_server(gsi_addr, app_state_clone, dispatcher_clone, settings_clone).await;
     });
 
     // Start update check in background (if enabled)
     {
-        let settings_guard = settings.lock().unwrap();
+        let settings_guard = lock_or_recover(&settings);
         let check_on_startup = settings_guard.updates.check_on_startup;
         let include_prereleases = settings_guard.updates.include_prereleases;
         drop(settings_guard);
 
         if check_on_startup {
-            let update_state = app_state.lock().unwrap().update_state.clone();
-            *update_state.lock().unwrap() = UpdateCheckState::Checking;
+            let update_state = lock_or_recover(&app_state).update_state.clone();
+            *lock_or_recover(&update_state) = UpdateCheckState::Checking;
 
             tokio::task::spawn_blocking(move || match check_for_update(include_prereleases) {
                 UpdateCheckResult::Available(info) => {
-                    *update_state.lock().unwrap() = UpdateCheckState::Available {
+                    *lock_or_recover(&update_state) = UpdateCheckState::Available {
                         version: info.version,
                         release_notes: info.release_notes,
                     };
                 }
                 UpdateCheckResult::UpToDate => {
-                    *update_state.lock().unwrap() = UpdateCheckState::UpToDate;
+                    *lock_or_recover(&update_state) = UpdateCheckState::UpToDate;
                 }
-                UpdateCheckResult::Error(msg) => {
-                    *update_state.lock().unwrap() = UpdateCheckState::Error(msg);
+                UpdateCheckResult::Error(e) => {
+                    *lock_or_recover(&update_state) = UpdateCheckState::failed(&e);
                 }
             });
         }
     }
 
+    // Re-check periodically when updates.check_interval_minutes > 0
+    crate::update::start_periodic_update_check(
+        settings.clone(),
+        lock_or_recover(&app_state).update_state.clone(),
+    );
+
     let minimap_settings = settings.clone();
     let minimap_state = app_state.clone();
     std::thread::spawn(move || {
@@ -107,28 +123,15 @@ async fn main() {
     // Start hotkey event handler in background
     let app_state_clone2 = app_state.clone();
     let dispatcher_clone2 = dispatcher.clone();
+    let hotkey_settings = settings.clone();
     std::thread::spawn(move || {
         while let Ok(event) = hotkey_rx.recv() {
             match event {
                 input::keyboard::HotkeyEvent::ComboTrigger => {
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled {
-                        if let Some(hero_type) = state.selected_hero {
-                            let hero_name = match hero_type {
-                                state::HeroType::Huskar => models::Hero::Huskar.to_game_name(),
-                                state::HeroType::Largo => models::Hero::Largo.to_game_name(),
-                                state::HeroType::LegionCommander => {
-                                    models::Hero::LegionCommander.to_game_name()
-                                }
-                                state::HeroType::Meepo => models::Hero::Meepo.to_game_name(),
-                                state::HeroType::OutworldDestroyer => {
-                                    models::Hero::ObsidianDestroyer.to_game_name()
-                                }
-                                state::HeroType::ShadowFiend => {
-                                    models::Hero::Nevermore.to_game_name()
-                                }
-                                state::HeroType::Tiny => models::Hero::Tiny.to_game_name(),
-                            };
+                        if let Some(hero_type) = state.standalone_hero() {
+                            let hero_name = hero_type.to_game_name();
                             info!("Triggering standalone combo for {}", hero_name);
                             drop(state); // Release lock before calling dispatcher
                             dispatcher_clone2.dispatch_standalone_trigger(hero_name);
@@ -139,10 +142,21 @@ async fn main() {
                         info!("Standalone scripts disabled");
                     }
                 }
+                input::keyboard::HotkeyEvent::HeroComboTrigger(hero_type) => {
+                    let state = lock_or_recover(&app_state_clone2);
+                    if state.standalone_enabled {
+                        let hero_name = hero_type.to_game_name();
+                        info!("Triggering standalone combo for {}", hero_name);
+                        drop(state); // Release lock before calling dispatcher
+                        dispatcher_clone2.dispatch_standalone_trigger(hero_name);
+                    } else {
+                        info!("Standalone scripts disabled");
+                    }
+                }
                 input::keyboard::HotkeyEvent::MeepoFarmToggle => {
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled
-                        && state.selected_hero == Some(state::HeroType::Meepo)
+                        && state.standalone_hero() == Some(state::HeroType::Meepo)
                     {
                         drop(state);
                         if let Some(script) = dispatcher_clone2
@@ -165,10 +179,23 @@ async fn main() {
                         if armed { "armed" } else { "disarmed" }
                     );
                 }
+                input::keyboard::HotkeyEvent::ArmletEmergencyToggle => {
+                    let last_event = lock_or_recover(&app_state_clone2).last_event.clone();
+                    if let Some(event) = last_event {
+                        let settings = lock_or_recover(&hotkey_settings).clone();
+                        crate::actions::armlet::emergency_toggle(&event, &settings);
+                    } else {
+                        info!("Emergency armlet hotkey ignored: no GSI event received yet");
+                    }
+                }
+                input::keyboard::HotkeyEvent::ComboRecorderToggle => {
+                    let hero = lock_or_recover(&app_state_clone2).standalone_hero();
+                    input::combo_recorder::toggle(hero, &hotkey_settings);
+                }
                 input::keyboard::HotkeyEvent::LargoQ => {
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled
-                        && state.selected_hero == Some(state::HeroType::Largo)
+                        && state.standalone_hero() == Some(state::HeroType::Largo)
                     {
                         drop(state);
                         if let Some(script) = dispatcher_clone2
@@ -187,9 +214,9 @@ async fn main() {
                     }
                 }
                 input::keyboard::HotkeyEvent::LargoW => {
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled
-                        && state.selected_hero == Some(state::HeroType::Largo)
+                        && state.standalone_hero() == Some(state::HeroType::Largo)
                     {
                         drop(state);
                         if let Some(script) = dispatcher_clone2
@@ -208,9 +235,9 @@ async fn main() {
                     }
                 }
                 input::keyboard::HotkeyEvent::LargoE => {
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled
-                        && state.selected_hero == Some(state::HeroType::Largo)
+                        && state.standalone_hero() == Some(state::HeroType::Largo)
                     {
                         drop(state);
                         if let Some(script) = dispatcher_clone2
@@ -231,9 +258,9 @@ async fn main() {
                 input::keyboard::HotkeyEvent::LargoR => {
                     // R key pressed - immediately stop the beat loop to prevent stale key presses
                     // GSI will confirm the state change shortly after
-                    let state = app_state_clone2.lock().unwrap();
+                    let state = lock_or_recover(&app_state_clone2);
                     if state.standalone_enabled
-                        && state.selected_hero == Some(state::HeroType::Largo)
+                        && state.standalone_hero() == Some(state::HeroType::Largo)
                     {
                         drop(state);
                         if let Some(script) = dispatcher_clone2
@@ -253,12 +280,15 @@ async fn main() {
         }
     });
 
-    // Block the main thread so background tasks keep running
-    // (The Tauri binary in src-tauri/ provides the GUI)
+    // Keep running until Ctrl+C (the Tauri binary in src-tauri/ provides the GUI),
+    // then let the GSI server release its port before the process exits.
     info!("Backend running (headless mode). Use the Tauri app for the GUI.");
-    loop {
-        std::thread::park();
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        warn!("Failed to listen for Ctrl+C: {}", e);
+        std::future::pending::<()>().await;
     }
+    crate::state::shutdown::request_shutdown();
+    let _ = tokio::time::timeout(std::time::Duration::from_secs(2), gsi_server).await;
 }
 
 
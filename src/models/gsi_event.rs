@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Item {
@@ -12,6 +14,22 @@ pub struct Item {
     pub item_charges: Option<u32>,
 }
 
+impl Item {
+    /// Charge count under whichever key this GSI build sends (`charges` or
+    /// `item_charges`).
+    pub fn charge_count(&self) -> Option<u32> {
+        self.charges.or(self.item_charges)
+    }
+
+    /// Castable, off cooldown and, for charge-based items, holding at least
+    /// one charge. GSI can report an empty Magic Wand as castable.
+    pub fn is_ready(&self) -> bool {
+        self.can_cast.unwrap_or(false)
+            && self.cooldown.unwrap_or(0) == 0
+            && self.charge_count() != Some(0)
+    }
+}
+
 impl Default for Item {
     fn default() -> Self {
         Self {
@@ -27,7 +45,10 @@ impl Default for Item {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Inventory block of a GSI payload. Slots missing from the payload (some
+/// configs omit stash or teleport slots) default to the empty `Item`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
 pub struct Items {
     pub neutral0: Item,
     pub slot0: Item,
@@ -61,6 +82,20 @@ impl Items {
             ("neutral0", &self.neutral0),
         ]
     }
+
+    /// [`Items::all_slots`] plus the backpack (`slot6`-`slot8`).
+    ///
+    /// Backpack items cannot be cast directly and have no keybinding; use this
+    /// only to detect items that could be swapped up.
+    pub fn all_slots_including_backpack(&self) -> Vec<(&str, &Item)> {
+        let mut slots = self.all_slots();
+        slots.extend([
+            ("slot6", &self.slot6),
+            ("slot7", &self.slot7),
+            ("slot8", &self.slot8),
+        ]);
+        slots
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -97,48 +132,91 @@ impl Abilities {
             _ => None,
         }
     }
+
+    /// Find an ability by internal name in any of the six slots. Slot order
+    /// shifts with shard, scepter and facet abilities, so never assume one.
+    pub fn find_by_name(&self, name: &str) -> Option<&Ability> {
+        (0..6)
+            .filter_map(|index| self.get_by_index(index))
+            .find(|ability| ability.name == name)
+    }
 }
 
+/// Hero block of a GSI payload.
+///
+/// Only the fields the scripts cannot work without are required; the rest
+/// default when a Dota patch drops them, and fields this struct does not know
+/// about land in `extra` (see `gsi::handler::warn_on_schema_drift`).
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Hero {
+    #[serde(default)]
     pub aghanims_scepter: bool,
+    #[serde(default)]
     pub aghanims_shard: bool,
     pub alive: bool,
+    #[serde(default)]
     pub attributes_level: u32,
-    #[serde(rename = "break")]
+    #[serde(rename = "break", default)]
     pub is_break: bool,
+    #[serde(default)]
     pub buyback_cooldown: u32,
+    #[serde(default)]
     pub buyback_cost: u32,
+    #[serde(default)]
     pub disarmed: bool,
+    #[serde(default)]
     pub facet: u32,
+    #[serde(default)]
     pub has_debuff: bool,
     pub health: u32,
     pub health_percent: u32,
+    #[serde(default)]
     pub hexed: bool,
+    #[serde(default)]
     pub id: u32,
+    #[serde(default)]
     pub level: u32,
+    #[serde(default)]
     pub magicimmune: bool,
     pub mana: u32,
     pub mana_percent: u32,
     pub max_health: u32,
     pub max_mana: u32,
+    #[serde(default)]
     pub muted: bool,
     pub name: String,
+    #[serde(default)]
     pub respawn_seconds: u32,
+    #[serde(default)]
     pub silenced: bool,
+    #[serde(default)]
     pub smoked: bool,
+    #[serde(default)]
     pub stunned: bool,
+    #[serde(default)]
     pub talent_1: bool,
+    #[serde(default)]
     pub talent_2: bool,
+    #[serde(default)]
     pub talent_3: bool,
+    #[serde(default)]
     pub talent_4: bool,
+    #[serde(default)]
     pub talent_5: bool,
+    #[serde(default)]
     pub talent_6: bool,
+    #[serde(default)]
     pub talent_7: bool,
+    #[serde(default)]
     pub talent_8: bool,
+    #[serde(default)]
     pub xp: u32,
+    #[serde(default)]
     pub xpos: i32,
+    #[serde(default)]
     pub ypos: i32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 impl Hero {
@@ -149,11 +227,54 @@ impl Hero {
     pub fn is_stunned(&self) -> bool {
         self.stunned
     }
+
+    /// Break is on: passives such as Berserker's Blood or Bristleback's
+    /// damage reduction do nothing.
+    pub fn is_broken(&self) -> bool {
+        self.is_break
+    }
+
+    /// Talent picks in GSI order: slots 1-2 are the level 10 pair, 3-4
+    /// level 15, 5-6 level 20 and 7-8 level 25.
+    pub fn talents(&self) -> [bool; 8] {
+        [
+            self.talent_1,
+            self.talent_2,
+            self.talent_3,
+            self.talent_4,
+            self.talent_5,
+            self.talent_6,
+            self.talent_7,
+            self.talent_8,
+        ]
+    }
+
+    /// 1-based slots of the talents picked so far.
+    pub fn picked_talent_slots(&self) -> Vec<u32> {
+        (1..)
+            .zip(self.talents())
+            .filter_map(|(slot, picked)| picked.then_some(slot))
+            .collect()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Map {
     pub clock_time: i32,
+    /// GSI `map.game_state`, e.g. `DOTA_GAMERULES_STATE_GAME_IN_PROGRESS`.
+    #[serde(default)]
+    pub game_state: Option<String>,
+    /// GSI `map.matchid`; changes when a new match starts.
+    #[serde(default)]
+    pub matchid: Option<String>,
+}
+
+impl Map {
+    /// Past the horn and before the ancient falls (not hero pick, strategy
+    /// time or pre-game).
+    pub fn game_in_progress(&self) -> bool {
+        self.game_state.as_deref() == Some("DOTA_GAMERULES_STATE_GAME_IN_PROGRESS")
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -170,3 +291,95 @@ pub struct GsiWebhookEvent {
     #[serde(default)]
     pub player: Option<Player>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_by_name_scans_all_six_ability_slots() {
+        let ability = |name: &str| Ability {
+            ability_active: true,
+            can_cast: true,
+            cooldown: 0,
+            level: 1,
+            name: name.to_string(),
+            passive: false,
+            ultimate: false,
+        };
+        let abilities = Abilities {
+            ability0: ability("huskar_inner_fire"),
+            ability1: ability("huskar_burning_spear"),
+            ability2: ability("generic_hidden"),
+            ability3: ability("huskar_life_break"),
+            ability4: ability("generic_hidden"),
+            ability5: ability("huskar_berserkers_blood"),
+        };
+
+        let blood = abilities.find_by_name("huskar_berserkers_blood");
+        assert_eq!(blood.map(|a| a.name.as_str()), Some("huskar_berserkers_blood"));
+        assert!(abilities.find_by_name("huskar_inner_vitality").is_none());
+    }
+
+    #[test]
+    fn charge_based_items_need_a_charge_to_be_ready() {
+        let wand = |charges| Item {
+            name: "item_magic_wand".to_string(),
+            can_cast: Some(true),
+            cooldown: Some(0),
+            item_charges: charges,
+            ..Default::default()
+        };
+        assert!(wand(Some(5)).is_ready());
+        assert!(!wand(Some(0)).is_ready());
+        assert!(wand(None).is_ready(), "items without charges only need can_cast");
+
+        let on_cooldown = Item {
+            cooldown: Some(3),
+            ..wand(Some(5))
+        };
+        assert!(!on_cooldown.is_ready());
+    }
+
+    #[test]
+    fn backpack_slots_are_listed_after_the_castable_ones() {
+        let items = Items {
+            slot7: Item {
+                name: "item_enchanted_mango".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let names: Vec<&str> = items
+            .all_slots_including_backpack()
+            .into_iter()
+            .map(|(slot, _)| slot)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "slot0", "slot1", "slot2", "slot3", "slot4", "slot5", "neutral0", "slot6",
+                "slot7", "slot8"
+            ]
+        );
+        assert!(!items.all_slots().iter().any(|(_, item)| item.name == "item_enchanted_mango"));
+    }
+
+    #[test]
+    fn only_the_game_in_progress_state_counts_as_in_progress() {
+        let map: Map = serde_json::from_str(r#"{"clock_time": -30}"#).unwrap();
+        assert!(!map.game_in_progress(), "older payloads omit game_state");
+
+        let map: Map = serde_json::from_str(
+            r#"{"clock_time": -30, "game_state": "DOTA_GAMERULES_STATE_PRE_GAME"}"#,
+        )
+        .unwrap();
+        assert!(!map.game_in_progress());
+
+        let map: Map = serde_json::from_str(
+            r#"{"clock_time": 600, "game_state": "DOTA_GAMERULES_STATE_GAME_IN_PROGRESS"}"#,
+        )
+        .unwrap();
+        assert!(map.game_in_progress());
+    }
+}
@@ -131,6 +131,282 @@ pub enum Hero {
 }
 
 impl Hero {
+    /// Every hero, in declaration order.
+    pub const ALL: [Hero; 125] = [
+        Hero::Abaddon,
+        Hero::AbyssalUnderlord,
+        Hero::Alchemist,
+        Hero::AncientApparition,
+        Hero::AntiMage,
+        Hero::ArcWarden,
+        Hero::Axe,
+        Hero::Bane,
+        Hero::Batrider,
+        Hero::Beastmaster,
+        Hero::Bloodseeker,
+        Hero::BountyHunter,
+        Hero::Brewmaster,
+        Hero::Bristleback,
+        Hero::Broodmother,
+        Hero::Centaur,
+        Hero::ChaosKnight,
+        Hero::Chen,
+        Hero::Clinkz,
+        Hero::CrystalMaiden,
+        Hero::DarkSeer,
+        Hero::DarkWillow,
+        Hero::Dawnbreaker,
+        Hero::Dazzle,
+        Hero::DeathProphet,
+        Hero::Disruptor,
+        Hero::DoomBringer,
+        Hero::DragonKnight,
+        Hero::DrowRanger,
+        Hero::EarthSpirit,
+        Hero::Earthshaker,
+        Hero::ElderTitan,
+        Hero::EmberSpirit,
+        Hero::Enchantress,
+        Hero::Enigma,
+        Hero::FacelessVoid,
+        Hero::Furion,
+        Hero::Grimstroke,
+        Hero::Gyrocopter,
+        Hero::Hoodwink,
+        Hero::Huskar,
+        Hero::Invoker,
+        Hero::Jakiro,
+        Hero::Juggernaut,
+        Hero::KeeperOfTheLight,
+        Hero::Kunkka,
+        Hero::Largo,
+        Hero::LegionCommander,
+        Hero::Leshrac,
+        Hero::Lich,
+        Hero::LifeStealer,
+        Hero::Lina,
+        Hero::Lion,
+        Hero::LoneDruid,
+        Hero::Luna,
+        Hero::Lycan,
+        Hero::Magnataur,
+        Hero::Marci,
+        Hero::Mars,
+        Hero::Medusa,
+        Hero::Meepo,
+        Hero::Mirana,
+        Hero::MonkeyKing,
+        Hero::Morphling,
+        Hero::Muerta,
+        Hero::NagaSiren,
+        Hero::Necrolyte,
+        Hero::Nevermore,
+        Hero::NightStalker,
+        Hero::NyxAssassin,
+        Hero::ObsidianDestroyer,
+        Hero::OgreMagi,
+        Hero::Omniknight,
+        Hero::Oracle,
+        Hero::Pangolier,
+        Hero::PhantomAssassin,
+        Hero::PhantomLancer,
+        Hero::Phoenix,
+        Hero::PrimalBeast,
+        Hero::Puck,
+        Hero::Pudge,
+        Hero::Pugna,
+        Hero::QueenOfPain,
+        Hero::Rattletrap,
+        Hero::Razor,
+        Hero::Riki,
+        Hero::Rubick,
+        Hero::SandKing,
+        Hero::ShadowDemon,
+        Hero::ShadowShaman,
+        Hero::Shredder,
+        Hero::Silencer,
+        Hero::SkeletonKing,
+        Hero::SkywrathMage,
+        Hero::Slardar,
+        Hero::Slark,
+        Hero::Snapfire,
+        Hero::Sniper,
+        Hero::Spectre,
+        Hero::SpiritBreaker,
+        Hero::StormSpirit,
+        Hero::Sven,
+        Hero::Techies,
+        Hero::TemplarAssassin,
+        Hero::Terrorblade,
+        Hero::Tidehunter,
+        Hero::Tinker,
+        Hero::Tiny,
+        Hero::Treant,
+        Hero::TrollWarlord,
+        Hero::Tusk,
+        Hero::Undying,
+        Hero::Ursa,
+        Hero::VengefulSpirit,
+        Hero::Venomancer,
+        Hero::Viper,
+        Hero::Visage,
+        Hero::VoidSpirit,
+        Hero::Warlock,
+        Hero::Weaver,
+        Hero::Windrunner,
+        Hero::WinterWyvern,
+        Hero::Wisp,
+        Hero::WitchDoctor,
+        Hero::Zuus,
+    ];
+
+    /// Name shown in the game client (e.g. `Windranger` for `windrunner`).
+    pub fn to_display_name(&self) -> &'static str {
+        match self {
+            Hero::Abaddon => "Abaddon",
+            Hero::AbyssalUnderlord => "Underlord",
+            Hero::Alchemist => "Alchemist",
+            Hero::AncientApparition => "Ancient Apparition",
+            Hero::AntiMage => "Anti-Mage",
+            Hero::ArcWarden => "Arc Warden",
+            Hero::Axe => "Axe",
+            Hero::Bane => "Bane",
+            Hero::Batrider => "Batrider",
+            Hero::Beastmaster => "Beastmaster",
+            Hero::Bloodseeker => "Bloodseeker",
+            Hero::BountyHunter => "Bounty Hunter",
+            Hero::Brewmaster => "Brewmaster",
+            Hero::Bristleback => "Bristleback",
+            Hero::Broodmother => "Broodmother",
+            Hero::Centaur => "Centaur Warrunner",
+            Hero::ChaosKnight => "Chaos Knight",
+            Hero::Chen => "Chen",
+            Hero::Clinkz => "Clinkz",
+            Hero::CrystalMaiden => "Crystal Maiden",
+            Hero::DarkSeer => "Dark Seer",
+            Hero::DarkWillow => "Dark Willow",
+            Hero::Dawnbreaker => "Dawnbreaker",
+            Hero::Dazzle => "Dazzle",
+            Hero::DeathProphet => "Death Prophet",
+            Hero::Disruptor => "Disruptor",
+            Hero::DoomBringer => "Doom",
+            Hero::DragonKnight => "Dragon Knight",
+            Hero::DrowRanger => "Drow Ranger",
+            Hero::EarthSpirit => "Earth Spirit",
+            Hero::Earthshaker => "Earthshaker",
+            Hero::ElderTitan => "Elder Titan",
+            Hero::EmberSpirit => "Ember Spirit",
+            Hero::Enchantress => "Enchantress",
+            Hero::Enigma => "Enigma",
+            Hero::FacelessVoid => "Faceless Void",
+            Hero::Furion => "Nature's Prophet",
+            Hero::Grimstroke => "Grimstroke",
+            Hero::Gyrocopter => "Gyrocopter",
+            Hero::Hoodwink => "Hoodwink",
+            Hero::Huskar => "Huskar",
+            Hero::Invoker => "Invoker",
+            Hero::Jakiro => "Jakiro",
+            Hero::Juggernaut => "Juggernaut",
+            Hero::KeeperOfTheLight => "Keeper of the Light",
+            Hero::Kunkka => "Kunkka",
+            Hero::Largo => "Largo",
+            Hero::LegionCommander => "Legion Commander",
+            Hero::Leshrac => "Leshrac",
+            Hero::Lich => "Lich",
+            Hero::LifeStealer => "Lifestealer",
+            Hero::Lina => "Lina",
+            Hero::Lion => "Lion",
+            Hero::LoneDruid => "Lone Druid",
+            Hero::Luna => "Luna",
+            Hero::Lycan => "Lycan",
+            Hero::Magnataur => "Magnus",
+            Hero::Marci => "Marci",
+            Hero::Mars => "Mars",
+            Hero::Medusa => "Medusa",
+            Hero::Meepo => "Meepo",
+            Hero::Mirana => "Mirana",
+            Hero::MonkeyKing => "Monkey King",
+            Hero::Morphling => "Morphling",
+            Hero::Muerta => "Muerta",
+            Hero::NagaSiren => "Naga Siren",
+            Hero::Necrolyte => "Necrophos",
+            Hero::Nevermore => "Shadow Fiend",
+            Hero::NightStalker => "Night Stalker",
+            Hero::NyxAssassin => "Nyx Assassin",
+            Hero::ObsidianDestroyer => "Outworld Destroyer",
+            Hero::OgreMagi => "Ogre Magi",
+            Hero::Omniknight => "Omniknight",
+            Hero::Oracle => "Oracle",
+            Hero::Pangolier => "Pangolier",
+            Hero::PhantomAssassin => "Phantom Assassin",
+            Hero::PhantomLancer => "Phantom Lancer",
+            Hero::Phoenix => "Phoenix",
+            Hero::PrimalBeast => "Primal Beast",
+            Hero::Puck => "Puck",
+            Hero::Pudge => "Pudge",
+            Hero::Pugna => "Pugna",
+            Hero::QueenOfPain => "Queen of Pain",
+            Hero::Rattletrap => "Clockwerk",
+            Hero::Razor => "Razor",
+            Hero::Riki => "Riki",
+            Hero::Rubick => "Rubick",
+            Hero::SandKing => "Sand King",
+            Hero::ShadowDemon => "Shadow Demon",
+            Hero::ShadowShaman => "Shadow Shaman",
+            Hero::Shredder => "Timbersaw",
+            Hero::Silencer => "Silencer",
+            Hero::SkeletonKing => "Wraith King",
+            Hero::SkywrathMage => "Skywrath Mage",
+            Hero::Slardar => "Slardar",
+            Hero::Slark => "Slark",
+            Hero::Snapfire => "Snapfire",
+            Hero::Sniper => "Sniper",
+            Hero::Spectre => "Spectre",
+            Hero::SpiritBreaker => "Spirit Breaker",
+            Hero::StormSpirit => "Storm Spirit",
+            Hero::Sven => "Sven",
+            Hero::Techies => "Techies",
+            Hero::TemplarAssassin => "Templar Assassin",
+            Hero::Terrorblade => "Terrorblade",
+            Hero::Tidehunter => "Tidehunter",
+            Hero::Tinker => "Tinker",
+            Hero::Tiny => "Tiny",
+            Hero::Treant => "Treant Protector",
+            Hero::TrollWarlord => "Troll Warlord",
+            Hero::Tusk => "Tusk",
+            Hero::Undying => "Undying",
+            Hero::Ursa => "Ursa",
+            Hero::VengefulSpirit => "Vengeful Spirit",
+            Hero::Venomancer => "Venomancer",
+            Hero::Viper => "Viper",
+            Hero::Visage => "Visage",
+            Hero::VoidSpirit => "Void Spirit",
+            Hero::Warlock => "Warlock",
+            Hero::Weaver => "Weaver",
+            Hero::Windrunner => "Windranger",
+            Hero::WinterWyvern => "Winter Wyvern",
+            Hero::Wisp => "Io",
+            Hero::WitchDoctor => "Witch Doctor",
+            Hero::Zuus => "Zeus",
+        }
+    }
+
+    /// Resolve any name a hero goes by: the GSI name
+    /// (`npc_dota_hero_windrunner`), the bare internal name (`windrunner`) or
+    /// the display name (`Windranger`). Case, spaces, underscores and
+    /// punctuation are ignored, so `shadow_fiend` and `Nature's Prophet`
+    /// resolve too.
+    pub fn from_alias(name: &str) -> Option<Self> {
+        let wanted = normalize_hero_name(name);
+        if wanted.is_empty() {
+            return None;
+        }
+        Self::ALL.into_iter().find(|hero| {
+            normalize_hero_name(hero.to_game_name()) == wanted
+                || normalize_hero_name(hero.to_display_name()) == wanted
+        })
+    }
+
     /// Convert Hero enum to the game's internal hero name string
     pub fn to_game_name(&self) -> &'static str {
         match self {
@@ -312,6 +588,7 @@ impl Hero {
             "npc_dota_hero_juggernaut" => Some(Hero::Juggernaut),
             "npc_dota_hero_keeper_of_the_light" => Some(Hero::KeeperOfTheLight),
             "npc_dota_hero_kunkka" => Some(Hero::Kunkka),
+            "npc_dota_hero_largo" => Some(Hero::Largo),
             "npc_dota_hero_legion_commander" => Some(Hero::LegionCommander),
             "npc_dota_hero_leshrac" => Some(Hero::Leshrac),
             "npc_dota_hero_lich" => Some(Hero::Lich),
@@ -394,3 +671,57 @@ impl Hero {
         }
     }
 }
+
+/// Lower-case alphanumerics of `name` without the `npc_dota_hero_` prefix.
+fn normalize_hero_name(name: &str) -> String {
+    let name = name.trim().to_ascii_lowercase();
+    name.strip_prefix("npc_dota_hero_")
+        .unwrap_or(&name)
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_hero_name, Hero};
+    use std::collections::HashMap;
+
+    #[test]
+    fn internal_and_display_names_resolve_to_the_same_hero() {
+        for (alias, hero) in [
+            ("npc_dota_hero_windrunner", Hero::Windrunner),
+            ("Windranger", Hero::Windrunner),
+            ("necrophos", Hero::Necrolyte),
+            ("npc_dota_hero_nevermore", Hero::Nevermore),
+            ("shadow_fiend", Hero::Nevermore),
+            ("Outworld Destroyer", Hero::ObsidianDestroyer),
+            ("Nature's Prophet", Hero::Furion),
+            ("furion", Hero::Furion),
+            ("Anti-Mage", Hero::AntiMage),
+            ("wraith_king", Hero::SkeletonKing),
+            ("Clockwerk", Hero::Rattletrap),
+            ("Io", Hero::Wisp),
+            ("  ZEUS ", Hero::Zuus),
+            ("largo", Hero::Largo),
+        ] {
+            assert_eq!(Hero::from_alias(alias), Some(hero), "{}", alias);
+        }
+        assert_eq!(Hero::from_alias("npc_dota_hero_"), None);
+        assert_eq!(Hero::from_alias("roshan"), None);
+    }
+
+    #[test]
+    fn every_hero_round_trips_and_no_alias_is_ambiguous() {
+        let mut owners = HashMap::new();
+        for hero in Hero::ALL {
+            assert_eq!(Hero::from_game_name(hero.to_game_name()), Some(hero));
+            assert_eq!(Hero::from_alias(hero.to_game_name()), Some(hero));
+            assert_eq!(Hero::from_alias(hero.to_display_name()), Some(hero));
+            for name in [hero.to_game_name(), hero.to_display_name()] {
+                let owner = *owners.entry(normalize_hero_name(name)).or_insert(hero);
+                assert_eq!(owner, hero, "{} is also an alias of {:?}", name, owner);
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub enum Item {
     AbyssalBlade,
     Aegis,
     AetherLens,
+    AghanimsShard,
     AncientJanggo,
     ArcaneBoots,
     Armlet,
@@ -125,6 +126,7 @@ impl Item {
             Item::AbyssalBlade => "item_abyssal_blade",
             Item::Aegis => "item_aegis",
             Item::AetherLens => "item_aether_lens",
+            Item::AghanimsShard => "item_aghanims_shard",
             Item::AncientJanggo => "item_ancient_janggo",
             Item::ArcaneBoots => "item_arcane_boots",
             Item::Armlet => "item_armlet",
@@ -245,6 +247,7 @@ impl Item {
             "item_abyssal_blade" => Some(Item::AbyssalBlade),
             "item_aegis" => Some(Item::Aegis),
             "item_aether_lens" => Some(Item::AetherLens),
+            "item_aghanims_shard" => Some(Item::AghanimsShard),
             "item_ancient_janggo" => Some(Item::AncientJanggo),
             "item_arcane_boots" => Some(Item::ArcaneBoots),
             "item_armlet" => Some(Item::Armlet),
@@ -3,6 +3,7 @@ use crate::observability::minimap_capture_backend::CaptureBackendResult;
 use crate::observability::minimap_capture_state::{
     MinimapCaptureHealth, MinimapCaptureStatusSnapshot,
 };
+use crate::state::poison::lock_or_recover;
 use crate::state::AppState;
 use std::sync::{Arc, Mutex};
 
@@ -105,8 +106,13 @@ pub fn start_minimap_capture_worker(
     let mut success_count: u64 = 0;
 
     loop {
+        if crate::state::shutdown::is_shutting_down() {
+            tracing::info!("Minimap capture worker stopped");
+            return;
+        }
+
         let config = {
-            let guard = settings.lock().unwrap();
+            let guard = lock_or_recover(&settings);
             guard.minimap_capture.clone()
         };
 
@@ -115,9 +121,7 @@ pub fn start_minimap_capture_worker(
             continue;
         }
 
-        let previous_failures = app_state
-            .lock()
-            .unwrap()
+        let previous_failures = lock_or_recover(&app_state)
             .minimap_capture
             .as_ref()
             .map(|snapshot| snapshot.consecutive_failures)
@@ -212,7 +216,7 @@ pub fn start_minimap_capture_worker(
             final_status.last_capture_duration_ms = Some(capture_duration_ms);
         }
 
-        app_state.lock().unwrap().minimap_capture = Some(final_status);
+        lock_or_recover(&app_state).minimap_capture = Some(final_status);
         std::thread::sleep(std::time::Duration::from_millis(config.capture_interval_ms));
     }
 }
@@ -1,4 +1,5 @@
 use crate::config::RuneAlertConfig;
+use crate::state::poison::lock_or_recover;
 use lazy_static::lazy_static;
 use std::sync::Mutex;
 
@@ -136,30 +137,36 @@ fn sound_hook_cell() -> &'static Mutex<SoundHook> {
 }
 
 pub fn process_clock_time(clock_time_seconds: i32, config: &RuneAlertConfig) -> RuneAlertSnapshot {
-    let mut manager = RUNE_ALERT_MANAGER.lock().unwrap();
+    let mut manager = lock_or_recover(&RUNE_ALERT_MANAGER);
     manager.update_settings(RuneAlertSettings::from(config));
     let _ = manager.update(clock_time_seconds);
     let snapshot = manager.snapshot(clock_time_seconds);
     drop(manager);
 
-    *LATEST_RUNE_ALERT_SNAPSHOT.lock().unwrap() = Some(snapshot.clone());
+    *lock_or_recover(&LATEST_RUNE_ALERT_SNAPSHOT) = Some(snapshot.clone());
     snapshot
 }
 
 #[cfg(test)]
 pub fn latest_rune_alert_snapshot() -> Option<RuneAlertSnapshot> {
-    LATEST_RUNE_ALERT_SNAPSHOT.lock().unwrap().clone()
+    lock_or_recover(&LATEST_RUNE_ALERT_SNAPSHOT).clone()
 }
 
-#[cfg(test)]
-pub fn reset_rune_alert_state_for_tests() {
-    *RUNE_ALERT_MANAGER.lock().unwrap() = RuneAlertManager::new(RuneAlertSettings {
+/// Forget alerts already fired and the last snapshot; the next clock tick
+/// starts a fresh schedule with the current settings.
+pub fn reset() {
+    *lock_or_recover(&RUNE_ALERT_MANAGER) = RuneAlertManager::new(RuneAlertSettings {
         enabled: true,
         alert_lead_seconds: 10,
         interval_seconds: 120,
         audio_enabled: true,
     });
-    *LATEST_RUNE_ALERT_SNAPSHOT.lock().unwrap() = None;
+    *lock_or_recover(&LATEST_RUNE_ALERT_SNAPSHOT) = None;
+}
+
+#[cfg(test)]
+pub fn reset_rune_alert_state_for_tests() {
+    reset();
     set_sound_hook_for_tests(default_sound_hook);
 }
 
@@ -1,6 +1,12 @@
+use crate::config::error::ErrorCategory;
+use crate::config::Settings;
 use crate::models::{GsiWebhookEvent, Hero};
 use crate::observability::minimap_capture_state::MinimapCaptureStatusSnapshot;
 use crate::observability::rune_alerts::RuneAlertSnapshot;
+use crate::state::automation_metrics::{shared_automation_metrics, AutomationMetrics};
+use crate::state::poison::lock_or_recover;
+use crate::update::UpdateError;
+use serde::Serialize;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
 
@@ -8,6 +14,8 @@ const GSI_ACTIVITY_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HeroType {
+    Bristleback,
+    Broodmother,
     Huskar,
     Largo,
     LegionCommander,
@@ -15,6 +23,7 @@ pub enum HeroType {
     OutworldDestroyer,
     ShadowFiend,
     Tiny,
+    Zeus,
 }
 
 /// Represents the current state of the auto-update check
@@ -32,44 +41,105 @@ pub enum UpdateCheckState {
     /// Currently downloading the update
     Downloading,
     /// Update check or download failed
-    Error(String),
+    Error {
+        message: String,
+        category: ErrorCategory,
+    },
     /// Already running the latest version
     UpToDate,
 }
 
+impl UpdateCheckState {
+    /// Failed check, download or rollback, keeping its category for the UI.
+    pub fn failed(error: &UpdateError) -> Self {
+        Self::Error {
+            message: error.to_string(),
+            category: error.category(),
+        }
+    }
+}
+
 impl HeroType {
-    pub fn from_hero_name(name: &str) -> Option<Self> {
-        match name {
-            name if name == Hero::Huskar.to_game_name() => Some(HeroType::Huskar),
-            name if name == Hero::Largo.to_game_name() => Some(HeroType::Largo),
-            name if name == Hero::LegionCommander.to_game_name() => Some(HeroType::LegionCommander),
-            name if name == Hero::Meepo.to_game_name() => Some(HeroType::Meepo),
-            name if name == Hero::ObsidianDestroyer.to_game_name() => {
-                Some(HeroType::OutworldDestroyer)
-            }
-            name if name == Hero::Nevermore.to_game_name() => Some(HeroType::ShadowFiend),
-            name if name == Hero::Tiny.to_game_name() => Some(HeroType::Tiny),
-            _ => None,
+    /// Every hero with a dedicated script, in UI display order.
+    pub const ALL: [HeroType; 10] = [
+        HeroType::Bristleback,
+        HeroType::Broodmother,
+        HeroType::Huskar,
+        HeroType::Largo,
+        HeroType::LegionCommander,
+        HeroType::Meepo,
+        HeroType::OutworldDestroyer,
+        HeroType::ShadowFiend,
+        HeroType::Tiny,
+        HeroType::Zeus,
+    ];
+
+    /// The `models::Hero` backing this hero type.
+    pub fn to_hero(&self) -> Hero {
+        match self {
+            HeroType::Bristleback => Hero::Bristleback,
+            HeroType::Broodmother => Hero::Broodmother,
+            HeroType::Huskar => Hero::Huskar,
+            HeroType::Largo => Hero::Largo,
+            HeroType::LegionCommander => Hero::LegionCommander,
+            HeroType::Meepo => Hero::Meepo,
+            HeroType::OutworldDestroyer => Hero::ObsidianDestroyer,
+            HeroType::ShadowFiend => Hero::Nevermore,
+            HeroType::Tiny => Hero::Tiny,
+            HeroType::Zeus => Hero::Zuus,
         }
     }
 
-    pub fn to_display_name(&self) -> &'static str {
+    /// GSI/internal hero name (e.g. `npc_dota_hero_nevermore`), used as the
+    /// dispatcher's script key.
+    pub fn to_game_name(&self) -> &'static str {
+        self.to_hero().to_game_name()
+    }
+
+    /// Name of this hero's `[heroes.<name>]` config section.
+    pub fn config_key(&self) -> &'static str {
         match self {
-            HeroType::Huskar => "Huskar",
-            HeroType::Largo => "Largo",
-            HeroType::LegionCommander => "Legion Commander",
-            HeroType::Meepo => "Meepo",
-            HeroType::OutworldDestroyer => "Outworld Destroyer",
-            HeroType::ShadowFiend => "Shadow Fiend",
-            HeroType::Tiny => "Tiny",
+            HeroType::Bristleback => "bristleback",
+            HeroType::Broodmother => "broodmother",
+            HeroType::Huskar => "huskar",
+            HeroType::Largo => "largo",
+            HeroType::LegionCommander => "legion_commander",
+            HeroType::Meepo => "meepo",
+            HeroType::OutworldDestroyer => "outworld_destroyer",
+            HeroType::ShadowFiend => "shadow_fiend",
+            HeroType::Tiny => "tiny",
+            HeroType::Zeus => "zeus",
         }
     }
+
+    /// Scripted hero for `hero`, if it has a dedicated script.
+    pub fn from_hero(hero: Hero) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|hero_type| hero_type.to_hero() == hero)
+    }
+
+    /// Accepts any alias [`Hero::from_alias`] does, so `npc_dota_hero_nevermore`,
+    /// `shadow_fiend` and `Shadow Fiend` all resolve.
+    pub fn from_hero_name(name: &str) -> Option<Self> {
+        Hero::from_alias(name).and_then(Self::from_hero)
+    }
+
+    pub fn from_display_name(name: &str) -> Option<Self> {
+        Self::from_hero_name(name)
+    }
+
+    pub fn to_display_name(&self) -> &'static str {
+        self.to_hero().to_display_name()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct QueueMetrics {
     pub events_processed: u64,
     pub events_dropped: u64,
+    /// Events not dispatched because they matched the previous frame.
+    pub events_deduplicated: u64,
     pub current_queue_depth: usize,
 }
 
@@ -78,6 +148,7 @@ impl Default for QueueMetrics {
         Self {
             events_processed: 0,
             events_dropped: 0,
+            events_deduplicated: 0,
             current_queue_depth: 0,
         }
     }
@@ -85,24 +156,36 @@ impl Default for QueueMetrics {
 
 #[derive(Debug, Clone)]
 pub struct AppState {
+    /// Manual selection or the last scripted hero GSI reported. A UI hint;
+    /// standalone hotkeys route through [`AppState::standalone_hero`].
     pub selected_hero: Option<HeroType>,
+    /// Scripted hero from the latest GSI event, shared with the keyboard
+    /// snapshot so hotkey routing follows hero changes without a refresh.
+    pub live_hero: Arc<Mutex<Option<HeroType>>>,
     pub gsi_enabled: bool,
     pub standalone_enabled: bool,
     pub last_event: Option<GsiWebhookEvent>,
     pub last_gsi_activity_at: Option<SystemTime>,
     pub metrics: QueueMetrics,
+    /// Selected hero's `standalone_key`; shared with the keyboard snapshot so
+    /// a GSI hero switch rebinds the combo trigger without a refresh.
     pub trigger_key: Arc<Mutex<String>>,
     pub sf_enabled: Arc<Mutex<bool>>,
     pub od_enabled: Arc<Mutex<bool>>,
+    /// Largo is the selected hero; shared with the keyboard snapshot so song
+    /// keys start and stop with hero changes.
+    pub largo_enabled: Arc<Mutex<bool>>,
     pub update_state: Arc<Mutex<UpdateCheckState>>,
     pub rune_alerts: Option<RuneAlertSnapshot>,
     pub minimap_capture: Option<MinimapCaptureStatusSnapshot>,
+    pub automation_metrics: Arc<Mutex<AutomationMetrics>>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
         Self {
             selected_hero: None,
+            live_hero: Arc::new(Mutex::new(None)),
             gsi_enabled: true,
             standalone_enabled: true,
             last_event: None,
@@ -111,9 +194,11 @@ impl Default for AppState {
             trigger_key: Arc::new(Mutex::new("Home".to_string())),
             sf_enabled: Arc::new(Mutex::new(false)),
             od_enabled: Arc::new(Mutex::new(false)),
+            largo_enabled: Arc::new(Mutex::new(false)),
             update_state: Arc::new(Mutex::new(UpdateCheckState::Idle)),
             rune_alerts: None,
             minimap_capture: None,
+            automation_metrics: shared_automation_metrics(),
         }
     }
 }
@@ -123,14 +208,31 @@ impl AppState {
         Arc::new(Mutex::new(Self::default()))
     }
 
-    pub fn update_from_gsi(&mut self, event: GsiWebhookEvent) {
+    /// Shared state with the GSI and standalone switches set from
+    /// `[startup]`, for builds where the user can flip them.
+    pub fn new_from_startup(settings: &Settings) -> Arc<Mutex<Self>> {
+        let (gsi_enabled, standalone_enabled) = settings.startup.launch_arm_state();
+        Arc::new(Mutex::new(Self {
+            gsi_enabled,
+            standalone_enabled,
+            ..Self::default()
+        }))
+    }
+
+    pub fn update_from_gsi(&mut self, event: GsiWebhookEvent, settings: &Settings) {
         // Update hero selection based on the GSI event if it changed
         let hero_type = HeroType::from_hero_name(&event.hero.name);
+        *lock_or_recover(&self.live_hero) = hero_type;
 
         if self.selected_hero != hero_type {
             self.selected_hero = hero_type;
-            *self.sf_enabled.lock().unwrap() = hero_type == Some(HeroType::ShadowFiend);
-            *self.od_enabled.lock().unwrap() = hero_type == Some(HeroType::OutworldDestroyer);
+            *lock_or_recover(&self.sf_enabled) = hero_type == Some(HeroType::ShadowFiend);
+            *lock_or_recover(&self.od_enabled) = hero_type == Some(HeroType::OutworldDestroyer);
+            *lock_or_recover(&self.largo_enabled) = hero_type == Some(HeroType::Largo);
+            if let Some(hero_type) = hero_type {
+                *lock_or_recover(&self.trigger_key) =
+                    settings.get_standalone_key(hero_type.config_key());
+            }
         }
 
         self.last_event = Some(event);
@@ -138,6 +240,13 @@ impl AppState {
         self.metrics.events_processed += 1;
     }
 
+    /// Hero that standalone hotkeys act for: the scripted hero GSI last
+    /// reported, falling back to the manual selection when GSI has not seen
+    /// one (no game yet, or an unscripted hero).
+    pub fn standalone_hero(&self) -> Option<HeroType> {
+        resolve_standalone_hero(&self.live_hero, self.selected_hero)
+    }
+
     pub fn has_recent_gsi_activity(&self) -> bool {
         self.last_gsi_activity_at
             .and_then(|last_seen| SystemTime::now().duration_since(last_seen).ok())
@@ -146,9 +255,18 @@ impl AppState {
     }
 }
 
+pub(crate) fn resolve_standalone_hero(
+    live_hero: &Mutex<Option<HeroType>>,
+    selected_hero: Option<HeroType>,
+) -> Option<HeroType> {
+    lock_or_recover(live_hero).or(selected_hero)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{HeroType};
+    use super::{AppState, HeroType};
+    use crate::config::Settings;
+    use crate::gsi::load_fixture_event;
     use crate::models::Hero;
 
     #[test]
@@ -157,4 +275,107 @@ mod tests {
         assert_eq!(HeroType::from_hero_name(game_name), Some(HeroType::Meepo));
         assert_eq!(HeroType::Meepo.to_display_name(), "Meepo");
     }
+
+    #[test]
+    fn every_hero_type_round_trips_through_game_and_display_names() {
+        for hero_type in HeroType::ALL {
+            assert_eq!(
+                HeroType::from_hero_name(hero_type.to_game_name()),
+                Some(hero_type)
+            );
+            assert_eq!(
+                HeroType::from_display_name(hero_type.to_display_name()),
+                Some(hero_type)
+            );
+        }
+    }
+
+    #[test]
+    fn hero_type_resolves_internal_names_display_names_and_config_keys() {
+        for (name, hero_type) in [
+            ("npc_dota_hero_nevermore", HeroType::ShadowFiend),
+            ("Nevermore", HeroType::ShadowFiend),
+            ("shadow_fiend", HeroType::ShadowFiend),
+            ("obsidian_destroyer", HeroType::OutworldDestroyer),
+            ("outworld_destroyer", HeroType::OutworldDestroyer),
+            ("legion commander", HeroType::LegionCommander),
+            ("npc_dota_hero_zuus", HeroType::Zeus),
+        ] {
+            assert_eq!(HeroType::from_hero_name(name), Some(hero_type), "{}", name);
+        }
+        for hero_type in HeroType::ALL {
+            assert_eq!(
+                HeroType::from_hero_name(hero_type.config_key()),
+                Some(hero_type)
+            );
+        }
+        assert_eq!(HeroType::from_hero_name("npc_dota_hero_windrunner"), None);
+    }
+
+    #[test]
+    fn startup_state_is_disarmed_unless_configured() {
+        let mut settings = Settings::default();
+        let state = AppState::new_from_startup(&settings);
+        let state = state.lock().unwrap();
+        assert!(!state.gsi_enabled && !state.standalone_enabled);
+        drop(state);
+
+        settings.startup.arm_on_launch = true;
+        let state = AppState::new_from_startup(&settings);
+        assert!(state.lock().unwrap().standalone_enabled);
+
+        settings.startup.restore_last_arm_state = true;
+        settings.startup.last_gsi_enabled = true;
+        let state = AppState::new_from_startup(&settings);
+        let state = state.lock().unwrap();
+        assert!(state.gsi_enabled && !state.standalone_enabled);
+    }
+
+    #[test]
+    fn broodmother_is_detected_from_gsi_name() {
+        assert_eq!(
+            HeroType::from_hero_name(Hero::Broodmother.to_game_name()),
+            Some(HeroType::Broodmother)
+        );
+    }
+
+    #[test]
+    fn trigger_key_follows_gsi_hero_changes() {
+        let mut settings = Settings::default();
+        settings.heroes.huskar.standalone_key = "F6".to_string();
+        settings.heroes.tiny.standalone_key = "F7".to_string();
+        let mut state = AppState::default();
+
+        state.update_from_gsi(
+            load_fixture_event("tests/fixtures/huskar_event.json"),
+            &settings,
+        );
+        assert_eq!(state.selected_hero, Some(HeroType::Huskar));
+        assert_eq!(*state.trigger_key.lock().unwrap(), "F6");
+
+        state.update_from_gsi(load_fixture_event("tests/fixtures/tiny_event.json"), &settings);
+        assert_eq!(state.selected_hero, Some(HeroType::Tiny));
+        assert_eq!(*state.trigger_key.lock().unwrap(), "F7");
+    }
+
+    #[test]
+    fn standalone_hero_prefers_live_gsi_hero_over_manual_selection() {
+        let settings = Settings::default();
+        let mut state = AppState::default();
+        state.selected_hero = Some(HeroType::Tiny);
+        assert_eq!(state.standalone_hero(), Some(HeroType::Tiny));
+
+        state.update_from_gsi(
+            load_fixture_event("tests/fixtures/huskar_event.json"),
+            &settings,
+        );
+        state.selected_hero = Some(HeroType::Tiny);
+        assert_eq!(state.standalone_hero(), Some(HeroType::Huskar));
+
+        let mut unscripted = load_fixture_event("tests/fixtures/huskar_event.json");
+        unscripted.hero.name = "npc_dota_hero_sven".to_string();
+        state.update_from_gsi(unscripted, &settings);
+        state.selected_hero = Some(HeroType::Tiny);
+        assert_eq!(state.standalone_hero(), Some(HeroType::Tiny));
+    }
 }
@@ -0,0 +1,87 @@
+use crate::state::poison::lock_or_recover;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+/// Counts of automation that actually fired this session.
+///
+/// One instance is shared process-wide so action call sites can record
+/// without holding `AppState`; `AppState::automation_metrics` points at it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AutomationMetrics {
+    /// Standalone combos triggered, keyed by hero game name.
+    pub combos_triggered: BTreeMap<String, u64>,
+    /// Defensive items auto-used in danger, keyed by item name.
+    pub defensive_items_used: BTreeMap<String, u64>,
+    pub healing_items_used: u64,
+    /// Transitions into the danger state.
+    pub danger_events: u64,
+    pub armlet_toggles: u64,
+}
+
+static AUTOMATION_METRICS: LazyLock<Arc<Mutex<AutomationMetrics>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(AutomationMetrics::default())));
+
+/// Handle to the process-wide counters.
+pub fn shared_automation_metrics() -> Arc<Mutex<AutomationMetrics>> {
+    Arc::clone(&AUTOMATION_METRICS)
+}
+
+fn record(update: impl FnOnce(&mut AutomationMetrics)) {
+    update(&mut lock_or_recover(&AUTOMATION_METRICS));
+}
+
+pub fn record_combo_triggered(hero_name: &str) {
+    record(|metrics| {
+        *metrics
+            .combos_triggered
+            .entry(hero_name.to_string())
+            .or_default() += 1
+    });
+}
+
+pub fn record_defensive_item_used(item_name: &str) {
+    record(|metrics| {
+        *metrics
+            .defensive_items_used
+            .entry(item_name.to_string())
+            .or_default() += 1
+    });
+}
+
+pub fn record_healing_item_used() {
+    record(|metrics| metrics.healing_items_used += 1);
+}
+
+pub fn record_danger_event() {
+    record(|metrics| metrics.danger_events += 1);
+}
+
+pub fn record_armlet_toggle() {
+    record(|metrics| metrics.armlet_toggles += 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorders_increment_shared_counters() {
+        let before = shared_automation_metrics().lock().unwrap().clone();
+
+        record_combo_triggered("npc_dota_hero_metrics_test");
+        record_combo_triggered("npc_dota_hero_metrics_test");
+        record_defensive_item_used("item_metrics_test");
+
+        let after = shared_automation_metrics().lock().unwrap().clone();
+        assert_eq!(
+            after.combos_triggered.get("npc_dota_hero_metrics_test"),
+            Some(&2)
+        );
+        assert_eq!(
+            after.defensive_items_used.get("item_metrics_test"),
+            Some(&1)
+        );
+        assert!(after.danger_events >= before.danger_events);
+    }
+}
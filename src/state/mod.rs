@@ -1,3 +1,8 @@
 pub mod app_state;
+pub mod automation_metrics;
+pub mod poison;
+pub mod reset;
+pub mod shutdown;
 
 pub use app_state::{AppState, HeroType, UpdateCheckState};
+pub use automation_metrics::AutomationMetrics;
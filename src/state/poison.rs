@@ -0,0 +1,188 @@
+//! Poison-tolerant locking for shared state.
+//!
+//! A thread that panics while holding a `Mutex` poisons it, and every later
+//! `lock().unwrap()` on it panics too, so one bug in a hero script could take
+//! the GSI loop, the keyboard hook and the UI commands down with it. These
+//! helpers take the lock anyway, log once, and clear the poison.
+//!
+//! Which one to use depends on whether the data is still trustworthy after
+//! an update that stopped half-way:
+//!
+//! | Helper | Use for | Examples |
+//! |---|---|---|
+//! | [`lock_or_recover`] | Data that is valid after any partial write: config, snapshots rebuilt on every GSI event, single timestamps, flags, logs and counters | `Settings`, `AppState`, `LATEST_GSI_EVENT_AT`, the synthetic-input metrics store, `RUNE_ALERT_MANAGER` and `LATEST_RUNE_ALERT_SNAPSHOT`, `BACKPACK_HEALING_HINT`, the activity buffer, `OD_LAST_EVENT`, `SF_LAST_EVENT`, `MEEPO_OBSERVED_STATE`, `ARMLET_LAST_TOGGLE`, trigger lockouts, Meepo's last defensive trigger, the dispatcher's `EQUIPPED_NEUTRAL_ITEM`, `INVENTORY_ITEMS` and `DISCOVERED_NEUTRAL_ITEMS`, `REPORTED_HERO_FIELDS`, Largo's emission guard and `BEAT_THREAD_STARTED`, shutdown wakers |
+//! | [`lock_or_reset`] | State machines and trackers whose fields must agree with each other; a half-applied transition is worse than starting over | `SOUL_RING_STATE`, `ARMLET_ROSHAN_STATE`, `ARMLET_STUCK_TRACKER`, Huskar's Berserker's Blood and Roshan Spears trackers, Shadow Fiend's held raze keys, Largo's ultimate state, the combo recording in progress |
+//!
+//! | [`read_or_recover`] / [`write_or_recover`] | The same, behind an `RwLock` | The keyboard listener's `KeyboardSnapshot` |
+//!
+//! A reset state is the same as after `reset_all_state()`: the next GSI
+//! event or key press rebuilds it. A worker parked on a `Condvar` over reset
+//! data waits through [`wait_or_reset`] or [`wait_timeout_or_reset`], so a
+//! panic elsewhere while it sleeps resets the data the same way.
+
+use std::sync::{
+    Condvar, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, WaitTimeoutResult,
+};
+use std::time::Duration;
+use tracing::warn;
+
+/// Lock `mutex`, keeping its data if a panicking thread poisoned it.
+pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        warn_recovering::<T>();
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+/// Read-lock `lock`, keeping its data if a panicking writer poisoned it.
+pub fn read_or_recover<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(|poisoned| {
+        warn_recovering::<T>();
+        lock.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+/// Write-lock `lock`, keeping its data if a panicking writer poisoned it.
+pub fn write_or_recover<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(|poisoned| {
+        warn_recovering::<T>();
+        lock.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+fn warn_recovering<T>() {
+    warn!(
+        "Recovering {} after a panic poisoned its lock",
+        std::any::type_name::<T>()
+    );
+}
+
+/// Lock `mutex`, resetting its data to `T::default()` if a panicking thread
+/// poisoned it.
+pub fn lock_or_reset<T: Default>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| reset_poisoned(mutex, poisoned.into_inner()))
+}
+
+/// `condvar.wait(guard)` on a guard from [`lock_or_reset`], resetting the
+/// data if the lock was poisoned while waiting.
+pub fn wait_or_reset<'a, T: Default>(
+    condvar: &Condvar,
+    mutex: &Mutex<T>,
+    guard: MutexGuard<'a, T>,
+) -> MutexGuard<'a, T> {
+    condvar
+        .wait(guard)
+        .unwrap_or_else(|poisoned| reset_poisoned(mutex, poisoned.into_inner()))
+}
+
+/// `condvar.wait_timeout(guard, timeout)` with the reset of [`wait_or_reset`].
+pub fn wait_timeout_or_reset<'a, T: Default>(
+    condvar: &Condvar,
+    mutex: &Mutex<T>,
+    guard: MutexGuard<'a, T>,
+    timeout: Duration,
+) -> (MutexGuard<'a, T>, WaitTimeoutResult) {
+    condvar
+        .wait_timeout(guard, timeout)
+        .unwrap_or_else(|poisoned| {
+            let (guard, result) = poisoned.into_inner();
+            (reset_poisoned(mutex, guard), result)
+        })
+}
+
+fn reset_poisoned<'a, T: Default>(
+    mutex: &Mutex<T>,
+    mut guard: MutexGuard<'a, T>,
+) -> MutexGuard<'a, T> {
+    warn!(
+        "Resetting {} after a panic poisoned its lock",
+        std::any::type_name::<T>()
+    );
+    mutex.clear_poison();
+    *guard = T::default();
+    guard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn poisoned(value: Vec<u32>) -> Arc<Mutex<Vec<u32>>> {
+        let mutex = Arc::new(Mutex::new(value));
+        let holder = Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let mut guard = holder.lock().unwrap();
+            guard.push(99);
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(mutex.is_poisoned());
+        mutex
+    }
+
+    #[test]
+    fn recover_keeps_the_data_and_clears_the_poison() {
+        let mutex = poisoned(vec![1]);
+
+        assert_eq!(*lock_or_recover(&mutex), vec![1, 99]);
+        assert!(!mutex.is_poisoned());
+        assert!(mutex.lock().is_ok());
+    }
+
+    #[test]
+    fn rwlock_recover_keeps_the_data_and_clears_the_poison() {
+        let lock = Arc::new(RwLock::new(vec![1]));
+        let holder = Arc::clone(&lock);
+        let _ = std::thread::spawn(move || {
+            let mut guard = holder.write().unwrap();
+            guard.push(99);
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(lock.is_poisoned());
+
+        assert_eq!(*read_or_recover(&lock), vec![1, 99]);
+        assert!(!lock.is_poisoned());
+        write_or_recover(&lock).push(2);
+        assert_eq!(*lock.read().unwrap(), vec![1, 99, 2]);
+    }
+
+    #[test]
+    fn reset_starts_over_from_default() {
+        let mutex = poisoned(vec![1]);
+
+        assert!(lock_or_reset(&mutex).is_empty());
+        assert!(!mutex.is_poisoned());
+        lock_or_reset(&mutex).push(2);
+        assert_eq!(*lock_or_reset(&mutex), vec![2]);
+    }
+
+    #[test]
+    fn wait_resets_data_poisoned_while_waiting() {
+        let pair = Arc::new((Mutex::new(vec![1]), Condvar::new()));
+        let mut guard = lock_or_reset(&pair.0);
+        let other = Arc::clone(&pair);
+        let poisoner = std::thread::spawn(move || {
+            let mut guard = other.0.lock().unwrap();
+            guard.push(99);
+            other.1.notify_all();
+            panic!("poison the lock while it is waited on");
+        });
+
+        // A spurious wakeup leaves the data untouched; keep waiting.
+        while *guard == vec![1] {
+            guard = wait_or_reset(&pair.1, &pair.0, guard);
+        }
+
+        assert!(guard.is_empty());
+        drop(guard);
+        assert!(poisoner.join().is_err());
+        assert!(!pair.0.is_poisoned());
+    }
+}
@@ -0,0 +1,49 @@
+//! Crate-wide reset of runtime automation state.
+//!
+//! Cooldowns, trackers and latches live in module statics so the GSI and
+//! keyboard threads can share them. A game that ends mid-fight, or a bug
+//! that wedges one of them, would otherwise keep that state until restart.
+//! Each module owning such a static exposes a `reset()`; [`reset_all_state`]
+//! calls them all. Settings, user toggles (Roshan mode, hero selection) and
+//! session logs are left alone.
+
+use crate::actions::activity::{push_activity, ActivityCategory};
+use crate::actions::heroes::{
+    broodmother, huskar, largo, meepo_macro, meepo_state, outworld_destroyer, shadow_fiend,
+};
+use crate::actions::{
+    armlet, combo_control, common, danger_detector, dispatcher, dispel, item_automation,
+    morph_tracker, position_tracker, soul_ring,
+};
+use crate::observability::rune_alerts;
+use tracing::info;
+
+/// Return every runtime automation static to its startup value.
+///
+/// Called from the UI's reset action and by the GSI loop when the match or
+/// hero changes. State on the dispatcher instance (retrigger cooldowns, hero
+/// scripts' own fields) is cleared by `ActionDispatcher::reset` alongside.
+pub fn reset_all_state() {
+    crate::gsi::handler::reset();
+    danger_detector::reset();
+    position_tracker::reset();
+    morph_tracker::reset();
+    item_automation::reset();
+    dispatcher::reset();
+    combo_control::reset();
+    common::reset();
+    dispel::reset();
+    armlet::reset();
+    soul_ring::reset();
+    huskar::reset();
+    largo::reset();
+    outworld_destroyer::reset();
+    shadow_fiend::reset();
+    broodmother::reset();
+    meepo_state::clear_meepo_observed_state();
+    meepo_macro::clear_meepo_macro_state();
+    crate::input::combo_recorder::reset();
+    rune_alerts::reset();
+    info!("♻️ Automation state reset");
+    push_activity(ActivityCategory::System, "Automation state reset");
+}
@@ -0,0 +1,115 @@
+//! Process-wide shutdown signal.
+//!
+//! Entry points call [`request_shutdown`] when the window closes, Ctrl+C
+//! arrives, or an update is about to relaunch the app. The GSI server stops
+//! through [`wait_for_shutdown`] (axum graceful shutdown), blocking worker
+//! loops poll [`is_shutting_down`] between jobs, and workers parked on a
+//! condvar register a waker with [`on_shutdown`].
+
+use crate::state::poison::lock_or_recover;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+use tokio::sync::Notify;
+use tracing::info;
+
+type ShutdownWaker = Box<dyn Fn() + Send + Sync>;
+
+#[derive(Default)]
+pub struct ShutdownSignal {
+    requested: AtomicBool,
+    notify: Notify,
+    wakers: Mutex<Vec<ShutdownWaker>>,
+}
+
+impl ShutdownSignal {
+    /// Flip the signal and wake every waiter. Returns `false` if shutdown was
+    /// already requested.
+    pub fn request(&self) -> bool {
+        if self.requested.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+        self.notify.notify_waiters();
+        for wake in lock_or_recover(&self.wakers).iter() {
+            wake();
+        }
+        true
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Acquire)
+    }
+
+    /// Resolve once [`ShutdownSignal::request`] has been called.
+    pub async fn wait(&self) {
+        let notified = self.notify.notified();
+        if self.is_requested() {
+            return;
+        }
+        notified.await;
+    }
+
+    /// Run `wake` when shutdown is requested, or right away if it already was.
+    pub fn on_request(&self, wake: impl Fn() + Send + Sync + 'static) {
+        let mut wakers = lock_or_recover(&self.wakers);
+        if self.is_requested() {
+            drop(wakers);
+            wake();
+            return;
+        }
+        wakers.push(Box::new(wake));
+    }
+}
+
+static SHUTDOWN: LazyLock<ShutdownSignal> = LazyLock::new(ShutdownSignal::default);
+
+pub fn request_shutdown() {
+    if SHUTDOWN.request() {
+        info!("Shutdown requested; stopping GSI server and background workers");
+    }
+}
+
+pub fn is_shutting_down() -> bool {
+    SHUTDOWN.is_requested()
+}
+
+pub async fn wait_for_shutdown() {
+    SHUTDOWN.wait().await
+}
+
+pub fn on_shutdown(wake: impl Fn() + Send + Sync + 'static) {
+    SHUTDOWN.on_request(wake)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn request_wakes_async_waiters_and_registered_wakers_once() {
+        let signal = Arc::new(ShutdownSignal::default());
+        let woken = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&woken);
+        signal.on_request(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let waiter = {
+            let signal = Arc::clone(&signal);
+            tokio::spawn(async move { signal.wait().await })
+        };
+        tokio::task::yield_now().await;
+
+        assert!(signal.request());
+        assert!(!signal.request());
+        waiter.await.unwrap();
+        signal.wait().await;
+        assert_eq!(woken.load(Ordering::SeqCst), 1);
+
+        let late = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&late);
+        signal.on_request(move || flag.store(true, Ordering::SeqCst));
+        assert!(late.load(Ordering::SeqCst), "late wakers run immediately");
+    }
+}
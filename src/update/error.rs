@@ -0,0 +1,115 @@
+//! Errors from checking, downloading, applying and rolling back updates.
+
+use crate::config::error::{ConfigError, ErrorCategory};
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UpdateError {
+    /// A request to GitHub failed or returned an error status.
+    #[error("Failed to {action}: {source}")]
+    Network {
+        /// What was being fetched, e.g. `"fetch releases"`.
+        action: &'static str,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Failed to deserialize releases: {0}")]
+    InvalidResponse(#[source] reqwest::Error),
+    #[error("No eligible releases found")]
+    NoEligibleRelease,
+    /// The release has no asset named `name`; `kind` says which one.
+    #[error("Missing {kind} asset: {name}")]
+    MissingAsset { kind: &'static str, name: String },
+    #[error("Failed to compare versions: {0}")]
+    Version(#[source] self_update::errors::Error),
+    #[error("This app still appears to be running from a ZIP-style layout. Install the MSI manually once, then use in-app updates from there.")]
+    NotMsiInstall,
+    #[error("Failed to resolve current exe: {0}")]
+    CurrentExe(#[source] io::Error),
+    #[error("Current exe has no parent directory")]
+    NoExeDir,
+    #[error("Failed to {action} {}: {source}", .path.display())]
+    Io {
+        /// Verb for the message, e.g. `"write"`.
+        action: &'static str,
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Failed to serialize rollback state: {0}")]
+    RollbackState(#[source] serde_json::Error),
+    /// The PowerShell process that installs or restores could not start.
+    #[error("Failed to launch {what} handoff: {source}")]
+    Handoff {
+        what: &'static str,
+        #[source]
+        source: io::Error,
+    },
+    #[error("No previous version is available to roll back to")]
+    NoRollback,
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+impl UpdateError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::Network { .. } => ErrorCategory::Network,
+            Self::InvalidResponse(_) | Self::Version(_) => ErrorCategory::Invalid,
+            Self::NoEligibleRelease | Self::MissingAsset { .. } | Self::NoRollback => {
+                ErrorCategory::NotFound
+            }
+            Self::NotMsiInstall | Self::CurrentExe(_) | Self::NoExeDir => {
+                ErrorCategory::Unsupported
+            }
+            Self::Io { source, .. } | Self::Handoff { source, .. } => {
+                ErrorCategory::from_io(source)
+            }
+            Self::RollbackState(_) => ErrorCategory::Internal,
+            Self::Config(error) => error.category(),
+        }
+    }
+
+    pub fn retryable(&self) -> bool {
+        self.category().retryable()
+    }
+
+    /// `Network` error while doing `action`, for use with `map_err`.
+    pub fn network(action: &'static str) -> impl FnOnce(reqwest::Error) -> Self {
+        move |source| Self::Network { action, source }
+    }
+
+    /// `Io` error for `action` on `path`, for use with `map_err`.
+    pub fn io(action: &'static str, path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io {
+            action,
+            path,
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_network_failures_are_retryable() {
+        let locked = UpdateError::io("write", "update.msi")(io::Error::from(
+            io::ErrorKind::PermissionDenied,
+        ));
+        assert_eq!(locked.category(), ErrorCategory::Permission);
+        assert!(!locked.retryable());
+
+        assert_eq!(
+            UpdateError::NotMsiInstall.category(),
+            ErrorCategory::Unsupported
+        );
+        let config = UpdateError::from(ConfigError::NoLocalAppData);
+        assert_eq!(config.category(), ErrorCategory::Unsupported);
+        assert_eq!(config.to_string(), "LOCALAPPDATA is not set");
+    }
+}
@@ -4,14 +4,23 @@
 //! 1. Check for newer versions on GitHub Releases
 //! 2. Download the latest MSI installer and config template
 //! 3. Launch a silent MSI upgrade handoff that relaunches the app
+//!
+//! The replaced executable is kept so [`rollback`] can restore it, and
+//! [`start_periodic_update_check`] optionally re-checks on an interval.
 
+mod error;
 mod msi;
+mod rollback;
+mod schedule;
 
 use self_update::cargo_crate_version;
 use self_update::version::bump_is_greater;
 use serde::Deserialize;
 use tracing::{error, info, warn};
 
+pub use error::UpdateError;
+pub use schedule::start_periodic_update_check;
+
 use crate::config::storage::{
     bootstrap_live_config, merge_template_with_local, ConfigPaths, EMBEDDED_CONFIG_TEMPLATE,
 };
@@ -36,7 +45,7 @@ pub enum UpdateCheckResult {
     /// Already running the latest version
     UpToDate,
     /// Error occurred during check
-    Error(String),
+    Error(UpdateError),
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,7 +62,7 @@ struct GitHubReleaseAsset {
     browser_download_url: String,
 }
 
-fn fetch_releases() -> Result<Vec<GitHubRelease>, String> {
+fn fetch_releases() -> Result<Vec<GitHubRelease>, UpdateError> {
     let releases_api_url = format!(
         "https://api.github.com/repos/{}/{}/releases",
         REPO_OWNER, REPO_NAME
@@ -64,12 +73,12 @@ fn fetch_releases() -> Result<Vec<GitHubRelease>, String> {
         .header(reqwest::header::USER_AGENT, "dota2-scripts-updater")
         .send()
         .and_then(|response| response.error_for_status())
-        .map_err(|e| format!("Failed to fetch releases: {}", e))?
+        .map_err(UpdateError::network("fetch releases"))?
         .json::<Vec<GitHubRelease>>()
-        .map_err(|e| format!("Failed to deserialize releases: {}", e))
+        .map_err(UpdateError::InvalidResponse)
 }
 
-fn latest_eligible_release(include_prereleases: bool) -> Result<GitHubRelease, String> {
+fn latest_eligible_release(include_prereleases: bool) -> Result<GitHubRelease, UpdateError> {
     let releases = fetch_releases()?;
 
     let filtered_releases = if include_prereleases {
@@ -84,7 +93,7 @@ fn latest_eligible_release(include_prereleases: bool) -> Result<GitHubRelease, S
     filtered_releases
         .into_iter()
         .next()
-        .ok_or_else(|| "No eligible releases found".to_string())
+        .ok_or(UpdateError::NoEligibleRelease)
 }
 
 /// Check for available updates on GitHub Releases.
@@ -103,9 +112,9 @@ pub fn check_for_update(include_prereleases: bool) -> UpdateCheckResult {
 
     let latest = match latest_eligible_release(include_prereleases) {
         Ok(release) => release,
-        Err(msg) => {
-            warn!("{}", msg);
-            return UpdateCheckResult::Error(msg);
+        Err(e) => {
+            warn!("{}", e);
+            return UpdateCheckResult::Error(e);
         }
     };
     let latest_version = latest.tag_name.trim_start_matches('v');
@@ -126,9 +135,9 @@ pub fn check_for_update(include_prereleases: bool) -> UpdateCheckResult {
             UpdateCheckResult::UpToDate
         }
         Err(e) => {
-            let msg = format!("Failed to compare versions: {}", e);
-            warn!("{}", msg);
-            UpdateCheckResult::Error(msg)
+            let e = UpdateError::Version(e);
+            warn!("{}", e);
+            UpdateCheckResult::Error(e)
         }
     }
 }
@@ -150,12 +159,12 @@ pub enum ApplyUpdateResult {
     /// Already up to date
     UpToDate,
     /// Error occurred during update
-    Error(String),
+    Error(UpdateError),
 }
 
-fn is_newer_than_current(tag_name: &str) -> Result<bool, String> {
+fn is_newer_than_current(tag_name: &str) -> Result<bool, UpdateError> {
     bump_is_greater(cargo_crate_version!(), tag_name.trim_start_matches('v'))
-        .map_err(|e| format!("Failed to compare versions: {}", e))
+        .map_err(UpdateError::Version)
 }
 
 /// Download the latest MSI/template assets, merge config, and hand off to msiexec.
@@ -167,30 +176,27 @@ fn is_newer_than_current(tag_name: &str) -> Result<bool, String> {
 pub fn apply_update(include_prereleases: bool) -> ApplyUpdateResult {
     info!("📥 Downloading and applying update...");
 
-    let latest = match latest_eligible_release(include_prereleases) {
-        Ok(release) => release,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
-
-    match is_newer_than_current(&latest.tag_name) {
-        Ok(false) => return ApplyUpdateResult::UpToDate,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-        Ok(true) => {}
-    }
-
-    let current_exe = match std::env::current_exe() {
-        Ok(path) => path,
+    match try_apply_update(include_prereleases) {
+        Ok(Some(new_version)) => ApplyUpdateResult::Success { new_version },
+        Ok(None) => ApplyUpdateResult::UpToDate,
         Err(e) => {
-            let msg = format!("Failed to resolve current exe: {}", e);
-            error!("{}", msg);
-            return ApplyUpdateResult::Error(msg);
+            error!("{}", e);
+            ApplyUpdateResult::Error(e)
         }
-    };
+    }
+}
 
-    if let Err(msg) = msi::ensure_msi_managed_install(&current_exe) {
-        return ApplyUpdateResult::Error(msg);
+/// [`apply_update`]; `Ok(None)` when already up to date, else the version
+/// being installed.
+fn try_apply_update(include_prereleases: bool) -> Result<Option<String>, UpdateError> {
+    let latest = latest_eligible_release(include_prereleases)?;
+    if !is_newer_than_current(&latest.tag_name)? {
+        return Ok(None);
     }
 
+    let current_exe = std::env::current_exe().map_err(UpdateError::CurrentExe)?;
+    msi::ensure_msi_managed_install(&current_exe)?;
+
     let assets = latest
         .assets
         .iter()
@@ -200,31 +206,15 @@ pub fn apply_update(include_prereleases: bool) -> ApplyUpdateResult {
         })
         .collect::<Vec<_>>();
     let install_assets =
-        match msi::select_release_assets(&latest.tag_name, "x86_64-pc-windows-msvc", &assets) {
-            Ok(assets) => assets,
-            Err(msg) => return ApplyUpdateResult::Error(msg),
-        };
+        msi::select_release_assets(&latest.tag_name, "x86_64-pc-windows-msvc", &assets)?;
 
-    let template_contents = match reqwest::blocking::get(&install_assets.template_url)
+    let template_contents = reqwest::blocking::get(&install_assets.template_url)
         .and_then(|response| response.error_for_status())
         .and_then(|response| response.text())
-    {
-        Ok(contents) => contents,
-        Err(e) => {
-            let msg = format!("Failed to download config template: {}", e);
-            error!("{}", msg);
-            return ApplyUpdateResult::Error(msg);
-        }
-    };
+        .map_err(UpdateError::network("download config template"))?;
 
-    let paths = match ConfigPaths::detect() {
-        Ok(paths) => paths,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
-    let live_config_path = match bootstrap_live_config(&paths, EMBEDDED_CONFIG_TEMPLATE) {
-        Ok(path) => path,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
+    let paths = ConfigPaths::detect()?;
+    let live_config_path = bootstrap_live_config(&paths, EMBEDDED_CONFIG_TEMPLATE)?;
     let error_log_path = live_config_path
         .parent()
         .and_then(|config_dir| config_dir.parent())
@@ -235,45 +225,62 @@ pub fn apply_update(include_prereleases: bool) -> ApplyUpdateResult {
                 .join("logs")
                 .join("update-error.log")
         });
-    let local_contents = match std::fs::read_to_string(&live_config_path) {
-        Ok(contents) => contents,
-        Err(e) => {
-            let msg = format!("Failed to read live config {}: {}", live_config_path.display(), e);
-            error!("{}", msg);
-            return ApplyUpdateResult::Error(msg);
-        }
-    };
-    let merged_contents = match merge_template_with_local(&template_contents, &local_contents) {
-        Ok(contents) => contents,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
+    let local_contents = std::fs::read_to_string(&live_config_path)
+        .map_err(UpdateError::io("read live config", &live_config_path))?;
+    let merged_contents = merge_template_with_local(&template_contents, &local_contents)?;
 
-    let msi_path = match msi::download_to_temp(&install_assets.msi_url, "msi") {
-        Ok(path) => path,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
-    let staged_config_path = match msi::write_temp_contents(&merged_contents, "toml") {
-        Ok(path) => path,
-        Err(msg) => return ApplyUpdateResult::Error(msg),
-    };
-    if let Err(msg) = msi::launch_msi_handoff(
+    match rollback::backup_current_exe(&paths.app_data_dir(), &current_exe, cargo_crate_version!()) {
+        Ok(state) => info!(
+            "💾 Backed up v{} to {} for rollback",
+            state.last_known_good_version,
+            state.backup_exe.display()
+        ),
+        Err(e) => warn!("{}; rollback will not be available for this update", e),
+    }
+
+    let msi_path = msi::download_to_temp(&install_assets.msi_url, "msi")?;
+    let staged_config_path = msi::write_temp_contents(&merged_contents, "toml")?;
+    msi::launch_msi_handoff(
         std::process::id(),
         &msi_path,
         &staged_config_path,
         &live_config_path,
         &error_log_path,
         &current_exe,
-    ) {
-        error!("{}", msg);
-        return ApplyUpdateResult::Error(msg);
-    }
+    )?;
 
     info!(
         "✅ Launched MSI update handoff for {} using {}",
         latest.tag_name,
         msi_path.display()
     );
-    ApplyUpdateResult::Success {
-        new_version: latest.tag_name.trim_start_matches('v').to_string(),
-    }
+    Ok(Some(latest.tag_name.trim_start_matches('v').to_string()))
+}
+
+/// Version the last in-app update replaced, if its backup can be restored.
+pub fn rollback_available() -> Option<String> {
+    let paths = ConfigPaths::detect().ok()?;
+    rollback::available_rollback(&paths.app_data_dir(), cargo_crate_version!())
+        .map(|state| state.last_known_good_version)
+}
+
+/// Restore the executable backed up by the last in-app update.
+///
+/// Launches a handoff that waits for this process to exit, copies the backup
+/// over the installed exe, and relaunches it. On success the caller must
+/// exit. Returns the version being restored.
+pub fn rollback() -> Result<String, UpdateError> {
+    let paths = ConfigPaths::detect()?;
+    let app_data_dir = paths.app_data_dir();
+    let state = rollback::available_rollback(&app_data_dir, cargo_crate_version!())
+        .ok_or(UpdateError::NoRollback)?;
+    let current_exe = std::env::current_exe().map_err(UpdateError::CurrentExe)?;
+    let error_log_path = app_data_dir.join("logs").join("update-error.log");
+
+    rollback::launch_rollback_handoff(&state, &current_exe, &error_log_path)?;
+    info!(
+        "⏪ Launched rollback handoff to v{}",
+        state.last_known_good_version
+    );
+    Ok(state.last_known_good_version)
 }
@@ -1,3 +1,4 @@
+use super::error::UpdateError;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
@@ -18,7 +19,7 @@ pub fn select_release_assets(
     version_tag: &str,
     target: &str,
     assets: &[ReleaseAssetRef<'_>],
-) -> Result<ReleaseInstallAssets, String> {
+) -> Result<ReleaseInstallAssets, UpdateError> {
     let msi_name = format!("dota2-scripts-{version_tag}-{target}.msi");
     let template_name = format!("dota2-scripts-{version_tag}-config.template.toml");
 
@@ -26,12 +27,18 @@ pub fn select_release_assets(
         .iter()
         .find(|asset| asset.name == msi_name)
         .map(|asset| asset.download_url.to_string())
-        .ok_or_else(|| format!("Missing MSI asset: {msi_name}"))?;
+        .ok_or_else(|| UpdateError::MissingAsset {
+            kind: "MSI",
+            name: msi_name.clone(),
+        })?;
     let template_url = assets
         .iter()
         .find(|asset| asset.name == template_name)
         .map(|asset| asset.download_url.to_string())
-        .ok_or_else(|| format!("Missing config template asset: {template_name}"))?;
+        .ok_or_else(|| UpdateError::MissingAsset {
+            kind: "config template",
+            name: template_name.clone(),
+        })?;
 
     Ok(ReleaseInstallAssets {
         msi_url,
@@ -77,51 +84,47 @@ pub fn build_msi_handoff_script(
     )
 }
 
-pub fn ensure_msi_managed_install(current_exe: &Path) -> Result<(), String> {
+pub fn ensure_msi_managed_install(current_exe: &Path) -> Result<(), UpdateError> {
     let install_dir = current_exe
         .parent()
-        .ok_or_else(|| "Current exe has no parent directory".to_string())?
+        .ok_or(UpdateError::NoExeDir)?
         .to_path_buf();
     let zip_style_config = install_dir
         .join("config")
         .join("config.toml");
 
     if zip_style_config.exists() {
-        return Err(
-            "This app still appears to be running from a ZIP-style layout. Install the MSI manually once, then use in-app updates from there."
-                .to_string(),
-        );
+        return Err(UpdateError::NotMsiInstall);
     }
 
     Ok(())
 }
 
-pub fn download_to_temp(url: &str, extension: &str) -> Result<PathBuf, String> {
+pub fn download_to_temp(url: &str, extension: &str) -> Result<PathBuf, UpdateError> {
     let response = reqwest::blocking::get(url)
         .and_then(|response| response.error_for_status())
-        .map_err(|e| format!("Download failed: {e}"))?;
+        .map_err(UpdateError::network("download the update"))?;
     let bytes = response
         .bytes()
-        .map_err(|e| format!("Failed to read download bytes: {e}"))?;
+        .map_err(UpdateError::network("read download bytes"))?;
 
     let path = std::env::temp_dir().join(format!(
         "dota2-scripts-update-{}.{}",
         rand::random::<u64>(),
         extension
     ));
-    std::fs::write(&path, &bytes).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    std::fs::write(&path, &bytes).map_err(UpdateError::io("write", &path))?;
 
     Ok(path)
 }
 
-pub fn write_temp_contents(contents: &str, extension: &str) -> Result<PathBuf, String> {
+pub fn write_temp_contents(contents: &str, extension: &str) -> Result<PathBuf, UpdateError> {
     let path = std::env::temp_dir().join(format!(
         "dota2-scripts-update-{}.{}",
         rand::random::<u64>(),
         extension
     ));
-    std::fs::write(&path, contents.as_bytes())
-        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    std::fs::write(&path, contents.as_bytes()).map_err(UpdateError::io("write", &path))?;
 
     Ok(path)
 }
@@ -133,7 +136,7 @@ pub fn launch_msi_handoff(
     live_config_path: &Path,
     error_log_path: &Path,
     relaunch_exe: &Path,
-) -> Result<(), String> {
+) -> Result<(), UpdateError> {
     let script = build_msi_handoff_script(
         current_pid,
         msi_path,
@@ -146,7 +149,10 @@ pub fn launch_msi_handoff(
     Command::new("powershell.exe")
         .args(["-NoProfile", "-WindowStyle", "Hidden", "-Command", &script])
         .spawn()
-        .map_err(|e| format!("Failed to launch MSI handoff: {e}"))?;
+        .map_err(|source| UpdateError::Handoff {
+            what: "MSI",
+            source,
+        })?;
 
     Ok(())
 }
@@ -210,7 +216,8 @@ mod tests {
 
         let error = ensure_msi_managed_install(&install_dir.join("dota2-scripts.exe")).unwrap_err();
 
-        assert!(error.contains("Install the MSI manually once"));
+        assert!(matches!(error, UpdateError::NotMsiInstall));
+        assert!(error.to_string().contains("Install the MSI manually once"));
     }
 
     #[test]
@@ -0,0 +1,162 @@
+//! Keep the replaced executable so a bad in-app update can be undone.
+//!
+//! Before the MSI handoff, [`backup_current_exe`] copies the running exe to
+//! `%LOCALAPPDATA%\dota2-scripts\backup\` and records it, with its version as
+//! the last-known-good version, in `rollback-state.json` next to it.
+//! [`rollback`] then restores that copy over the installed exe through a
+//! hidden PowerShell handoff once this process has exited.
+
+use super::error::UpdateError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const STATE_FILE_NAME: &str = "rollback-state.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollbackState {
+    /// Version that was running before the last in-app update.
+    pub last_known_good_version: String,
+    pub backup_exe: PathBuf,
+}
+
+fn backup_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("backup")
+}
+
+fn state_path(app_data_dir: &Path) -> PathBuf {
+    backup_dir(app_data_dir).join(STATE_FILE_NAME)
+}
+
+/// Copy `current_exe` into the backup folder and record it as last-known-good.
+///
+/// Only one backup is kept; an older one is replaced.
+pub fn backup_current_exe(
+    app_data_dir: &Path,
+    current_exe: &Path,
+    version: &str,
+) -> Result<RollbackState, UpdateError> {
+    let dir = backup_dir(app_data_dir);
+    std::fs::create_dir_all(&dir).map_err(UpdateError::io("create", &dir))?;
+
+    if let Some(previous) = read_state(app_data_dir) {
+        let _ = std::fs::remove_file(previous.backup_exe);
+    }
+
+    let backup_exe = dir.join(format!("dota2-scripts-v{version}.exe"));
+    std::fs::copy(current_exe, &backup_exe).map_err(UpdateError::io("back up to", &backup_exe))?;
+
+    let state = RollbackState {
+        last_known_good_version: version.to_string(),
+        backup_exe,
+    };
+    let contents = serde_json::to_string_pretty(&state).map_err(UpdateError::RollbackState)?;
+    let path = state_path(app_data_dir);
+    std::fs::write(&path, contents).map_err(UpdateError::io("write", &path))?;
+
+    Ok(state)
+}
+
+pub fn read_state(app_data_dir: &Path) -> Option<RollbackState> {
+    let contents = std::fs::read_to_string(state_path(app_data_dir)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// The recorded backup, if its exe still exists and is not the running version.
+pub fn available_rollback(app_data_dir: &Path, current_version: &str) -> Option<RollbackState> {
+    read_state(app_data_dir).filter(|state| {
+        state.last_known_good_version != current_version && state.backup_exe.is_file()
+    })
+}
+
+pub fn build_rollback_script(
+    current_pid: u32,
+    backup_exe: &Path,
+    installed_exe: &Path,
+    error_log_path: &Path,
+) -> String {
+    let backup = ps_quote(backup_exe);
+    let installed = ps_quote(installed_exe);
+    let error_log = ps_quote(error_log_path);
+
+    format!(
+        "$ErrorActionPreference = 'Stop'; \
+         $errorLogDir = Split-Path -Parent {error_log}; \
+         New-Item -ItemType Directory -Force -Path $errorLogDir | Out-Null; \
+         Remove-Item -Path {error_log} -ErrorAction SilentlyContinue; \
+         try {{ \
+           Wait-Process -Id {current_pid} -ErrorAction SilentlyContinue; \
+           Copy-Item -Path {backup} -Destination {installed} -Force; \
+           Start-Process {installed}; \
+         }} catch {{ \
+           Set-Content -Path {error_log} -Value $_; \
+           exit 1; \
+         }}",
+    )
+}
+
+/// Launch the restore handoff. The caller must exit so the exe can be replaced.
+pub fn launch_rollback_handoff(
+    state: &RollbackState,
+    installed_exe: &Path,
+    error_log_path: &Path,
+) -> Result<(), UpdateError> {
+    let script = build_rollback_script(
+        std::process::id(),
+        &state.backup_exe,
+        installed_exe,
+        error_log_path,
+    );
+
+    Command::new("powershell.exe")
+        .args(["-NoProfile", "-WindowStyle", "Hidden", "-Command", &script])
+        .spawn()
+        .map_err(|source| UpdateError::Handoff {
+            what: "rollback",
+            source,
+        })?;
+
+    Ok(())
+}
+
+fn ps_quote(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_is_offered_only_after_the_version_changes() {
+        let temp = tempfile::tempdir().unwrap();
+        let app_dir = temp.path().join("dota2-scripts");
+        let exe = temp.path().join("dota2-scripts.exe");
+        std::fs::write(&exe, b"old build").unwrap();
+
+        let state = backup_current_exe(&app_dir, &exe, "0.15.0").unwrap();
+        assert_eq!(std::fs::read(&state.backup_exe).unwrap(), b"old build");
+        assert_eq!(read_state(&app_dir), Some(state.clone()));
+
+        assert_eq!(available_rollback(&app_dir, "0.15.0"), None);
+        assert_eq!(available_rollback(&app_dir, "0.16.0"), Some(state.clone()));
+
+        std::fs::remove_file(&state.backup_exe).unwrap();
+        assert_eq!(available_rollback(&app_dir, "0.16.0"), None);
+    }
+
+    #[test]
+    fn rollback_script_waits_for_exit_then_restores_and_relaunches() {
+        let script = build_rollback_script(
+            4242,
+            Path::new(r"C:\Users\pc\AppData\Local\dota2-scripts\backup\dota2-scripts-v0.15.0.exe"),
+            Path::new(r"C:\Program Files\dota2-scripts\dota2-scripts.exe"),
+            Path::new(r"C:\Users\pc\AppData\Local\dota2-scripts\logs\update-error.log"),
+        );
+
+        let wait = script.find("Wait-Process -Id 4242").unwrap();
+        let copy = script.find("Copy-Item").unwrap();
+        let relaunch = script.find("Start-Process").unwrap();
+        assert!(wait < copy && copy < relaunch);
+    }
+}
@@ -0,0 +1,113 @@
+//! Optional periodic update check.
+//!
+//! When `updates.check_interval_minutes` is non-zero, a background thread
+//! re-runs [`check_for_update`] on that interval and publishes a found
+//! release to the shared [`UpdateCheckState`] so the UI banner appears. It
+//! never applies an update; the user still has to choose "Update now".
+
+use crate::state::poison::lock_or_recover;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+use super::{check_for_update, UpdateCheckResult};
+use crate::config::Settings;
+use crate::state::shutdown::is_shutting_down;
+use crate::state::UpdateCheckState;
+
+/// How often the thread wakes to re-read the interval and the shutdown flag.
+const TICK: Duration = Duration::from_secs(1);
+
+/// State to publish after a background check, or `None` to leave it alone.
+///
+/// Background checks stay quiet: failures and "up to date" results are not
+/// surfaced, and a manual check, download, or already-shown banner is never
+/// overwritten.
+pub fn periodic_state_after(
+    current: &UpdateCheckState,
+    result: UpdateCheckResult,
+) -> Option<UpdateCheckState> {
+    match (current, result) {
+        (
+            UpdateCheckState::Idle | UpdateCheckState::UpToDate | UpdateCheckState::Error { .. },
+            UpdateCheckResult::Available(info),
+        ) => Some(UpdateCheckState::Available {
+            version: info.version,
+            release_notes: info.release_notes,
+        }),
+        _ => None,
+    }
+}
+
+/// Spawn the periodic check thread. It idles while the interval is 0 and
+/// exits on shutdown.
+pub fn start_periodic_update_check(
+    settings: Arc<Mutex<Settings>>,
+    update_state: Arc<Mutex<UpdateCheckState>>,
+) {
+    std::thread::spawn(move || {
+        let mut last_check = Instant::now();
+        while !is_shutting_down() {
+            std::thread::sleep(TICK);
+
+            let (interval_minutes, include_prereleases) = {
+                let settings = lock_or_recover(&settings);
+                (
+                    settings.updates.check_interval_minutes,
+                    settings.updates.include_prereleases,
+                )
+            };
+            if interval_minutes == 0 {
+                last_check = Instant::now();
+                continue;
+            }
+            if last_check.elapsed() < Duration::from_secs(u64::from(interval_minutes) * 60) {
+                continue;
+            }
+            last_check = Instant::now();
+
+            debug!("Running periodic update check");
+            let result = check_for_update(include_prereleases);
+            let mut state = lock_or_recover(&update_state);
+            if let Some(next) = periodic_state_after(&state, result) {
+                if let UpdateCheckState::Available { version, .. } = &next {
+                    info!("🔔 Periodic check found update v{}", version);
+                }
+                *state = next;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::{UpdateError, UpdateInfo};
+
+    fn available(version: &str) -> UpdateCheckResult {
+        UpdateCheckResult::Available(UpdateInfo {
+            version: version.to_string(),
+            release_notes: None,
+        })
+    }
+
+    #[test]
+    fn background_check_only_surfaces_new_releases_over_settled_states() {
+        let shown = periodic_state_after(&UpdateCheckState::UpToDate, available("0.16.0"));
+        assert!(matches!(
+            shown,
+            Some(UpdateCheckState::Available { ref version, .. }) if version == "0.16.0"
+        ));
+        assert!(periodic_state_after(&UpdateCheckState::Idle, available("0.16.0")).is_some());
+
+        for busy in [UpdateCheckState::Checking, UpdateCheckState::Downloading] {
+            assert!(periodic_state_after(&busy, available("0.16.0")).is_none());
+        }
+        assert!(periodic_state_after(
+            &UpdateCheckState::Idle,
+            UpdateCheckResult::Error(UpdateError::NoEligibleRelease)
+        )
+        .is_none());
+        assert!(periodic_state_after(&UpdateCheckState::Idle, UpdateCheckResult::UpToDate).is_none());
+    }
+}
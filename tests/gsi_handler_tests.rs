@@ -55,6 +55,25 @@ async fn test_healing_item_detection() {
     assert_eq!(event.items.slot2.charges, Some(15));
 }
 
+#[tokio::test]
+async fn test_load_minimal_items_fixture() {
+    let json_data = fs::read_to_string("tests/fixtures/minimal_items_event.json")
+        .expect("Failed to read minimal items fixture");
+
+    let event: GsiWebhookEvent =
+        serde_json::from_str(&json_data).expect("Failed to deserialize minimal items event");
+
+    // Only slot0-5 are present; stash, neutral and teleport slots default to empty
+    assert_eq!(event.items.slot1.name, "item_armlet");
+    assert_eq!(event.items.stash0.name, "empty");
+    assert_eq!(event.items.teleport0.name, "empty");
+    assert_eq!(event.items.neutral0.name, "empty");
+
+    // Charges sent as `item_charges` instead of `charges`
+    assert_eq!(event.items.slot2.charges, None);
+    assert_eq!(event.items.slot2.charge_count(), Some(15));
+}
+
 #[tokio::test]
 async fn test_load_outworld_destroyer_fixture() {
     let json_data = fs::read_to_string("tests/fixtures/outworld_destroyer_event.json")
@@ -0,0 +1,142 @@
+use dota2_scripts::actions::executor::ActionExecutor;
+use dota2_scripts::actions::ActionDispatcher;
+use dota2_scripts::config::error::ErrorCategory;
+use dota2_scripts::config::Settings;
+use dota2_scripts::gsi::server::{next_free_port, rebind_gsi_server, serve_gsi};
+use dota2_scripts::state::AppState;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+async fn spawn_gsi_server() -> (String, Arc<Mutex<AppState>>) {
+    let app_state = AppState::new();
+    // Keep hero scripts out of the request path; only routing is under test.
+    app_state.lock().unwrap().gsi_enabled = false;
+
+    let settings = Arc::new(Mutex::new(Settings::default()));
+    let dispatcher = Arc::new(ActionDispatcher::new(
+        settings.clone(),
+        ActionExecutor::new(),
+    ));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind ephemeral port");
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    tokio::spawn(serve_gsi(listener, app_state.clone(), dispatcher, settings));
+
+    (url, app_state)
+}
+
+async fn post_json(url: &str, body: String) -> reqwest::StatusCode {
+    reqwest::Client::new()
+        .post(url)
+        .header("content-type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .expect("POST to GSI server failed")
+        .status()
+}
+
+#[tokio::test]
+async fn test_post_fixture_updates_app_state() {
+    let (url, app_state) = spawn_gsi_server().await;
+    let json_data = fs::read_to_string("tests/fixtures/huskar_event.json")
+        .expect("Failed to read huskar fixture");
+
+    assert_eq!(post_json(&url, json_data).await, reqwest::StatusCode::OK);
+
+    // The handler only enqueues; wait for the processor to drain the event.
+    let mut processed = false;
+    for _ in 0..100 {
+        {
+            let state = app_state.lock().unwrap();
+            if state.metrics.events_processed == 1 {
+                let last_event = state.last_event.as_ref().expect("last_event should be set");
+                assert_eq!(last_event.hero.name, "npc_dota_hero_huskar");
+                processed = true;
+            }
+        }
+        if processed {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(processed, "event never reached AppState");
+}
+
+#[tokio::test]
+async fn test_post_malformed_json_is_rejected() {
+    let (url, app_state) = spawn_gsi_server().await;
+
+    let status = post_json(&url, "{\"hero\": {\"name\": ".to_string()).await;
+
+    assert!(status.is_client_error(), "unexpected status {}", status);
+    assert_eq!(app_state.lock().unwrap().metrics.events_processed, 0);
+    assert!(app_state.lock().unwrap().last_event.is_none());
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_reports_queue_and_automation_counters() {
+    let (url, app_state) = spawn_gsi_server().await;
+    app_state.lock().unwrap().metrics.events_processed = 7;
+
+    let response = reqwest::get(format!("{}metrics", url))
+        .await
+        .expect("GET /metrics failed");
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("metrics body is JSON");
+    assert_eq!(body["queue"]["events_processed"], 7);
+    assert!(body["automation"]["combos_triggered"].is_object());
+    assert!(body["automation"]["armlet_toggles"].is_u64());
+}
+
+#[tokio::test]
+async fn test_rebind_to_a_port_in_use_is_refused_with_a_clear_error() {
+    let taken = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind ephemeral port");
+    let addr = taken.local_addr().unwrap();
+
+    let error = rebind_gsi_server(addr)
+        .await
+        .expect_err("rebinding onto a taken port should fail");
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Port {} is already in use. Is another instance of the app running?",
+            addr.port()
+        )
+    );
+    assert_eq!(error.category(), ErrorCategory::Conflict);
+}
+
+#[tokio::test]
+async fn test_rebind_again_to_the_pending_address_is_a_no_op() {
+    let free = std::net::TcpListener::bind("127.0.0.1:0").expect("Failed to bind ephemeral port");
+    let addr = free.local_addr().unwrap();
+    drop(free);
+
+    rebind_gsi_server(addr)
+        .await
+        .expect("rebinding onto a free port should succeed");
+    rebind_gsi_server(addr)
+        .await
+        .expect("asking again for the address being moved to should not bind it twice");
+}
+
+#[tokio::test]
+async fn test_next_free_port_skips_the_taken_one() {
+    let taken = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind ephemeral port");
+    let addr = taken.local_addr().unwrap();
+
+    let suggested = next_free_port(addr.ip(), addr.port()).expect("a nearby port should be free");
+
+    assert!(suggested > addr.port());
+    assert!(std::net::TcpListener::bind((addr.ip(), suggested)).is_ok());
+}